@@ -2,24 +2,32 @@
 //!
 //! This module defines the [`Behavior`] trait, which is the fundamental
 //! abstraction for all behavior tree nodes. The trait is generic over a
-//! context type `C`, allowing nodes to access game state and make decisions.
+//! blackboard type `C` that composites and decorators thread through the
+//! tree, so nodes share state through the context instead of captures.
 
 use crate::Status;
 
+/// Blackboard for trees that need no shared context.
+pub type Blackboard = ();
+
 /// A behavior tree node that can be evaluated against a context.
 pub trait Behavior<C>: Send + Sync {
     /// Evaluate this behavior node against the given context.
     ///
+    /// Nodes take `&mut self` so they can keep their own bookkeeping (e.g. a
+    /// cooldown timestamp) between ticks.
+    ///
     /// # Arguments
     ///
     /// * `ctx` - Mutable reference to the context/blackboard. Nodes can read
-    ///   game state and modify it (e.g., to store intermediate results).
+    ///   game state and modify it (e.g., to store intermediate results for
+    ///   later siblings).
     ///
     /// # Returns
     ///
     /// - `Status::Success` if the behavior succeeded
     /// - `Status::Failure` if the behavior failed
-    fn tick(&self, ctx: &mut C) -> Status;
+    fn tick(&mut self, ctx: &mut C) -> Status;
 }
 
 /// Blanket implementation for boxed behaviors.
@@ -28,7 +36,71 @@ pub trait Behavior<C>: Send + Sync {
 /// enabling dynamic dispatch and heterogeneous collections of nodes.
 impl<C> Behavior<C> for Box<dyn Behavior<C>> {
     #[inline]
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         (**self).tick(ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{inverter, sequence};
+
+    /// A caller-defined blackboard, as the runtime AI would pass.
+    struct Scratch {
+        target: Option<u32>,
+        attacks: u32,
+    }
+
+    struct PickTarget;
+    impl Behavior<Scratch> for PickTarget {
+        fn tick(&mut self, ctx: &mut Scratch) -> Status {
+            ctx.target = Some(7);
+            Status::Success
+        }
+    }
+
+    struct AttackTarget;
+    impl Behavior<Scratch> for AttackTarget {
+        fn tick(&mut self, ctx: &mut Scratch) -> Status {
+            match ctx.target {
+                Some(_) => {
+                    ctx.attacks += 1;
+                    Status::Success
+                }
+                None => Status::Failure,
+            }
+        }
+    }
+
+    struct Succeed;
+    impl Behavior<Blackboard> for Succeed {
+        fn tick(&mut self, _ctx: &mut Blackboard) -> Status {
+            Status::Success
+        }
+    }
+
+    #[test]
+    fn blackboard_writes_are_visible_to_later_siblings() {
+        let mut tree = sequence(vec![Box::new(PickTarget), Box::new(AttackTarget)]);
+
+        let mut ctx = Scratch {
+            target: None,
+            attacks: 0,
+        };
+        assert_eq!(tree.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.target, Some(7));
+        assert_eq!(ctx.attacks, 1);
+
+        // Without the write, the sibling sees an empty blackboard
+        let mut tree = sequence(vec![Box::new(AttackTarget)]);
+        ctx.target = None;
+        assert_eq!(tree.tick(&mut ctx), Status::Failure);
+    }
+
+    #[test]
+    fn unit_blackboard_needs_no_context() {
+        let mut tree = inverter::<Blackboard>(Box::new(Succeed));
+        assert_eq!(tree.tick(&mut ()), Status::Failure);
+    }
+}
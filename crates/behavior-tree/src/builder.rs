@@ -3,8 +3,17 @@
 //! This module provides helper functions to reduce boilerplate when building
 //! behavior trees. Instead of writing verbose `Box::new(Sequence::new(vec![...]))`,
 //! you can use shorter functions like `sequence(vec![...])`.
+//!
+//! Trees can also be described as data with [`TreeDef`] (serializable with the
+//! `serde` feature) and built against a [`LeafRegistry`] of named leaves.
+
+use std::collections::BTreeMap;
+use std::fmt;
 
-use crate::{AlwaysSucceed, Behavior, Inverter, Selector, Sequence};
+use crate::{
+    AlwaysFail, AlwaysSucceed, Behavior, Cooldown, CurrentTick, Inverter, RepeatUntil, Selector,
+    Sequence,
+};
 
 /// Creates a sequence node.
 ///
@@ -37,3 +46,240 @@ pub fn inverter<C: 'static>(child: Box<dyn Behavior<C>>) -> Box<dyn Behavior<C>>
 pub fn always_succeed<C: 'static>(child: Box<dyn Behavior<C>>) -> Box<dyn Behavior<C>> {
     Box::new(AlwaysSucceed::new(child))
 }
+
+/// Creates an always-fail node.
+///
+/// Shorthand for `Box::new(AlwaysFail::new(child))`.
+#[inline]
+pub fn always_fail<C: 'static>(child: Box<dyn Behavior<C>>) -> Box<dyn Behavior<C>> {
+    Box::new(AlwaysFail::new(child))
+}
+
+/// Creates a repeat-until node that retries its child up to `max_attempts` times.
+///
+/// Shorthand for `Box::new(RepeatUntil::new(child, max_attempts))`.
+#[inline]
+pub fn repeat_until<C: 'static>(
+    child: Box<dyn Behavior<C>>,
+    max_attempts: usize,
+) -> Box<dyn Behavior<C>> {
+    Box::new(RepeatUntil::new(child, max_attempts))
+}
+
+/// Creates a cooldown node that skips its child for `duration` ticks after a success.
+///
+/// Shorthand for `Box::new(Cooldown::new(child, duration))`.
+#[inline]
+pub fn cooldown<C: CurrentTick + 'static>(
+    child: Box<dyn Behavior<C>>,
+    duration: u64,
+) -> Box<dyn Behavior<C>> {
+    Box::new(Cooldown::new(child, duration))
+}
+
+/// Constructs a fresh leaf behavior each time a tree is built.
+type LeafFactory<C> = Box<dyn Fn() -> Box<dyn Behavior<C>> + Send + Sync>;
+
+/// Serializable description of a behavior tree.
+///
+/// Content files name their leaves; [`TreeDef::build`] resolves the names
+/// against a [`LeafRegistry`] supplied by the game.
+///
+/// ```text
+/// Selector([
+///     Sequence([Leaf("enemy_adjacent"), Leaf("attack")]),
+///     Leaf("wander"),
+/// ])
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TreeDef {
+    Sequence(Vec<TreeDef>),
+    Selector(Vec<TreeDef>),
+    Inverter(Box<TreeDef>),
+    AlwaysSucceed(Box<TreeDef>),
+    /// A leaf registered under this name.
+    Leaf(String),
+}
+
+impl TreeDef {
+    /// Builds the described tree, resolving leaves against `leaves`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownLeaf`] for a leaf name missing from the
+    /// registry and [`BuildError::EmptyComposite`] for a sequence or selector
+    /// without children.
+    pub fn build<C: 'static>(
+        &self,
+        leaves: &LeafRegistry<C>,
+    ) -> Result<Box<dyn Behavior<C>>, BuildError> {
+        Ok(match self {
+            Self::Sequence(children) => {
+                sequence(Self::build_children(children, "Sequence", leaves)?)
+            }
+            Self::Selector(children) => {
+                selector(Self::build_children(children, "Selector", leaves)?)
+            }
+            Self::Inverter(child) => inverter(child.build(leaves)?),
+            Self::AlwaysSucceed(child) => always_succeed(child.build(leaves)?),
+            Self::Leaf(name) => leaves
+                .create(name)
+                .ok_or_else(|| BuildError::UnknownLeaf(name.clone()))?,
+        })
+    }
+
+    fn build_children<C: 'static>(
+        children: &[TreeDef],
+        kind: &'static str,
+        leaves: &LeafRegistry<C>,
+    ) -> Result<Vec<Box<dyn Behavior<C>>>, BuildError> {
+        if children.is_empty() {
+            return Err(BuildError::EmptyComposite(kind));
+        }
+        children.iter().map(|child| child.build(leaves)).collect()
+    }
+}
+
+/// Named leaf constructors that [`TreeDef::build`] resolves against.
+pub struct LeafRegistry<C> {
+    leaves: BTreeMap<String, LeafFactory<C>>,
+}
+
+impl<C> LeafRegistry<C> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            leaves: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `factory` under `name`, replacing any previous leaf.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn() -> Box<dyn Behavior<C>> + Send + Sync + 'static,
+    ) {
+        self.leaves.insert(name.into(), Box::new(factory));
+    }
+
+    /// Constructs the leaf registered under `name`.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Behavior<C>>> {
+        self.leaves.get(name).map(|factory| factory())
+    }
+}
+
+impl<C> Default for LeafRegistry<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors raised while building a tree from a [`TreeDef`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// No leaf is registered under this name.
+    UnknownLeaf(String),
+    /// A `Sequence` or `Selector` has no children.
+    EmptyComposite(&'static str),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLeaf(name) => write!(f, "unknown behavior tree leaf '{}'", name),
+            Self::EmptyComposite(kind) => write!(f, "{} must have at least one child", kind),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Status;
+
+    struct Counter {
+        value: i32,
+    }
+
+    struct Increment;
+    impl Behavior<Counter> for Increment {
+        fn tick(&mut self, ctx: &mut Counter) -> Status {
+            ctx.value += 1;
+            Status::Success
+        }
+    }
+
+    struct Fail;
+    impl Behavior<Counter> for Fail {
+        fn tick(&mut self, _ctx: &mut Counter) -> Status {
+            Status::Failure
+        }
+    }
+
+    fn leaves() -> LeafRegistry<Counter> {
+        let mut leaves = LeafRegistry::new();
+        leaves.register("increment", || Box::new(Increment));
+        leaves.register("fail", || Box::new(Fail));
+        leaves
+    }
+
+    fn leaf(name: &str) -> TreeDef {
+        TreeDef::Leaf(name.to_string())
+    }
+
+    fn sample() -> TreeDef {
+        TreeDef::Selector(vec![
+            TreeDef::Sequence(vec![leaf("increment"), leaf("fail")]),
+            TreeDef::Inverter(Box::new(leaf("fail"))),
+            TreeDef::AlwaysSucceed(Box::new(leaf("increment"))),
+        ])
+    }
+
+    #[test]
+    fn builds_tree_from_registered_leaves() {
+        let mut tree = sample().build(&leaves()).unwrap();
+
+        let mut ctx = Counter { value: 0 };
+        assert_eq!(tree.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.value, 1); // Selector stopped at the inverter
+    }
+
+    #[test]
+    fn build_rejects_unknown_leaf() {
+        let def = TreeDef::Sequence(vec![leaf("increment"), leaf("flee")]);
+
+        let error = def.build(&leaves()).err().unwrap();
+        assert_eq!(error, BuildError::UnknownLeaf("flee".to_string()));
+        assert_eq!(error.to_string(), "unknown behavior tree leaf 'flee'");
+    }
+
+    #[test]
+    fn build_rejects_empty_composite() {
+        let def = TreeDef::Inverter(Box::new(TreeDef::Selector(vec![])));
+
+        assert_eq!(
+            def.build(&leaves()).err(),
+            Some(BuildError::EmptyComposite("Selector"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn tree_def_round_trips_through_ron() {
+        let ron = ron::to_string(&sample()).unwrap();
+        assert_eq!(ron::from_str::<TreeDef>(&ron).unwrap(), sample());
+
+        let authored: TreeDef = ron::from_str(
+            r#"Selector([
+                Sequence([Leaf("increment"), Leaf("fail")]),
+                Inverter(Leaf("fail")),
+                AlwaysSucceed(Leaf("increment")),
+            ])"#,
+        )
+        .unwrap();
+        assert_eq!(authored, sample());
+    }
+}
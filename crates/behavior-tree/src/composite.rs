@@ -44,9 +44,9 @@ impl<C> Sequence<C> {
 }
 
 impl<C> Behavior<C> for Sequence<C> {
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         // Execute children in order until one fails
-        for child in &self.children {
+        for child in &mut self.children {
             match child.tick(ctx) {
                 Status::Success => continue,               // Move to next child
                 Status::Failure => return Status::Failure, // Short-circuit
@@ -118,9 +118,9 @@ impl<C> Selector<C> {
 }
 
 impl<C> Behavior<C> for Selector<C> {
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         // Try children in order until one succeeds
-        for child in &self.children {
+        for child in &mut self.children {
             match child.tick(ctx) {
                 Status::Success => return Status::Success, // Short-circuit
                 Status::Failure => continue,               // Try next child
@@ -151,7 +151,7 @@ impl<C> UtilitySelector<C> {
 }
 
 impl<C> Behavior<C> for UtilitySelector<C> {
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         // Compute scores for all options
         let mut scores: Vec<(usize, u32)> = self
             .options
@@ -185,7 +185,7 @@ mod tests {
 
     struct Increment;
     impl Behavior<TestContext> for Increment {
-        fn tick(&self, ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
             ctx.value += 1;
             Status::Success
         }
@@ -193,7 +193,7 @@ mod tests {
 
     struct Decrement;
     impl Behavior<TestContext> for Decrement {
-        fn tick(&self, ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
             ctx.value -= 1;
             Status::Success
         }
@@ -201,14 +201,14 @@ mod tests {
 
     struct FailAlways;
     impl Behavior<TestContext> for FailAlways {
-        fn tick(&self, _ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, _ctx: &mut TestContext) -> Status {
             Status::Failure
         }
     }
 
     #[test]
     fn sequence_all_success() {
-        let seq = Sequence::new(vec![Box::new(Increment), Box::new(Increment)]);
+        let mut seq = Sequence::new(vec![Box::new(Increment), Box::new(Increment)]);
 
         let mut ctx = TestContext { value: 0 };
         assert_eq!(seq.tick(&mut ctx), Status::Success);
@@ -217,7 +217,7 @@ mod tests {
 
     #[test]
     fn sequence_fails_on_first_failure() {
-        let seq = Sequence::new(vec![
+        let mut seq = Sequence::new(vec![
             Box::new(Increment),
             Box::new(FailAlways),
             Box::new(Increment), // Should not execute
@@ -230,7 +230,7 @@ mod tests {
 
     #[test]
     fn selector_succeeds_on_first_success() {
-        let sel = Selector::new(vec![
+        let mut sel = Selector::new(vec![
             Box::new(FailAlways),
             Box::new(Increment),
             Box::new(Decrement), // Should not execute
@@ -243,7 +243,7 @@ mod tests {
 
     #[test]
     fn selector_fails_when_all_fail() {
-        let sel = Selector::new(vec![Box::new(FailAlways), Box::new(FailAlways)]);
+        let mut sel = Selector::new(vec![Box::new(FailAlways), Box::new(FailAlways)]);
 
         let mut ctx = TestContext { value: 0 };
         assert_eq!(sel.tick(&mut ctx), Status::Failure);
@@ -1,7 +1,9 @@
 //! Decorator behavior nodes.
 //!
 //! Decorators wrap a single child behavior and modify its result or execution.
-//! This module provides [`Inverter`] (NOT logic) and [`AlwaysSucceed`] (error suppression).
+//! This module provides [`Inverter`] (NOT logic), [`AlwaysSucceed`] (error suppression),
+//! [`AlwaysFail`] (guard branches), [`RepeatUntil`] (bounded retry) and
+//! [`Cooldown`] (rate limiting).
 
 use crate::{Behavior, Status};
 
@@ -25,7 +27,7 @@ impl<C> Inverter<C> {
 }
 
 impl<C> Behavior<C> for Inverter<C> {
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         self.child.tick(ctx).invert()
     }
 }
@@ -53,13 +55,136 @@ impl<C> AlwaysSucceed<C> {
 }
 
 impl<C> Behavior<C> for AlwaysSucceed<C> {
-    fn tick(&self, ctx: &mut C) -> Status {
+    fn tick(&mut self, ctx: &mut C) -> Status {
         // Execute child but ignore the result
         let _ = self.child.tick(ctx);
         Status::Success
     }
 }
 
+/// Always returns `Failure`, regardless of the child's result.
+///
+/// # Semantics
+///
+/// - The child is still ticked, so its side effects happen
+/// - Whatever the child returns, the result is `Failure`
+///
+/// This mirrors [`AlwaysSucceed`] and is useful for guard branches in a
+/// [`Selector`](crate::Selector): the branch can record something on the
+/// context and still let the selector fall through to the next child.
+pub struct AlwaysFail<C> {
+    child: Box<dyn Behavior<C>>,
+}
+
+impl<C> AlwaysFail<C> {
+    /// Creates a new always-fail wrapper around the given child behavior.
+    pub fn new(child: Box<dyn Behavior<C>>) -> Self {
+        Self { child }
+    }
+}
+
+impl<C> Behavior<C> for AlwaysFail<C> {
+    fn tick(&mut self, ctx: &mut C) -> Status {
+        // Execute child but ignore the result
+        let _ = self.child.tick(ctx);
+        Status::Failure
+    }
+}
+
+/// Re-ticks its child until it succeeds, at most `max_attempts` times.
+///
+/// # Semantics
+///
+/// - Returns `Success` as soon as the child returns `Success`
+/// - Returns `Failure` once the child has failed `max_attempts` times
+/// - With `max_attempts == 0` the child is never ticked and the result is `Failure`
+///
+/// All attempts happen within a single tick; there is no Running state.
+///
+/// Ticks are deterministic, so a child that leaves the context unchanged when
+/// it fails will fail again on every attempt. The child must mutate the
+/// context between attempts (e.g. advance a cursor or mark a candidate as
+/// rejected) for retries to be meaningful.
+pub struct RepeatUntil<C> {
+    child: Box<dyn Behavior<C>>,
+    max_attempts: usize,
+}
+
+impl<C> RepeatUntil<C> {
+    /// Creates a new retry wrapper that ticks the child up to `max_attempts` times.
+    pub fn new(child: Box<dyn Behavior<C>>, max_attempts: usize) -> Self {
+        Self {
+            child,
+            max_attempts,
+        }
+    }
+}
+
+impl<C> Behavior<C> for RepeatUntil<C> {
+    fn tick(&mut self, ctx: &mut C) -> Status {
+        for _ in 0..self.max_attempts {
+            if self.child.tick(ctx).is_success() {
+                return Status::Success;
+            }
+        }
+        Status::Failure
+    }
+}
+
+/// Read access to the current game tick, implemented by blackboards used
+/// with [`Cooldown`].
+pub trait CurrentTick {
+    /// The tick the tree is being evaluated at.
+    fn current_tick(&self) -> u64;
+}
+
+/// Skips its child until `duration` ticks have passed since the child last
+/// succeeded.
+///
+/// # Semantics
+///
+/// - Before the child has ever succeeded, it is always ticked
+/// - While `current_tick < last_success_tick + duration`, returns `Failure`
+///   **without** ticking the child
+/// - Otherwise ticks the child and returns its result, recording the tick
+///   on `Success`
+///
+/// The tick comes from the context, never from a clock, so evaluation stays
+/// deterministic.
+pub struct Cooldown<C> {
+    child: Box<dyn Behavior<C>>,
+    duration: u64,
+    last_success_tick: Option<u64>,
+}
+
+impl<C> Cooldown<C> {
+    /// Creates a cooldown of `duration` ticks around the given child behavior.
+    pub fn new(child: Box<dyn Behavior<C>>, duration: u64) -> Self {
+        Self {
+            child,
+            duration,
+            last_success_tick: None,
+        }
+    }
+}
+
+impl<C: CurrentTick> Behavior<C> for Cooldown<C> {
+    fn tick(&mut self, ctx: &mut C) -> Status {
+        let now = ctx.current_tick();
+        if let Some(last) = self.last_success_tick
+            && now < last.saturating_add(self.duration)
+        {
+            return Status::Failure;
+        }
+
+        let status = self.child.tick(ctx);
+        if status.is_success() {
+            self.last_success_tick = Some(now);
+        }
+        status
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,7 +195,7 @@ mod tests {
 
     struct IsPositive;
     impl Behavior<TestContext> for IsPositive {
-        fn tick(&self, ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
             if ctx.value > 0 {
                 Status::Success
             } else {
@@ -81,7 +206,7 @@ mod tests {
 
     struct Increment;
     impl Behavior<TestContext> for Increment {
-        fn tick(&self, ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
             ctx.value += 1;
             Status::Success
         }
@@ -89,15 +214,51 @@ mod tests {
 
     struct FailAndIncrement;
     impl Behavior<TestContext> for FailAndIncrement {
-        fn tick(&self, ctx: &mut TestContext) -> Status {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
             ctx.value += 1;
             Status::Failure
         }
     }
 
+    struct IncrementThenCheck;
+    impl Behavior<TestContext> for IncrementThenCheck {
+        fn tick(&mut self, ctx: &mut TestContext) -> Status {
+            ctx.value += 1;
+            IsPositive.tick(ctx)
+        }
+    }
+
+    /// Mock tick counter for cooldown tests.
+    struct Clock {
+        tick: u64,
+        fired: u32,
+    }
+
+    impl CurrentTick for Clock {
+        fn current_tick(&self) -> u64 {
+            self.tick
+        }
+    }
+
+    struct Fire;
+    impl Behavior<Clock> for Fire {
+        fn tick(&mut self, ctx: &mut Clock) -> Status {
+            ctx.fired += 1;
+            Status::Success
+        }
+    }
+
+    struct Misfire;
+    impl Behavior<Clock> for Misfire {
+        fn tick(&mut self, ctx: &mut Clock) -> Status {
+            ctx.fired += 1;
+            Status::Failure
+        }
+    }
+
     #[test]
     fn inverter_inverts_success() {
-        let inverter = Inverter::new(Box::new(IsPositive));
+        let mut inverter = Inverter::new(Box::new(IsPositive));
 
         let mut ctx = TestContext { value: 10 };
         assert_eq!(inverter.tick(&mut ctx), Status::Failure);
@@ -105,7 +266,7 @@ mod tests {
 
     #[test]
     fn inverter_inverts_failure() {
-        let inverter = Inverter::new(Box::new(IsPositive));
+        let mut inverter = Inverter::new(Box::new(IsPositive));
 
         let mut ctx = TestContext { value: -10 };
         assert_eq!(inverter.tick(&mut ctx), Status::Success);
@@ -113,7 +274,7 @@ mod tests {
 
     #[test]
     fn always_succeed_on_success() {
-        let always = AlwaysSucceed::new(Box::new(Increment));
+        let mut always = AlwaysSucceed::new(Box::new(Increment));
 
         let mut ctx = TestContext { value: 0 };
         assert_eq!(always.tick(&mut ctx), Status::Success);
@@ -122,10 +283,87 @@ mod tests {
 
     #[test]
     fn always_succeed_on_failure() {
-        let always = AlwaysSucceed::new(Box::new(FailAndIncrement));
+        let mut always = AlwaysSucceed::new(Box::new(FailAndIncrement));
 
         let mut ctx = TestContext { value: 0 };
         assert_eq!(always.tick(&mut ctx), Status::Success);
         assert_eq!(ctx.value, 1); // Child still executed
     }
+
+    #[test]
+    fn always_fail_runs_child_and_fails() {
+        let mut on_success = AlwaysFail::new(Box::new(Increment));
+        let mut on_failure = AlwaysFail::new(Box::new(FailAndIncrement));
+
+        let mut ctx = TestContext { value: 0 };
+        assert_eq!(on_success.tick(&mut ctx), Status::Failure);
+        assert_eq!(on_failure.tick(&mut ctx), Status::Failure);
+        assert_eq!(ctx.value, 2); // Both children still executed
+    }
+
+    #[test]
+    fn repeat_until_succeeds_on_first_try() {
+        let mut repeat = RepeatUntil::new(Box::new(IncrementThenCheck), 3);
+
+        let mut ctx = TestContext { value: 0 };
+        assert_eq!(repeat.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.value, 1); // One attempt
+    }
+
+    #[test]
+    fn repeat_until_succeeds_on_last_try() {
+        let mut repeat = RepeatUntil::new(Box::new(IncrementThenCheck), 3);
+
+        let mut ctx = TestContext { value: -2 };
+        assert_eq!(repeat.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.value, 1); // Three attempts
+    }
+
+    #[test]
+    fn repeat_until_fails_when_attempts_are_exhausted() {
+        let mut repeat = RepeatUntil::new(Box::new(IncrementThenCheck), 3);
+
+        let mut ctx = TestContext { value: -5 };
+        assert_eq!(repeat.tick(&mut ctx), Status::Failure);
+        assert_eq!(ctx.value, -2); // Stopped after three attempts
+
+        let mut never = RepeatUntil::new(Box::new(IncrementThenCheck), 0);
+        assert_eq!(never.tick(&mut ctx), Status::Failure);
+        assert_eq!(ctx.value, -2); // Child never ticked
+    }
+
+    #[test]
+    fn cooldown_skips_child_until_window_passes() {
+        let mut cooldown = Cooldown::new(Box::new(Fire), 10);
+
+        let mut ctx = Clock { tick: 5, fired: 0 };
+        assert_eq!(cooldown.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.fired, 1);
+
+        // Cooling down: the child is not ticked
+        for tick in [5, 9, 14] {
+            ctx.tick = tick;
+            assert_eq!(cooldown.tick(&mut ctx), Status::Failure);
+        }
+        assert_eq!(ctx.fired, 1);
+
+        // Window has passed
+        ctx.tick = 15;
+        assert_eq!(cooldown.tick(&mut ctx), Status::Success);
+        assert_eq!(ctx.fired, 2);
+        ctx.tick = 24;
+        assert_eq!(cooldown.tick(&mut ctx), Status::Failure);
+        assert_eq!(ctx.fired, 2);
+    }
+
+    #[test]
+    fn cooldown_starts_only_on_success() {
+        let mut cooldown = Cooldown::new(Box::new(Misfire), 10);
+
+        let mut ctx = Clock { tick: 0, fired: 0 };
+        assert_eq!(cooldown.tick(&mut ctx), Status::Failure);
+        ctx.tick = 1;
+        assert_eq!(cooldown.tick(&mut ctx), Status::Failure);
+        assert_eq!(ctx.fired, 2); // Failures never start the cooldown
+    }
 }
@@ -6,14 +6,15 @@
 //! - **No delta time**: Every tick completes immediately (turn-based semantics)
 //! - **No Running state**: Actions either succeed or fail instantly
 //! - **Minimal state**: Optimized for ZK circuit efficiency
-//! - **Zero dependencies**: Pure Rust with no external crates
+//! - **Zero dependencies**: Pure Rust with no external crates (serde is optional)
 //!
 //! # Architecture
 //!
-//! - [`Behavior`]: Core trait for all nodes
+//! - [`Behavior`]: Core trait for all nodes, generic over a blackboard context
 //! - [`Status`]: Success or Failure (no Running state)
 //! - Composite nodes: [`Sequence`], [`Selector`]
-//! - Decorator nodes: [`Inverter`], [`AlwaysSucceed`]
+//! - Decorator nodes: [`Inverter`], [`AlwaysSucceed`], [`AlwaysFail`], [`RepeatUntil`],
+//!   [`Cooldown`]
 
 pub mod behavior;
 pub mod builder;
@@ -22,7 +23,8 @@ pub mod decorator;
 pub mod status;
 
 // Re-export core types for ergonomic API
-pub use behavior::Behavior;
+pub use behavior::{Behavior, Blackboard};
+pub use builder::{BuildError, LeafRegistry, TreeDef};
 pub use composite::{Selector, Sequence, UtilitySelector};
-pub use decorator::{AlwaysSucceed, Inverter};
+pub use decorator::{AlwaysFail, AlwaysSucceed, Cooldown, CurrentTick, Inverter, RepeatUntil};
 pub use status::Status;
@@ -22,6 +22,22 @@ impl SessionId {
     }
 }
 
+impl From<zk::SessionId> for SessionId {
+    fn from(id: zk::SessionId) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl TryFrom<&SessionId> for zk::SessionId {
+    type Error = SuiError;
+
+    fn try_from(id: &SessionId) -> Result<Self> {
+        id.as_str()
+            .parse()
+            .map_err(|e: zk::SessionIdError| SuiError::InvalidConfig(e.to_string()))
+    }
+}
+
 /// Transaction digest.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TxDigest(pub String);
@@ -140,3 +156,23 @@ fn convert_sp1_proof_to_sui(_proof: &zk::ProofData) -> Result<(Vec<u8>, Vec<u8>)
         "SP1 feature not enabled".to_string(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_id_round_trips_through_canonical_form() {
+        let canonical = zk::SessionId::new([0x5a; zk::SESSION_ID_LEN]);
+        let sui_id = SessionId::from(canonical);
+        assert_eq!(sui_id.as_str(), canonical.to_string());
+        assert_eq!(zk::SessionId::try_from(&sui_id).unwrap(), canonical);
+
+        // Local session names are not on-chain object IDs
+        let local = SessionId::new("session_1700000000".to_string());
+        assert!(matches!(
+            zk::SessionId::try_from(&local),
+            Err(SuiError::InvalidConfig(_))
+        ));
+    }
+}
@@ -25,6 +25,7 @@ pub trait OracleFactory: Send + Sync {
 /// ├── tables.toml (placeholder)
 /// ├── items.ron
 /// ├── npcs.ron
+/// ├── actions/ (optional, overrides embedded profiles)
 /// └── maps/
 ///     └── {map_name}.ron
 /// ```
@@ -161,8 +162,17 @@ impl OracleFactory for ContentOracleFactory {
         // Build map oracle (terrain only)
         let map_oracle = MapOracleImpl::new(dimensions, tiles);
 
+        // Build action oracle (embedded profiles + data directory overrides)
+        let action_profiles = factory.load_action_profiles().unwrap_or_else(|e| {
+            panic!(
+                "Failed to load action profiles from {}: {}",
+                self.data_dir.display(),
+                e
+            )
+        });
+        let actions_oracle = ActionOracleImpl::from_registry(action_profiles);
+
         // Build other oracles
-        let actions_oracle = ActionOracleImpl::new();
         let config_oracle = ConfigOracleImpl::new(config);
 
         OracleBundle::new(
@@ -1,10 +1,10 @@
 //! Maintains the CLI message log in response to runtime events.
-use runtime::{Event, GameStateEvent};
+use runtime::{CrossDirection, Event, GameStateEvent, HealthThreshold};
 
 use client_frontend_core::{
     EffectVisibility,
     event::{EventConsumer, EventImpact},
-    format::format_action_and_effects,
+    format::{describe_action_failure, format_action_and_effects},
     message::{MessageEntry, MessageLevel, MessageLog},
 };
 
@@ -54,10 +54,12 @@ impl CliEventConsumer {
         &mut self,
         action: &game_core::Action,
         phase: &str,
+        error_code: &str,
         error: &str,
         timestamp: u64,
     ) {
-        let text = format!("{} failed during {}: {}", action.actor(), phase, error);
+        let reason = describe_action_failure(error_code).unwrap_or(error);
+        let text = format!("{} failed during {}: {}", action.actor(), phase, reason);
         self.log.push(MessageEntry::new(
             text,
             Some(timestamp),
@@ -85,15 +87,48 @@ impl EventConsumer for CliEventConsumer {
                 nonce: _,
                 action,
                 phase,
+                error_code,
                 error,
                 clock,
             }) => {
                 // Filter out system actions from message log
                 if !action.actor().is_system() {
-                    self.push_failure(action, phase.as_str(), error, *clock);
+                    self.push_failure(action, phase.as_str(), error_code, error, *clock);
                 }
                 EventImpact::redraw()
             }
+            Event::GameState(GameStateEvent::TurnTimedOut { actor, clock, .. }) => {
+                self.log.push(MessageEntry::new(
+                    format!("{} ran out of time", actor),
+                    Some(*clock),
+                    MessageLevel::Warning,
+                ));
+                EventImpact::redraw()
+            }
+            Event::GameState(GameStateEvent::HealthThresholdCrossed {
+                entity,
+                threshold: HealthThreshold::Critical,
+                direction: CrossDirection::Down,
+            }) => {
+                self.log.push(MessageEntry::new(
+                    format!("{} is critically wounded", entity),
+                    None,
+                    MessageLevel::Warning,
+                ));
+                EventImpact::redraw()
+            }
+            Event::GameState(GameStateEvent::HealthThresholdCrossed { .. }) => {
+                // Other bands show on the HP bar, which the action already redrew
+                EventImpact::none()
+            }
+            Event::GameState(GameStateEvent::EquipmentBroke { entity, item }) => {
+                self.log.push(MessageEntry::new(
+                    format!("{}'s equipment broke (handle: {:?})", entity, item),
+                    None,
+                    MessageLevel::Warning,
+                ));
+                EventImpact::redraw()
+            }
             Event::GameState(GameStateEvent::StateRestored {
                 from_nonce,
                 to_nonce,
@@ -105,6 +140,31 @@ impl EventConsumer for CliEventConsumer {
                 ));
                 EventImpact::redraw()
             }
+            Event::GameState(GameStateEvent::RolledBack {
+                from_nonce,
+                to_nonce,
+                ..
+            }) => {
+                self.message_log_mut().push_text(format!(
+                    "Last action undone: nonce {} → {}",
+                    from_nonce, to_nonce
+                ));
+                EventImpact::redraw()
+            }
+            Event::GameState(GameStateEvent::SessionHalted {
+                error_code,
+                reason,
+                clock,
+                ..
+            }) => {
+                // Terminal failure - no further turns will execute
+                self.log.push(MessageEntry::new(
+                    format!("Session halted [{}]: {}", error_code, reason),
+                    Some(*clock),
+                    MessageLevel::Error,
+                ));
+                EventImpact::redraw()
+            }
             Event::Proof(_) => {
                 // Proof events are not displayed in CLI to keep focus on gameplay
                 EventImpact::none()
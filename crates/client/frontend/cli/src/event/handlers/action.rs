@@ -201,11 +201,15 @@ where
                 self.tx_action.send(Action::Character(action)).await?;
             }
 
-            game_core::TargetingMode::SingleTarget { range, .. } => {
-                // Entity targeting - enter position-based targeting mode
+            game_core::TargetingMode::SingleTarget { .. }
+            | game_core::TargetingMode::Chain { .. }
+            | game_core::TargetingMode::LineOfSight { .. }
+            | game_core::TargetingMode::Burst { .. } => {
+                // Entity or area targeting - enter position-based targeting mode
                 if let Some(input_mode) = TargetingInputMode::from_targeting_mode(&targeting) {
                     // Find nearest valid target to place cursor
-                    let valid_targets = self.find_targets_in_range(range);
+                    let range = targeting.entity_range().unwrap_or(0);
+                    let valid_targets = self.find_targets_in_range(&range);
                     let cursor_pos = valid_targets
                         .first()
                         .and_then(|&id| self.view_model.actors.iter().find(|a| a.id == id))
@@ -226,7 +230,9 @@ where
                 }
             }
 
-            game_core::TargetingMode::Directional { .. } => {
+            game_core::TargetingMode::Directional { .. }
+            | game_core::TargetingMode::Line { .. }
+            | game_core::TargetingMode::Cone { .. } => {
                 // Direction targeting - enter targeting mode
                 if let Some(input_mode) = TargetingInputMode::from_targeting_mode(&targeting) {
                     let player_pos = self
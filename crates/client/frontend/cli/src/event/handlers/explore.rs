@@ -0,0 +1,97 @@
+//! Auto-explore handlers (start, per-turn stepping, interruption).
+
+use anyhow::Result;
+use client_frontend_core::{
+    EventConsumer,
+    services::{ExploreStep, explore},
+};
+use game_core::{Action, ActionInput, ActionKind, CharacterAction, EntityId, SystemActionKind};
+use runtime::{Event as RuntimeEvent, GameStateEvent};
+
+use super::super::EventLoop;
+
+impl<C> EventLoop<C>
+where
+    C: EventConsumer,
+{
+    /// Start auto-explore and take the first step (the player is waiting to act).
+    pub(in crate::event) async fn start_auto_explore(&mut self) -> Result<()> {
+        if self.view_model.player.position.is_none() {
+            return Ok(());
+        }
+
+        self.app_state.auto_explore = true;
+        self.auto_explore_step().await
+    }
+
+    /// Keep exploring as runtime events arrive.
+    ///
+    /// Steps again whenever a turn is prepared for the player, and stops if a
+    /// player action fails.
+    pub(in crate::event) async fn continue_auto_explore(
+        &mut self,
+        event: &RuntimeEvent,
+    ) -> Result<()> {
+        match event {
+            RuntimeEvent::GameState(GameStateEvent::ActionExecuted {
+                action,
+                after_state,
+                ..
+            }) if matches!(
+                action,
+                Action::System {
+                    kind: SystemActionKind::PrepareTurn(_)
+                }
+            ) && after_state.turn.current_actor == EntityId::PLAYER =>
+            {
+                self.auto_explore_step().await
+            }
+            RuntimeEvent::GameState(GameStateEvent::ActionFailed { action, error, .. })
+                if action.actor() == EntityId::PLAYER =>
+            {
+                self.stop_auto_explore(&format!("Auto-explore stopped: {}", error));
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Stop auto-explore and tell the player why.
+    pub(in crate::event) fn stop_auto_explore(&mut self, reason: &str) {
+        self.app_state.auto_explore = false;
+        self.consumer
+            .message_log_mut()
+            .push_text(format!("[{}] {}", self.view_model.turn.clock, reason));
+    }
+
+    /// Plan and submit one auto-explore step, or stop when there is nothing to do.
+    async fn auto_explore_step(&mut self) -> Result<()> {
+        match explore::next_step(&self.view_model, self.oracles.map.as_ref()) {
+            ExploreStep::Move(direction) => {
+                let action = CharacterAction::new(
+                    EntityId::PLAYER,
+                    ActionKind::Move,
+                    ActionInput::Direction(direction),
+                );
+                if self
+                    .tx_action
+                    .send(Action::Character(action))
+                    .await
+                    .is_err()
+                {
+                    self.app_state.auto_explore = false;
+                    tracing::error!("Action channel closed");
+                }
+            }
+            ExploreStep::EnemyInSight(enemy) => {
+                self.app_state.set_highlighted_entity(Some(enemy));
+                self.stop_auto_explore("Enemy in sight, auto-explore stopped.");
+            }
+            ExploreStep::Complete => {
+                self.stop_auto_explore("Nothing left to explore.");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -42,6 +42,13 @@ where
         key: KeyEvent,
         terminal: &mut Tui,
     ) -> Result<bool> {
+        // Any key press interrupts auto-explore
+        if self.app_state.auto_explore {
+            self.stop_auto_explore("Auto-explore interrupted.");
+            self.render(terminal)?;
+            return Ok(false);
+        }
+
         match self.input.handle_key(key, &self.app_state.mode) {
             KeyAction::Quit => {
                 self.consumer
@@ -162,6 +169,11 @@ where
                 self.render(terminal)?;
                 Ok(false)
             }
+            KeyAction::AutoExplore => {
+                self.start_auto_explore().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
             KeyAction::SaveGame => {
                 self.handle_save_game().await?;
                 self.render(terminal)?;
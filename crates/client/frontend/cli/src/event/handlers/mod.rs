@@ -3,6 +3,7 @@
 //! This module contains handler methods organized by responsibility:
 //! - `input`: Keyboard input and directional handling
 //! - `action`: Action execution (slots, abilities, targeting)
+//! - `explore`: Auto-explore stepping
 //! - `targeting`: Auto-targeting and entity cycling
 //! - `rendering`: Terminal rendering
 //!
@@ -10,6 +11,7 @@
 //! and are automatically available to the EventLoop through Rust's module system.
 
 mod action;
+mod explore;
 mod input;
 mod rendering;
 mod targeting;
@@ -168,6 +168,11 @@ where
                         self.render(terminal)?;
                     }
                 }
+
+                if self.app_state.auto_explore {
+                    self.continue_auto_explore(&event).await?;
+                    self.render(terminal)?;
+                }
                 Ok(false)
             }
             Err(RecvError::Closed) => {
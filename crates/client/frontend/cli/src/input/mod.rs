@@ -41,6 +41,8 @@ pub enum KeyAction {
     ConfirmTarget,
     /// Pick up item at player's position.
     PickupItem,
+    /// Start auto-exploring toward the nearest unexplored tile.
+    AutoExplore,
     /// Create a manual checkpoint (save game).
     SaveGame,
     /// Open save/load menu to view checkpoints.
@@ -132,6 +134,7 @@ impl InputHandler {
             KeyCode::Char('a') => KeyAction::OpenAbilityMenu,
             KeyCode::Char('x') => KeyAction::ToggleExamine,
             KeyCode::Char('g') => KeyAction::PickupItem,
+            KeyCode::Char('e') => KeyAction::AutoExplore,
             KeyCode::Char('s') => {
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
                     KeyAction::SaveGame // Ctrl+S to save
@@ -73,6 +73,10 @@ impl PresentationMapper for RatatuiTheme {
             TerrainKind::Void => (' ', Color::Reset),
             TerrainKind::Water => ('~', Color::Blue),
             TerrainKind::Custom(_) => ('?', Color::LightMagenta),
+            TerrainKind::Exit => ('>', Color::Yellow),
+            TerrainKind::Spikes => ('^', Color::LightRed),
+            TerrainKind::Lava => ('~', Color::Red),
+            TerrainKind::Mud => (',', Color::Yellow),
         };
 
         (glyph.to_string(), Style::default().fg(color))
@@ -0,0 +1,208 @@
+//! Actor stats panel shown by the examine view.
+//!
+//! Lists everything the ViewModel knows about an actor: faction, resources,
+//! core stats, equipment and active status effects. The lines are rebuilt
+//! from the ViewModel on every frame, so they follow the examined actor as
+//! events change it.
+
+use client_frontend_core::view_model::{PresentationMapper, entities::ActorView};
+use game_core::{ItemHandle, Tick};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Build the stats lines for `actor` as of `clock`.
+///
+/// `type_name` labels the actor (e.g. "Player" or "NPC"). Status effect
+/// durations are shown as the ticks left until they expire.
+pub fn lines<T: PresentationMapper<Style = Style>>(
+    type_name: &str,
+    actor: &ActorView,
+    clock: Tick,
+    theme: &T,
+) -> Vec<Line<'static>> {
+    let stats = &actor.stats;
+    let (hp_cur, hp_max) = stats.hp();
+    let (mp_cur, mp_max) = stats.mp();
+    let (lucidity_cur, lucidity_max) = stats.lucidity();
+    let core = &stats.core;
+
+    let mut lines = vec![
+        field("Type: ", type_name.to_string()),
+        field("ID: ", format!("{:?}", actor.id)),
+        field("Faction: ", format!("{:?}", actor.faction)),
+        field(
+            "Position: ",
+            match actor.position {
+                Some(pos) => format!("({}, {})", pos.x, pos.y),
+                None => "Not on map".to_string(),
+            },
+        ),
+        Line::from(vec![
+            label("HP: "),
+            Span::styled(
+                format!("{}/{}", hp_cur, hp_max),
+                theme.style_health(hp_cur, hp_max),
+            ),
+        ]),
+        Line::from(vec![
+            label("MP: "),
+            Span::styled(
+                format!("{}/{}", mp_cur, mp_max),
+                theme.style_energy(mp_cur, mp_max),
+            ),
+        ]),
+        field("Lucidity: ", format!("{}/{}", lucidity_cur, lucidity_max)),
+        field(
+            "Speed: ",
+            format!(
+                "{} phys / {} cog / {} ritual",
+                stats.speed.physical, stats.speed.cognitive, stats.speed.ritual
+            ),
+        ),
+        field("Level: ", core.level.to_string()),
+        field(
+            "STR/CON/DEX: ",
+            format!("{}/{}/{}", core.str, core.con, core.dex),
+        ),
+        field(
+            "INT/WIL/EGO: ",
+            format!("{}/{}/{}", core.int, core.wil, core.ego),
+        ),
+        field(
+            "Weapon: ",
+            equipped(
+                actor.equipment.weapon,
+                actor.equipment.weapon_durability,
+                "uses",
+            ),
+        ),
+        field(
+            "Armor: ",
+            equipped(
+                actor.equipment.armor,
+                actor.equipment.armor_durability,
+                "hits",
+            ),
+        ),
+    ];
+
+    let mut effects = actor.status_effects.active_at(clock).peekable();
+    if effects.peek().is_none() {
+        lines.push(field("Status: ", "None".to_string()));
+    } else {
+        lines.push(Line::from(label("Status:")));
+        for effect in effects {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:?}", effect.kind),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(" ({} ticks)", effect.expires_at - clock)),
+            ]));
+        }
+    }
+
+    lines
+}
+
+/// Describe an equipment slot: the item handle and how much wear it can take.
+fn equipped(item: Option<ItemHandle>, durability: Option<u16>, unit: &str) -> String {
+    match (item, durability) {
+        (None, _) => "None".to_string(),
+        (Some(handle), None) => handle.0.to_string(),
+        (Some(handle), Some(left)) => format!("{} ({} {} left)", handle.0, left, unit),
+    }
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    Span::styled(text, Style::default().fg(Color::White))
+}
+
+fn field(name: &'static str, value: String) -> Line<'static> {
+    Line::from(vec![label(name), Span::raw(value)])
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActorTemplate, EntityId, Faction, GameState, Position, StatusEffectKind, TraitProfile,
+    };
+
+    use super::*;
+    use crate::presentation::theme::RatatuiTheme;
+
+    fn text(lines: &[Line<'_>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lists_every_actor_field() {
+        let mut state = GameState::empty();
+        let template = ActorTemplate::builder()
+            .faction(Faction::GoblinClan)
+            .trait_profile(TraitProfile::default())
+            .build();
+        let npc = state.add_npc(&template, Position::new(3, 4)).unwrap();
+        let clock = 1_000;
+        let actor = state.entities.actor_mut(npc).unwrap();
+        actor.resources.hp = 7;
+        actor.equipment.weapon = Some(ItemHandle(10));
+        actor.equipment.weapon_durability = Some(5);
+        actor
+            .status_effects
+            .add(StatusEffectKind::Poisoned, clock + 300);
+        actor.status_effects.add(
+            StatusEffectKind::Aggro {
+                toward: EntityId::PLAYER,
+            },
+            clock + 50,
+        );
+        // Already expired, so not listed
+        actor.status_effects.add(StatusEffectKind::Rooted, clock);
+        let view = ActorView::from_actor(state.entities.actor(npc).unwrap());
+        let (_, hp_max) = view.stats.hp();
+        let core = &view.stats.core;
+
+        let rendered = text(&lines("NPC", &view, clock, &RatatuiTheme));
+
+        for expected in [
+            "Type: NPC".to_string(),
+            format!("ID: {:?}", npc),
+            "Faction: GoblinClan".to_string(),
+            "Position: (3, 4)".to_string(),
+            format!("HP: 7/{}", hp_max),
+            format!("STR/CON/DEX: {}/{}/{}", core.str, core.con, core.dex),
+            format!("INT/WIL/EGO: {}/{}/{}", core.int, core.wil, core.ego),
+            "Weapon: 10 (5 uses left)".to_string(),
+            "Armor: None".to_string(),
+            "Status:".to_string(),
+            "  Poisoned (300 ticks)".to_string(),
+            format!(
+                "  {:?} (50 ticks)",
+                StatusEffectKind::Aggro {
+                    toward: EntityId::PLAYER
+                }
+            ),
+        ] {
+            assert!(
+                rendered.contains(&expected),
+                "missing {expected:?} in {rendered:#?}"
+            );
+        }
+        assert!(rendered.iter().any(|line| line.starts_with("MP: ")));
+        assert!(rendered.iter().any(|line| line.starts_with("Lucidity: ")));
+        assert!(rendered.iter().any(|line| line.starts_with("Speed: ")));
+        assert!(!rendered.iter().any(|line| line.contains("Rooted")));
+    }
+}
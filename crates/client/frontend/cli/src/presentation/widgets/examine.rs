@@ -1,6 +1,6 @@
 //! Examine widget for detailed entity and tile inspection.
 
-use client_frontend_core::view_model::{PresentationMapper, ViewModel, entities::ActorView};
+use client_frontend_core::view_model::{PresentationMapper, ViewModel};
 use game_core::{EntityId, Position, env::MapOracle};
 use ratatui::{
     Frame,
@@ -10,6 +10,8 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
+use super::actor_stats;
+
 /// Context for examine panel rendering.
 ///
 /// Groups related parameters to reduce function argument count.
@@ -168,6 +170,7 @@ fn render_tile_info(
 /// Render entity details for the highlighted entity.
 ///
 /// Shows information about the currently highlighted entity (NPC, prop, or item).
+/// Actors get the full [stats panel](actor_stats::lines).
 /// If no entity is highlighted, shows a placeholder message.
 fn render_entity_details<T: PresentationMapper<Style = Style>>(
     frame: &mut Frame,
@@ -187,7 +190,7 @@ fn render_entity_details<T: PresentationMapper<Style = Style>>(
     // Find the highlighted entity (prioritize actors, then props, then items)
     if let Some(actor) = view_model.actors.iter().find(|a| a.id == entity_id) {
         let entity_type = if actor.is_player { "Player" } else { "NPC" };
-        let lines = render_actor_details(entity_type, actor, theme);
+        let lines = actor_stats::lines(entity_type, actor, view_model.turn.clock, theme);
 
         let paragraph =
             Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(entity_type));
@@ -222,52 +225,6 @@ fn render_entity_details<T: PresentationMapper<Style = Style>>(
     frame.render_widget(paragraph, area);
 }
 
-/// Render actor (NPC) details.
-fn render_actor_details<'a, T: PresentationMapper<Style = Style>>(
-    type_name: &'a str,
-    actor: &'a ActorView,
-    theme: &T,
-) -> Vec<Line<'a>> {
-    let (hp_cur, hp_max) = actor.stats.hp();
-    let (mp_cur, mp_max) = actor.stats.mp();
-
-    vec![
-        Line::from(vec![
-            Span::styled("Type: ", Style::default().fg(Color::White)),
-            Span::raw(type_name),
-        ]),
-        Line::from(vec![
-            Span::styled("ID: ", Style::default().fg(Color::White)),
-            Span::raw(format!("{:?}", actor.id)),
-        ]),
-        Line::from(vec![
-            Span::styled("Position: ", Style::default().fg(Color::White)),
-            Span::raw(match actor.position {
-                Some(pos) => format!("({}, {})", pos.x, pos.y),
-                None => "Not on map".to_string(),
-            }),
-        ]),
-        Line::from(vec![
-            Span::styled("HP: ", Style::default().fg(Color::White)),
-            Span::styled(
-                format!("{}/{}", hp_cur, hp_max),
-                theme.style_health(hp_cur, hp_max),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("MP: ", Style::default().fg(Color::White)),
-            Span::styled(
-                format!("{}/{}", mp_cur, mp_max),
-                theme.style_energy(mp_cur, mp_max),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("Speed: ", Style::default().fg(Color::White)),
-            Span::raw(actor.stats.speed.physical.to_string()),
-        ]),
-    ]
-}
-
 /// Render prop details.
 fn render_prop_details<'a>(
     prop: &'a client_frontend_core::view_model::entities::PropView,
@@ -8,6 +8,7 @@
 
 pub mod ability_menu;
 pub mod action_slots;
+pub mod actor_stats;
 pub mod examine;
 pub mod footer;
 pub mod game_area;
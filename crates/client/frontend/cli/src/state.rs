@@ -143,15 +143,26 @@ impl TargetingInputMode {
             game_core::TargetingMode::None | game_core::TargetingMode::SelfOnly => None,
 
             // Entity targeting - use cursor with entity requirement
-            game_core::TargetingMode::SingleTarget { range, .. } => Some(Self::Position {
+            game_core::TargetingMode::SingleTarget { range, .. }
+            | game_core::TargetingMode::Chain { range, .. } => Some(Self::Position {
                 require_entity: true,
                 max_range: Some(*range),
             }),
+            game_core::TargetingMode::LineOfSight { max_range } => Some(Self::Position {
+                require_entity: true,
+                max_range: Some(u32::from(*max_range)),
+            }),
+
+            // Position targeting - any tile can be the centre
+            game_core::TargetingMode::Burst { .. } => Some(Self::Position {
+                require_entity: false,
+                max_range: None,
+            }),
 
             // Direction targeting - arrow keys
-            game_core::TargetingMode::Directional { .. } => {
-                Some(Self::Direction { selected: None })
-            }
+            game_core::TargetingMode::Directional { .. }
+            | game_core::TargetingMode::Line { .. }
+            | game_core::TargetingMode::Cone { .. } => Some(Self::Direction { selected: None }),
         }
     }
 }
@@ -175,6 +186,8 @@ pub struct AppState {
     pub manual_cursor: Option<CursorState>,
     /// Action hotkey slots (1-9 keys) - user-configurable
     pub action_slots: ActionSlots,
+    /// True while auto-explore keeps stepping on each player turn.
+    pub auto_explore: bool,
     /// Save Menu status message log (for blockchain operations).
     ///
     /// Persists across Save Menu open/close to maintain operation history.
@@ -212,6 +225,7 @@ impl Default for AppState {
             highlighted_entity: None,
             manual_cursor: None,
             action_slots: ActionSlots::new(),
+            auto_explore: false,
             save_menu_log: MessageLog::new(50), // Keep last 50 blockchain operation messages
         }
     }
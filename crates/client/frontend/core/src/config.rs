@@ -142,6 +142,9 @@ impl EffectVisibility {
             AppliedValue::Summon { .. } => self.show_summon,
             AppliedValue::ItemAcquired { .. } => true, // Show item acquisitions
             AppliedValue::ItemUsed { .. } => true,     // Show item usage
+            AppliedValue::ItemEquipped { .. } => true, // Show equipment swaps
+            AppliedValue::PropInteracted { .. } => true, // Show levers, doors, etc.
+            AppliedValue::HazardCreated { .. } => true, // Show caltrops, fire walls, etc.
             AppliedValue::None => false,               // Never show empty effects
         }
     }
@@ -1,7 +1,12 @@
 //! Utilities for reacting to runtime events inside UI layers.
 use runtime::Event;
+use tokio::sync::broadcast::{self, error::TryRecvError};
 
 use crate::message::MessageLog;
+use crate::services::{UpdateScope, ViewModelUpdater};
+
+/// Default upper bound on the events [`BatchedConsumer`] handles at once.
+pub const DEFAULT_MAX_BATCH: usize = 64;
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct EventImpact {
@@ -36,3 +41,259 @@ pub trait EventConsumer {
     where
         Self: Sized;
 }
+
+/// Events handled together by [`BatchedConsumer::process_batch`].
+#[derive(Debug)]
+pub struct EventBatch {
+    /// Every event in the batch, in arrival order
+    pub events: Vec<Event>,
+    /// Combined impact reported by the consumer
+    pub impact: EventImpact,
+    /// Combined scope of the ViewModel updates that were applied
+    pub scope: UpdateScope,
+}
+
+/// Wraps an [`EventConsumer`] to handle bursts of events at once.
+///
+/// The consumer still sees every event, so the message log is complete, but
+/// ViewModel updates are coalesced: every section in an [`UpdateScope`] is
+/// rebuilt from the event's own state, so only the last event of each
+/// distinct scope is applied, in arrival order.
+pub struct BatchedConsumer<C> {
+    consumer: C,
+    max_batch: usize,
+}
+
+impl<C> BatchedConsumer<C>
+where
+    C: EventConsumer,
+{
+    pub fn new(consumer: C) -> Self {
+        Self {
+            consumer,
+            max_batch: DEFAULT_MAX_BATCH,
+        }
+    }
+
+    /// Caps how many events one batch takes, bounding the latency of a
+    /// burst. Values below 1 are treated as 1.
+    pub fn with_max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = max_batch.max(1);
+        self
+    }
+
+    pub fn consumer(&self) -> &C {
+        &self.consumer
+    }
+
+    pub fn consumer_mut(&mut self) -> &mut C {
+        &mut self.consumer
+    }
+
+    pub fn into_inner(self) -> C {
+        self.consumer
+    }
+
+    /// Handles `first` together with the events already queued on `rx`.
+    ///
+    /// Never waits for more events. Each event goes through the consumer;
+    /// those it flags for redraw are coalesced by [`ViewModelUpdater::scope`]
+    /// and `update` (normally [`ViewModelUpdater::update`]) is called once
+    /// per distinct scope.
+    pub fn process_batch(
+        &mut self,
+        first: Event,
+        rx: &mut broadcast::Receiver<Event>,
+        mut update: impl FnMut(&Event) -> UpdateScope,
+    ) -> EventBatch {
+        let mut events = vec![first];
+        while events.len() < self.max_batch {
+            match rx.try_recv() {
+                Ok(event) => events.push(event),
+                // Lagging skips to the oldest retained event, which is still queued
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+            }
+        }
+
+        // Index of the last event for each distinct scope
+        let mut impact = EventImpact::none();
+        let mut latest: Vec<(UpdateScope, usize)> = Vec::new();
+        for (index, event) in events.iter().enumerate() {
+            let event_impact = self.consumer.on_event(event);
+            impact = impact.combine(event_impact);
+
+            let scope = ViewModelUpdater::scope(event);
+            if !event_impact.requires_redraw || scope.is_empty() {
+                continue;
+            }
+            match latest.iter_mut().find(|(known, _)| *known == scope) {
+                Some(entry) => entry.1 = index,
+                None => latest.push((scope, index)),
+            }
+        }
+
+        latest.sort_by_key(|&(_, index)| index);
+        let scope = latest
+            .into_iter()
+            .fold(UpdateScope::empty(), |scope, (_, index)| {
+                scope | update(&events[index])
+            });
+
+        EventBatch {
+            events,
+            impact,
+            scope,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        Action, ActionInput, ActionKind, ActionResult, CharacterAction, EntityId, GameState,
+        StateDelta, engine::TransitionPhase,
+    };
+    use runtime::GameStateEvent;
+
+    use super::*;
+
+    /// Redraws on every event and counts them.
+    struct CountingConsumer {
+        log: MessageLog,
+        seen: usize,
+    }
+
+    impl EventConsumer for CountingConsumer {
+        fn on_event(&mut self, _event: &Event) -> EventImpact {
+            self.seen += 1;
+            EventImpact::redraw()
+        }
+
+        fn message_log(&self) -> &MessageLog {
+            &self.log
+        }
+
+        fn message_log_mut(&mut self) -> &mut MessageLog {
+            &mut self.log
+        }
+
+        fn take_message_log(self) -> MessageLog {
+            self.log
+        }
+    }
+
+    fn batched() -> BatchedConsumer<CountingConsumer> {
+        BatchedConsumer::new(CountingConsumer {
+            log: MessageLog::new(16),
+            seen: 0,
+        })
+    }
+
+    fn wait() -> Action {
+        Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ))
+    }
+
+    fn executed(nonce: u64, before: &GameState, after: &GameState) -> Event {
+        Event::GameState(GameStateEvent::ActionExecuted {
+            nonce,
+            action: wait(),
+            delta: Box::new(StateDelta::from_states(wait(), before, after)),
+            clock: after.turn.clock,
+            before_state: Box::new(before.clone()),
+            after_state: Box::new(after.clone()),
+            action_result: ActionResult::default(),
+        })
+    }
+
+    /// A combat burst: three hits on the player and one clock advance.
+    fn burst() -> Vec<Event> {
+        let mut states = vec![GameState::with_player()];
+        for step in 0..4 {
+            let mut next = states.last().unwrap().clone();
+            if step == 2 {
+                next.turn.clock += 100;
+            } else {
+                next.entities.player_mut().resources.hp -= 1;
+            }
+            states.push(next);
+        }
+
+        let mut events: Vec<_> = states
+            .windows(2)
+            .zip(0..)
+            .map(|(pair, nonce)| executed(nonce, &pair[0], &pair[1]))
+            .collect();
+        events.push(Event::GameState(GameStateEvent::ActionFailed {
+            nonce: 4,
+            action: wait(),
+            phase: TransitionPhase::PreValidate,
+            error_code: "ACTION_BLOCKED".to_string(),
+            error: "blocked".to_string(),
+            clock: 100,
+        }));
+        events
+    }
+
+    fn nonce(event: &Event) -> u64 {
+        match event {
+            Event::GameState(GameStateEvent::ActionExecuted { nonce, .. }) => *nonce,
+            other => panic!("unexpected update for {other:?}"),
+        }
+    }
+
+    #[test]
+    fn burst_updates_once_per_distinct_scope() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let mut events = burst().into_iter();
+        let first = events.next().unwrap();
+        for event in events {
+            tx.send(event).unwrap();
+        }
+
+        let mut consumer = batched();
+        let mut updated = Vec::new();
+        let batch = consumer.process_batch(first, &mut rx, |event| {
+            updated.push(nonce(event));
+            ViewModelUpdater::scope(event)
+        });
+
+        // Every event reached the consumer, but the hits before the clock
+        // advance are superseded by the last hit
+        assert_eq!(batch.events.len(), 5);
+        assert_eq!(consumer.consumer().seen, 5);
+        assert_eq!(updated, vec![2, 3]);
+        assert!(
+            batch
+                .scope
+                .contains(UpdateScope::ACTORS | UpdateScope::TURN)
+        );
+        assert!(batch.impact.requires_redraw);
+    }
+
+    #[test]
+    fn max_batch_leaves_the_rest_queued() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let mut events = burst().into_iter();
+        let first = events.next().unwrap();
+        for event in events {
+            tx.send(event).unwrap();
+        }
+
+        let mut consumer = batched().with_max_batch(2);
+        let mut updates = 0;
+        let batch = consumer.process_batch(first, &mut rx, |event| {
+            updates += 1;
+            ViewModelUpdater::scope(event)
+        });
+
+        // Two hits with the same scope: one update
+        assert_eq!(batch.events.len(), 2);
+        assert_eq!(updates, 1);
+        assert_eq!(nonce(&rx.try_recv().unwrap()), 2);
+    }
+}
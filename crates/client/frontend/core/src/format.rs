@@ -21,10 +21,22 @@ pub fn format_action_message(action: &Action, actor_name: &str) -> String {
         Action::Character(char_action) => {
             let kind_verb = match char_action.kind {
                 ActionKind::MeleeAttack => "attacks",
+                ActionKind::PowerAttack => "power attacks",
+                ActionKind::Cleave => "cleaves",
+                ActionKind::RangedAttack => "shoots",
+                ActionKind::Caltrops => "scatters caltrops",
+                ActionKind::PiercingShot => "fires a piercing shot",
+                ActionKind::Lightning => "strikes with lightning",
+                ActionKind::Fireball => "hurls a fireball",
+                ActionKind::Summon => "summons a minion",
+                ActionKind::Heal => "heals",
                 ActionKind::Move => "moves",
                 ActionKind::Wait => "waits",
                 ActionKind::PickupItem => "picks up",
                 ActionKind::UseItem => "uses",
+                ActionKind::Interact => "interacts with",
+                ActionKind::EquipWeapon | ActionKind::EquipArmor => "equips",
+                ActionKind::Revive => "revives",
             };
 
             match &char_action.input {
@@ -84,7 +96,7 @@ where
             let target_name = entity_name(effect.target);
 
             match &effect.applied_value {
-                AppliedValue::Damage { actual, .. } => {
+                AppliedValue::Damage { actual } => {
                     if *actual > 0 {
                         let mut msg = format!("{} takes {} damage", target_name, actual);
                         if effect.flags.critical {
@@ -161,6 +173,22 @@ where
                     target_name, slot, handle
                 )),
 
+                AppliedValue::ItemEquipped { handle, .. } => Some(format!(
+                    "{} equips item (handle: {:?})",
+                    target_name, handle
+                )),
+
+                AppliedValue::PropInteracted { is_active, .. } => Some(format!(
+                    "{} is now {}",
+                    target_name,
+                    if *is_active { "active" } else { "inactive" }
+                )),
+
+                AppliedValue::HazardCreated { tiles } => Some(format!(
+                    "{} leaves a hazard on {} tiles",
+                    target_name, tiles
+                )),
+
                 AppliedValue::None => None, // No message for empty effects
             }
         })
@@ -186,6 +214,27 @@ where
     (action_msg, effect_msgs)
 }
 
+/// Returns a player-facing explanation for an action failure code.
+///
+/// Codes come from `ActionFailed::error_code` (see `game_core::GameError::error_code`).
+/// Returns `None` for codes without a friendly message; callers should fall
+/// back to the raw error text.
+pub fn describe_action_failure(error_code: &str) -> Option<&'static str> {
+    let message = match error_code {
+        "ACTION_BLOCKED" => "The way is blocked",
+        "ACTION_OCCUPIED" => "Someone is already standing there",
+        "ACTION_OUT_OF_BOUNDS" | "ACTION_INVALID_POSITION" => "You can't go there",
+        "ACTION_OUT_OF_RANGE" => "The target is out of range",
+        "ACTION_INVALID_TARGET" | "ACTION_TARGET_NOT_FOUND" => "There is nothing to target",
+        "ACTION_ON_COOLDOWN" => "That ability is still on cooldown",
+        "ACTION_NOT_AVAILABLE" => "You can't do that right now",
+        "ACTION_INSUFFICIENT_RESOURCES" => "Not enough resources",
+        "ACTION_REQUIREMENTS_NOT_MET" => "Requirements not met",
+        _ => return None,
+    };
+    Some(message)
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -0,0 +1,235 @@
+//! Auto-explore planning for frontends.
+//!
+//! Reads the engine's fog of war (see [`game_core::VisibilitySet`], mirrored
+//! in [`ViewModel::visibility`]) and plans one step at a time toward the
+//! nearest reachable tile the player has never seen.
+
+use std::collections::{HashSet, VecDeque};
+
+use game_core::{CardinalDirection, EntityId, Position, Visibility, env::MapOracle};
+
+use crate::view_model::ViewModel;
+
+/// Outcome of planning a single auto-explore step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExploreStep {
+    /// Move one tile toward the nearest unexplored tile.
+    Move(CardinalDirection),
+    /// A living enemy is in sight; exploring must stop.
+    EnemyInSight(EntityId),
+    /// No reachable tile is left unexplored.
+    Complete,
+}
+
+/// Number of passable tiles the player has never seen.
+pub fn unexplored_count<M: MapOracle + ?Sized>(view_model: &ViewModel, map: &M) -> usize {
+    let dimensions = map.dimensions();
+    (0..dimensions.height as i32)
+        .flat_map(|y| (0..dimensions.width as i32).map(move |x| Position::new(x, y)))
+        .filter(|&position| is_passable(map, position) && is_unexplored(view_model, position))
+        .count()
+}
+
+/// Plan the player's next auto-explore step.
+///
+/// Stops for the nearest living NPC hostile to the player that stands on a
+/// tile the player can see and has a line of sight to. Otherwise runs a
+/// breadth-first search over passable tiles not blocked by other actors and
+/// returns the first step toward the closest unexplored tile. Neighbors are
+/// visited in [`CardinalDirection::all`] order, so ties resolve the same way
+/// every time.
+pub fn next_step<M: MapOracle + ?Sized>(view_model: &ViewModel, map: &M) -> ExploreStep {
+    let Some(start) = view_model.player.position else {
+        return ExploreStep::Complete;
+    };
+
+    let living_npcs = || {
+        view_model
+            .npcs()
+            .filter(|npc| npc.stats.resource_current.hp > 0)
+            .filter_map(|npc| npc.position.map(|position| (npc, position)))
+    };
+
+    if let Some((enemy, _)) = living_npcs()
+        .filter(|(npc, _)| view_model.player.faction.is_hostile_to(&npc.faction))
+        .filter(|&(_, position)| {
+            view_model.visibility.is_visible(&position) && map.line_of_sight(start, position)
+        })
+        .min_by_key(|&(npc, position)| (start.chebyshev_distance(position), npc.id))
+    {
+        return ExploreStep::EnemyInSight(enemy.id);
+    }
+
+    let blocked: HashSet<Position> = living_npcs().map(|(_, position)| position).collect();
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::new();
+
+    for direction in CardinalDirection::all() {
+        let next = step(start, direction);
+        if is_passable(map, next) && !blocked.contains(&next) && visited.insert(next) {
+            queue.push_back((next, direction));
+        }
+    }
+
+    while let Some((position, first_step)) = queue.pop_front() {
+        if is_unexplored(view_model, position) {
+            return ExploreStep::Move(first_step);
+        }
+
+        for direction in CardinalDirection::all() {
+            let next = step(position, direction);
+            if is_passable(map, next) && !blocked.contains(&next) && visited.insert(next) {
+                queue.push_back((next, first_step));
+            }
+        }
+    }
+
+    ExploreStep::Complete
+}
+
+fn step(position: Position, direction: CardinalDirection) -> Position {
+    let (dx, dy) = direction.offset();
+    Position::new(position.x + dx, position.y + dy)
+}
+
+fn is_passable<M: MapOracle + ?Sized>(map: &M, position: Position) -> bool {
+    map.tile(position).is_some_and(|tile| tile.is_passable())
+}
+
+fn is_unexplored(view_model: &ViewModel, position: Position) -> bool {
+    view_model.visibility.get(&position) == Visibility::Unseen
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActionKind, ActorTemplate, CharacterAction, Faction,
+        GameConfig, GameEngine, GameState, TraitProfile, VisibilitySet,
+    };
+    use runtime::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+
+    use super::*;
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .actions([ActionAbility::new(ActionKind::Move)].into_iter().collect())
+            .build()
+    }
+
+    fn oracles(width: u32, height: u32) -> OracleBundle {
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(width, height)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        )
+    }
+
+    /// Spawns the player at (1, 1) and lets them look around, as a new session does.
+    fn spawn(oracles: &OracleBundle) -> GameState {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(1, 1))
+            .unwrap();
+        state.refresh_visibility(oracles.map.as_ref());
+        state
+    }
+
+    /// Executes the planned move through the engine and refreshes the view.
+    fn walk(
+        state: &mut GameState,
+        view_model: &mut ViewModel,
+        oracles: &OracleBundle,
+        direction: CardinalDirection,
+    ) {
+        state.turn.current_actor = EntityId::PLAYER;
+        state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(state.turn.clock);
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Move,
+            ActionInput::Direction(direction),
+        ));
+        GameEngine::new(state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+        view_model.rebuild_from_state(state, oracles.map.as_ref());
+    }
+
+    /// Walks until planning stops moving, checking that every step uncovers
+    /// new tiles, and returns why it stopped.
+    fn explore(
+        state: &mut GameState,
+        view_model: &mut ViewModel,
+        oracles: &OracleBundle,
+    ) -> ExploreStep {
+        let map = oracles.map.as_ref();
+        let mut remaining = unexplored_count(view_model, map);
+        loop {
+            match next_step(view_model, map) {
+                ExploreStep::Move(direction) => {
+                    walk(state, view_model, oracles, direction);
+                    let now = unexplored_count(view_model, map);
+                    assert!(now < remaining, "{} -> {}", remaining, now);
+                    remaining = now;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[test]
+    fn each_step_reduces_unexplored_tiles() {
+        let oracles = oracles(30, 3);
+        let mut state = spawn(&oracles);
+        let mut view_model = ViewModel::from_initial_state(&state, oracles.map.as_ref());
+        assert!(unexplored_count(&view_model, oracles.map.as_ref()) > 0);
+
+        let outcome = explore(&mut state, &mut view_model, &oracles);
+
+        assert_eq!(outcome, ExploreStep::Complete);
+        assert_eq!(unexplored_count(&view_model, oracles.map.as_ref()), 0);
+    }
+
+    #[test]
+    fn halts_when_an_enemy_comes_into_sight() {
+        let oracles = oracles(30, 3);
+        let mut state = spawn(&oracles);
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(20, 1))
+            .unwrap();
+        let mut view_model = ViewModel::from_initial_state(&state, oracles.map.as_ref());
+
+        let outcome = explore(&mut state, &mut view_model, &oracles);
+
+        assert_eq!(outcome, ExploreStep::EnemyInSight(goblin));
+        let player = view_model.player.position.unwrap();
+        assert_eq!(
+            player.chebyshev_distance(Position::new(20, 1)),
+            VisibilitySet::SIGHT_RADIUS
+        );
+        // Stays halted until the enemy leaves sight
+        assert_eq!(next_step(&view_model, oracles.map.as_ref()), outcome);
+    }
+
+    #[test]
+    fn walks_past_actors_that_are_not_hostile() {
+        let oracles = oracles(30, 3);
+        let mut state = spawn(&oracles);
+        state
+            .add_npc(&template(Faction::Friendly), Position::new(5, 1))
+            .unwrap();
+        let mut view_model = ViewModel::from_initial_state(&state, oracles.map.as_ref());
+
+        let outcome = explore(&mut state, &mut view_model, &oracles);
+
+        assert_eq!(outcome, ExploreStep::Complete);
+    }
+}
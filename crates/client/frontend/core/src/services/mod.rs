@@ -1,6 +1,8 @@
 //! Service layer for ViewModel updates and business logic.
 
+pub mod explore;
 pub mod targeting;
 pub mod updater;
 
+pub use explore::ExploreStep;
 pub use updater::{UpdateScope, ViewModelUpdater};
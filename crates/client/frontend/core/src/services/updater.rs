@@ -57,6 +57,9 @@ bitflags! {
         /// Map occupancy grid changed (entities moved).
         const OCCUPANCY   = 0b10000000;
 
+        /// The player's fog of war changed.
+        const VISIBILITY  = 0b100000000;
+
         /// All entity types changed.
         const ENTITIES = Self::ACTORS.bits() | Self::PROPS.bits() | Self::ITEMS.bits();
 
@@ -67,7 +70,8 @@ bitflags! {
                   | Self::ACTORS.bits()
                   | Self::PROPS.bits()
                   | Self::ITEMS.bits()
-                  | Self::OCCUPANCY.bits();
+                  | Self::OCCUPANCY.bits()
+                  | Self::VISIBILITY.bits();
     }
 }
 
@@ -167,12 +171,110 @@ impl ViewModelUpdater {
                 UpdateScope::empty()
             }
 
+            GameStateEvent::TurnTimedOut { .. } => {
+                // The substituted action reports its own changes when it executes
+                UpdateScope::empty()
+            }
+
+            GameStateEvent::HealthThresholdCrossed { .. } => {
+                // The HP change itself arrived with the preceding ActionExecuted
+                UpdateScope::empty()
+            }
+
+            GameStateEvent::EquipmentBroke { .. } => {
+                // The unequipped item arrived with the preceding ActionExecuted
+                UpdateScope::empty()
+            }
+
             GameStateEvent::StateRestored { .. } => {
                 // State was restored from checkpoint - full UI refresh needed
                 // Mark everything as changed for complete re-render
                 UpdateScope::all()
             }
+
+            GameStateEvent::RolledBack { state, .. } => {
+                // The last action was undone - rebuild from the restored state
+                view_model.rebuild_from_state(state, map_oracle);
+                UpdateScope::ALL
+            }
+
+            GameStateEvent::SessionHalted { .. } => {
+                // Session stopped - the last committed state is still current
+                UpdateScope::empty()
+            }
+        }
+    }
+
+    /// Scope [`update`](Self::update) would report for `event`, without
+    /// touching any ViewModel.
+    ///
+    /// Every section in the scope is rebuilt from the event's own state, so
+    /// of several events with the same scope only the last one needs to be
+    /// applied.
+    pub fn scope(event: &Event) -> UpdateScope {
+        match event {
+            Event::GameState(GameStateEvent::ActionExecuted { delta, .. }) => {
+                Self::delta_scope(delta)
+            }
+            Event::GameState(
+                GameStateEvent::StateRestored { .. } | GameStateEvent::RolledBack { .. },
+            ) => UpdateScope::all(),
+            Event::GameState(_) | Event::Proof(_) | Event::ActionRef(_) => UpdateScope::empty(),
+        }
+    }
+
+    /// Sections of the ViewModel that `delta` invalidates.
+    ///
+    /// An empty delta means "delta info not provided" and invalidates
+    /// everything (see [`apply_delta`](Self::apply_delta)).
+    fn delta_scope(delta: &StateDelta) -> UpdateScope {
+        if delta.is_empty() {
+            return UpdateScope::ALL;
+        }
+
+        let mut scope = UpdateScope::empty();
+
+        if !delta.turn.is_empty() {
+            scope |= UpdateScope::TURN;
+        }
+        if !delta.entities.actors.is_empty() {
+            scope |= UpdateScope::ACTORS;
+        }
+        if !delta.entities.props.is_empty() {
+            scope |= UpdateScope::PROPS;
+        }
+        if !delta.entities.items.is_empty() {
+            scope |= UpdateScope::ITEMS;
+        }
+
+        // NOTE: WorldChanges tracks occupancy (entity movements) and hazard
+        // overlays; the map view does not render hazards yet.
+        // Terrain is static and never changes during gameplay.
+        // Map widget computes entity positions dynamically from ViewModel.actors/props,
+        // so we don't need to rebuild MapView for occupancy changes.
+        //
+        // If terrain modifications are added in the future, WorldChanges should
+        // add a separate `terrain: Vec<TerrainChanges>` field, and we would
+        // rebuild the map only when terrain actually changes:
+        //
+        //   if !delta.world.terrain.is_empty() {
+        //       scope |= UpdateScope::MAP;
+        //   }
+        //
+        // For now, just flag occupancy change without rebuilding map:
+        if !delta.world.is_empty() {
+            scope |= UpdateScope::OCCUPANCY;
+        }
+        if delta.world.visibility {
+            scope |= UpdateScope::VISIBILITY;
+        }
+
+        // World summary follows entity changes
+        if scope.has_entity_changes() {
+            scope |= UpdateScope::WORLD;
         }
+
+        scope
     }
 
     /// Apply StateDelta to ViewModel with selective updates.
@@ -208,67 +310,38 @@ impl ViewModelUpdater {
         }
 
         // Delta is available → Selective updates for performance
-        let mut scope = UpdateScope::empty();
+        let scope = Self::delta_scope(delta);
 
-        // Update turn state if changed
-        if !delta.turn.is_empty() {
+        if scope.contains(UpdateScope::TURN) {
             view_model.turn.update_from_state(state);
-            scope |= UpdateScope::TURN;
         }
 
-        // Update entities if changed
-        if !delta.entities.is_empty() {
-            // Check if actors changed (player or NPCs)
-            if !delta.entities.actors.is_empty() {
-                view_model.actors = collect_actors(state);
-                view_model.player = view_model
-                    .actors
-                    .first()
-                    .expect("Player must exist")
-                    .clone();
-                scope |= UpdateScope::ACTORS;
-
-                #[cfg(debug_assertions)]
-                view_model.validate_invariants();
-            }
+        if scope.contains(UpdateScope::ACTORS) {
+            view_model.actors = collect_actors(state);
+            view_model.player = view_model
+                .actors
+                .first()
+                .expect("Player must exist")
+                .clone();
 
-            // Check if props changed
-            if !delta.entities.props.is_empty() {
-                view_model.props = collect_props(state);
-                scope |= UpdateScope::PROPS;
-            }
+            #[cfg(debug_assertions)]
+            view_model.validate_invariants();
+        }
 
-            // Check if items changed
-            if !delta.entities.items.is_empty() {
-                view_model.items = collect_items(state);
-                scope |= UpdateScope::ITEMS;
-            }
+        if scope.contains(UpdateScope::PROPS) {
+            view_model.props = collect_props(state);
         }
 
-        // Update world state if changed
-        if !delta.world.is_empty() {
-            // NOTE: Currently WorldChanges only tracks occupancy (entity movements).
-            // Terrain is static and never changes during gameplay.
-            // Map widget computes entity positions dynamically from ViewModel.actors/props,
-            // so we don't need to rebuild MapView for occupancy changes.
-            //
-            // If terrain modifications are added in the future, WorldChanges should
-            // add a separate `terrain: Vec<TerrainChanges>` field, and we would
-            // rebuild the map only when terrain actually changes:
-            //
-            //   if !delta.world.terrain.is_empty() {
-            //       view_model.map = MapView::from_state(map_oracle, state);
-            //       scope |= UpdateScope::MAP;
-            //   }
-            //
-            // For now, just flag occupancy change without rebuilding map:
-            scope |= UpdateScope::OCCUPANCY;
+        if scope.contains(UpdateScope::ITEMS) {
+            view_model.items = collect_items(state);
         }
 
-        // Update world summary only if entities changed
-        if scope.has_entity_changes() {
+        if scope.contains(UpdateScope::WORLD) {
             view_model.world.update_from_state(state);
-            scope |= UpdateScope::WORLD;
+        }
+
+        if scope.contains(UpdateScope::VISIBILITY) {
+            view_model.visibility = state.world.visibility.clone();
         }
 
         // Update sync nonce
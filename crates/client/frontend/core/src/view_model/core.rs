@@ -1,6 +1,6 @@
 //! Core ViewModel structure.
 
-use game_core::{GameState, env::MapOracle};
+use game_core::{GameState, VisibilitySet, env::MapOracle};
 
 use super::entities::{
     ActorView, ItemView, PropView, collect_actors, collect_items, collect_props,
@@ -42,6 +42,9 @@ pub struct ViewModel {
     /// Aggregate world statistics.
     pub world: WorldSummary,
 
+    /// What the player sees now and has seen before (fog of war).
+    pub visibility: VisibilitySet,
+
     /// Last synchronized GameState nonce for sync verification.
     pub last_sync_nonce: u64,
 }
@@ -71,6 +74,7 @@ impl ViewModel {
             props: collect_props(state),
             items: collect_items(state),
             world: WorldSummary::from_state(state),
+            visibility: state.world.visibility.clone(),
             last_sync_nonce: state.turn.nonce,
         };
 
@@ -95,6 +99,7 @@ impl ViewModel {
         self.props = collect_props(state);
         self.items = collect_items(state);
         self.world = WorldSummary::from_state(state);
+        self.visibility = state.world.visibility.clone();
         self.last_sync_nonce = state.turn.nonce;
 
         #[cfg(debug_assertions)]
@@ -5,8 +5,8 @@
 
 use arrayvec::ArrayVec;
 use game_core::{
-    ActionAbility, EntityId, GameConfig, ItemHandle, PassiveAbility, Position, PropKind, Tick,
-    stats::StatsSnapshot,
+    ActionAbility, EntityId, Equipment, Faction, GameConfig, ItemHandle, PassiveAbility, Position,
+    PropKind, StatusEffects, Tick, stats::StatsSnapshot,
 };
 
 /// Actor view (Player + NPCs) for rendering and targeting.
@@ -18,6 +18,7 @@ pub struct ActorView {
     pub id: EntityId,
     pub position: Option<Position>,
     pub is_player: bool,
+    pub faction: Faction,
     /// Complete stats snapshot from game-core.
     /// Use `.hp()`, `.mp()` methods to get current/max values.
     pub stats: StatsSnapshot,
@@ -29,6 +30,11 @@ pub struct ActorView {
     pub actions: ArrayVec<ActionAbility, { GameConfig::MAX_ACTIONS }>,
     /// Passive abilities that provide automatic benefits.
     pub passives: ArrayVec<PassiveAbility, { GameConfig::MAX_PASSIVES }>,
+    /// Status effects, including expired ones not yet pruned.
+    /// Use `.active_at(clock)` to list the ones in effect.
+    pub status_effects: StatusEffects,
+    /// Equipped weapon and armor.
+    pub equipment: Equipment,
 }
 
 impl ActorView {
@@ -37,10 +43,13 @@ impl ActorView {
             id: actor.id,
             position: actor.position,
             is_player: actor.id == EntityId::PLAYER,
+            faction: actor.faction,
             stats: actor.snapshot(),
             ready_at: actor.ready_at,
             actions: actor.actions.clone(),
             passives: actor.passives.clone(),
+            status_effects: actor.status_effects.clone(),
+            equipment: actor.equipment.clone(),
         }
     }
 }
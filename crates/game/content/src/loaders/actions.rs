@@ -1,10 +1,36 @@
 // Action profile loader
 //!
 //! Loads action profiles from RON data files.
+//!
+//! The built-in profiles are embedded at compile time. Designers can add or
+//! override abilities without recompiling by dropping extra RON files (a list
+//! of `ActionProfile`) into a directory loaded via
+//! [`ActionProfileRegistry::load_with_overrides`].
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use game_core::action::Requirement;
+use game_core::{ActionKind, ActionProfile, ActionTag, EffectKind, TargetingMode};
+
+use crate::loaders::{LoadResult, read_file};
 
-use game_core::ActionKind;
-use game_core::ActionProfile;
-use std::collections::HashMap;
+/// Embedded default profile files: (file name, RON source).
+const EMBEDDED_PROFILES: &[(&str, &str)] = &[
+    // Basic actions (Wait, Interact, UseItem, etc.)
+    ("basic.ron", include_str!("../../data/actions/basic.ron")),
+    // Movement actions (Move, Dash, etc.)
+    (
+        "movement.ron",
+        include_str!("../../data/actions/movement.ron"),
+    ),
+    // Attack actions (MeleeAttack, PowerAttack, RangedAttack, Lightning, etc.)
+    ("attack.ron", include_str!("../../data/actions/attack.ron")),
+    // Item actions (PickupItem, UseItem, etc.)
+    ("items.ron", include_str!("../../data/actions/items.ron")),
+    // Non-damaging spells (Summon, etc.)
+    ("magic.ron", include_str!("../../data/actions/magic.ron")),
+];
 
 /// Registry for action profiles.
 ///
@@ -17,41 +43,141 @@ pub struct ActionProfileRegistry {
 impl ActionProfileRegistry {
     /// Loads all action profiles from embedded RON data files.
     pub fn load() -> Result<Self, String> {
-        let mut profiles = HashMap::new();
-
-        // Load basic actions (Wait, Interact, UseItem, etc.)
-        let basic_ron = include_str!("../../data/actions/basic.ron");
-        let basic_profiles: Vec<ActionProfile> =
-            ron::from_str(basic_ron).map_err(|e| format!("Failed to parse basic.ron: {}", e))?;
-        for profile in basic_profiles {
-            profiles.insert(profile.kind, profile);
+        let mut registry = Self {
+            profiles: HashMap::new(),
+        };
+
+        for (name, source) in EMBEDDED_PROFILES {
+            registry
+                .extend_from_ron(source)
+                .map_err(|e| format!("Failed to parse {}: {}", name, e))?;
+        }
+
+        Ok(registry)
+    }
+
+    /// Loads the embedded profiles, then overlays every `.ron` file in `dir`.
+    ///
+    /// Files are applied in file name order so the result is deterministic.
+    /// A profile for an already registered kind replaces the previous one.
+    pub fn load_with_overrides(dir: &Path) -> LoadResult<Self> {
+        let mut registry = Self::load().map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut paths = std::fs::read_dir(dir)
+            .map_err(|e| anyhow::anyhow!("Failed to read directory {}: {}", dir.display(), e))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        for path in paths {
+            registry.extend(Self::load_file(&path)?);
         }
 
-        // Load movement actions (Move, Dash, etc.)
-        let movement_ron = include_str!("../../data/actions/movement.ron");
-        let movement_profiles: Vec<ActionProfile> = ron::from_str(movement_ron)
-            .map_err(|e| format!("Failed to parse movement.ron: {}", e))?;
-        for profile in movement_profiles {
-            profiles.insert(profile.kind, profile);
+        Ok(registry)
+    }
+
+    /// Loads and validates the profiles listed in a single RON file.
+    pub fn load_file(path: &Path) -> LoadResult<Vec<ActionProfile>> {
+        let content = read_file(path)?;
+        Self::parse_profiles(&content).map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))
+    }
+
+    /// Parses and validates a RON list of action profiles.
+    ///
+    /// Fails if any profile is invalid or a kind appears more than once.
+    pub fn parse_profiles(source: &str) -> LoadResult<Vec<ActionProfile>> {
+        let profiles: Vec<ActionProfile> = ron::from_str(source)
+            .map_err(|e| anyhow::anyhow!("Failed to parse action profiles RON: {}", e))?;
+
+        let mut seen = HashSet::new();
+        for profile in &profiles {
+            if !seen.insert(profile.kind) {
+                anyhow::bail!("Duplicate action profile for {:?}", profile.kind);
+            }
+            Self::validate_profile(profile)?;
         }
 
-        // Load attack actions (MeleeAttack, PowerAttack, RangedAttack, etc.)
-        let attack_ron = include_str!("../../data/actions/attack.ron");
-        let attack_profiles: Vec<ActionProfile> =
-            ron::from_str(attack_ron).map_err(|e| format!("Failed to parse attack.ron: {}", e))?;
-        for profile in attack_profiles {
-            profiles.insert(profile.kind, profile);
+        Ok(profiles)
+    }
+
+    /// Parses profiles from a RON string and registers them.
+    ///
+    /// Returns the number of profiles registered.
+    pub fn extend_from_ron(&mut self, source: &str) -> LoadResult<usize> {
+        let profiles = Self::parse_profiles(source)?;
+        let count = profiles.len();
+        self.extend(profiles);
+        Ok(count)
+    }
+
+    /// Registers a profile, returning the one it replaced (if any).
+    pub fn insert(&mut self, profile: ActionProfile) -> Option<ActionProfile> {
+        self.profiles.insert(profile.kind, profile)
+    }
+
+    /// Checks a profile for values the engine cannot execute sensibly.
+    ///
+    /// Rules:
+    /// - `base_cost` must be greater than 0
+    /// - Directional and line of sight targeting need a range of at least 1
+    /// - Line and cone targeting need a length of at least 1
+    /// - Chain targeting needs to strike at least 1 target
+    /// - Cooldowns and resource costs must be non-zero when present
+    /// - HP percentage requirements must be within 0-100
+    /// - Attack-tagged profiles must contain a Damage effect
+    pub fn validate_profile(profile: &ActionProfile) -> LoadResult<()> {
+        let kind = profile.kind;
+
+        if profile.base_cost == 0 {
+            anyhow::bail!("{:?}: base_cost must be greater than 0", kind);
         }
 
-        // Load item actions (PickupItem, UseItem, etc.)
-        let items_ron = include_str!("../../data/actions/items.ron");
-        let items_profiles: Vec<ActionProfile> =
-            ron::from_str(items_ron).map_err(|e| format!("Failed to parse items.ron: {}", e))?;
-        for profile in items_profiles {
-            profiles.insert(profile.kind, profile);
+        if let TargetingMode::Directional { range: 0, .. } = profile.targeting {
+            anyhow::bail!("{:?}: directional range must be at least 1", kind);
         }
 
-        Ok(Self { profiles })
+        if let TargetingMode::LineOfSight { max_range: 0 } = profile.targeting {
+            anyhow::bail!("{:?}: line of sight max_range must be at least 1", kind);
+        }
+
+        if let TargetingMode::Line { length: 0 } | TargetingMode::Cone { length: 0, .. } =
+            profile.targeting
+        {
+            anyhow::bail!("{:?}: line and cone length must be at least 1", kind);
+        }
+
+        if let TargetingMode::Chain { max_hops: 0, .. } = profile.targeting {
+            anyhow::bail!("{:?}: chain max_hops must be at least 1", kind);
+        }
+
+        if profile.cooldown == Some(0) {
+            anyhow::bail!("{:?}: cooldown must be omitted (None) instead of 0", kind);
+        }
+
+        if let Some(cost) = profile.resource_costs.iter().find(|c| c.amount == 0) {
+            anyhow::bail!("{:?}: resource cost for {:?} is 0", kind, cost.resource);
+        }
+
+        for requirement in &profile.requirements {
+            if let Requirement::MinHpPercent(percent) | Requirement::MaxHpPercent(percent) =
+                requirement
+                && *percent > 100
+            {
+                anyhow::bail!("{:?}: HP requirement {}% exceeds 100%", kind, percent);
+            }
+        }
+
+        let is_attack = profile.tags.contains(&ActionTag::Attack);
+        let has_damage = profile
+            .effects
+            .iter()
+            .any(|effect| matches!(effect.kind, EffectKind::Damage(_)));
+        if is_attack && !has_damage {
+            anyhow::bail!("{:?}: attack profile has no Damage effect", kind);
+        }
+
+        Ok(())
     }
 
     /// Gets an action profile by kind.
@@ -81,6 +207,14 @@ impl ActionProfileRegistry {
     }
 }
 
+impl Extend<ActionProfile> for ActionProfileRegistry {
+    fn extend<I: IntoIterator<Item = ActionProfile>>(&mut self, profiles: I) {
+        for profile in profiles {
+            self.insert(profile);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +242,63 @@ mod tests {
         let wait_profile = registry.get(ActionKind::Wait);
         assert_eq!(wait_profile.kind, ActionKind::Wait);
     }
+
+    const POWER_ATTACK: &str = r#"
+    ActionProfile(
+        kind: PowerAttack,
+        tags: [Attack, Melee, Physical],
+        targeting: SingleTarget(range: 1, requires_los: false),
+        base_cost: 250,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: Damage(DamageEffect(
+                    formula: Constant(42),
+                    damage_type: Physical,
+                    can_crit: false,
+                )),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: Some(500),
+    )
+    "#;
+
+    #[test]
+    fn test_custom_profile_overrides_default() {
+        let mut registry = ActionProfileRegistry::load().unwrap();
+        let count = registry
+            .extend_from_ron(&format!("[{}]", POWER_ATTACK))
+            .unwrap();
+
+        assert_eq!(count, 1);
+        let power = registry.get(ActionKind::PowerAttack);
+        assert_eq!(power.base_cost, 250);
+        assert_eq!(power.cooldown, Some(500));
+    }
+
+    #[test]
+    fn test_rejects_invalid_profiles() {
+        let zero_cost = POWER_ATTACK.replace("base_cost: 250", "base_cost: 0");
+        assert!(ActionProfileRegistry::parse_profiles(&format!("[{}]", zero_cost)).is_err());
+
+        let no_damage = r#"[
+            ActionProfile(
+                kind: PowerAttack,
+                tags: [Attack],
+                targeting: SingleTarget(range: 1, requires_los: false),
+                base_cost: 100,
+                resource_costs: [],
+                effects: [],
+                requirements: [],
+                cooldown: None,
+            ),
+        ]"#;
+        assert!(ActionProfileRegistry::parse_profiles(no_damage).is_err());
+
+        let duplicated = format!("[{0}, {0}]", POWER_ATTACK);
+        assert!(ActionProfileRegistry::parse_profiles(&duplicated).is_err());
+    }
 }
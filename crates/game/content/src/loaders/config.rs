@@ -21,9 +21,83 @@ impl ConfigLoader {
     /// Returns a GameConfig.
     pub fn load(path: &Path) -> LoadResult<GameConfig> {
         let content = read_file(path)?;
-        let config: GameConfig = toml::from_str(&content)
+        Self::parse(&content)
+    }
+
+    /// Parse config data from a TOML string.
+    ///
+    /// Missing keys keep their defaults. Faction relations are listed as
+    /// `[[faction_relations]]` tables with `from`, `to` and `relation` keys.
+    pub fn parse(content: &str) -> LoadResult<GameConfig> {
+        let config: GameConfig = toml::from_str(content)
             .map_err(|e| anyhow::anyhow!("Failed to parse config TOML: {}", e))?;
 
         Ok(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Faction, FactionRelations, Relation};
+
+    use super::*;
+
+    #[test]
+    fn test_loads_faction_relations() {
+        let config = ConfigLoader::parse(
+            r#"
+            activation_radius = 7
+
+            [[faction_relations]]
+            from = "GoblinClan"
+            to = "OrcHorde"
+            relation = "Allied"
+
+            [[faction_relations]]
+            from = "Wildlife"
+            to = "GoblinClan"
+            relation = "Hostile"
+            "#,
+        )
+        .unwrap();
+        let relations = &config.faction_relations;
+
+        assert_eq!(config.activation_radius, 7);
+        assert_eq!(
+            relations.relation(Faction::GoblinClan, Faction::OrcHorde),
+            Relation::Allied
+        );
+        // Entries are directed; the reverse pair keeps its built-in stance
+        assert_eq!(
+            relations.relation(Faction::OrcHorde, Faction::GoblinClan),
+            Relation::Hostile
+        );
+        assert_eq!(
+            relations.relation(Faction::Wildlife, Faction::GoblinClan),
+            Relation::Hostile
+        );
+        assert_eq!(
+            relations.relation(Faction::GoblinClan, Faction::Wildlife),
+            Relation::Neutral
+        );
+    }
+
+    #[test]
+    fn test_missing_relations_use_builtin_rules() {
+        let config = ConfigLoader::parse("").unwrap();
+
+        assert_eq!(config.faction_relations, FactionRelations::new());
+        assert_eq!(
+            config
+                .faction_relations
+                .relation(Faction::GoblinClan, Faction::GoblinClan),
+            Relation::Allied
+        );
+        assert_eq!(
+            config
+                .faction_relations
+                .relation(Faction::Player, Faction::UndeadLegion),
+            Relation::Hostile
+        );
+    }
+}
@@ -3,7 +3,8 @@
 use std::path::{Path, PathBuf};
 
 use crate::loaders::{
-    ActorLoader, ConfigLoader, ItemLoader, LoadResult, MapLoader, load_trait_registry,
+    ActionProfileRegistry, ActorLoader, ConfigLoader, ItemLoader, LoadResult, MapLoader,
+    load_trait_registry,
 };
 use crate::traits::TraitRegistry;
 
@@ -17,6 +18,9 @@ use crate::traits::TraitRegistry;
 /// ├── tables.toml
 /// ├── items.ron
 /// ├── npcs.ron
+/// ├── actions/
+/// │   ├── attack.ron
+/// │   └── custom.ron
 /// └── maps/
 ///     ├── test_dungeon.ron
 ///     └── boss_arena.ron
@@ -55,6 +59,18 @@ impl ContentFactory {
         load_trait_registry(&traits_dir)
     }
 
+    /// Load action profiles, overlaying `actions/*.ron` on the embedded defaults.
+    ///
+    /// Falls back to the embedded profiles when the `actions/` directory is absent.
+    pub fn load_action_profiles(&self) -> LoadResult<ActionProfileRegistry> {
+        let actions_dir = self.data_dir.join("actions");
+        if actions_dir.is_dir() {
+            ActionProfileRegistry::load_with_overrides(&actions_dir)
+        } else {
+            ActionProfileRegistry::load().map_err(|e| anyhow::anyhow!(e))
+        }
+    }
+
     /// Load actor catalog from `actors.ron`.
     ///
     /// Loads both players and NPCs with their templates and trait profiles.
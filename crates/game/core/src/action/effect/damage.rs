@@ -5,6 +5,12 @@ use crate::action::error::ActionError;
 use crate::action::execute::EffectContext;
 use crate::action::formula::{Formula, evaluate};
 use crate::action::types::{AppliedValue, DamageType};
+use crate::state::{ActorState, EntityId, Equipment, GameState, ItemHandle, PassiveKind};
+use crate::stats::compute_actor_bonuses;
+use crate::traits::Relation;
+
+/// Percentage of damage a `FireResistance`/`ColdResistance` passive absorbs.
+const ELEMENTAL_RESISTANCE_PERCENT: u32 = 50;
 
 /// Deal damage to target.
 #[derive(Clone, Debug, PartialEq)]
@@ -39,8 +45,9 @@ impl DamageEffect {
 
     /// Apply damage to target.
     pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
-        // 1. Evaluate formula
+        // 1. Evaluate formula, minus what the target's cover absorbs
         let planned = evaluate(&self.formula, ctx)?;
+        let planned = planned - planned * ctx.cover_reduction.min(100) / 100;
 
         // 2. Get target actor
         let actor = ctx
@@ -49,19 +56,41 @@ impl DamageEffect {
             .actor_mut(ctx.target)
             .ok_or(ActionError::TargetNotFound)?;
 
-        // 3. Calculate actual damage (capped at current HP)
-        // TODO: Apply resistance/armor based on damage_type
+        // 3. Apply the target's resistance to this damage type
+        let planned = planned - planned * resistance(actor, self.damage_type) / 100;
+
+        // 4. Calculate actual damage (capped at current HP, so HP floors at 0)
+        // The cap runs here, in the shared effect code, so host and zkVM
+        // executions record the same value.
+        // TODO: Apply armor
         // TODO: Check for critical hit based on can_crit flag
         let actual_damage = planned.min(actor.resources.hp);
 
-        // 4. Apply damage
+        // 5. Apply damage; any hit breaks an interruptible channel
         actor.resources.hp = actor.resources.hp.saturating_sub(actual_damage);
+        if actual_damage > 0 {
+            actor.interrupt_channel();
+        }
+
+        // 6. A landed hit wears down the armor absorbing it and the weapon dealing it
+        if actual_damage > 0 {
+            if self.damage_type == DamageType::Physical {
+                wear_down(ctx.state, ctx.target, Equipment::wear_armor);
+            }
+            if self.formula.uses_weapon() {
+                wear_down(ctx.state, ctx.caster, Equipment::wear_weapon);
+            }
+        }
+
+        // 7. The target retaliates against whoever hurt it
+        if actual_damage > 0 {
+            provoke(ctx)?;
+        }
 
-        // 5. Update accumulated damage in context
+        // 8. Update accumulated damage in context
         ctx.accumulated_damage += actual_damage;
 
         Ok(AppliedValue::Damage {
-            planned,
             actual: actual_damage,
         })
     }
@@ -76,3 +105,90 @@ impl DamageEffect {
         ExecutionPhase::Primary
     }
 }
+
+/// Marks the target as aggro'd towards the caster for the configured duration.
+///
+/// Only actors provoke: damage dealt by `SYSTEM` (hazards, damage over time)
+/// and self-inflicted damage leave the target's stance unchanged, as does
+/// damage from an actor the target's faction already treats as hostile.
+///
+/// # Errors
+///
+/// Returns `ActionError::EffectFailed` if every status effect slot of the
+/// target is held by an effect that is still running.
+fn provoke(ctx: &mut EffectContext) -> Result<(), ActionError> {
+    let Ok(config) = ctx.env.config() else {
+        return Ok(());
+    };
+    let duration = config.aggro_duration();
+    if duration == 0 || ctx.caster == ctx.target {
+        return Ok(());
+    }
+
+    let (Some(caster), Some(target)) = (
+        ctx.state.entities.actor(ctx.caster),
+        ctx.state.entities.actor(ctx.target),
+    ) else {
+        return Ok(());
+    };
+    let relation = config
+        .faction_relations()
+        .relation(target.faction, caster.faction);
+    if relation == Relation::Hostile {
+        return Ok(());
+    }
+
+    let clock = ctx.state.turn.clock;
+    let target = ctx
+        .state
+        .entities
+        .actor_mut(ctx.target)
+        .ok_or(ActionError::TargetNotFound)?;
+    if !target
+        .status_effects
+        .set_aggro(ctx.caster, clock + duration, clock)
+    {
+        return Err(ActionError::EffectFailed(format!(
+            "no status effect slot left for aggro on {:?}",
+            ctx.target
+        )));
+    }
+
+    Ok(())
+}
+
+/// Wears down one piece of the entity's equipment with `wear`.
+///
+/// A broken item is unequipped, so the entity's bonuses are recomputed.
+fn wear_down(
+    state: &mut GameState,
+    entity: EntityId,
+    wear: fn(&mut Equipment) -> Option<ItemHandle>,
+) {
+    if let Some(actor) = state.entities.actor_mut(entity)
+        && wear(&mut actor.equipment).is_some()
+    {
+        actor.bonuses = compute_actor_bonuses();
+    }
+}
+
+/// Percentage of `damage_type` damage the actor's passives absorb (100 = immune).
+///
+/// Damage types without a matching passive, such as `True`, are taken in full.
+fn resistance(actor: &ActorState, damage_type: DamageType) -> u32 {
+    match damage_type {
+        DamageType::Poison
+            if actor.has_passive(PassiveKind::PoisonImmunity)
+                || actor.has_passive(PassiveKind::Construct) =>
+        {
+            100
+        }
+        DamageType::Fire if actor.has_passive(PassiveKind::FireResistance) => {
+            ELEMENTAL_RESISTANCE_PERCENT
+        }
+        DamageType::Cold if actor.has_passive(PassiveKind::ColdResistance) => {
+            ELEMENTAL_RESISTANCE_PERCENT
+        }
+        _ => 0,
+    }
+}
@@ -0,0 +1,249 @@
+//! Equipment-related effect implementations.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue};
+use crate::env::ItemKind;
+use crate::state::{Equipment, ItemHandle};
+use crate::stats::compute_actor_bonuses;
+
+/// Equip a weapon from the caster's inventory.
+///
+/// This effect:
+/// 1. Gets the inventory slot from ActionInput::InventorySlot
+/// 2. Takes one weapon out of that slot and equips it
+/// 3. Puts the previously equipped weapon (if any) back into the inventory
+/// 4. Recomputes the caster's bonuses
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquipWeaponEffect;
+
+impl EquipWeaponEffect {
+    /// Create a new EquipWeapon effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the slot holds a weapon and the swap fits in the inventory.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        EquipSlot::Weapon.pre_validate(ctx)
+    }
+
+    /// Apply: swap the slot's weapon with the equipped one.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        EquipSlot::Weapon.apply(ctx)
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for EquipWeapon effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+}
+
+impl Default for EquipWeaponEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Equip armor from the caster's inventory.
+///
+/// Same flow as [`EquipWeaponEffect`], for the armor slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EquipArmorEffect;
+
+impl EquipArmorEffect {
+    /// Create a new EquipArmor effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the slot holds armor and the swap fits in the inventory.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        EquipSlot::Armor.pre_validate(ctx)
+    }
+
+    /// Apply: swap the slot's armor with the equipped one.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        EquipSlot::Armor.apply(ctx)
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for EquipArmor effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+}
+
+impl Default for EquipArmorEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Equipment slot targeted by an equip effect.
+#[derive(Clone, Copy)]
+enum EquipSlot {
+    Weapon,
+    Armor,
+}
+
+impl EquipSlot {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Weapon => "weapon",
+            Self::Armor => "armor",
+        }
+    }
+
+    fn accepts(self, kind: &ItemKind) -> bool {
+        match self {
+            Self::Weapon => matches!(kind, ItemKind::Weapon(_)),
+            Self::Armor => matches!(kind, ItemKind::Armor(_)),
+        }
+    }
+
+    fn equipped(self, equipment: &Equipment) -> Option<ItemHandle> {
+        match self {
+            Self::Weapon => equipment.weapon,
+            Self::Armor => equipment.armor,
+        }
+    }
+
+    fn equip(
+        self,
+        equipment: &mut Equipment,
+        handle: ItemHandle,
+        durability: Option<u16>,
+    ) -> Option<ItemHandle> {
+        match self {
+            Self::Weapon => equipment.equip_weapon(handle, durability),
+            Self::Armor => equipment.equip_armor(handle, durability),
+        }
+    }
+
+    fn pre_validate(self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let (slot, _, _) = self.item_in_slot(ctx)?;
+
+        let caster = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+
+        // The slot only frees up if this was its last item
+        let slot_frees_up = caster
+            .inventory
+            .get_slot(slot as usize)
+            .is_some_and(|item| item.quantity == 1);
+        if let Some(previous) = self.equipped(&caster.equipment)
+            && !slot_frees_up
+            && !caster
+                .inventory
+                .can_hold(previous, ctx.max_stack(previous)?)
+        {
+            return Err(ActionError::EffectFailed(format!(
+                "Inventory is full, cannot unequip current {}",
+                self.name()
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn apply(self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let (slot, handle, durability) = self.item_in_slot(ctx)?;
+        let previous_max_stack = match ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .and_then(|caster| self.equipped(&caster.equipment))
+        {
+            Some(previous) => ctx.max_stack(previous)?,
+            None => 1,
+        };
+
+        let caster = ctx
+            .state
+            .entities
+            .actor_mut(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+
+        caster
+            .inventory
+            .decrease_quantity(slot as usize, 1)
+            .map_err(|e| {
+                ActionError::EffectFailed(format!("Failed to take {}: {}", self.name(), e))
+            })?;
+
+        let previous = self.equip(&mut caster.equipment, handle, durability);
+        if let Some(previous) = previous
+            && caster.inventory.add_item(previous, 1, previous_max_stack) > 0
+        {
+            return Err(ActionError::EffectFailed(format!(
+                "Failed to stow previous {}: Inventory is full",
+                self.name()
+            )));
+        }
+
+        caster.bonuses = compute_actor_bonuses();
+
+        Ok(AppliedValue::ItemEquipped { handle, previous })
+    }
+
+    /// Resolves the input slot and checks it holds an item for this equipment slot.
+    ///
+    /// Returns the slot, the item's handle and the durability it is equipped with.
+    fn item_in_slot(
+        self,
+        ctx: &EffectContext,
+    ) -> Result<(u8, ItemHandle, Option<u16>), ActionError> {
+        let slot = match ctx.action_input {
+            ActionInput::InventorySlot(s) => *s,
+            _ => {
+                return Err(ActionError::EffectFailed(
+                    "Equip effects require InventorySlot input".to_string(),
+                ));
+            }
+        };
+
+        let caster = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        let handle = caster
+            .inventory
+            .get_slot(slot as usize)
+            .ok_or_else(|| ActionError::EffectFailed(format!("Inventory slot {} is empty", slot)))?
+            .handle;
+
+        let items_oracle = ctx
+            .env
+            .items()
+            .map_err(|_| ActionError::ItemsNotAvailable)?;
+        let item_def = items_oracle.definition(handle).ok_or_else(|| {
+            ActionError::EffectFailed(format!("Item definition not found for handle {:?}", handle))
+        })?;
+
+        if !self.accepts(&item_def.kind) {
+            return Err(ActionError::EffectFailed(format!(
+                "Item cannot be equipped as {}",
+                self.name()
+            )));
+        }
+
+        Ok((slot, handle, item_def.kind.durability()))
+    }
+}
@@ -0,0 +1,99 @@
+//! Hazard creation effect implementation.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue};
+use crate::state::{HazardOverlay, Position, Tick};
+
+/// Cover tiles with a temporary hazard (caltrops, fire wall, ...).
+///
+/// Every passable tile within `radius` (Chebyshev distance) of the centre
+/// gets a [`HazardOverlay`] dealing `damage` and expiring `duration` ticks
+/// after the effect. A hazard already on a tile is replaced.
+///
+/// The centre is the [`ActionInput::Position`] when one is given, otherwise
+/// the target's position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateHazardEffect {
+    /// Damage dealt on entering the tile or starting a turn on it.
+    pub damage: u32,
+    /// Ticks the hazard stays on the map.
+    pub duration: Tick,
+    /// Chebyshev radius around the centre (0 = single tile).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub radius: u32,
+}
+
+impl CreateHazardEffect {
+    /// Create a new CreateHazard effect.
+    pub fn new(damage: u32, duration: Tick, radius: u32) -> Self {
+        Self {
+            damage,
+            duration,
+            radius,
+        }
+    }
+
+    /// Pre-validate: Check the centre exists and at least one tile is covered.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        if self.tiles(ctx)?.is_empty() {
+            return Err(ActionError::Blocked);
+        }
+        Ok(())
+    }
+
+    /// Apply: place the hazard on every covered tile.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let tiles = self.tiles(ctx)?;
+        let hazard = HazardOverlay::new(
+            self.damage,
+            ctx.state.turn.clock.saturating_add(self.duration),
+        );
+
+        for &position in &tiles {
+            ctx.state.world.tile_map.set_hazard(position, hazard);
+        }
+
+        Ok(AppliedValue::HazardCreated {
+            tiles: tiles.len() as u32,
+        })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for CreateHazard effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    /// Passable in-bounds tiles around the centre, in row-major order.
+    fn tiles(&self, ctx: &EffectContext) -> Result<Vec<Position>, ActionError> {
+        let center = match ctx.action_input {
+            ActionInput::Position(position) => *position,
+            _ => ctx
+                .state
+                .actor_position(ctx.target)
+                .ok_or(ActionError::TargetNotFound)?,
+        };
+        let map = ctx.env.map().map_err(|_| ActionError::MapNotAvailable)?;
+        let radius = self.radius as i32;
+
+        let mut tiles = Vec::new();
+        for y in center.y - radius..=center.y + radius {
+            for x in center.x - radius..=center.x + radius {
+                let position = Position::new(x, y);
+                if map.dimensions().contains(position)
+                    && map.tile(position).is_some_and(|tile| tile.is_passable())
+                {
+                    tiles.push(position);
+                }
+            }
+        }
+        Ok(tiles)
+    }
+}
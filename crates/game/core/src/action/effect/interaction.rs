@@ -1,5 +1,11 @@
 //! Interaction types for world objects.
 
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue};
+use crate::state::EntityId;
+
 /// Type of interaction with world objects.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10,3 +16,97 @@ pub enum InteractionType {
     Use,
     Talk,
 }
+
+/// Operate a prop in the world (pull a lever, open or close a door).
+///
+/// This effect:
+/// 1. Gets the prop EntityId from ActionInput::Target
+/// 2. Updates the prop's `is_active` flag according to the interaction type:
+///    - `Use` toggles it (levers, switches)
+///    - `Open` sets it, `Close` clears it (doors)
+///
+/// `PickUp` and `Talk` have no meaning for props and are rejected.
+///
+/// **Important**: Like AcquireItem, this effect does NOT validate range; the
+/// action's SingleTarget range is checked during action validation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InteractEffect {
+    pub interaction_type: InteractionType,
+}
+
+impl InteractEffect {
+    /// Create a new Interact effect.
+    pub fn new(interaction_type: InteractionType) -> Self {
+        Self { interaction_type }
+    }
+
+    /// Pre-validate: Check the target is a prop and the interaction applies to props.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let prop_id = Self::target_prop(ctx)?;
+
+        ctx.state
+            .entities
+            .prop(prop_id)
+            .ok_or_else(|| ActionError::EffectFailed(format!("Prop {} not found", prop_id)))?;
+
+        if matches!(
+            self.interaction_type,
+            InteractionType::PickUp | InteractionType::Talk
+        ) {
+            return Err(ActionError::EffectFailed(format!(
+                "{:?} interaction is not supported for props",
+                self.interaction_type
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Apply the interaction: update the prop's active flag.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let prop_id = Self::target_prop(ctx)?;
+
+        let prop = ctx
+            .state
+            .entities
+            .prop_mut(prop_id)
+            .ok_or_else(|| ActionError::EffectFailed(format!("Prop {} not found", prop_id)))?;
+
+        prop.is_active = match self.interaction_type {
+            InteractionType::Use => !prop.is_active,
+            InteractionType::Open => true,
+            InteractionType::Close => false,
+            InteractionType::PickUp | InteractionType::Talk => {
+                return Err(ActionError::EffectFailed(format!(
+                    "{:?} interaction is not supported for props",
+                    self.interaction_type
+                )));
+            }
+        };
+
+        Ok(AppliedValue::PropInteracted {
+            prop_id,
+            is_active: prop.is_active,
+        })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for Interact effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    fn target_prop(ctx: &EffectContext) -> Result<EntityId, ActionError> {
+        match ctx.action_input {
+            ActionInput::Target(id) => Ok(*id),
+            _ => Err(ActionError::EffectFailed(
+                "InteractEffect requires Target input".to_string(),
+            )),
+        }
+    }
+}
@@ -9,8 +9,9 @@ use crate::action::types::{ActionInput, AppliedValue};
 ///
 /// This effect:
 /// 1. Gets the item EntityId from ActionInput::Target
-/// 2. Adds it to the caster's inventory
-/// 3. Removes the item entity from the world
+/// 2. Adds it to the caster's inventory, stacking up to the item's `max_stack`
+/// 3. Removes the item entity from the world, or leaves whatever did not fit
+///    on the ground
 ///
 /// This is a general-purpose effect for any scenario where an existing world item
 /// should be transferred to inventory (picking up drops, looting containers, etc.).
@@ -43,20 +44,23 @@ impl AcquireItemEffect {
         };
 
         // Check item exists in world
-        let _item = ctx
+        let item = ctx
             .state
             .entities
             .item(item_id)
             .ok_or_else(|| ActionError::EffectFailed(format!("Item {} not found", item_id)))?;
 
-        // Check caster has inventory space
+        // Check caster has room for at least one of the item
         let caster = ctx
             .state
             .entities
             .actor(ctx.caster)
             .ok_or(ActionError::ActorNotFound)?;
 
-        if caster.inventory.is_full() {
+        if !caster
+            .inventory
+            .can_hold(item.handle, ctx.max_stack(item.handle)?)
+        {
             return Err(ActionError::EffectFailed("Inventory is full".to_string()));
         }
 
@@ -85,6 +89,8 @@ impl AcquireItemEffect {
         // Store item data before removing from world
         let handle = item.handle;
         let quantity = item.quantity;
+        let position = item.position;
+        let max_stack = ctx.max_stack(handle)?;
 
         // Add to caster's inventory first (before removing from world)
         let caster = ctx
@@ -93,10 +99,21 @@ impl AcquireItemEffect {
             .actor_mut(ctx.caster)
             .ok_or(ActionError::ActorNotFound)?;
 
-        caster
-            .inventory
-            .add_item(handle, quantity)
-            .map_err(|e| ActionError::EffectFailed(format!("Failed to add to inventory: {}", e)))?;
+        let overflow = caster.inventory.add_item(handle, quantity, max_stack);
+        if overflow > 0 {
+            // The rest stays on the ground
+            let item =
+                ctx.state.entities.item_mut(item_id).ok_or_else(|| {
+                    ActionError::EffectFailed("Item not found in world".to_string())
+                })?;
+            item.quantity = overflow;
+
+            return Ok(AppliedValue::ItemAcquired {
+                item_id,
+                handle,
+                quantity: quantity - overflow,
+            });
+        }
 
         // Remove item entity from world
         let item_index = ctx
@@ -108,6 +125,7 @@ impl AcquireItemEffect {
             .ok_or_else(|| ActionError::EffectFailed("Item not found in world".to_string()))?;
 
         let _ = ctx.state.entities.items.remove(item_index);
+        ctx.state.world.tile_map.remove_occupant(&position, item_id);
 
         Ok(AppliedValue::ItemAcquired {
             item_id,
@@ -187,6 +205,15 @@ impl UseConsumableEffect {
             ));
         }
 
+        // Check the slot is not cooling down from a previous use
+        let clock = ctx.state.turn.clock;
+        if item_state.is_cooling_down(clock) {
+            return Err(ActionError::EffectFailed(format!(
+                "Inventory slot {} is cooling down until tick {} (now {})",
+                slot, item_state.cooldown_until, clock
+            )));
+        }
+
         Ok(())
     }
 
@@ -234,6 +261,12 @@ impl UseConsumableEffect {
             }
         };
 
+        let cooldown_until = ctx
+            .state
+            .turn
+            .clock
+            .saturating_add(consumable_data.cooldown);
+
         // Execute all consumable effects
         // Note: We're executing effects here, but they operate on the same EffectContext
         // This means the consumable effects will affect the caster (ctx.target = ctx.caster for consumables)
@@ -258,6 +291,14 @@ impl UseConsumableEffect {
                 ActionError::EffectFailed(format!("Failed to decrease item quantity: {}", e))
             })?;
 
+        // Start the cooldown on the remaining stack. A used-up slot is gone,
+        // so the handle is checked in case a later slot shifted into place.
+        if let Some(remaining) = caster_mut.inventory.get_slot_mut(slot as usize)
+            && remaining.handle == handle
+        {
+            remaining.cooldown_until = cooldown_until;
+        }
+
         Ok(AppliedValue::ItemUsed { slot, handle })
     }
 
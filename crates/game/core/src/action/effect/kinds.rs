@@ -12,11 +12,15 @@ use crate::stats::ResourceKind;
 use super::condition::Condition;
 use super::damage::DamageEffect;
 use super::displacement::Displacement;
-use super::interaction::InteractionType;
+use super::equipment::{EquipArmorEffect, EquipWeaponEffect};
+use super::hazard::CreateHazardEffect;
+use super::interaction::InteractEffect;
 use super::item::{AcquireItemEffect, UseConsumableEffect};
 use super::movement::{MoveSelfEffect, MoveTargetEffect, SwapEffect};
 use super::resource::{RestoreResourceEffect, SetResourceEffect};
+use super::revive::ReviveEffect;
 use super::status::{ApplyStatusEffect, ClearBuffsEffect, ClearDebuffsEffect, RemoveStatusEffect};
+use super::summon::SummonEffect;
 
 /// The actual effect to apply.
 ///
@@ -35,6 +39,7 @@ pub enum EffectKind {
     // ========================================================================
     RestoreResource(RestoreResourceEffect),
     SetResource(SetResourceEffect),
+    Revive(ReviveEffect),
 
     // ========================================================================
     // Status Effects
@@ -58,26 +63,31 @@ pub enum EffectKind {
     UseConsumable(UseConsumableEffect),
 
     // ========================================================================
-    // Complex/Unimplemented (keeping as enum variants for now)
+    // Equipment
     // ========================================================================
-    /// Summon entity.
-    Summon {
-        template_id: String,
-        count: u32,
-        duration: Option<Tick>,
-    },
+    EquipWeapon(EquipWeaponEffect),
+    EquipArmor(EquipArmorEffect),
 
+    // ========================================================================
+    // World Interaction
+    // ========================================================================
+    Interact(InteractEffect),
+    CreateHazard(CreateHazardEffect),
+
+    // ========================================================================
+    // Spawning
+    // ========================================================================
+    Summon(SummonEffect),
+
+    // ========================================================================
+    // Complex/Unimplemented (keeping as enum variants for now)
+    // ========================================================================
     /// Transform caster into different form.
     Transform {
         into_template: String,
         duration: Option<Tick>,
     },
 
-    /// Interact with world object.
-    Interact {
-        interaction_type: InteractionType,
-    },
-
     /// Conditional effect (if-then-else).
     Conditional {
         condition: Condition,
@@ -148,6 +158,7 @@ impl EffectKind {
             Self::Damage(e) => e.pre_validate(ctx),
             Self::RestoreResource(e) => e.pre_validate(ctx),
             Self::SetResource(e) => e.pre_validate(ctx),
+            Self::Revive(e) => e.pre_validate(ctx),
             Self::ApplyStatus(e) => e.pre_validate(ctx),
             Self::RemoveStatus(e) => e.pre_validate(ctx),
             Self::ClearDebuffs(e) => e.pre_validate(ctx),
@@ -157,13 +168,14 @@ impl EffectKind {
             Self::Swap(e) => e.pre_validate(ctx),
             Self::AcquireItem(e) => e.pre_validate(ctx),
             Self::UseConsumable(e) => e.pre_validate(ctx),
+            Self::EquipWeapon(e) => e.pre_validate(ctx),
+            Self::EquipArmor(e) => e.pre_validate(ctx),
+            Self::Interact(e) => e.pre_validate(ctx),
+            Self::CreateHazard(e) => e.pre_validate(ctx),
+            Self::Summon(e) => e.pre_validate(ctx),
 
             // Unimplemented effects - no validation yet
-            Self::Summon { .. }
-            | Self::Transform { .. }
-            | Self::Interact { .. }
-            | Self::Conditional { .. }
-            | Self::Repeat { .. } => Ok(()),
+            Self::Transform { .. } | Self::Conditional { .. } | Self::Repeat { .. } => Ok(()),
         }
     }
 
@@ -178,6 +190,7 @@ impl EffectKind {
             Self::Damage(e) => e.apply(ctx),
             Self::RestoreResource(e) => e.apply(ctx),
             Self::SetResource(e) => e.apply(ctx),
+            Self::Revive(e) => e.apply(ctx),
             Self::ApplyStatus(e) => e.apply(ctx),
             Self::RemoveStatus(e) => e.apply(ctx),
             Self::ClearDebuffs(e) => e.apply(ctx),
@@ -187,17 +200,16 @@ impl EffectKind {
             Self::Swap(e) => e.apply(ctx),
             Self::AcquireItem(e) => e.apply(ctx),
             Self::UseConsumable(e) => e.apply(ctx),
+            Self::EquipWeapon(e) => e.apply(ctx),
+            Self::EquipArmor(e) => e.apply(ctx),
+            Self::Interact(e) => e.apply(ctx),
+            Self::CreateHazard(e) => e.apply(ctx),
+            Self::Summon(e) => e.apply(ctx),
 
             // Unimplemented effects
-            Self::Summon { .. } => Err(crate::action::error::ActionError::NotImplemented(
-                "Summon effect not yet implemented".to_string(),
-            )),
             Self::Transform { .. } => Err(crate::action::error::ActionError::NotImplemented(
                 "Transform effect not yet implemented".to_string(),
             )),
-            Self::Interact { .. } => Err(crate::action::error::ActionError::NotImplemented(
-                "Interact effect not yet implemented".to_string(),
-            )),
             Self::Conditional { .. } => Err(crate::action::error::ActionError::NotImplemented(
                 "Conditional effect not yet implemented".to_string(),
             )),
@@ -218,6 +230,7 @@ impl EffectKind {
             Self::Damage(e) => e.post_validate(ctx),
             Self::RestoreResource(e) => e.post_validate(ctx),
             Self::SetResource(e) => e.post_validate(ctx),
+            Self::Revive(e) => e.post_validate(ctx),
             Self::ApplyStatus(e) => e.post_validate(ctx),
             Self::RemoveStatus(e) => e.post_validate(ctx),
             Self::ClearDebuffs(e) => e.post_validate(ctx),
@@ -227,13 +240,14 @@ impl EffectKind {
             Self::Swap(e) => e.post_validate(ctx),
             Self::AcquireItem(e) => e.post_validate(ctx),
             Self::UseConsumable(e) => e.post_validate(ctx),
+            Self::EquipWeapon(e) => e.post_validate(ctx),
+            Self::EquipArmor(e) => e.post_validate(ctx),
+            Self::Interact(e) => e.post_validate(ctx),
+            Self::CreateHazard(e) => e.post_validate(ctx),
+            Self::Summon(e) => e.post_validate(ctx),
 
             // Unimplemented effects - no validation yet
-            Self::Summon { .. }
-            | Self::Transform { .. }
-            | Self::Interact { .. }
-            | Self::Conditional { .. }
-            | Self::Repeat { .. } => Ok(()),
+            Self::Transform { .. } | Self::Conditional { .. } | Self::Repeat { .. } => Ok(()),
         }
     }
 }
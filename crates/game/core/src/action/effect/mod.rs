@@ -38,25 +38,35 @@
 mod condition;
 mod damage;
 mod displacement;
+mod equipment;
+mod hazard;
 mod interaction;
 mod item;
 mod kinds;
 mod movement;
+mod periodic;
 mod phase;
 mod resource;
+mod revive;
 mod status;
+mod summon;
 
 // Re-export core types
 pub use condition::Condition;
 pub use damage::DamageEffect;
 pub use displacement::Displacement;
-pub use interaction::InteractionType;
+pub use equipment::{EquipArmorEffect, EquipWeaponEffect};
+pub use hazard::CreateHazardEffect;
+pub use interaction::{InteractEffect, InteractionType};
 pub use item::{AcquireItemEffect, UseConsumableEffect};
 pub use kinds::EffectKind;
 pub use movement::{MoveSelfEffect, MoveTargetEffect, SwapEffect};
+pub(crate) use periodic::apply_damage_over_time;
 pub use phase::ExecutionPhase;
 pub use resource::{RestoreResourceEffect, SetResourceEffect};
+pub use revive::ReviveEffect;
 pub use status::{ApplyStatusEffect, ClearBuffsEffect, ClearDebuffsEffect, RemoveStatusEffect};
+pub use summon::SummonEffect;
 
 // ============================================================================
 // Action Effect (with phase and priority)
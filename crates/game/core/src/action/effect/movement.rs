@@ -1,10 +1,11 @@
 //! Movement effect implementations.
 
-use crate::action::effect::{Displacement, ExecutionPhase};
+use crate::action::effect::{DamageEffect, Displacement, EffectKind, ExecutionPhase};
 use crate::action::error::ActionError;
 use crate::action::execute::EffectContext;
+use crate::action::formula::Formula;
 use crate::action::types::{ActionInput, AppliedValue};
-use crate::state::Position;
+use crate::state::{EntityId, Position};
 
 /// Move the caster.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -23,7 +24,7 @@ impl MoveSelfEffect {
     ///
     /// This validates BEFORE any state changes:
     /// - Destination is within map bounds
-    /// - Destination tile is passable
+    /// - Destination tile is passable and not shut by a closed door
     /// - Destination is not occupied
     pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
         let destination = self.calculate_destination(ctx, ctx.caster)?;
@@ -56,6 +57,11 @@ impl MoveSelfEffect {
             .ok_or(ActionError::ActorNotFound)?
             .position = Some(to);
 
+        // Entering a hazard tile hurts
+        apply_terrain_hazard(ctx, ctx.caster, to)?;
+        ctx.state.apply_hazard(ctx.caster);
+        refresh_player_view(ctx, ctx.caster);
+
         Ok(AppliedValue::Movement { from, to })
     }
 
@@ -194,6 +200,11 @@ impl MoveTargetEffect {
             .ok_or(ActionError::TargetNotFound)?
             .position = Some(to);
 
+        // Being pushed onto a hazard tile hurts too
+        apply_terrain_hazard(ctx, ctx.target, to)?;
+        ctx.state.apply_hazard(ctx.target);
+        refresh_player_view(ctx, ctx.target);
+
         Ok(AppliedValue::Movement { from, to })
     }
 
@@ -350,7 +361,7 @@ fn validate_destination(
 
     // Check if tile is passable
     let tile = map.tile(destination).ok_or(ActionError::InvalidPosition)?;
-    if !tile.is_passable() {
+    if !tile.is_passable() || ctx.state.is_blocked(destination) {
         return Err(ActionError::Blocked);
     }
 
@@ -405,3 +416,48 @@ fn calculate_destination_away(from: Position, away_from: Position, distance: u32
 
     Position::new(new_x, new_y)
 }
+
+/// Deals the hazard damage of the terrain at `to` to `moved`.
+///
+/// The damage is dealt by `SYSTEM` through [`DamageEffect`] in a context of
+/// its own, so resistances and the HP floor apply as for damage over time,
+/// and the action's own `accumulated_damage` is left untouched. Actors that
+/// are already out of HP are skipped.
+fn apply_terrain_hazard(
+    ctx: &mut EffectContext,
+    moved: EntityId,
+    to: Position,
+) -> Result<(), ActionError> {
+    let map = ctx.env.map().map_err(|_| ActionError::MapNotAvailable)?;
+    let Some((damage_type, amount)) = map.tile(to).and_then(|tile| tile.hazard_damage()) else {
+        return Ok(());
+    };
+    let alive = ctx
+        .state
+        .entities
+        .actor(moved)
+        .is_some_and(|actor| actor.is_alive());
+    if !alive {
+        return Ok(());
+    }
+
+    let input = ActionInput::None;
+    let mut hazard = EffectContext::new(EntityId::SYSTEM, moved, ctx.state, ctx.env, &input);
+    let damage = EffectKind::Damage(DamageEffect::new(
+        Formula::Constant(u32::from(amount)),
+        damage_type,
+    ));
+    damage.apply(&mut hazard)?;
+
+    Ok(())
+}
+
+/// Recomputes the player's visibility after `moved` changed position.
+fn refresh_player_view(ctx: &mut EffectContext, moved: EntityId) {
+    if moved != EntityId::PLAYER {
+        return;
+    }
+    if let Ok(map) = ctx.env.map() {
+        ctx.state.refresh_visibility(map);
+    }
+}
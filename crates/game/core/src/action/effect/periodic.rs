@@ -0,0 +1,112 @@
+//! Damage-over-time from status effects.
+
+use crate::action::effect::{DamageEffect, EffectKind};
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::formula::Formula;
+use crate::action::types::{ActionInput, DamageType};
+use crate::env::GameEnv;
+use crate::state::{EntityId, GameState, StatusEffectKind};
+
+/// Damage type dealt each owner turn by a status effect, if it deals any.
+fn periodic_damage_type(kind: StatusEffectKind) -> Option<DamageType> {
+    match kind {
+        StatusEffectKind::Poisoned => Some(DamageType::Poison),
+        StatusEffectKind::Burning => Some(DamageType::Fire),
+        _ => None,
+    }
+}
+
+/// Deals one tick of every damage-over-time effect active on `entity`.
+///
+/// Each effect hits for its stored magnitude through [`EffectKind::Damage`],
+/// so resistances and the HP floor behave exactly as they do for attacks.
+/// The damage is dealt by `SYSTEM`. Actors that are already out of HP are
+/// skipped.
+///
+/// Returns the total damage dealt.
+pub(crate) fn apply_damage_over_time(
+    state: &mut GameState,
+    env: &GameEnv<'_>,
+    entity: EntityId,
+) -> Result<u32, ActionError> {
+    let clock = state.turn.clock;
+    let actor = state
+        .entities
+        .actor(entity)
+        .ok_or(ActionError::TargetNotFound)?;
+    if !actor.is_alive() {
+        return Ok(0);
+    }
+
+    let ticks: Vec<_> = actor
+        .status_effects
+        .active_at(clock)
+        .filter(|effect| effect.magnitude > 0)
+        .filter_map(|effect| {
+            periodic_damage_type(effect.kind).map(|damage_type| (damage_type, effect.magnitude))
+        })
+        .collect();
+    if ticks.is_empty() {
+        return Ok(0);
+    }
+
+    let input = ActionInput::None;
+    let mut ctx = EffectContext::new(EntityId::SYSTEM, entity, state, env, &input);
+    for (damage_type, magnitude) in ticks {
+        let damage =
+            EffectKind::Damage(DamageEffect::new(Formula::Constant(magnitude), damage_type));
+        damage.apply(&mut ctx)?;
+    }
+
+    Ok(ctx.accumulated_damage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PassiveAbility, PassiveKind};
+
+    fn burning_player(hp: u32, magnitude: u32) -> GameState {
+        let mut state = GameState::with_player();
+        let player = state.entities.player_mut();
+        player.resources.hp = hp;
+        player
+            .status_effects
+            .add_with_magnitude(StatusEffectKind::Burning, 100, magnitude);
+        state
+    }
+
+    #[test]
+    fn only_damaging_effects_have_a_damage_type() {
+        assert_eq!(
+            periodic_damage_type(StatusEffectKind::Burning),
+            Some(DamageType::Fire)
+        );
+        assert_eq!(
+            periodic_damage_type(StatusEffectKind::Poisoned),
+            Some(DamageType::Poison)
+        );
+        assert_eq!(periodic_damage_type(StatusEffectKind::Slowed), None);
+    }
+
+    #[test]
+    fn fire_resistance_halves_burn_damage() {
+        let mut state = burning_player(50, 10);
+        let player = EntityId::PLAYER;
+
+        let dealt = apply_damage_over_time(&mut state, &GameEnv::empty(), player).unwrap();
+        assert_eq!(dealt, 10);
+
+        let mut state = burning_player(50, 10);
+        let _ = state
+            .entities
+            .player_mut()
+            .passives
+            .try_push(PassiveAbility::new(PassiveKind::FireResistance));
+
+        let dealt = apply_damage_over_time(&mut state, &GameEnv::empty(), player).unwrap();
+        assert_eq!(dealt, 5);
+        assert_eq!(state.entities.player().resources.hp, 45);
+    }
+}
@@ -0,0 +1,85 @@
+//! Revive effect implementation.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::AppliedValue;
+use crate::stats::ResourceKind;
+
+/// Bring a downed ally back to a fraction of its max HP.
+///
+/// A downed actor has 0 HP but is still on the map (see
+/// [`ActorState::is_downed`](crate::state::ActorState::is_downed)). Only
+/// actors not hostile to the caster can be revived. The revived actor is
+/// scheduled again by the next activation pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviveEffect {
+    /// Percentage of max HP restored (at least 1 HP).
+    pub hp_percent: u32,
+}
+
+impl ReviveEffect {
+    /// Create a new Revive effect.
+    pub fn new(hp_percent: u32) -> Self {
+        Self { hp_percent }
+    }
+
+    /// Pre-validate: Check the target is a downed ally of the caster.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let target = ctx
+            .state
+            .entities
+            .actor(ctx.target)
+            .ok_or(ActionError::TargetNotFound)?;
+        if !target.is_downed() {
+            return Err(ActionError::InvalidTarget);
+        }
+
+        let caster = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        if caster.faction.is_hostile_to(&target.faction) {
+            return Err(ActionError::InvalidTarget);
+        }
+
+        Ok(())
+    }
+
+    /// Apply: set the target's HP to `hp_percent` of its max.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let actor = ctx
+            .state
+            .entities
+            .actor_mut(ctx.target)
+            .ok_or(ActionError::TargetNotFound)?;
+
+        let max = actor.snapshot().resource_max.get(ResourceKind::Hp);
+        let restored = (max.saturating_mul(self.hp_percent) / 100).clamp(1, max.max(1));
+        actor.resources.hp = restored;
+
+        ctx.accumulated_healing += restored;
+
+        Ok(AppliedValue::Healing {
+            planned: restored,
+            actual: restored,
+        })
+    }
+
+    /// Post-validate: The target must be standing again.
+    pub fn post_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        match ctx.state.entities.actor(ctx.target) {
+            Some(actor) if actor.is_alive() => Ok(()),
+            _ => Err(ActionError::EffectFailed(
+                "revived actor is still down".to_string(),
+            )),
+        }
+    }
+
+    /// Get default execution phase for Revive effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+}
@@ -0,0 +1,141 @@
+//! Summon effect implementation.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue, CardinalDirection};
+use crate::state::types::StatusEffectKind;
+use crate::state::{Position, Tick};
+
+/// Spawn a temporary minion next to the caster.
+///
+/// The minion is built from the actor template `def_id`, joins the caster's
+/// faction and is active immediately. It carries a
+/// [`StatusEffectKind::Lifespan`] expiring `lifespan` ticks after the summon.
+///
+/// The spawn tile comes from an [`ActionInput::Position`] adjacent to the
+/// caster; without one, the first free neighbour in
+/// [`CardinalDirection::all`] order is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummonEffect {
+    /// Actor template to spawn.
+    pub def_id: String,
+    /// Ticks the minion stays in the world.
+    pub lifespan: Tick,
+}
+
+impl SummonEffect {
+    /// Create a new Summon effect.
+    pub fn new(def_id: impl Into<String>, lifespan: Tick) -> Self {
+        Self {
+            def_id: def_id.into(),
+            lifespan,
+        }
+    }
+
+    /// Pre-validate: Check the template exists and a spawn tile is free.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        self.template(ctx)?;
+        spawn_position(ctx).map(|_| ())
+    }
+
+    /// Apply: spawn the minion and make it active.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let template = self.template(ctx)?;
+        let position = spawn_position(ctx)?;
+        let faction = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?
+            .faction;
+
+        let entity_id = ctx
+            .state
+            .add_npc(&template, position)
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+
+        let clock = ctx.state.turn.clock;
+        let minion = ctx
+            .state
+            .entities
+            .actor_mut(entity_id)
+            .ok_or(ActionError::ActorNotFound)?;
+        minion.faction = faction;
+        minion.ready_at = Some(clock);
+        minion.status_effects.add(
+            StatusEffectKind::Lifespan,
+            clock.saturating_add(self.lifespan),
+        );
+        ctx.state.turn.active_actors.insert(entity_id);
+
+        Ok(AppliedValue::Summon { entity_id })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for Summon effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    fn template(&self, ctx: &EffectContext) -> Result<crate::env::ActorTemplate, ActionError> {
+        ctx.env
+            .actors()
+            .map_err(|_| ActionError::EffectFailed("actor oracle not available".to_string()))?
+            .template(&self.def_id)
+            .ok_or_else(|| ActionError::EffectFailed(format!("unknown actor '{}'", self.def_id)))
+    }
+}
+
+/// Picks the tile the minion appears on.
+fn spawn_position(ctx: &EffectContext) -> Result<Position, ActionError> {
+    let origin = ctx
+        .state
+        .actor_position(ctx.caster)
+        .ok_or(ActionError::ActorNotFound)?;
+
+    if let ActionInput::Position(position) = ctx.action_input {
+        if origin.chebyshev_distance(*position) != 1 {
+            return Err(ActionError::OutOfRange);
+        }
+        return check_free(ctx, *position).map(|_| *position);
+    }
+
+    CardinalDirection::all()
+        .into_iter()
+        .map(|dir| {
+            let (dx, dy) = dir.offset();
+            Position::new(origin.x + dx, origin.y + dy)
+        })
+        .find(|&position| check_free(ctx, position).is_ok())
+        .ok_or(ActionError::Occupied)
+}
+
+/// A spawn tile must be on the map, passable and free of actors.
+fn check_free(ctx: &EffectContext, position: Position) -> Result<(), ActionError> {
+    let map = ctx.env.map().map_err(|_| ActionError::MapNotAvailable)?;
+    if !map.dimensions().contains(position) {
+        return Err(ActionError::OutOfBounds);
+    }
+
+    let tile = map.tile(position).ok_or(ActionError::InvalidPosition)?;
+    if !tile.is_passable() {
+        return Err(ActionError::Blocked);
+    }
+
+    if ctx
+        .state
+        .entities
+        .all_actors()
+        .any(|actor| actor.position == Some(position))
+    {
+        return Err(ActionError::Occupied);
+    }
+
+    Ok(())
+}
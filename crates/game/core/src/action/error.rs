@@ -45,6 +45,10 @@ pub enum ActionError {
     #[error("Out of range")]
     OutOfRange,
 
+    /// Something blocks sight between the actor and the target.
+    #[error("Line of sight to target is blocked")]
+    LineOfSightBlocked,
+
     /// Position is out of map bounds.
     #[error("Position out of bounds")]
     OutOfBounds,
@@ -106,6 +110,7 @@ impl GameError for ActionError {
             ActorDead | NotActorsTurn | ActorNotReady => ErrorSeverity::Recoverable,
             InvalidTarget | OutOfRange | OutOfBounds => ErrorSeverity::Validation,
             InvalidPosition | Blocked | Occupied => ErrorSeverity::Recoverable,
+            LineOfSightBlocked => ErrorSeverity::Recoverable,
             MapNotAvailable | ItemsNotAvailable => ErrorSeverity::Fatal,
             InsufficientResources | OnCooldown | ActionNotAvailable => ErrorSeverity::Recoverable,
             RequirementsNotMet(_) => ErrorSeverity::Validation,
@@ -125,6 +130,7 @@ impl GameError for ActionError {
             ProfileNotFound => "ACTION_PROFILE_NOT_FOUND",
             InvalidTarget => "ACTION_INVALID_TARGET",
             OutOfRange => "ACTION_OUT_OF_RANGE",
+            LineOfSightBlocked => "ACTION_LINE_OF_SIGHT_BLOCKED",
             OutOfBounds => "ACTION_OUT_OF_BOUNDS",
             InvalidPosition => "ACTION_INVALID_POSITION",
             Blocked => "ACTION_BLOCKED",
@@ -327,6 +333,92 @@ impl GameError for DeactivateError {
     }
 }
 
+/// Errors that can occur when ticking an actor's status effects.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickStatusError {
+    /// System actor validation failed.
+    #[error("tick status effects action must be executed by SYSTEM actor")]
+    NotSystemActor {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Entity not found in game state.
+    #[error("entity {entity} not found in game state")]
+    EntityNotFound {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// A damage-over-time effect could not be applied.
+    #[error("damage over time on entity {entity} failed: {error}")]
+    DamageOverTime {
+        entity: EntityId,
+        error: ActionError,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+}
+
+impl TickStatusError {
+    /// Creates a NotSystemActor error with context.
+    pub fn not_system_actor(nonce: u64) -> Self {
+        Self::NotSystemActor {
+            context: ErrorContext::new(nonce)
+                .with_message("system action executed by non-system actor"),
+        }
+    }
+
+    /// Creates an EntityNotFound error with context.
+    pub fn entity_not_found(entity: EntityId, nonce: u64) -> Self {
+        Self::EntityNotFound {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("entity not found"),
+        }
+    }
+
+    /// Creates a DamageOverTime error with context.
+    pub fn damage_over_time(entity: EntityId, error: ActionError, nonce: u64) -> Self {
+        Self::DamageOverTime {
+            entity,
+            error,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("damage over time failed"),
+        }
+    }
+}
+
+impl GameError for TickStatusError {
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NotSystemActor { .. } => ErrorSeverity::Validation,
+            Self::EntityNotFound { .. } => ErrorSeverity::Validation,
+            Self::DamageOverTime { error, .. } => error.severity(),
+        }
+    }
+
+    fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::NotSystemActor { context } => Some(context),
+            Self::EntityNotFound { context, .. } => Some(context),
+            Self::DamageOverTime { context, .. } => Some(context),
+        }
+    }
+
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotSystemActor { .. } => "TICK_STATUS_NOT_SYSTEM_ACTOR",
+            Self::EntityNotFound { .. } => "TICK_STATUS_ENTITY_NOT_FOUND",
+            Self::DamageOverTime { .. } => "TICK_STATUS_DAMAGE_OVER_TIME_FAILED",
+        }
+    }
+}
+
 /// Errors that can occur when removing entity from world.
 #[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -403,3 +495,240 @@ impl GameError for RemoveFromWorldError {
         }
     }
 }
+
+/// Errors that can occur when resolving an opportunity attack.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpportunityAttackError {
+    /// System actor validation failed.
+    #[error("opportunity attack action must be executed by SYSTEM actor")]
+    NotSystemActor {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Attacker or target is missing or already dead.
+    #[error("entity {entity} not found in game state or dead")]
+    EntityNotFound {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// The attacker cannot react: not hostile, not adjacent to the vacated
+    /// tile, or its melee attack is not ready.
+    #[error("entity {attacker} cannot react to entity {target}")]
+    NoReaction {
+        attacker: EntityId,
+        target: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// The reaction's melee attack could not be applied.
+    #[error("opportunity attack by entity {attacker} failed: {error}")]
+    Strike {
+        attacker: EntityId,
+        error: ActionError,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+}
+
+impl OpportunityAttackError {
+    /// Creates a NotSystemActor error with context.
+    pub fn not_system_actor(nonce: u64) -> Self {
+        Self::NotSystemActor {
+            context: ErrorContext::new(nonce)
+                .with_message("system action executed by non-system actor"),
+        }
+    }
+
+    /// Creates an EntityNotFound error with context.
+    pub fn entity_not_found(entity: EntityId, nonce: u64) -> Self {
+        Self::EntityNotFound {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("entity not found or dead"),
+        }
+    }
+
+    /// Creates a NoReaction error with context.
+    pub fn no_reaction(
+        attacker: EntityId,
+        target: EntityId,
+        reason: &'static str,
+        nonce: u64,
+    ) -> Self {
+        Self::NoReaction {
+            attacker,
+            target,
+            context: ErrorContext::new(nonce)
+                .with_actor(attacker)
+                .with_message(reason),
+        }
+    }
+
+    /// Creates a Strike error with context.
+    pub fn strike(attacker: EntityId, error: ActionError, nonce: u64) -> Self {
+        Self::Strike {
+            attacker,
+            error,
+            context: ErrorContext::new(nonce)
+                .with_actor(attacker)
+                .with_message("opportunity attack failed"),
+        }
+    }
+}
+
+impl GameError for OpportunityAttackError {
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NotSystemActor { .. } => ErrorSeverity::Validation,
+            Self::EntityNotFound { .. } => ErrorSeverity::Validation,
+            Self::NoReaction { .. } => ErrorSeverity::Validation,
+            Self::Strike { error, .. } => error.severity(),
+        }
+    }
+
+    fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::NotSystemActor { context } => Some(context),
+            Self::EntityNotFound { context, .. } => Some(context),
+            Self::NoReaction { context, .. } => Some(context),
+            Self::Strike { context, .. } => Some(context),
+        }
+    }
+
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotSystemActor { .. } => "OPPORTUNITY_ATTACK_NOT_SYSTEM_ACTOR",
+            Self::EntityNotFound { .. } => "OPPORTUNITY_ATTACK_ENTITY_NOT_FOUND",
+            Self::NoReaction { .. } => "OPPORTUNITY_ATTACK_NO_REACTION",
+            Self::Strike { .. } => "OPPORTUNITY_ATTACK_STRIKE_FAILED",
+        }
+    }
+}
+
+/// Errors that can occur when dropping a dead actor's inventory.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropLootError {
+    /// System actor validation failed.
+    #[error("drop loot action must be executed by SYSTEM actor")]
+    NotSystemActor {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Entity not found in game state.
+    #[error("entity {entity} not found in game state")]
+    EntityNotFound {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Entity has no position to drop its items at.
+    #[error("entity {entity} is not in the world")]
+    NotInWorld {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Map oracle unavailable, so drop tiles cannot be checked.
+    #[error("map not available")]
+    MapNotAvailable {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// No entity ids are left for the dropped items.
+    #[error("entity ids exhausted while dropping loot of {entity}")]
+    EntityIdsExhausted {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+}
+
+impl DropLootError {
+    /// Creates a NotSystemActor error with context.
+    pub fn not_system_actor(nonce: u64) -> Self {
+        Self::NotSystemActor {
+            context: ErrorContext::new(nonce)
+                .with_message("system action executed by non-system actor"),
+        }
+    }
+
+    /// Creates an EntityNotFound error with context.
+    pub fn entity_not_found(entity: EntityId, nonce: u64) -> Self {
+        Self::EntityNotFound {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("entity not found"),
+        }
+    }
+
+    /// Creates a NotInWorld error with context.
+    pub fn not_in_world(entity: EntityId, nonce: u64) -> Self {
+        Self::NotInWorld {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("entity has no position"),
+        }
+    }
+
+    /// Creates a MapNotAvailable error with context.
+    pub fn map_not_available(nonce: u64) -> Self {
+        Self::MapNotAvailable {
+            context: ErrorContext::new(nonce).with_message("map oracle not available"),
+        }
+    }
+
+    /// Creates an EntityIdsExhausted error with context.
+    pub fn entity_ids_exhausted(entity: EntityId, nonce: u64) -> Self {
+        Self::EntityIdsExhausted {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("entity id allocator exhausted"),
+        }
+    }
+}
+
+impl GameError for DropLootError {
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NotSystemActor { .. } => ErrorSeverity::Validation,
+            Self::EntityNotFound { .. } => ErrorSeverity::Validation,
+            Self::NotInWorld { .. } => ErrorSeverity::Validation,
+            Self::MapNotAvailable { .. } => ErrorSeverity::Fatal,
+            Self::EntityIdsExhausted { .. } => ErrorSeverity::Fatal,
+        }
+    }
+
+    fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::NotSystemActor { context } => Some(context),
+            Self::EntityNotFound { context, .. } => Some(context),
+            Self::NotInWorld { context, .. } => Some(context),
+            Self::MapNotAvailable { context } => Some(context),
+            Self::EntityIdsExhausted { context, .. } => Some(context),
+        }
+    }
+
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotSystemActor { .. } => "DROP_LOOT_NOT_SYSTEM_ACTOR",
+            Self::EntityNotFound { .. } => "DROP_LOOT_ENTITY_NOT_FOUND",
+            Self::NotInWorld { .. } => "DROP_LOOT_NOT_IN_WORLD",
+            Self::MapNotAvailable { .. } => "DROP_LOOT_MAP_NOT_AVAILABLE",
+            Self::EntityIdsExhausted { .. } => "DROP_LOOT_ENTITY_IDS_EXHAUSTED",
+        }
+    }
+}
@@ -7,7 +7,7 @@
 use crate::action::effect::ActionEffect;
 use crate::action::types::{ActionInput, EffectResult};
 use crate::env::GameEnv;
-use crate::state::{EntityId, GameState};
+use crate::state::{EntityId, GameState, ItemHandle};
 
 use crate::action::error::ActionError;
 
@@ -35,6 +35,14 @@ pub struct EffectContext<'a> {
     /// User/AI input for this action (e.g., direction, position).
     pub action_input: &'a ActionInput,
 
+    /// Position of the target in a chain (0 = initial target).
+    ///
+    /// Always 0 unless the action uses `TargetingMode::Chain`.
+    pub hop: u32,
+
+    /// Percentage of damage absorbed by the target's cover (0 = exposed).
+    pub cover_reduction: u32,
+
     /// Total damage dealt in this action so far.
     pub accumulated_damage: u32,
 
@@ -60,11 +68,37 @@ impl<'a> EffectContext<'a> {
             state,
             env,
             action_input,
+            hop: 0,
+            cover_reduction: 0,
             accumulated_damage: 0,
             accumulated_healing: 0,
             was_critical: false,
         }
     }
+
+    /// Sets the target's position in a chain.
+    pub fn with_hop(mut self, hop: u32) -> Self {
+        self.hop = hop;
+        self
+    }
+
+    /// Sets the damage reduction granted by the target's cover.
+    pub fn with_cover(mut self, cover_reduction: u32) -> Self {
+        self.cover_reduction = cover_reduction;
+        self
+    }
+
+    /// Looks up how many of `handle` stack in one inventory slot.
+    pub fn max_stack(&self, handle: ItemHandle) -> Result<u16, ActionError> {
+        let items_oracle = self
+            .env
+            .items()
+            .map_err(|_| ActionError::ItemsNotAvailable)?;
+        let item_def = items_oracle.definition(handle).ok_or_else(|| {
+            ActionError::EffectFailed(format!("Item definition not found for handle {:?}", handle))
+        })?;
+        Ok(item_def.max_stack)
+    }
 }
 
 // ============================================================================
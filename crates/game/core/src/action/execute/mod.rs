@@ -51,6 +51,7 @@ use crate::state::GameState;
 // ============================================================================
 
 pub use context::EffectContext;
+pub use pipeline::resolve_targets;
 
 // ============================================================================
 // Public API
@@ -63,12 +64,13 @@ pub use context::EffectContext;
 /// - Action profile exists in oracle
 /// - Target is valid for the targeting mode
 /// - Target is in range
+/// - Target is in line of sight, for `TargetingMode::LineOfSight`
 ///
 /// ## Future Validations
 /// - Resource costs (lucidity, mana)
 /// - Cooldowns
 /// - Requirements (items, status, etc.)
-/// - Line of sight
+/// - Line of sight for `SingleTarget` and `Chain` with `requires_los`
 ///
 /// ## Errors
 /// - `ActionError::ActorNotFound` - Actor doesn't exist
@@ -77,6 +79,7 @@ pub use context::EffectContext;
 /// - `ActionError::InvalidTarget` - Wrong target type for action
 /// - `ActionError::TargetNotFound` - Target doesn't exist
 /// - `ActionError::OutOfRange` - Target too far away
+/// - `ActionError::LineOfSightBlocked` - Terrain blocks the shot
 pub fn pre_validate(
     action: &CharacterAction,
     state: &GameState,
@@ -114,6 +117,20 @@ pub fn apply(
     pipeline::apply(action, state, env)
 }
 
+/// Apply a reaction: Execute effects without spending the actor's time.
+///
+/// Used by system actions that make an actor strike outside its turn, such
+/// as opportunity attacks. Targets and effects are handled as in [`apply`]
+/// and the action's cooldown starts, but the actor's `ready_at` is unchanged.
+/// Callers are responsible for validating the reaction.
+pub(crate) fn apply_reaction(
+    action: &CharacterAction,
+    state: &mut GameState,
+    env: &GameEnv<'_>,
+) -> Result<ActionResult, ActionError> {
+    pipeline::apply_reaction(action, state, env)
+}
+
 /// Post-validation: Check invariants after execution.
 ///
 /// This validates:
@@ -15,10 +15,13 @@
 //! - **Composable**: Effects execute independently with shared context
 //! - **Fail-fast**: Any error stops execution and propagates up
 
-use crate::action::TargetingMode;
-use crate::action::types::{ActionInput, ActionResult, CharacterAction};
-use crate::env::GameEnv;
-use crate::state::{EntityId, GameState};
+use crate::action::types::{
+    ActionInput, ActionResult, CardinalDirection, CharacterAction, EffectResult,
+};
+use crate::action::{ActionKind, ActionProfile, ActionTag, TargetingMode};
+use crate::env::{GameEnv, MapOracle};
+use crate::state::{EntityId, GameState, Position};
+use crate::stats::apply_movement_multiplier;
 
 use super::context::{EffectContext, apply_effect};
 use crate::action::error::ActionError;
@@ -36,11 +39,14 @@ use crate::action::error::ActionError;
 /// 4. For each target:
 ///    - Sort effects by phase (PreEffect → Primary → PostEffect → Finalize)
 ///    - Within same phase, sort by priority (higher first)
-///    - Create `EffectContext` with mutable state access
+///    - Create `EffectContext` with mutable state access (and the target's
+///      cover, for `Ranged` actions)
 ///    - Apply each effect via `apply_effect`
 ///    - Collect `EffectResult` for each effect
-/// 5. Apply action cost to actor's ready_at timestamp
-/// 6. Build and return `ActionResult` with all effect results
+/// 5. Apply action cost to actor's ready_at timestamp (for `Move`, scaled by
+///    the movement cost multiplier of the terrain entered)
+/// 6. Start the action's cooldown (`cooldown_until = clock + cooldown`)
+/// 7. Build and return `ActionResult` with all effect results
 ///
 /// ## Phase Execution Order
 /// - `PreEffect` (0): Setup, positioning, buffs
@@ -77,14 +83,89 @@ pub(super) fn apply(
         .map_err(|_| ActionError::ProfileNotFound)?
         .action_profile(action.kind);
 
+    // 4-6. Resolve targets and execute effects for each
+    let effect_results = apply_effects(action, &profile, state, env)?;
+
+    // Moving onto difficult terrain takes longer
+    let cost = if action.kind == ActionKind::Move {
+        apply_movement_multiplier(cost, entered_terrain_multiplier(action.actor, state, env))
+    } else {
+        cost
+    };
+
+    // 7. Apply action cost to actor's ready_at timestamp
+    // This happens AFTER all effects to ensure effects don't accidentally modify
+    // the ready_at that we're trying to update
+    let current_tick = state.turn.clock;
+    if let Some(actor) = state.entities.actor_mut(action.actor) {
+        if let Some(ready_at) = actor.ready_at {
+            actor.ready_at = Some(ready_at + cost);
+        }
+
+        // 8. Start the action's cooldown (if the profile defines one)
+        if let Some(cooldown) = profile.cooldown {
+            actor.set_action_cooldown(action.kind, current_tick + cooldown);
+        }
+    }
+
+    // 9. Build ActionResult from collected effect results
+    Ok(ActionResult::from_effects(effect_results))
+}
+
+/// Movement cost multiplier of the tile `actor` stands on, 100 (1x) when the
+/// actor or its tile is unknown.
+fn entered_terrain_multiplier(actor: EntityId, state: &GameState, env: &GameEnv<'_>) -> u16 {
+    let tile = state
+        .actor_position(actor)
+        .and_then(|position| env.map().ok()?.tile(position));
+    tile.map_or(100, |tile| tile.movement_cost_multiplier())
+}
+
+/// Apply a reaction: the action's effects without its time cost.
+///
+/// Targets are resolved and effects applied exactly as in [`apply`], and the
+/// action's cooldown starts, but the actor's `ready_at` is left alone: a
+/// reaction happens outside the actor's turn and does not delay its next one.
+pub(super) fn apply_reaction(
+    action: &CharacterAction,
+    state: &mut GameState,
+    env: &GameEnv<'_>,
+) -> Result<ActionResult, ActionError> {
+    let profile = env
+        .actions()
+        .map_err(|_| ActionError::ProfileNotFound)?
+        .action_profile(action.kind);
+
+    let effect_results = apply_effects(action, &profile, state, env)?;
+
+    let current_tick = state.turn.clock;
+    if let Some(cooldown) = profile.cooldown
+        && let Some(actor) = state.entities.actor_mut(action.actor)
+    {
+        actor.set_action_cooldown(action.kind, current_tick + cooldown);
+    }
+
+    Ok(ActionResult::from_effects(effect_results))
+}
+
+/// Resolve the action's targets and apply its effects to each, in order.
+fn apply_effects(
+    action: &CharacterAction,
+    profile: &ActionProfile,
+    state: &mut GameState,
+    env: &GameEnv<'_>,
+) -> Result<Vec<EffectResult>, ActionError> {
     // 4. Resolve targets
-    let targets = resolve_targets(action, state, env, &profile)?;
+    let targets = resolve_targets(action, state, env, profile)?;
 
     // 5. Collect all effect results
     let mut effect_results = Vec::new();
+    let is_ranged = profile.tags.contains(&ActionTag::Ranged);
 
-    // 6. Execute effects for each target
-    for target in targets {
+    // 6. Execute effects for each target (in chain order for Chain targeting,
+    //    nearest first for Line targeting, by EntityId for Cone and Burst)
+    let is_chain = matches!(profile.targeting, TargetingMode::Chain { .. });
+    for (hop, target) in (0u32..).zip(targets) {
         // Sort effects by phase and priority
         let mut effects = profile.effects.clone();
         effects.sort_by(|a, b| {
@@ -93,8 +174,16 @@ pub(super) fn apply(
                 .then_with(|| b.priority.cmp(&a.priority)) // Higher priority first
         });
 
+        let cover = if is_ranged {
+            cover_reduction(action.actor, target, state, env)
+        } else {
+            0
+        };
+
         // Create effect context
-        let mut ctx = EffectContext::new(action.actor, target, state, env, &action.input);
+        let mut ctx = EffectContext::new(action.actor, target, state, env, &action.input)
+            .with_hop(if is_chain { hop } else { 0 })
+            .with_cover(cover);
 
         // Apply effects in order with three-phase execution
         for effect in &effects {
@@ -110,17 +199,7 @@ pub(super) fn apply(
         }
     }
 
-    // 7. Apply action cost to actor's ready_at timestamp
-    // This happens AFTER all effects to ensure effects don't accidentally modify
-    // the ready_at that we're trying to update
-    if let Some(actor) = state.entities.actor_mut(action.actor)
-        && let Some(ready_at) = actor.ready_at
-    {
-        actor.ready_at = Some(ready_at + cost);
-    }
-
-    // 8. Build ActionResult from collected effect results
-    Ok(ActionResult::from_effects(effect_results))
+    Ok(effect_results)
 }
 
 // ============================================================================
@@ -132,12 +211,19 @@ pub(super) fn apply(
 /// ## Targeting Modes
 /// - `None`: No targets (empty vec)
 /// - `SelfOnly`: Actor as target
-/// - `SingleTarget`: Single entity from action.targets
+/// - `SingleTarget`, `LineOfSight`: Single entity from action.targets
+/// - `Chain`: Entity from action.targets, then the hops found by `resolve_chain`
 /// - `Directional`: Actor as target (for movement actions)
-fn resolve_targets(
+/// - `Line`: Actors along the input direction, found by `resolve_line`
+/// - `Cone`: Actors in the wedge along the input direction, found by `resolve_cone`
+/// - `Burst`: Actors around the input position, found by `resolve_burst`
+///
+/// Public so planners can predict who an action would strike without
+/// executing it.
+pub fn resolve_targets(
     action: &CharacterAction,
-    _state: &GameState,
-    _env: &GameEnv<'_>,
+    state: &GameState,
+    env: &GameEnv<'_>,
     profile: &crate::action::ActionProfile,
 ) -> Result<Vec<EntityId>, ActionError> {
     match &profile.targeting {
@@ -145,7 +231,7 @@ fn resolve_targets(
 
         TargetingMode::SelfOnly => Ok(vec![action.actor]),
 
-        TargetingMode::SingleTarget { .. } => {
+        TargetingMode::SingleTarget { .. } | TargetingMode::LineOfSight { .. } => {
             if let ActionInput::Target(target) = action.input {
                 Ok(vec![target])
             } else {
@@ -153,9 +239,389 @@ fn resolve_targets(
             }
         }
 
+        TargetingMode::Chain {
+            hop_range,
+            max_hops,
+            ..
+        } => {
+            if let ActionInput::Target(target) = action.input {
+                Ok(resolve_chain(
+                    action.actor,
+                    target,
+                    state,
+                    *hop_range,
+                    *max_hops,
+                ))
+            } else {
+                Err(ActionError::InvalidTarget)
+            }
+        }
+
+        TargetingMode::Burst {
+            radius,
+            friendly_fire,
+        } => {
+            if let ActionInput::Position(center) = action.input {
+                Ok(resolve_burst(
+                    action.actor,
+                    center,
+                    u32::from(*radius),
+                    *friendly_fire,
+                    state,
+                ))
+            } else {
+                Err(ActionError::InvalidTarget)
+            }
+        }
+
         TargetingMode::Directional { .. } => {
             // For movement actions, return actor as target
             Ok(vec![action.actor])
         }
+
+        TargetingMode::Line { length } => {
+            if let ActionInput::Direction(direction) = action.input {
+                resolve_line(action.actor, direction, *length, state, env)
+            } else {
+                Err(ActionError::InvalidTarget)
+            }
+        }
+
+        TargetingMode::Cone { length, width } => {
+            if let ActionInput::Direction(direction) = action.input {
+                resolve_cone(action.actor, direction, *length, *width, state, env)
+            } else {
+                Err(ActionError::InvalidTarget)
+            }
+        }
+    }
+}
+
+/// Walk a beam from the caster along `direction`.
+///
+/// Each step collects the living actors on the tile (in occupancy order) until
+/// `length` tiles are covered, the next tile is off the map, or it blocks
+/// sight. The caster itself is never struck.
+fn resolve_line(
+    caster: EntityId,
+    direction: CardinalDirection,
+    length: u32,
+    state: &GameState,
+    env: &GameEnv<'_>,
+) -> Result<Vec<EntityId>, ActionError> {
+    let map = env.map().map_err(|_| ActionError::MapNotAvailable)?;
+    let mut position = state
+        .actor_position(caster)
+        .ok_or(ActionError::ActorNotFound)?;
+    let (dx, dy) = direction.offset();
+
+    let mut targets = Vec::new();
+    for _ in 0..length {
+        position = Position::new(position.x + dx, position.y + dy);
+        let open = map.dimensions().contains(position)
+            && map.tile(position).is_some_and(|tile| !tile.blocks_sight());
+        if !open {
+            break;
+        }
+
+        if let Some(occupants) = state.world.tile_map.occupants(&position) {
+            targets.extend(occupants.iter().copied().filter(|&id| {
+                id != caster && state.entities.actor(id).is_some_and(|a| a.is_alive())
+            }));
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Collect the living actors inside a cone from the caster, by `EntityId`.
+fn resolve_cone(
+    caster: EntityId,
+    direction: CardinalDirection,
+    length: u32,
+    width: u32,
+    state: &GameState,
+    env: &GameEnv<'_>,
+) -> Result<Vec<EntityId>, ActionError> {
+    let map = env.map().map_err(|_| ActionError::MapNotAvailable)?;
+    let origin = state
+        .actor_position(caster)
+        .ok_or(ActionError::ActorNotFound)?;
+    let tiles = cone_tiles(origin, direction, length, width, map);
+
+    let mut targets: Vec<EntityId> = state
+        .entities
+        .all_actors()
+        .filter(|actor| actor.id != caster && actor.is_alive())
+        .filter(|actor| actor.position.is_some_and(|pos| tiles.contains(&pos)))
+        .map(|actor| actor.id)
+        .collect();
+    targets.sort_unstable();
+    Ok(targets)
+}
+
+/// Tiles covered by a cone from `origin`, in row-major order.
+///
+/// With `(dx, dy)` the direction offset and `(ox, oy)` a tile's offset from
+/// the origin, `forward = ox·dx + oy·dy` and `side = |ox·dy − oy·dx|`. A tile
+/// is covered when it lies within `length` (Chebyshev distance), `forward > 0`,
+/// `side × length ≤ width × forward`, it does not block sight, and the caster
+/// can see it. Diagonal directions scale both terms alike, so the wedge keeps
+/// its angle.
+fn cone_tiles(
+    origin: Position,
+    direction: CardinalDirection,
+    length: u32,
+    width: u32,
+    map: &(dyn MapOracle + '_),
+) -> Vec<Position> {
+    let (dx, dy) = direction.offset();
+    let reach = length as i32;
+
+    let mut tiles = Vec::new();
+    for oy in -reach..=reach {
+        for ox in -reach..=reach {
+            let forward = ox * dx + oy * dy;
+            let side = (ox * dy - oy * dx).abs();
+            if forward <= 0 || side * reach > width as i32 * forward {
+                continue;
+            }
+
+            let position = Position::new(origin.x + ox, origin.y + oy);
+            let open = map.dimensions().contains(position)
+                && map.tile(position).is_some_and(|tile| !tile.blocks_sight());
+            if open && map.line_of_sight(origin, position) {
+                tiles.push(position);
+            }
+        }
+    }
+    tiles
+}
+
+/// Collect the living actors within `radius` of `center`, by `EntityId`.
+///
+/// Without `friendly_fire`, only actors hostile to the caster are kept.
+fn resolve_burst(
+    caster: EntityId,
+    center: Position,
+    radius: u32,
+    friendly_fire: bool,
+    state: &GameState,
+) -> Vec<EntityId> {
+    let faction = state.entities.actor(caster).map(|actor| actor.faction);
+
+    let mut targets: Vec<EntityId> = state
+        .entities
+        .all_actors()
+        .filter(|actor| actor.is_alive())
+        .filter(|actor| {
+            actor
+                .position
+                .is_some_and(|pos| center.chebyshev_distance(pos) <= radius)
+        })
+        .filter(|actor| {
+            friendly_fire || faction.is_some_and(|faction| faction.is_hostile_to(&actor.faction))
+        })
+        .map(|actor| actor.id)
+        .collect();
+    targets.sort_unstable();
+    targets
+}
+
+/// Walk a chain from `first` through the caster's nearest enemies.
+///
+/// Each hop goes to the living, positioned actor hostile to the caster that
+/// is closest (Chebyshev distance) to the previous target, within `hop_range`
+/// and not struck yet. Equal distances resolve to the lower `EntityId`.
+/// The chain stops after `max_hops` targets or when nothing is in range.
+fn resolve_chain(
+    caster: EntityId,
+    first: EntityId,
+    state: &GameState,
+    hop_range: u32,
+    max_hops: u32,
+) -> Vec<EntityId> {
+    let mut chain = vec![first];
+    let Some(faction) = state.entities.actor(caster).map(|actor| actor.faction) else {
+        return chain;
+    };
+    let mut from: Option<Position> = state.actor_position(first);
+
+    while (chain.len() as u32) < max_hops {
+        let Some(origin) = from else {
+            break;
+        };
+        let next = state
+            .entities
+            .all_actors()
+            .filter(|actor| {
+                actor.is_alive()
+                    && faction.is_hostile_to(&actor.faction)
+                    && !chain.contains(&actor.id)
+            })
+            .filter_map(|actor| {
+                let distance = origin.chebyshev_distance(actor.position?);
+                (distance <= hop_range).then_some((distance, actor.id, actor.position))
+            })
+            .min_by_key(|&(distance, id, _)| (distance, id));
+
+        let Some((_, id, position)) = next else {
+            break;
+        };
+        chain.push(id);
+        from = position;
+    }
+
+    chain
+}
+
+// ============================================================================
+// Cover
+// ============================================================================
+
+/// Damage reduction (percent) from the target's partial cover against a ranged attack.
+///
+/// A target is in partial cover when the tile next to it, one step along the
+/// line back to the attacker, blocks sight. Adjacent attackers ignore cover.
+/// The reduction comes from `GameConfig::cover_damage_reduction`.
+fn cover_reduction(
+    attacker: EntityId,
+    target: EntityId,
+    state: &GameState,
+    env: &GameEnv<'_>,
+) -> u32 {
+    let (Some(from), Some(to)) = (state.actor_position(attacker), state.actor_position(target))
+    else {
+        return 0;
+    };
+    if from.chebyshev_distance(to) <= 1 {
+        return 0;
+    }
+
+    let cover = Position::new(
+        to.x + (from.x - to.x).signum(),
+        to.y + (from.y - to.y).signum(),
+    );
+    let in_cover = env
+        .map()
+        .ok()
+        .and_then(|map| map.tile(cover))
+        .is_some_and(|tile| tile.blocks_sight());
+    if !in_cover {
+        return 0;
+    }
+
+    env.cover_damage_reduction().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::action::{ActionEffect, ActionKind, ActionProfile, DamageType, EffectKind, Formula};
+    use crate::env::{ActionOracle, ActionSnapshot, ActorTemplate, SnapshotActionOracle};
+    use crate::traits::{Faction, TraitProfile};
+
+    const CENTER: Position = Position { x: 4, y: 4 };
+
+    fn fireball(friendly_fire: bool) -> ActionSnapshot {
+        let profile = ActionProfile {
+            kind: ActionKind::Lightning,
+            tags: vec![ActionTag::Spell, ActionTag::Aoe],
+            targeting: TargetingMode::Burst {
+                radius: 1,
+                friendly_fire,
+            },
+            base_cost: 100,
+            resource_costs: vec![],
+            effects: vec![ActionEffect::new(EffectKind::damage(
+                Formula::Constant(10),
+                DamageType::Fire,
+                false,
+            ))],
+            requirements: vec![],
+            cooldown: None,
+        };
+        ActionSnapshot::new(BTreeMap::from([(ActionKind::Lightning, profile)]))
+    }
+
+    /// Casts a radius-1 burst at `CENTER` among three goblins and an ally.
+    ///
+    /// Returns the struck entities and every actor's lost HP, by `EntityId`.
+    fn cast(friendly_fire: bool) -> (Vec<EntityId>, Vec<(EntityId, u32)>) {
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+
+        let goblin = ActorTemplate::builder()
+            .faction(Faction::Hostile)
+            .trait_profile(TraitProfile::default())
+            .build();
+        let ally = ActorTemplate::builder()
+            .faction(Faction::Friendly)
+            .trait_profile(TraitProfile::default())
+            .build();
+        for (template, position) in [
+            (&goblin, Position::new(4, 5)),
+            (&goblin, Position::new(5, 3)),
+            (&goblin, Position::new(7, 4)),
+            (&ally, Position::new(3, 4)),
+        ] {
+            state.add_npc(template, position).unwrap();
+        }
+        let before = state.clone();
+
+        let snapshot = fireball(friendly_fire);
+        let actions = SnapshotActionOracle::new(&snapshot);
+        let actions: &dyn ActionOracle = &actions;
+        let env = GameEnv::new(None, None, Some(actions), None, None, None);
+
+        let action = CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Lightning,
+            ActionInput::Position(CENTER),
+        );
+        let result = apply(&action, &mut state, &env).unwrap();
+
+        let lost = before
+            .entities
+            .all_actors()
+            .map(|actor| {
+                let after = state.entities.actor(actor.id).unwrap();
+                (actor.id, actor.resources.hp - after.resources.hp)
+            })
+            .collect();
+        (result.summary.affected_entities, lost)
+    }
+
+    #[test]
+    fn burst_strikes_hostiles_within_radius_in_entity_order() {
+        let (struck, lost) = cast(false);
+
+        assert_eq!(struck, vec![EntityId(1), EntityId(2)]);
+        assert_eq!(
+            lost,
+            vec![
+                (EntityId::PLAYER, 0),
+                (EntityId(1), 10),
+                (EntityId(2), 10),
+                (EntityId(3), 0),
+                (EntityId(4), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn friendly_fire_strikes_allies_within_radius() {
+        let (struck, lost) = cast(true);
+
+        // The caster stands outside the burst and is spared either way
+        assert_eq!(struck, vec![EntityId(1), EntityId(2), EntityId(4)]);
+        assert_eq!(lost[0], (EntityId::PLAYER, 0));
+        assert_eq!(lost[3], (EntityId(3), 0));
+        assert_eq!(lost[4], (EntityId(4), 10));
     }
 }
@@ -4,7 +4,7 @@
 
 use crate::action::TargetingMode;
 use crate::action::error::ActionError;
-use crate::action::profile::ActionProfile;
+use crate::action::profile::{ActionProfile, ActionTag};
 use crate::action::types::{ActionInput, CharacterAction};
 use crate::env::GameEnv;
 use crate::state::{ActorState, GameState, Position};
@@ -57,7 +57,8 @@ pub(super) fn pre_validate(
     validate_resource_costs(actor, &profile)?;
 
     // 8. Validate target based on targeting mode
-    validate_targeting(action, state, env, &profile.targeting)?;
+    let targeting = melee_targeting(actor, &profile, env)?;
+    validate_targeting(action, state, env, &targeting)?;
 
     Ok(())
 }
@@ -97,11 +98,35 @@ fn validate_resource_costs(actor: &ActorState, profile: &ActionProfile) -> Resul
     Ok(())
 }
 
+/// Extend a melee action's range to the reach of the actor's weapon.
+///
+/// Non-melee profiles, and melee profiles that already reach further, are
+/// returned unchanged.
+fn melee_targeting(
+    actor: &ActorState,
+    profile: &ActionProfile,
+    env: &GameEnv<'_>,
+) -> Result<TargetingMode, ActionError> {
+    let mut targeting = profile.targeting.clone();
+    if !profile.tags.contains(&ActionTag::Melee) {
+        return Ok(targeting);
+    }
+
+    if let TargetingMode::SingleTarget { range, .. } = &mut targeting
+        && actor.equipment.weapon.is_some()
+    {
+        let items = env.items().map_err(|_| ActionError::ItemsNotAvailable)?;
+        *range = (*range).max(u32::from(actor.melee_reach(items)));
+    }
+
+    Ok(targeting)
+}
+
 /// Validate targeting based on action's targeting mode.
 fn validate_targeting(
     action: &CharacterAction,
     state: &GameState,
-    _env: &GameEnv<'_>,
+    env: &GameEnv<'_>,
     targeting: &TargetingMode,
 ) -> Result<(), ActionError> {
     match targeting {
@@ -110,33 +135,8 @@ fn validate_targeting(
             Ok(())
         }
 
-        TargetingMode::SingleTarget {
-            range,
-            requires_los: _,
-        } => {
-            // Must have a single entity input
-            let target_id = match action.input {
-                ActionInput::Target(id) => id,
-                _ => return Err(ActionError::InvalidTarget),
-            };
-
-            // Get actor position
-            let actor_pos = state
-                .actor_position(action.actor)
-                .ok_or(ActionError::ActorNotFound)?;
-
-            // Try to find target position from any entity type: Actor, Item, or Prop
-            let target_pos = state
-                .actor_position(target_id)
-                .or_else(|| {
-                    // Not an actor - try item
-                    state.entities.item(target_id).map(|item| item.position)
-                })
-                .or_else(|| {
-                    // Not an item - try prop
-                    state.entities.prop(target_id).map(|prop| prop.position)
-                })
-                .ok_or(ActionError::TargetNotFound)?;
+        TargetingMode::SingleTarget { range, .. } | TargetingMode::Chain { range, .. } => {
+            let (actor_pos, target_pos) = locate_target(action, state)?;
 
             // Check range (Chebyshev distance)
             let distance = calculate_distance(actor_pos, target_pos);
@@ -147,7 +147,38 @@ fn validate_targeting(
             Ok(())
         }
 
-        TargetingMode::Directional { range: _, width: _ } => {
+        TargetingMode::LineOfSight { max_range } => {
+            let (actor_pos, target_pos) = locate_target(action, state)?;
+
+            if calculate_distance(actor_pos, target_pos) > u32::from(*max_range) {
+                return Err(ActionError::OutOfRange);
+            }
+
+            let map = env.map().map_err(|_| ActionError::MapNotAvailable)?;
+            if !map.line_of_sight(actor_pos, target_pos) {
+                return Err(ActionError::LineOfSightBlocked);
+            }
+
+            Ok(())
+        }
+
+        TargetingMode::Burst { .. } => {
+            // Must have a position input on the map
+            let ActionInput::Position(center) = action.input else {
+                return Err(ActionError::InvalidTarget);
+            };
+
+            let map = env.map().map_err(|_| ActionError::MapNotAvailable)?;
+            if !map.contains(center) {
+                return Err(ActionError::OutOfBounds);
+            }
+
+            Ok(())
+        }
+
+        TargetingMode::Directional { .. }
+        | TargetingMode::Line { .. }
+        | TargetingMode::Cone { .. } => {
             // Must have a direction input
             match action.input {
                 ActionInput::Direction(_) => Ok(()),
@@ -157,6 +188,40 @@ fn validate_targeting(
     }
 }
 
+/// Resolve the positions of the actor and its entity target.
+///
+/// The target may be an actor, an item or a prop.
+fn locate_target(
+    action: &CharacterAction,
+    state: &GameState,
+) -> Result<(Position, Position), ActionError> {
+    // Must have a single entity input
+    let target_id = match action.input {
+        ActionInput::Target(id) => id,
+        _ => return Err(ActionError::InvalidTarget),
+    };
+
+    // Get actor position
+    let actor_pos = state
+        .actor_position(action.actor)
+        .ok_or(ActionError::ActorNotFound)?;
+
+    // Try to find target position from any entity type: Actor, Item, or Prop
+    let target_pos = state
+        .actor_position(target_id)
+        .or_else(|| {
+            // Not an actor - try item
+            state.entities.item(target_id).map(|item| item.position)
+        })
+        .or_else(|| {
+            // Not an item - try prop
+            state.entities.prop(target_id).map(|prop| prop.position)
+        })
+        .ok_or(ActionError::TargetNotFound)?;
+
+    Ok((actor_pos, target_pos))
+}
+
 /// Calculate Chebyshev distance (chessboard distance) between two positions.
 ///
 /// This is `max(|dx|, |dy|)`, which treats diagonal movement as having the same
@@ -184,3 +249,142 @@ pub(super) fn post_validate(
     // TODO: Implement post-validation
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::ActionKind;
+    use crate::env::{
+        ActorTemplate, ItemDefinition, ItemKind, ItemOracle, ItemsSnapshot, MapDimensions,
+        MapOracle, MapSnapshot, SnapshotItemOracle, SnapshotMapOracle, StaticTile, TerrainKind,
+        WeaponData, WeaponKind,
+    };
+    use crate::state::{EntityId, ItemHandle};
+
+    const BOW: TargetingMode = TargetingMode::LineOfSight { max_range: 5 };
+
+    /// An 8x8 floor with a single wall at (3, 0).
+    fn walled_map() -> MapSnapshot {
+        let tiles = (0..64)
+            .map(|index| {
+                let terrain = if index == 3 {
+                    TerrainKind::Wall
+                } else {
+                    TerrainKind::Floor
+                };
+                Some(StaticTile::new(terrain))
+            })
+            .collect();
+        MapSnapshot::new(MapDimensions::new(8, 8), tiles)
+    }
+
+    /// Validates a shot from the player at the origin to a goblin at `target`.
+    fn shoot(target: Position) -> Result<(), ActionError> {
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        let goblin = state.add_npc(&ActorTemplate::test_actor(), target).unwrap();
+
+        let snapshot = walled_map();
+        let map = SnapshotMapOracle::new(&snapshot);
+        let map: &dyn MapOracle = &map;
+        let env = GameEnv::new(Some(map), None, None, None, None, None);
+
+        let action = CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::RangedAttack,
+            ActionInput::Target(goblin),
+        );
+        validate_targeting(&action, &state, &env, &BOW)
+    }
+
+    #[test]
+    fn clear_shot_within_range_is_valid() {
+        assert_eq!(shoot(Position::new(0, 5)), Ok(()));
+        assert_eq!(shoot(Position::new(4, 3)), Ok(()));
+    }
+
+    #[test]
+    fn wall_blocks_the_shot() {
+        assert_eq!(
+            shoot(Position::new(5, 0)),
+            Err(ActionError::LineOfSightBlocked)
+        );
+    }
+
+    #[test]
+    fn target_beyond_max_range_is_rejected() {
+        assert_eq!(shoot(Position::new(0, 6)), Err(ActionError::OutOfRange));
+        assert_eq!(shoot(Position::new(6, 0)), Err(ActionError::OutOfRange));
+    }
+
+    const WEAPON: ItemHandle = ItemHandle(1);
+
+    /// Validates a melee attack from the player at the origin to a goblin at
+    /// `target`, wielding a weapon of the given reach if any.
+    fn strike(reach: Option<u8>, target: Position) -> Result<(), ActionError> {
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        let goblin = state.add_npc(&ActorTemplate::test_actor(), target).unwrap();
+
+        let mut items = ItemsSnapshot::empty();
+        if let Some(reach) = reach {
+            let spear = ItemKind::Weapon(WeaponData {
+                kind: WeaponKind::Spear,
+                damage: 12,
+                reach,
+                durability: None,
+            });
+            items = ItemsSnapshot::new(vec![(WEAPON, ItemDefinition::new(WEAPON, spear, 1))]);
+            state.entities.player_mut().equipment.equip_weapon(WEAPON, None);
+        }
+        let items = SnapshotItemOracle::new(&items);
+        let items: &dyn ItemOracle = &items;
+        let env = GameEnv::new(None, Some(items), None, None, None, None);
+
+        let profile = ActionProfile {
+            kind: ActionKind::MeleeAttack,
+            tags: vec![ActionTag::Attack, ActionTag::Melee],
+            targeting: TargetingMode::melee_attack(),
+            base_cost: 100,
+            resource_costs: vec![],
+            effects: vec![],
+            requirements: vec![],
+            cooldown: None,
+        };
+        let targeting = melee_targeting(state.entities.player(), &profile, &env)?;
+
+        let action = CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(goblin),
+        );
+        validate_targeting(&action, &state, &env, &targeting)
+    }
+
+    #[test]
+    fn reach_two_weapon_strikes_two_tiles_away() {
+        assert_eq!(strike(Some(2), Position::new(2, 0)), Ok(()));
+        assert_eq!(strike(Some(2), Position::new(2, 2)), Ok(()));
+        assert_eq!(
+            strike(Some(2), Position::new(3, 0)),
+            Err(ActionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn default_reach_only_strikes_adjacent_tiles() {
+        for reach in [None, Some(WeaponData::DEFAULT_REACH)] {
+            assert_eq!(strike(reach, Position::new(1, 1)), Ok(()));
+            assert_eq!(
+                strike(reach, Position::new(2, 0)),
+                Err(ActionError::OutOfRange)
+            );
+        }
+    }
+}
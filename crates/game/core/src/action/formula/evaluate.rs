@@ -29,6 +29,7 @@ use crate::action::execute::EffectContext;
 /// - `Product`: Product of sub-formulas (percent-based multiplication)
 /// - `Min`: Minimum of sub-formulas
 /// - `Max`: Maximum of sub-formulas
+/// - `ChainFalloff`: Sub-formula scaled once per chain hop
 ///
 /// ## Error Handling
 /// - `ActorNotFound` if caster doesn't exist
@@ -169,6 +170,14 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
             .iter()
             .map(|f| evaluate(f, ctx))
             .try_fold(0u32, |max, res| res.map(|v| max.max(v))),
+
+        Formula::ChainFalloff { formula, percent } => {
+            let mut value = evaluate(formula, ctx)?;
+            for _ in 0..ctx.hop {
+                value = value * percent / 100;
+            }
+            Ok(value)
+        }
     }
 }
 
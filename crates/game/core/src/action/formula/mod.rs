@@ -4,6 +4,7 @@
 //! - Character stats (STR, INT, etc.)
 //! - Current/max resources (HP, MP, Lucidity)
 //! - Previous effects in the same action (damage chains)
+//! - Position in a chain of targets (chain lightning falloff)
 //! - Weapon damage
 //! - Arithmetic combinations (sum, product, min, max)
 //!
@@ -21,6 +22,9 @@
 //!
 //! // 30% of previous damage (damage chain)
 //! Formula::FromPreviousDamage { percent: 30 }
+//!
+//! // 40 damage, halved on every hop of a chain
+//! Formula::ChainFalloff { formula: Box::new(Formula::Constant(40)), percent: 50 }
 //! ```
 
 pub mod evaluate;
@@ -90,4 +94,26 @@ pub enum Formula {
 
     /// Maximum of multiple formulas.
     Max(Vec<Formula>),
+
+    /// Formula scaled by `percent` once per chain hop.
+    ///
+    /// The initial target gets the full value; see `TargetingMode::Chain`.
+    ChainFalloff { formula: Box<Formula>, percent: u32 },
+}
+
+impl Formula {
+    /// Returns true if the value depends on the caster's weapon.
+    ///
+    /// Damage from such a formula is a weapon attack and wears the weapon down.
+    pub fn uses_weapon(&self) -> bool {
+        match self {
+            Formula::WeaponDamage { .. } => true,
+            Formula::Sum(formulas)
+            | Formula::Product(formulas)
+            | Formula::Min(formulas)
+            | Formula::Max(formulas) => formulas.iter().any(Formula::uses_weapon),
+            Formula::ChainFalloff { formula, .. } => formula.uses_weapon(),
+            _ => false,
+        }
+    }
 }
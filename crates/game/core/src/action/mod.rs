@@ -21,7 +21,7 @@
 //! - `types`: Core types (CharacterAction, ActionInput, ActionResult)
 //! - `execute`: Action execution pipeline (resolve targets + apply effects)
 //! - `system`: System actions (PrepareTurn, ActionCost, Activation)
-//! - `root`: Action root computation for ZK proofs
+//! - `root`: Action root and Merkle inclusion proofs for ZK proofs
 
 pub mod effect;
 pub mod error;
@@ -35,16 +35,25 @@ pub mod types;
 
 // Re-export commonly used types
 pub use effect::{
-    ActionEffect, Condition, Displacement, EffectKind, ExecutionPhase, InteractionType,
-    RestoreResourceEffect,
+    ActionEffect, Condition, CreateHazardEffect, Displacement, EffectKind, EquipArmorEffect,
+    EquipWeaponEffect, ExecutionPhase, InteractEffect, InteractionType, RestoreResourceEffect,
+    ReviveEffect, SummonEffect,
 };
-pub use error::{ActionError, ActivationError, DeactivateError, RemoveFromWorldError, TurnError};
-pub use execute::{EffectContext, apply, post_validate, pre_validate};
+pub use error::{
+    ActionError, ActivationError, DeactivateError, DropLootError, OpportunityAttackError,
+    RemoveFromWorldError, TickStatusError, TurnError,
+};
+pub use execute::{EffectContext, apply, post_validate, pre_validate, resolve_targets};
 pub use formula::Formula;
 pub use profile::{ActionKind, ActionProfile, ActionTag, Requirement, ResourceCost};
 #[cfg(feature = "serde")]
-pub use root::compute_actions_root;
-pub use system::{ActivationAction, DeactivateAction, PrepareTurnAction, RemoveFromWorldAction};
+pub use root::{
+    ActionMerkleProof, compute_actions_merkle_root, compute_actions_root, merkle_proof,
+};
+pub use system::{
+    ActivationAction, DeactivateAction, DropLootAction, OpportunityAttackAction, PrepareTurnAction,
+    RemoveFromWorldAction, TickStatusEffectsAction,
+};
 pub use targeting::TargetingMode;
 pub use types::{
     ActionInput, ActionResult, ActionSummary, AppliedValue, CardinalDirection, CharacterAction,
@@ -93,6 +102,9 @@ pub enum SystemActionKind {
     Activation(ActivationAction),
     Deactivate(DeactivateAction),
     RemoveFromWorld(RemoveFromWorldAction),
+    TickStatusEffects(TickStatusEffectsAction),
+    OpportunityAttack(OpportunityAttackAction),
+    DropLoot(DropLootAction),
 }
 
 /// Top-level action enum that can be either a character action or system action.
@@ -150,6 +162,9 @@ impl Action {
                 SystemActionKind::Activation(action) => action.cost(env),
                 SystemActionKind::Deactivate(action) => action.cost(env),
                 SystemActionKind::RemoveFromWorld(action) => action.cost(env),
+                SystemActionKind::TickStatusEffects(action) => action.cost(env),
+                SystemActionKind::OpportunityAttack(action) => action.cost(env),
+                SystemActionKind::DropLoot(action) => action.cost(env),
             },
         };
 
@@ -167,6 +182,9 @@ impl Action {
                 SystemActionKind::Activation(_) => "activation",
                 SystemActionKind::Deactivate(_) => "deactivate",
                 SystemActionKind::RemoveFromWorld(_) => "remove_from_world",
+                SystemActionKind::TickStatusEffects(_) => "tick_status_effects",
+                SystemActionKind::OpportunityAttack(_) => "opportunity_attack",
+                SystemActionKind::DropLoot(_) => "drop_loot",
             },
         }
     }
@@ -196,6 +214,24 @@ impl From<RemoveFromWorldAction> for SystemActionKind {
     }
 }
 
+impl From<TickStatusEffectsAction> for SystemActionKind {
+    fn from(action: TickStatusEffectsAction) -> Self {
+        Self::TickStatusEffects(action)
+    }
+}
+
+impl From<OpportunityAttackAction> for SystemActionKind {
+    fn from(action: OpportunityAttackAction) -> Self {
+        Self::OpportunityAttack(action)
+    }
+}
+
+impl From<DropLootAction> for SystemActionKind {
+    fn from(action: DropLootAction) -> Self {
+        Self::DropLoot(action)
+    }
+}
+
 // ============================================================================
 // Available Actions Query
 // ============================================================================
@@ -33,47 +33,67 @@ pub enum ActionKind {
     /// Use an item from inventory.
     UseItem,
 
-    // /// Interact with props (doors, levers, etc.).
-    // Interact,
+    /// Interact with props (doors, levers, etc.).
+    Interact,
+
+    /// Equip a weapon from inventory.
+    EquipWeapon,
+
+    /// Equip armor from inventory.
+    EquipArmor,
 
     // ========================================================================
     // Combat - Melee
     // ========================================================================
     /// Basic melee attack.
     MeleeAttack,
-    // /// Powerful melee attack with extra damage.
-    // PowerAttack,
-    //
+
+    /// Powerful melee attack with extra damage.
+    PowerAttack,
+
+    /// Sweeping blow that strikes everyone in a short wedge ahead.
+    Cleave,
     // /// High damage when attacking from behind.
     // Backstab,
-    //
-    // /// Attack multiple adjacent enemies.
-    // Cleave,
 
     // ========================================================================
     // Combat - Ranged
     // ========================================================================
-    // /// Basic ranged attack.
-    // RangedAttack,
-    //
+    /// Basic ranged attack.
+    RangedAttack,
+
+    /// Scatter caltrops around a target, hurting anyone who steps on them.
+    Caltrops,
+
+    /// Shot that flies on through every actor in a straight line.
+    PiercingShot,
     // /// Aimed shot with bonus accuracy.
     // AimedShot,
 
+    // ========================================================================
+    // Support
+    // ========================================================================
+    /// Bring a downed adjacent ally back on its feet with partial HP.
+    Revive,
     // ========================================================================
     // Magic - Offensive
     // ========================================================================
-    // /// Fire magic attack.
-    // Fireball,
-    //
-    // /// Lightning magic attack.
-    // Lightning,
+    /// Lightning that arcs from the target to nearby enemies.
+    Lightning,
+
+    /// Ball of fire that bursts around a target tile, scorching everyone caught.
+    Fireball,
 
+    // ========================================================================
+    // Magic - Summoning
+    // ========================================================================
+    /// Call a temporary minion onto a tile next to the caster.
+    Summon,
     // ========================================================================
     // Magic - Support
     // ========================================================================
-    // /// Heal self or allies.
-    // Heal,
-    //
+    /// Restore HP to the caster or a nearby ally.
+    Heal,
     // /// Create protective barrier.
     // Shield,
     //
@@ -119,9 +139,32 @@ impl ActionKind {
             ActionKind::Wait => "wait",
             ActionKind::PickupItem => "pickup_item",
             ActionKind::UseItem => "use_item",
+            ActionKind::Interact => "interact",
+            ActionKind::EquipWeapon => "equip_weapon",
+            ActionKind::EquipArmor => "equip_armor",
 
             // Combat - Melee
             ActionKind::MeleeAttack => "melee_attack",
+            ActionKind::PowerAttack => "power_attack",
+            ActionKind::Cleave => "cleave",
+
+            // Combat - Ranged
+            ActionKind::RangedAttack => "ranged_attack",
+            ActionKind::Caltrops => "caltrops",
+            ActionKind::PiercingShot => "piercing_shot",
+
+            // Support
+            ActionKind::Revive => "revive",
+
+            // Magic - Offensive
+            ActionKind::Lightning => "lightning",
+            ActionKind::Fireball => "fireball",
+
+            // Magic - Summoning
+            ActionKind::Summon => "summon",
+
+            // Magic - Support
+            ActionKind::Heal => "heal",
         }
     }
 
@@ -135,8 +178,26 @@ impl ActionKind {
             ActionKind::Wait,
             ActionKind::PickupItem,
             ActionKind::UseItem,
+            ActionKind::Interact,
+            ActionKind::EquipWeapon,
+            ActionKind::EquipArmor,
             // Combat - Melee
             ActionKind::MeleeAttack,
+            ActionKind::PowerAttack,
+            ActionKind::Cleave,
+            // Combat - Ranged
+            ActionKind::RangedAttack,
+            ActionKind::Caltrops,
+            ActionKind::PiercingShot,
+            // Support
+            ActionKind::Revive,
+            // Magic - Offensive
+            ActionKind::Lightning,
+            ActionKind::Fireball,
+            // Magic - Summoning
+            ActionKind::Summon,
+            // Magic - Support
+            ActionKind::Heal,
         ]
     }
 }
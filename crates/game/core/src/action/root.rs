@@ -44,3 +44,234 @@ pub fn compute_actions_root(actions: &[Action]) -> [u8; 32] {
 
     hasher.finalize().into()
 }
+
+/// Domain prefix for leaf hashes, so a leaf can never pass as an inner node.
+#[cfg(feature = "serde")]
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain prefix for inner node hashes.
+#[cfg(feature = "serde")]
+const NODE_PREFIX: u8 = 0x01;
+
+/// Computes a binary Merkle root over the actions.
+///
+/// Unlike [`compute_actions_root`], which hashes the whole batch in one pass,
+/// this commits to every action separately, so a single action's inclusion
+/// can be shown with [`merkle_proof`] without revealing the rest of the batch.
+///
+/// # Tree Layout
+///
+/// - Leaf: `SHA-256(0x00 || bincode(action))`
+/// - Node: `SHA-256(0x01 || left || right)`
+/// - Levels are built left to right; an unpaired last node is carried up
+///   unchanged rather than duplicated
+/// - An empty batch has the root `SHA-256("")`
+///
+/// This matches the RFC 6962 (Certificate Transparency) tree hash.
+#[cfg(feature = "serde")]
+pub fn compute_actions_merkle_root(actions: &[Action]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut level: Vec<[u8; 32]> = actions.iter().map(leaf_hash).collect();
+    if level.is_empty() {
+        return Sha256::digest([]).into();
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields one or two nodes"),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Builds the inclusion proof for the action at `index`.
+///
+/// Returns `None` if `index` is out of bounds.
+#[cfg(feature = "serde")]
+pub fn merkle_proof(actions: &[Action], index: usize) -> Option<ActionMerkleProof> {
+    if index >= actions.len() {
+        return None;
+    }
+
+    let mut level: Vec<[u8; 32]> = actions.iter().map(leaf_hash).collect();
+    let mut position = index;
+    let mut siblings = Vec::new();
+
+    while level.len() > 1 {
+        // A carried-up last node has no sibling on this level
+        let sibling = position ^ 1;
+        if let Some(hash) = level.get(sibling) {
+            siblings.push(*hash);
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields one or two nodes"),
+            })
+            .collect();
+        position /= 2;
+    }
+
+    Some(ActionMerkleProof {
+        index,
+        leaf_count: actions.len(),
+        siblings,
+    })
+}
+
+/// Inclusion proof for one action under [`compute_actions_merkle_root`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ActionMerkleProof {
+    /// Position of the action in the batch.
+    pub index: usize,
+    /// Number of actions in the batch.
+    pub leaf_count: usize,
+    /// Sibling hashes from the leaf level up to just below the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+#[cfg(feature = "serde")]
+impl ActionMerkleProof {
+    /// Checks that `action` sits at `self.index` of a batch with the given root.
+    ///
+    /// The leaf count tells which levels carried the path's node up without a
+    /// sibling, so the proof only verifies against the batch size it was
+    /// built for.
+    pub fn verify(&self, root: &[u8; 32], action: &Action) -> bool {
+        if self.index >= self.leaf_count {
+            return false;
+        }
+
+        let mut hash = leaf_hash(action);
+        let mut position = self.index;
+        let mut width = self.leaf_count;
+        let mut siblings = self.siblings.iter();
+
+        while width > 1 {
+            let sibling = position ^ 1;
+            if sibling < width {
+                let Some(other) = siblings.next() else {
+                    return false;
+                };
+                hash = if position.is_multiple_of(2) {
+                    node_hash(&hash, other)
+                } else {
+                    node_hash(other, &hash)
+                };
+            }
+            position /= 2;
+            width = width.div_ceil(2);
+        }
+
+        siblings.next().is_none() && hash == *root
+    }
+}
+
+#[cfg(feature = "serde")]
+fn leaf_hash(action: &Action) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    if let Ok(action_bytes) = bincode::serialize(action) {
+        hasher.update(&action_bytes);
+    }
+    hasher.finalize().into()
+}
+
+#[cfg(feature = "serde")]
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::action::{ActionInput, ActionKind, CharacterAction};
+    use crate::state::EntityId;
+
+    fn batch(len: u32) -> Vec<Action> {
+        (0..len)
+            .map(|i| {
+                Action::character(CharacterAction::new(
+                    EntityId(i),
+                    ActionKind::Wait,
+                    ActionInput::None,
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merkle_root_is_stable_and_commits_to_every_action() {
+        let actions = batch(5);
+        let root = compute_actions_merkle_root(&actions);
+        assert_eq!(root, compute_actions_merkle_root(&batch(5)));
+        assert_ne!(root, compute_actions_merkle_root(&batch(4)));
+
+        let mut swapped = actions.clone();
+        swapped.swap(1, 2);
+        assert_ne!(root, compute_actions_merkle_root(&swapped));
+
+        // A single action's root is its leaf hash
+        assert_eq!(
+            compute_actions_merkle_root(&actions[..1]),
+            leaf_hash(&actions[0])
+        );
+    }
+
+    #[test]
+    fn every_proof_verifies_for_any_batch_size() {
+        for len in 1..=9 {
+            let actions = batch(len);
+            let root = compute_actions_merkle_root(&actions);
+
+            for (index, action) in actions.iter().enumerate() {
+                let proof = merkle_proof(&actions, index).unwrap();
+                assert!(proof.verify(&root, action), "len {len}, index {index}");
+
+                // The same proof must not vouch for a different action
+                let other = &actions[(index + 1) % actions.len()];
+                if other != action {
+                    assert!(!proof.verify(&root, other), "len {len}, index {index}");
+                }
+            }
+            assert_eq!(merkle_proof(&actions, actions.len()), None);
+        }
+    }
+
+    #[test]
+    fn tampered_proof_is_rejected() {
+        let actions = batch(6);
+        let root = compute_actions_merkle_root(&actions);
+        let proof = merkle_proof(&actions, 4).unwrap();
+
+        let mut flipped = proof.clone();
+        flipped.siblings[0][0] ^= 1;
+        assert!(!flipped.verify(&root, &actions[4]));
+
+        let mut moved = proof.clone();
+        moved.index = 5;
+        assert!(!moved.verify(&root, &actions[4]));
+
+        let mut truncated = proof;
+        truncated.siblings.pop();
+        assert!(!truncated.verify(&root, &actions[4]));
+    }
+}
@@ -1,31 +1,28 @@
 //! Entity activation system action.
 //!
 //! Manages NPC activation and deactivation based on proximity to the player,
-//! implementing the activation radius game mechanic.
+//! implementing the activation region game mechanic.
 
 use crate::action::ActionTransition;
 use crate::action::error::ActivationError;
 use crate::env::GameEnv;
 use crate::state::{EntityId, GameState, Tick};
-
-/// Distance threshold for NPC activation (Chebyshev distance).
-///
-/// NPCs within this distance of the player are activated (added to the active set
-/// and assigned ready_at timestamps). NPCs beyond this distance are deactivated.
-const ACTIVATION_RADIUS: u32 = 10;
+use crate::stats::initiative_delay;
 
 /// System action that updates NPC activation based on player position.
 ///
 /// This action:
-/// 1. Gets player position
+/// 1. Gets player position and the configured
+///    [`ActivationShape`](crate::config::ActivationShape)
 /// 2. For all NPCs:
-///    - If within activation radius and inactive: activate (set ready_at)
-///    - If beyond activation radius and active: deactivate (clear ready_at, remove from active set)
+///    - If inside the shape and inactive: activate (set ready_at,
+///      delayed by [`initiative_delay`] so faster NPCs act first)
+///    - If outside the shape and active: deactivate (clear ready_at, remove from active set)
 ///
 /// # Invariants
 ///
 /// - Player must exist in the game state
-/// - Activation distance uses Chebyshev metric (chessboard distance)
+/// - Without a config oracle, the default shape (a Chebyshev square) is used
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActivationAction;
@@ -65,16 +62,21 @@ impl ActionTransition for ActivationAction {
 
         // Get current clock time for activation
         let current_clock = state.turn.clock;
-
-        // Phase 1: Iterate tiles within activation radius to activate nearby NPCs
-        // This is O(radius²)
-        let radius = ACTIVATION_RADIUS as i32;
-        for dy in -radius..=radius {
-            for dx in -radius..=radius {
+        let initiative_window = u64::from(env.initiative_window().unwrap_or(0));
+        let shape = env.activation_shape().unwrap_or_default();
+
+        // Phase 1: Iterate tiles within the shape's range to activate nearby NPCs
+        // This is O(range²)
+        let range = shape.range() as i32;
+        for dy in -range..=range {
+            for dx in -range..=range {
                 let tile_pos = crate::state::Position {
                     x: player_pos.x + dx,
                     y: player_pos.y + dy,
                 };
+                if !shape.contains(player_pos, tile_pos) {
+                    continue;
+                }
 
                 // Get occupants at this tile
                 let Some(occupants) = state.world.tile_map.occupants(&tile_pos) else {
@@ -87,18 +89,22 @@ impl ActionTransition for ActivationAction {
                         continue;
                     }
 
-                    // Only activate if not already active
+                    // Only activate living actors that are not already active
+                    // (downed actors stay on the map until revived)
                     if let Some(actor) = state.entities.actor_mut(entity_id)
                         && actor.ready_at.is_none()
+                        && actor.is_alive()
                     {
-                        actor.ready_at = Some(current_clock);
+                        let delay =
+                            initiative_delay(initiative_window, actor.snapshot().speed.physical);
+                        actor.ready_at = Some(current_clock + delay);
                         state.turn.active_actors.insert(entity_id);
                     }
                 }
             }
         }
 
-        // Phase 2: Deactivate actors that moved outside radius
+        // Phase 2: Deactivate actors that moved outside the shape
         // Iterate active_actors set (typically small) and check distance
         let active_actors: Vec<EntityId> = state.turn.active_actors.iter().copied().collect();
         for entity_id in active_actors {
@@ -114,9 +120,8 @@ impl ActionTransition for ActivationAction {
                     continue;
                 };
 
-                let distance = calculate_distance(player_pos, actor_pos, env);
-                if distance > ACTIVATION_RADIUS {
-                    // Deactivate: NPC moved beyond radius
+                if !shape.contains(player_pos, actor_pos) {
+                    // Deactivate: NPC moved outside the shape
                     actor.ready_at = None;
                     state.turn.active_actors.remove(&entity_id);
                 }
@@ -155,17 +160,3 @@ impl ActionTransition for ActivationAction {
         0
     }
 }
-
-/// Calculate Chebyshev distance (chessboard distance) between two positions.
-///
-/// This is `max(|dx|, |dy|)`, which treats diagonal movement as having the same
-/// cost as orthogonal movement (like a chess king).
-fn calculate_distance(
-    from: crate::state::Position,
-    to: crate::state::Position,
-    _env: &GameEnv<'_>,
-) -> u32 {
-    let dx = (from.x - to.x).abs();
-    let dy = (from.y - to.y).abs();
-    dx.max(dy) as u32
-}
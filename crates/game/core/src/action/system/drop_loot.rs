@@ -0,0 +1,135 @@
+//! Drop loot system action.
+//!
+//! A dead actor's inventory is spilled onto the map as ground items, so that
+//! whatever it carried can be picked up again.
+
+use crate::action::error::DropLootError;
+use crate::action::{ActionTransition, CardinalDirection};
+use crate::config::GameConfig;
+use crate::env::{GameEnv, MapOracle};
+use crate::state::{EntityId, GameState, ItemState, Position, Tick};
+
+/// System action that turns an actor's inventory into ground items.
+///
+/// Each inventory slot becomes one `ItemState` with the slot's handle and
+/// quantity. Items land on the actor's tile while it has a free occupant
+/// slot, then spill onto the adjacent passable tiles in
+/// [`CardinalDirection::all`] order.
+///
+/// Dropping stops at the first slot that does not fit: when the world item
+/// list is full or no nearby tile has room. Slots that were not dropped stay
+/// in the inventory. Running out of entity ids is fatal instead, since no
+/// later spawn can succeed either.
+///
+/// # Invariants
+///
+/// - Entity must exist in the game state and have a position
+/// - Every dropped slot is removed from the inventory
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropLootAction {
+    /// The entity whose inventory is dropped
+    pub entity: EntityId,
+}
+
+impl DropLootAction {
+    /// Creates a new DropLoot action.
+    pub fn new(entity: EntityId) -> Self {
+        Self { entity }
+    }
+}
+
+impl ActionTransition for DropLootAction {
+    type Error = DropLootError;
+    type Result = ();
+
+    fn actor(&self) -> EntityId {
+        EntityId::SYSTEM
+    }
+
+    fn pre_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        // Verify this action is executed by the SYSTEM actor
+        if self.actor() != EntityId::SYSTEM {
+            return Err(DropLootError::not_system_actor(nonce));
+        }
+
+        // Verify entity exists and is still on the map
+        let actor = state
+            .entities
+            .actor(self.entity)
+            .ok_or_else(|| DropLootError::entity_not_found(self.entity, nonce))?;
+        if actor.position.is_none() {
+            return Err(DropLootError::not_in_world(self.entity, nonce));
+        }
+
+        Ok(())
+    }
+
+    fn apply(&self, state: &mut GameState, env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+        let map = env
+            .map()
+            .map_err(|_| DropLootError::map_not_available(nonce))?;
+
+        let Some(actor) = state.entities.actor(self.entity) else {
+            return Err(DropLootError::entity_not_found(self.entity, nonce));
+        };
+        let Some(origin) = actor.position else {
+            return Err(DropLootError::not_in_world(self.entity, nonce));
+        };
+        let slots = actor.inventory.items.clone();
+
+        let mut dropped = 0;
+        for slot in &slots {
+            if state.entities.items.len() >= GameConfig::MAX_WORLD_ITEMS {
+                break;
+            }
+            let Some(position) = drop_tile(origin, state, map) else {
+                break;
+            };
+            let id = state
+                .allocate_entity_id()
+                .map_err(|_| DropLootError::entity_ids_exhausted(self.entity, nonce))?;
+
+            let item = ItemState::new(id, position, slot.handle, slot.quantity);
+            if state.entities.items.push(item).is_err() {
+                break;
+            }
+            state.world.tile_map.add_occupant(position, id);
+            dropped += 1;
+        }
+
+        if let Some(actor) = state.entities.actor_mut(self.entity) {
+            actor.inventory.items.drain(..dropped);
+        }
+
+        Ok(())
+    }
+
+    fn cost(&self, _env: &GameEnv<'_>) -> Tick {
+        0 // System actions have no time cost
+    }
+}
+
+/// Picks the tile the next item lands on: `origin`, else the first adjacent
+/// passable tile with a free occupant slot.
+fn drop_tile(origin: Position, state: &GameState, map: &dyn MapOracle) -> Option<Position> {
+    let neighbours = CardinalDirection::all().into_iter().map(|dir| {
+        let (dx, dy) = dir.offset();
+        Position::new(origin.x + dx, origin.y + dy)
+    });
+
+    std::iter::once(origin).chain(neighbours).find(|&position| {
+        let has_room = state
+            .world
+            .tile_map
+            .occupants(&position)
+            .is_none_or(|occupants| occupants.len() < GameConfig::MAX_OCCUPANTS_PER_TILE);
+
+        map.dimensions().contains(position)
+            && map.tile(position).is_some_and(|tile| tile.is_passable())
+            && has_room
+    })
+}
@@ -6,6 +6,9 @@
 //! - Turn scheduling and actor selection
 //! - Entity activation/deactivation based on game rules
 //! - Entity removal from world and turn scheduling
+//! - Status effect expiry at turn start
+//! - Opportunity attacks provoked by moving away from a hostile actor
+//! - Dropping a dead actor's inventory as ground items
 //!
 //! Note: Action cost application is now handled directly within character action
 //! execution (see `action::execute::pipeline`) to avoid double validation overhead.
@@ -16,10 +19,16 @@
 
 mod activation;
 mod deactivate;
+mod drop_loot;
+mod opportunity_attack;
 mod prepare_turn;
 mod remove_from_world;
+mod tick_status;
 
 pub use activation::ActivationAction;
 pub use deactivate::DeactivateAction;
+pub use drop_loot::DropLootAction;
+pub use opportunity_attack::OpportunityAttackAction;
 pub use prepare_turn::PrepareTurnAction;
 pub use remove_from_world::RemoveFromWorldAction;
+pub use tick_status::TickStatusEffectsAction;
@@ -0,0 +1,119 @@
+//! Opportunity attack system action.
+//!
+//! An actor that moves off a tile next to a hostile actor provokes a free
+//! melee attack from it. The reaction is resolved as a system action so that
+//! it happens outside the attacker's turn, yet goes through the same
+//! validation pipeline and proof as any other action.
+
+use crate::action::error::OpportunityAttackError;
+use crate::action::execute;
+use crate::action::{ActionInput, ActionKind, ActionTransition, CharacterAction};
+use crate::env::GameEnv;
+use crate::state::{EntityId, GameState, Position, Tick};
+
+/// System action that lets `attacker` strike `target` as it leaves `vacated`.
+///
+/// The attacker's `MeleeAttack` profile is applied to the target as a
+/// reaction: its effects and cooldown apply, but the attacker's `ready_at`
+/// does not move. The target has already stepped away, so only the vacated
+/// tile is checked for reach.
+///
+/// # Invariants
+///
+/// - Attacker and target exist and are alive
+/// - Attacker is hostile to the target
+/// - Attacker is adjacent (Chebyshev distance 1) to the vacated tile
+/// - Attacker's `MeleeAttack` is readied (known, enabled, off cooldown)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpportunityAttackAction {
+    /// The entity reacting to the move
+    pub attacker: EntityId,
+
+    /// The entity that moved away
+    pub target: EntityId,
+
+    /// The tile the target left
+    pub vacated: Position,
+}
+
+impl OpportunityAttackAction {
+    /// Creates a new OpportunityAttack action.
+    pub fn new(attacker: EntityId, target: EntityId, vacated: Position) -> Self {
+        Self {
+            attacker,
+            target,
+            vacated,
+        }
+    }
+
+    fn strike(&self) -> CharacterAction {
+        CharacterAction::new(
+            self.attacker,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(self.target),
+        )
+    }
+}
+
+impl ActionTransition for OpportunityAttackAction {
+    type Error = OpportunityAttackError;
+    type Result = ();
+
+    fn actor(&self) -> EntityId {
+        EntityId::SYSTEM
+    }
+
+    fn pre_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        // Verify this action is executed by the SYSTEM actor
+        if self.actor() != EntityId::SYSTEM {
+            return Err(OpportunityAttackError::not_system_actor(nonce));
+        }
+
+        let attacker = state
+            .entities
+            .actor(self.attacker)
+            .filter(|actor| actor.is_alive())
+            .ok_or_else(|| OpportunityAttackError::entity_not_found(self.attacker, nonce))?;
+        let target = state
+            .entities
+            .actor(self.target)
+            .filter(|actor| actor.is_alive())
+            .ok_or_else(|| OpportunityAttackError::entity_not_found(self.target, nonce))?;
+
+        let no_reaction =
+            |reason| OpportunityAttackError::no_reaction(self.attacker, self.target, reason, nonce);
+
+        if !attacker.faction.is_hostile_to(&target.faction) {
+            return Err(no_reaction("attacker is not hostile to the target"));
+        }
+
+        let threatens = attacker
+            .position
+            .is_some_and(|position| position.chebyshev_distance(self.vacated) <= 1);
+        if !threatens {
+            return Err(no_reaction("attacker is not adjacent to the vacated tile"));
+        }
+
+        if !attacker.can_use_action(ActionKind::MeleeAttack, state.turn.clock) {
+            return Err(no_reaction("attacker has no readied melee attack"));
+        }
+
+        Ok(())
+    }
+
+    fn apply(&self, state: &mut GameState, env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        execute::apply_reaction(&self.strike(), state, env)
+            .map_err(|error| OpportunityAttackError::strike(self.attacker, error, nonce))?;
+
+        Ok(())
+    }
+
+    fn cost(&self, _env: &GameEnv<'_>) -> Tick {
+        0 // System actions have no time cost
+    }
+}
@@ -14,12 +14,19 @@ use crate::state::{EntityId, GameState, Tick};
 /// 1. Finds the active entity with the smallest `ready_at` timestamp
 /// 2. Advances the game clock to that timestamp
 /// 3. Sets the entity as the current actor
+/// 4. Decays hazards that expired by the new clock
+/// 5. Deals the damage of any hazard the entity starts its turn on
+///
+/// The engine then runs [`TickStatusEffectsAction`] for the selected entity as
+/// part of the same action, dealing damage over time and expiring effects.
 ///
 /// # Invariants
 ///
 /// - At least one entity must be active (have a `ready_at` value)
 /// - The selected entity's `ready_at` must not be before the current clock
 /// - Tie-breaking uses entity ID (lower ID acts first)
+///
+/// [`TickStatusEffectsAction`]: crate::action::TickStatusEffectsAction
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrepareTurnAction;
@@ -76,6 +83,10 @@ impl ActionTransition for PrepareTurnAction {
         // Set current actor
         state.turn.current_actor = entity;
 
+        // Turn-start hazard processing: decay first, then burn the actor
+        state.world.tile_map.decay_hazards(ready_at);
+        state.apply_hazard(entity);
+
         Ok(())
     }
 
@@ -1,7 +1,8 @@
 //! Remove entity from world system action.
 //!
 //! This action removes an entity from their current position in the world,
-//! clearing both their position field and the world occupancy map.
+//! clearing both their position field and the world occupancy map. Ground
+//! items have no existence off the map, so they are deleted outright.
 
 use crate::action::ActionTransition;
 use crate::action::error::RemoveFromWorldError;
@@ -10,11 +11,14 @@ use crate::state::{EntityId, GameState, Tick};
 
 /// System action that removes an entity from the world.
 ///
-/// This action only:
+/// For an actor, this action only:
 /// 1. Clears the entity's position from world occupancy
 /// 2. Sets the entity's `position` to None
 ///
-/// Does NOT affect:
+/// For a ground item, the item is dropped from world occupancy and from the
+/// world item list.
+///
+/// Does NOT affect (actors):
 /// - Turn scheduling (active_actors, ready_at)
 /// - Entity stats or inventory
 /// - Entity existence (still in entities state)
@@ -24,10 +28,11 @@ use crate::state::{EntityId, GameState, Tick};
 /// - Entity death (corpse removed from map)
 /// - Entity leaving the game area
 /// - Entity being picked up or stored
+/// - Empty ground item being cleared away
 ///
 /// # Invariants
 ///
-/// - Entity must exist in the game state
+/// - Entity must exist in the game state as an actor or a ground item
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveFromWorldAction {
@@ -59,10 +64,10 @@ impl ActionTransition for RemoveFromWorldAction {
         }
 
         // Verify entity exists
-        state
-            .entities
-            .actor(self.entity)
-            .ok_or_else(|| RemoveFromWorldError::entity_not_found(self.entity, nonce))?;
+        if state.entities.actor(self.entity).is_none() && state.entities.item(self.entity).is_none()
+        {
+            return Err(RemoveFromWorldError::entity_not_found(self.entity, nonce));
+        }
 
         Ok(())
     }
@@ -77,6 +82,18 @@ impl ActionTransition for RemoveFromWorldAction {
 
             // Clear position
             actor.position = None;
+        } else if let Some(item) = state.entities.item(self.entity) {
+            // Ground items leave the world entirely
+            let position = item.position;
+            state.world.tile_map.remove_occupant(&position, self.entity);
+            if let Some(index) = state
+                .entities
+                .items
+                .iter()
+                .position(|item| item.id == self.entity)
+            {
+                let _ = state.entities.items.remove(index);
+            }
         }
 
         Ok(())
@@ -84,7 +101,8 @@ impl ActionTransition for RemoveFromWorldAction {
 
     fn post_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
         // Verify entity no longer has a position
-        if state.actor_position(self.entity).is_some() {
+        if state.actor_position(self.entity).is_some() || state.entities.item(self.entity).is_some()
+        {
             return Err(RemoveFromWorldError::StillHasPosition {
                 entity: self.entity,
                 nonce: state.turn.nonce,
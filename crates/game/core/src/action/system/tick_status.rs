@@ -0,0 +1,206 @@
+//! Status effect ticking system action.
+//!
+//! Deals damage over time, expires the status effects of the actor whose turn
+//! is starting and keeps its cached bonuses in sync. Runs as part of every
+//! PrepareTurn.
+
+use crate::action::ActionTransition;
+use crate::action::effect::apply_damage_over_time;
+use crate::action::error::TickStatusError;
+use crate::env::GameEnv;
+use crate::state::{EntityId, GameState, Tick};
+use crate::stats::compute_actor_bonuses;
+
+/// System action that ticks an actor's status effects at its turn start.
+///
+/// This action:
+/// 1. Deals one tick of each active damage-over-time effect (`Burning`,
+///    `Poisoned`), which may bring the actor to 0 HP
+/// 2. Removes every status effect whose `expires_at` is at or before the clock
+/// 3. Recomputes the actor's cached `ActorBonuses` if anything was removed
+///
+/// `Lifespan` is left in place even when expired: the turn-start despawn
+/// reaction reads it to remove the entity.
+///
+/// The engine runs this right after [`PrepareTurnAction`] selects the actor,
+/// so bonuses are current before the actor acts. An effect expiring at tick
+/// `t` is therefore gone at the owner's first turn starting at or after `t`.
+///
+/// # Invariants
+///
+/// - Entity must exist in the game state
+///
+/// [`PrepareTurnAction`]: crate::action::PrepareTurnAction
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickStatusEffectsAction {
+    /// The entity whose status effects are ticked
+    pub entity: EntityId,
+}
+
+impl TickStatusEffectsAction {
+    /// Creates a new TickStatusEffects action.
+    pub fn new(entity: EntityId) -> Self {
+        Self { entity }
+    }
+}
+
+impl ActionTransition for TickStatusEffectsAction {
+    type Error = TickStatusError;
+    type Result = ();
+
+    fn actor(&self) -> EntityId {
+        EntityId::SYSTEM
+    }
+
+    fn pre_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        // Verify this action is executed by the SYSTEM actor
+        if self.actor() != EntityId::SYSTEM {
+            return Err(TickStatusError::not_system_actor(nonce));
+        }
+
+        // Verify entity exists
+        state
+            .entities
+            .actor(self.entity)
+            .ok_or_else(|| TickStatusError::entity_not_found(self.entity, nonce))?;
+
+        Ok(())
+    }
+
+    fn apply(&self, state: &mut GameState, env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let clock = state.turn.clock;
+        let nonce = state.turn.nonce;
+
+        // Damage-over-time ticks first; effects that ran out deal nothing
+        apply_damage_over_time(state, env, self.entity)
+            .map_err(|error| TickStatusError::damage_over_time(self.entity, error, nonce))?;
+
+        let actor = state
+            .entities
+            .actor_mut(self.entity)
+            .ok_or_else(|| TickStatusError::entity_not_found(self.entity, nonce))?;
+
+        // Bonuses are cached, so refresh them whenever the effect set shrinks
+        if actor.status_effects.prune_expired(clock) {
+            actor.bonuses = compute_actor_bonuses();
+        }
+
+        Ok(())
+    }
+
+    fn cost(&self, _env: &GameEnv<'_>) -> Tick {
+        0 // System actions have no time cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{Action, PrepareTurnAction, SystemActionKind};
+    use crate::engine::GameEngine;
+    use crate::state::{ActorFields, StatusEffectKind};
+    use crate::stats::Bonus;
+
+    const TURN: Tick = 100;
+
+    /// Starts the player's next turn, `TURN` ticks after the previous one.
+    fn next_turn(state: &mut GameState) {
+        let player = state.entities.player_mut();
+        player.ready_at = player.ready_at.map(|tick| tick + TURN);
+
+        let prepare = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        GameEngine::new(state)
+            .execute(GameEnv::empty(), &prepare)
+            .unwrap();
+    }
+
+    #[test]
+    fn poison_expires_after_three_owner_turns() {
+        let mut state = GameState::with_player();
+        state
+            .entities
+            .player_mut()
+            .status_effects
+            .add(StatusEffectKind::Poisoned, 3 * TURN);
+
+        for turn in 1..=2 {
+            next_turn(&mut state);
+            let effects = &state.entities.player().status_effects;
+            assert!(
+                effects.has(StatusEffectKind::Poisoned, state.turn.clock),
+                "poison gone early, at owner turn {turn}"
+            );
+        }
+
+        next_turn(&mut state);
+        assert!(state.entities.player().status_effects.is_empty());
+    }
+
+    #[test]
+    fn expiry_recomputes_cached_bonuses() {
+        let mut state = GameState::with_player();
+        let player = state.entities.player_mut();
+        player.status_effects.add(StatusEffectKind::Slowed, TURN);
+        player.bonuses.speed.physical.add(Bonus::flat(-20));
+
+        let slowed = player.bonuses.clone();
+        next_turn(&mut state);
+
+        let player = state.entities.player();
+        assert!(player.status_effects.is_empty());
+        assert_ne!(player.bonuses, slowed);
+        assert_eq!(player.bonuses, compute_actor_bonuses());
+    }
+
+    #[test]
+    fn expired_lifespan_is_kept_for_despawn() {
+        let mut state = GameState::with_player();
+        state
+            .entities
+            .player_mut()
+            .status_effects
+            .add(StatusEffectKind::Lifespan, TURN);
+
+        next_turn(&mut state);
+
+        let effects = &state.entities.player().status_effects;
+        assert!(effects.has_expired(StatusEffectKind::Lifespan, state.turn.clock));
+    }
+
+    #[test]
+    fn burn_that_outlasts_hp_kills_the_actor() {
+        let mut state = GameState::with_player();
+        let player = state.entities.player_mut();
+        player.resources.hp = 8;
+        player
+            .status_effects
+            .add_with_magnitude(StatusEffectKind::Burning, 10 * TURN, 5);
+
+        next_turn(&mut state);
+        assert_eq!(state.entities.player().resources.hp, 3);
+
+        let player = state.entities.player_mut();
+        player.ready_at = player.ready_at.map(|tick| tick + TURN);
+        let prepare = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let outcome = GameEngine::new(&mut state)
+            .execute(GameEnv::empty(), &prepare)
+            .unwrap();
+
+        // HP floors at 0 and the resources change is what reports the death
+        let player = state.entities.player();
+        assert_eq!(player.resources.hp, 0);
+        assert!(!player.is_alive());
+        let change = outcome
+            .delta
+            .entities
+            .actors
+            .updated
+            .iter()
+            .find(|change| change.id == EntityId::PLAYER)
+            .unwrap();
+        assert!(change.fields.contains(ActorFields::RESOURCES));
+    }
+}
@@ -5,13 +5,14 @@
 //! - None: No target
 //! - SelfOnly: Caster only
 //! - SingleTarget: One entity within range
+//! - Chain: One entity within range, then hops to nearby enemies
+//! - LineOfSight: One entity within range and in clear view (projectiles)
+//! - Burst: Every actor around a chosen position
 //! - Directional: Direction-based (for movement)
 //!
 //! ## Future Extensions
 //! When needed, add:
-//! - AOE targeting (circle, cone, line)
 //! - Multi-target selection
-//! - Chain targeting
 //! - Target filters (team, type, status)
 
 /// How an action selects targets.
@@ -41,6 +42,51 @@ pub enum TargetingMode {
         requires_los: bool,
     },
 
+    /// Chained entity targets.
+    ///
+    /// Action targets one entity within range like `SingleTarget`, then jumps
+    /// to the nearest living enemy of the caster not yet struck, measured from
+    /// the previous target. Ties are broken by `EntityId`, so the chain is
+    /// deterministic. Effects see the hop index in `EffectContext::hop`.
+    Chain {
+        /// Maximum range to the initial target in tiles (Chebyshev distance).
+        range: u32,
+
+        /// Whether line of sight is required for the initial target.
+        requires_los: bool,
+
+        /// Maximum distance of each jump in tiles (Chebyshev distance).
+        hop_range: u32,
+
+        /// Maximum number of entities struck, including the initial target.
+        max_hops: u32,
+    },
+
+    /// Projectile targeting.
+    ///
+    /// Action targets one entity within `max_range` (Chebyshev distance) that
+    /// the caster can see: no tile strictly between the two may block sight,
+    /// as decided by `MapOracle::line_of_sight`. Used for bows, thrown
+    /// weapons and bolts.
+    LineOfSight {
+        /// Maximum range in tiles (Chebyshev distance).
+        max_range: u8,
+    },
+
+    /// Area around a position.
+    ///
+    /// Action requires a position and strikes every living actor within
+    /// `radius` of it (Chebyshev distance), in `EntityId` order. Without
+    /// `friendly_fire` only actors hostile to the caster are struck; with it,
+    /// allies and the caster itself are struck too.
+    Burst {
+        /// Reach from the centre in tiles (Chebyshev distance).
+        radius: u8,
+
+        /// Whether actors not hostile to the caster are struck as well.
+        friendly_fire: bool,
+    },
+
     /// Direction-based targeting.
     ///
     /// Action requires a cardinal direction.
@@ -53,17 +99,70 @@ pub enum TargetingMode {
         /// TODO: Implement width-based targeting.
         width: Option<u32>,
     },
+
+    /// Beam along a direction.
+    ///
+    /// Action requires a cardinal direction and strikes every living actor on the
+    /// tiles stepping away from the caster, nearest first. The beam stops
+    /// after `length` tiles, at the first sight-blocking tile, or at the map
+    /// edge, whichever comes first.
+    Line {
+        /// Maximum number of tiles the beam travels.
+        length: u32,
+    },
+
+    /// Wedge spreading out along a direction.
+    ///
+    /// Action requires a cardinal direction and strikes every living actor on
+    /// a tile inside the wedge that the caster can see, in `EntityId` order.
+    /// A tile is inside when it is within `length` (Chebyshev distance), in
+    /// front of the caster, and its sideways offset is at most
+    /// `width × forward / length`, so the wedge reaches `width` tiles to each
+    /// side at its far end. Walls block the wedge behind them.
+    Cone {
+        /// Maximum reach in tiles (Chebyshev distance).
+        length: u32,
+
+        /// Tiles to each side of the centre line at full length.
+        width: u32,
+    },
 }
 
 impl TargetingMode {
     /// Returns true if this mode requires a target entity.
     pub fn requires_entity_target(&self) -> bool {
-        matches!(self, TargetingMode::SingleTarget { .. })
+        matches!(
+            self,
+            TargetingMode::SingleTarget { .. }
+                | TargetingMode::Chain { .. }
+                | TargetingMode::LineOfSight { .. }
+        )
+    }
+
+    /// Returns the maximum range to the target entity, for modes that need one.
+    pub fn entity_range(&self) -> Option<u32> {
+        match self {
+            TargetingMode::SingleTarget { range, .. } | TargetingMode::Chain { range, .. } => {
+                Some(*range)
+            }
+            TargetingMode::LineOfSight { max_range } => Some(u32::from(*max_range)),
+            _ => None,
+        }
     }
 
     /// Returns true if this mode requires a direction.
     pub fn requires_direction(&self) -> bool {
-        matches!(self, TargetingMode::Directional { .. })
+        matches!(
+            self,
+            TargetingMode::Directional { .. }
+                | TargetingMode::Line { .. }
+                | TargetingMode::Cone { .. }
+        )
+    }
+
+    /// Returns true if this mode requires a position.
+    pub fn requires_position(&self) -> bool {
+        matches!(self, TargetingMode::Burst { .. })
     }
 
     /// Returns true if this is self-only targeting.
@@ -106,6 +205,39 @@ impl TargetingMode {
         }
     }
 
+    /// Creates a chain targeting mode that jumps between nearby enemies.
+    pub fn chain(range: u32, hop_range: u32, max_hops: u32) -> Self {
+        Self::Chain {
+            range,
+            requires_los: true,
+            hop_range,
+            max_hops,
+        }
+    }
+
+    /// Creates a projectile targeting mode that needs a clear line.
+    pub fn projectile(max_range: u8) -> Self {
+        Self::LineOfSight { max_range }
+    }
+
+    /// Creates a burst targeting mode that spares the caster's allies.
+    pub fn burst(radius: u8) -> Self {
+        Self::Burst {
+            radius,
+            friendly_fire: false,
+        }
+    }
+
+    /// Creates a beam targeting mode that travels `length` tiles.
+    pub fn line(length: u32) -> Self {
+        Self::Line { length }
+    }
+
+    /// Creates a cone targeting mode reaching `length` tiles.
+    pub fn cone(length: u32, width: u32) -> Self {
+        Self::Cone { length, width }
+    }
+
     /// Creates a self-buff targeting mode.
     pub fn self_buff() -> Self {
         Self::SelfOnly
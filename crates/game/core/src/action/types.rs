@@ -117,7 +117,7 @@ pub enum ActionInput {
     /// The effect implementation determines how to interpret this entity.
     /// For example:
     /// - DamageEffect interprets this as an Actor to damage
-    /// - PickupItemEffect interprets this as an Item to pick up
+    /// - AcquireItemEffect (the PickupItem action) interprets this as an Item to pick up
     /// - InteractEffect interprets this as a Prop or Actor to interact with
     Target(EntityId),
 
@@ -132,7 +132,7 @@ pub enum ActionInput {
 
     /// Target an inventory slot.
     ///
-    /// Used by item-related effects (UseConsumableEffect, EquipItemEffect)
+    /// Used by item-related effects (UseConsumableEffect, EquipWeaponEffect)
     /// to specify which inventory slot contains the item to use/equip.
     InventorySlot(u8),
 }
@@ -204,9 +204,10 @@ pub struct EffectResult {
 pub enum AppliedValue {
     /// Damage was dealt.
     Damage {
-        /// Planned damage amount.
-        planned: u32,
-        /// Actual damage dealt (after resistance, etc.).
+        /// HP actually lost, capped at the target's remaining HP.
+        ///
+        /// Overkill is not recorded: a lethal hit reports exactly the HP the
+        /// target had left.
         actual: u32,
     },
 
@@ -272,6 +273,28 @@ pub enum AppliedValue {
         handle: crate::state::ItemHandle,
     },
 
+    /// Item was equipped from inventory.
+    ItemEquipped {
+        /// The item handle now equipped.
+        handle: crate::state::ItemHandle,
+        /// The previously equipped item, returned to inventory.
+        previous: Option<crate::state::ItemHandle>,
+    },
+
+    /// A prop was operated (lever pulled, door opened, etc.).
+    PropInteracted {
+        /// The prop entity ID that was operated.
+        prop_id: EntityId,
+        /// Whether the prop is active after the interaction.
+        is_active: bool,
+    },
+
+    /// A hazard was placed on the map.
+    HazardCreated {
+        /// Number of tiles covered.
+        tiles: u32,
+    },
+
     /// No value (for effects like Wait, or failed effects).
     None,
 }
@@ -390,7 +413,7 @@ impl ActionResult {
 
         for effect in effects {
             // Aggregate damage
-            if let AppliedValue::Damage { actual, .. } = effect.applied_value {
+            if let AppliedValue::Damage { actual } = effect.applied_value {
                 summary.total_damage += actual;
             }
 
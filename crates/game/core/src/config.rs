@@ -1,10 +1,30 @@
+use crate::action::CardinalDirection;
+use crate::state::{Position, Tick};
+use crate::traits::FactionRelations;
+
 /// Game configuration constants and tunable parameters.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GameConfig {
     /// Radius around the player within which NPCs are activated and scheduled.
     /// Entities outside this radius are deactivated to save computation.
     pub activation_radius: u32,
+    /// Region around the player within which the Activation system action
+    /// activates NPCs and outside which it deactivates them.
+    pub activation_shape: ActivationShape,
+    /// Percentage of ranged damage absorbed when the target is in partial cover.
+    pub cover_damage_reduction: u32,
+    /// Base delay (ticks) before an NPC's first turn after activation, scaled
+    /// down by physical speed. 0 schedules every activated NPC immediately.
+    pub initiative_window: u32,
+    /// Ticks a dead actor may stay on the map before it is cleaned up.
+    pub corpse_grace_period: Tick,
+    /// Ticks an actor stays hostile towards whoever last damaged it.
+    /// 0 disables retaliation.
+    pub aggro_duration: Tick,
+    /// How factions regard each other, on top of the built-in hostility rules.
+    pub faction_relations: FactionRelations,
 }
 
 impl GameConfig {
@@ -26,15 +46,31 @@ impl GameConfig {
 
     // ===== runtime-tunable defaults =====
     pub const DEFAULT_ACTIVATION_RADIUS: u32 = 5;
+    /// The square region activation has always used; `activation_radius` does
+    /// not feed into it.
+    pub const DEFAULT_ACTIVATION_SHAPE: ActivationShape = ActivationShape::Chebyshev(10);
+    pub const DEFAULT_COVER_DAMAGE_REDUCTION: u32 = 50;
+    pub const DEFAULT_INITIATIVE_WINDOW: u32 = 50;
+    pub const DEFAULT_CORPSE_GRACE_PERIOD: Tick = 1_000;
+    pub const DEFAULT_AGGRO_DURATION: Tick = 500;
 
     pub fn new() -> Self {
         Self {
             activation_radius: Self::DEFAULT_ACTIVATION_RADIUS,
+            activation_shape: Self::DEFAULT_ACTIVATION_SHAPE,
+            cover_damage_reduction: Self::DEFAULT_COVER_DAMAGE_REDUCTION,
+            initiative_window: Self::DEFAULT_INITIATIVE_WINDOW,
+            corpse_grace_period: Self::DEFAULT_CORPSE_GRACE_PERIOD,
+            aggro_duration: Self::DEFAULT_AGGRO_DURATION,
+            faction_relations: FactionRelations::new(),
         }
     }
 
     pub fn with_activation_radius(activation_radius: u32) -> Self {
-        Self { activation_radius }
+        Self {
+            activation_radius,
+            ..Self::new()
+        }
     }
 }
 
@@ -43,3 +79,160 @@ impl Default for GameConfig {
         Self::new()
     }
 }
+
+/// Region around the player in which NPCs are activated.
+///
+/// All distances are measured in tiles from the player's position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActivationShape {
+    /// Square of the given Chebyshev radius.
+    Chebyshev(u32),
+
+    /// Disc of the given Euclidean radius.
+    Euclidean(u32),
+
+    /// Field of view: tiles within `range` (Euclidean) whose bearing is at
+    /// most `half_angle_deg` away from `facing`. The player's own tile is
+    /// always inside.
+    Cone {
+        range: u32,
+        facing: CardinalDirection,
+        half_angle_deg: u8,
+    },
+}
+
+impl ActivationShape {
+    /// Returns the largest Chebyshev distance any covered tile can have.
+    pub fn range(&self) -> u32 {
+        match *self {
+            Self::Chebyshev(range) | Self::Euclidean(range) | Self::Cone { range, .. } => range,
+        }
+    }
+
+    /// Returns true if `position` lies inside the shape centred on `origin`.
+    pub fn contains(&self, origin: Position, position: Position) -> bool {
+        let ox = i64::from(position.x) - i64::from(origin.x);
+        let oy = i64::from(position.y) - i64::from(origin.y);
+        let within_disc = |range: u32| ox * ox + oy * oy <= i64::from(range).pow(2);
+
+        match *self {
+            Self::Chebyshev(range) => ox.abs().max(oy.abs()) <= i64::from(range),
+            Self::Euclidean(range) => within_disc(range),
+            Self::Cone {
+                range,
+                facing,
+                half_angle_deg,
+            } => {
+                let (dx, dy) = facing.offset();
+                let (dx, dy) = (i64::from(dx), i64::from(dy));
+                let forward = ox * dx + oy * dy;
+                let side = (ox * dy - oy * dx).abs();
+                within_disc(range)
+                    && ((ox, oy) == (0, 0) || within_angle(forward, side, half_angle_deg))
+            }
+        }
+    }
+}
+
+impl Default for ActivationShape {
+    fn default() -> Self {
+        GameConfig::DEFAULT_ACTIVATION_SHAPE
+    }
+}
+
+/// `tan(θ)` scaled by 1000, for θ = 0..=89 degrees.
+const TAN_MILLI: [i64; 90] = [
+    0, 17, 35, 52, 70, 87, 105, 123, 141, 158, 176, 194, 213, 231, 249, 268, 287, 306, 325, 344,
+    364, 384, 404, 424, 445, 466, 488, 510, 532, 554, 577, 601, 625, 649, 675, 700, 727, 754, 781,
+    810, 839, 869, 900, 933, 966, 1000, 1036, 1072, 1111, 1150, 1192, 1235, 1280, 1327, 1376, 1428,
+    1483, 1540, 1600, 1664, 1732, 1804, 1881, 1963, 2050, 2145, 2246, 2356, 2475, 2605, 2747, 2904,
+    3078, 3271, 3487, 3732, 4011, 4331, 4705, 5145, 5671, 6314, 7115, 8144, 9514, 11430, 14301,
+    19081, 28636, 57290,
+];
+
+/// Returns true if an offset `forward` tiles ahead and `side` tiles across
+/// the facing axis is at most `half_angle_deg` off that axis.
+///
+/// Integer-only so that activation stays deterministic inside the zkVM.
+fn within_angle(forward: i64, side: i64, half_angle_deg: u8) -> bool {
+    match half_angle_deg {
+        0..=89 => forward > 0 && side * 1000 <= TAN_MILLI[half_angle_deg as usize] * forward,
+        90 => forward >= 0,
+        91..=179 => {
+            // Inside unless strictly within the opposite, narrower cone
+            let behind = TAN_MILLI[(180 - half_angle_deg) as usize];
+            forward >= 0 || side * 1000 >= behind * -forward
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER: Position = Position { x: 10, y: 10 };
+
+    fn at(dx: i32, dy: i32) -> Position {
+        Position::new(PLAYER.x + dx, PLAYER.y + dy)
+    }
+
+    #[test]
+    fn chebyshev_covers_a_square() {
+        let shape = ActivationShape::Chebyshev(3);
+        assert!(shape.contains(PLAYER, at(3, 3)));
+        assert!(shape.contains(PLAYER, at(-3, 0)));
+        assert!(!shape.contains(PLAYER, at(4, 0)));
+        assert!(!shape.contains(PLAYER, at(1, -4)));
+    }
+
+    #[test]
+    fn euclidean_cuts_the_corners() {
+        let shape = ActivationShape::Euclidean(3);
+        assert!(shape.contains(PLAYER, at(3, 0)));
+        assert!(shape.contains(PLAYER, at(2, -2)));
+        assert!(!shape.contains(PLAYER, at(3, 3)));
+        assert!(!shape.contains(PLAYER, at(0, 4)));
+    }
+
+    #[test]
+    fn cone_covers_only_what_lies_ahead() {
+        let shape = ActivationShape::Cone {
+            range: 5,
+            facing: CardinalDirection::East,
+            half_angle_deg: 45,
+        };
+        assert!(shape.contains(PLAYER, PLAYER));
+        assert!(shape.contains(PLAYER, at(5, 0)));
+        assert!(shape.contains(PLAYER, at(3, 3)));
+        assert!(shape.contains(PLAYER, at(3, -2)));
+        // Too wide, behind the player, or out of range
+        assert!(!shape.contains(PLAYER, at(2, 3)));
+        assert!(!shape.contains(PLAYER, at(-1, 0)));
+        assert!(!shape.contains(PLAYER, at(0, 1)));
+        assert!(!shape.contains(PLAYER, at(6, 0)));
+    }
+
+    #[test]
+    fn wide_cone_leaves_only_a_blind_spot_behind() {
+        let shape = ActivationShape::Cone {
+            range: 5,
+            facing: CardinalDirection::North,
+            half_angle_deg: 135,
+        };
+        assert!(shape.contains(PLAYER, at(0, 4)));
+        assert!(shape.contains(PLAYER, at(4, 0)));
+        assert!(shape.contains(PLAYER, at(-3, -3)));
+        assert!(!shape.contains(PLAYER, at(-1, -3)));
+        assert!(!shape.contains(PLAYER, at(0, -2)));
+    }
+
+    #[test]
+    fn default_shape_is_the_legacy_square() {
+        assert_eq!(
+            GameConfig::default().activation_shape,
+            ActivationShape::Chebyshev(10)
+        );
+    }
+}
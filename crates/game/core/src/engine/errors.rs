@@ -1,8 +1,8 @@
 //! Error types for action execution pipeline.
 
 use crate::action::{
-    ActionError, ActionTransition, ActivationAction, DeactivateAction, PrepareTurnAction,
-    RemoveFromWorldAction,
+    ActionError, ActionTransition, ActivationAction, DeactivateAction, DropLootAction,
+    OpportunityAttackAction, PrepareTurnAction, RemoveFromWorldAction, TickStatusEffectsAction,
 };
 use crate::error::{ErrorContext, ErrorSeverity, GameError};
 
@@ -114,6 +114,18 @@ pub enum ExecuteError {
     #[cfg_attr(feature = "serde", serde(skip))]
     RemoveFromWorld(TransitionPhaseError<<RemoveFromWorldAction as ActionTransition>::Error>),
 
+    #[error("tick status effects action failed: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    TickStatusEffects(TransitionPhaseError<<TickStatusEffectsAction as ActionTransition>::Error>),
+
+    #[error("opportunity attack action failed: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    OpportunityAttack(TransitionPhaseError<<OpportunityAttackAction as ActionTransition>::Error>),
+
+    #[error("drop loot action failed: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    DropLoot(TransitionPhaseError<<DropLootAction as ActionTransition>::Error>),
+
     #[error("hook chain too deep: hook '{hook_name}' reached depth {depth}")]
     HookChainTooDeep {
         hook_name: String,
@@ -187,6 +199,9 @@ impl ExecuteError {
             Self::Activation(e) => Some(e.phase),
             Self::Deactivate(e) => Some(e.phase),
             Self::RemoveFromWorld(e) => Some(e.phase),
+            Self::TickStatusEffects(e) => Some(e.phase),
+            Self::OpportunityAttack(e) => Some(e.phase),
+            Self::DropLoot(e) => Some(e.phase),
             Self::HookChainTooDeep { .. }
             | Self::SystemActionNotFromSystem { .. }
             | Self::ActorNotCurrent { .. } => None,
@@ -246,6 +261,9 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.severity(),
             Self::Deactivate(e) => e.severity(),
             Self::RemoveFromWorld(e) => e.severity(),
+            Self::TickStatusEffects(e) => e.severity(),
+            Self::OpportunityAttack(e) => e.severity(),
+            Self::DropLoot(e) => e.severity(),
             Self::HookChainTooDeep { .. } => ErrorSeverity::Fatal,
             Self::SystemActionNotFromSystem { .. } => ErrorSeverity::Validation,
             Self::ActorNotCurrent { .. } => ErrorSeverity::Validation,
@@ -259,6 +277,9 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.context(),
             Self::Deactivate(e) => e.context(),
             Self::RemoveFromWorld(e) => e.context(),
+            Self::TickStatusEffects(e) => e.context(),
+            Self::OpportunityAttack(e) => e.context(),
+            Self::DropLoot(e) => e.context(),
             Self::HookChainTooDeep { context, .. } => Some(context),
             Self::SystemActionNotFromSystem { context, .. } => Some(context),
             Self::ActorNotCurrent { context, .. } => Some(context),
@@ -272,6 +293,9 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.error_code(),
             Self::Deactivate(e) => e.error_code(),
             Self::RemoveFromWorld(e) => e.error_code(),
+            Self::TickStatusEffects(e) => e.error_code(),
+            Self::OpportunityAttack(e) => e.error_code(),
+            Self::DropLoot(e) => e.error_code(),
             Self::HookChainTooDeep { .. } => "EXECUTE_HOOK_CHAIN_TOO_DEEP",
             Self::SystemActionNotFromSystem { .. } => "EXECUTE_SYSTEM_ACTION_INVALID",
             Self::ActorNotCurrent { .. } => "EXECUTE_ACTOR_NOT_CURRENT",
@@ -69,6 +69,9 @@ impl<'a> GameEngine<'a> {
         // Execute the action through transition pipeline and get result
         let action_result = transition::execute_transition(action, self.state, &env)?;
 
+        // Keep death timestamps in step with HP
+        self.state.record_deaths();
+
         // Increment nonce after successful execution
         self.state.turn.nonce += 1;
 
@@ -1,6 +1,8 @@
 //! Action transition dispatch and execution logic.
 
-use crate::action::{Action, ActionResult, ActionTransition, SystemActionKind, execute};
+use crate::action::{
+    Action, ActionResult, ActionTransition, SystemActionKind, TickStatusEffectsAction, execute,
+};
 use crate::env::GameEnv;
 use crate::state::GameState;
 
@@ -73,6 +75,10 @@ pub(super) fn execute_transition(
             match kind {
                 SystemActionKind::PrepareTurn(transition) => {
                     drive_transition(transition, state, env).map_err(ExecuteError::PrepareTurn)?;
+
+                    // Tick the new actor's status effects before it acts
+                    let tick = TickStatusEffectsAction::new(state.turn.current_actor);
+                    drive_transition(&tick, state, env).map_err(ExecuteError::TickStatusEffects)?;
                 }
                 SystemActionKind::Activation(transition) => {
                     drive_transition(transition, state, env).map_err(ExecuteError::Activation)?;
@@ -84,6 +90,17 @@ pub(super) fn execute_transition(
                     drive_transition(transition, state, env)
                         .map_err(ExecuteError::RemoveFromWorld)?;
                 }
+                SystemActionKind::TickStatusEffects(transition) => {
+                    drive_transition(transition, state, env)
+                        .map_err(ExecuteError::TickStatusEffects)?;
+                }
+                SystemActionKind::OpportunityAttack(transition) => {
+                    drive_transition(transition, state, env)
+                        .map_err(ExecuteError::OpportunityAttack)?;
+                }
+                SystemActionKind::DropLoot(transition) => {
+                    drive_transition(transition, state, env).map_err(ExecuteError::DropLoot)?;
+                }
             }
             Ok(None)
         }
@@ -112,7 +112,10 @@ impl ActorTemplate {
             ),
             species: self.species,
             faction: self.faction,
+            objective: None,
             ready_at: None,
+            channel: None,
+            died_at: None,
         }
     }
 
@@ -121,9 +124,11 @@ impl ActorTemplate {
         ActorTemplateBuilder::default()
     }
 
-    /// Create a test actor template with default stats
+    /// Create a test actor template with default stats and traits
     pub fn test_actor() -> Self {
-        ActorTemplate::builder().build()
+        ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build()
     }
 }
 
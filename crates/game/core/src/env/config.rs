@@ -1,7 +1,29 @@
 //! Configuration oracle for exposing game configuration to the engine.
 
+use crate::config::ActivationShape;
+use crate::state::Tick;
+use crate::traits::FactionRelations;
+
 /// Provides access to runtime configuration values.
 pub trait ConfigOracle: Send + Sync {
     /// Returns the activation radius around the player within which NPCs are activated.
     fn activation_radius(&self) -> u32;
+
+    /// Returns the region around the player within which NPCs are activated.
+    fn activation_shape(&self) -> ActivationShape;
+
+    /// Returns the percentage of ranged damage absorbed by partial cover.
+    fn cover_damage_reduction(&self) -> u32;
+
+    /// Returns the base delay before an activated NPC's first turn.
+    fn initiative_window(&self) -> u32;
+
+    /// Returns how long a dead actor may stay on the map before cleanup.
+    fn corpse_grace_period(&self) -> Tick;
+
+    /// Returns how long a damaged actor stays hostile towards its attacker.
+    fn aggro_duration(&self) -> Tick;
+
+    /// Returns the table of how factions regard each other.
+    fn faction_relations(&self) -> &FactionRelations;
 }
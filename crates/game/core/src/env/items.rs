@@ -1,4 +1,4 @@
-use crate::state::ItemHandle;
+use crate::state::{ItemHandle, Tick};
 
 pub trait ItemOracle: Send + Sync {
     fn definition(&self, handle: ItemHandle) -> Option<ItemDefinition>;
@@ -144,12 +144,47 @@ pub enum ItemKind {
     Custom(u16),
 }
 
+impl ItemKind {
+    /// Uses an equipped weapon or armor of this kind has before it breaks.
+    ///
+    /// `None` for indestructible equipment and for items that cannot be
+    /// equipped.
+    pub fn durability(&self) -> Option<u16> {
+        match self {
+            ItemKind::Weapon(weapon) => weapon.durability,
+            ItemKind::Armor(armor) => armor.durability,
+            _ => None,
+        }
+    }
+}
+
 /// Weapon-specific data.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WeaponData {
     pub kind: WeaponKind,
     pub damage: u16,
+
+    /// Chebyshev distance a melee attack with this weapon can cover.
+    ///
+    /// 1 strikes adjacent tiles only; polearms use 2. Omitted in data, it
+    /// defaults to [`WeaponData::DEFAULT_REACH`].
+    #[cfg_attr(feature = "serde", serde(default = "WeaponData::default_reach"))]
+    pub reach: u8,
+
+    /// Attacks the weapon lands before it breaks. `None` never breaks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub durability: Option<u16>,
+}
+
+impl WeaponData {
+    /// Reach of weapons that do not set one, and of unarmed attacks.
+    pub const DEFAULT_REACH: u8 = 1;
+
+    #[cfg(feature = "serde")]
+    fn default_reach() -> u8 {
+        Self::DEFAULT_REACH
+    }
 }
 
 /// Armor-specific data.
@@ -158,6 +193,10 @@ pub struct WeaponData {
 pub struct ArmorData {
     pub kind: ArmorKind,
     pub defense: u16,
+
+    /// Hits the armor absorbs before it breaks. `None` never breaks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub durability: Option<u16>,
 }
 
 /// Consumable-specific data.
@@ -171,4 +210,8 @@ pub struct ConsumableData {
 
     /// Action cost to use this consumable (0 = free action).
     pub use_cost: u32,
+
+    /// Ticks before the same inventory slot can be used again (0 = none).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cooldown: Tick,
 }
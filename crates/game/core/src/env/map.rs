@@ -1,3 +1,4 @@
+use crate::action::DamageType;
 use crate::state::Position;
 
 /// Static map oracle exposing immutable layout information.
@@ -11,6 +12,39 @@ pub trait MapOracle: Send + Sync {
     fn contains(&self, position: Position) -> bool {
         self.dimensions().contains(position)
     }
+
+    /// Whether nothing blocks sight strictly between `from` and `to`.
+    ///
+    /// Walks the Bresenham line between the two tiles, so the result is exact
+    /// and identical on every platform. The endpoints themselves are not
+    /// checked; an intermediate tile that blocks sight or lies off the map
+    /// breaks the line.
+    fn line_of_sight(&self, from: Position, to: Position) -> bool {
+        let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+        let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+        let mut err = dx + dy;
+        let (mut x, mut y) = (from.x, from.y);
+
+        loop {
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+
+            let position = Position::new(x, y);
+            if position == to {
+                return true;
+            }
+            if self.tile(position).is_none_or(|tile| tile.blocks_sight()) {
+                return false;
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -42,7 +76,8 @@ pub struct StaticTile {
 
 impl StaticTile {
     /// Creates a tile with the given base terrain. Gameplay-only semantics such as
-    /// hazards, doors, or triggers belong to runtime state rather than the map oracle.
+    /// placed hazards, doors, or triggers belong to runtime state rather than the
+    /// map oracle; only hazards built into the terrain live here.
     pub const fn new(terrain: TerrainKind) -> Self {
         Self { terrain }
     }
@@ -54,6 +89,22 @@ impl StaticTile {
     pub fn is_passable(self) -> bool {
         self.terrain.is_passable()
     }
+
+    pub fn blocks_sight(self) -> bool {
+        self.terrain.blocks_sight()
+    }
+
+    pub fn is_exit(self) -> bool {
+        self.terrain.is_exit()
+    }
+
+    pub fn hazard_damage(self) -> Option<(DamageType, u16)> {
+        self.terrain.hazard_damage()
+    }
+
+    pub fn movement_cost_multiplier(self) -> u16 {
+        self.terrain.movement_cost_multiplier()
+    }
 }
 
 /// Canonical terrain classes for static map tiles.
@@ -65,10 +116,121 @@ pub enum TerrainKind {
     Void,
     Water,
     Custom(u16),
+    /// Walkable map exit (stairs, gates). NPCs that reach one leave play.
+    Exit,
+    /// Walkable floor that wounds whoever steps onto it.
+    Spikes,
+    /// Walkable molten rock that burns whoever steps onto it.
+    Lava,
+    /// Walkable ground that takes twice as long to wade into.
+    Mud,
 }
 
 impl TerrainKind {
     pub fn is_passable(self) -> bool {
-        matches!(self, TerrainKind::Floor)
+        matches!(
+            self,
+            TerrainKind::Floor
+                | TerrainKind::Exit
+                | TerrainKind::Spikes
+                | TerrainKind::Lava
+                | TerrainKind::Mud
+        )
+    }
+
+    pub fn is_exit(self) -> bool {
+        matches!(self, TerrainKind::Exit)
+    }
+
+    pub fn blocks_sight(self) -> bool {
+        matches!(self, TerrainKind::Wall)
+    }
+
+    /// Damage dealt to an actor entering a tile of this terrain, if any.
+    ///
+    /// Unlike the runtime hazard overlays on the tile map, this damage is
+    /// part of the static layout and goes through the damage pipeline, so
+    /// resistances apply.
+    pub fn hazard_damage(self) -> Option<(DamageType, u16)> {
+        match self {
+            TerrainKind::Spikes => Some((DamageType::Physical, 5)),
+            TerrainKind::Lava => Some((DamageType::Fire, 20)),
+            _ => None,
+        }
+    }
+
+    /// Fixed-point multiplier (100 = 1x) on the cost of moving onto a tile
+    /// of this terrain.
+    pub fn movement_cost_multiplier(self) -> u16 {
+        match self {
+            TerrainKind::Mud => 200,
+            _ => 100,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open floor with walls on the listed tiles.
+    struct WalledMap {
+        dimensions: MapDimensions,
+        walls: Vec<Position>,
+    }
+
+    impl WalledMap {
+        fn new(walls: &[(i32, i32)]) -> Self {
+            Self {
+                dimensions: MapDimensions::new(6, 6),
+                walls: walls.iter().map(|&(x, y)| Position::new(x, y)).collect(),
+            }
+        }
+    }
+
+    impl MapOracle for WalledMap {
+        fn dimensions(&self) -> MapDimensions {
+            self.dimensions
+        }
+
+        fn tile(&self, position: Position) -> Option<StaticTile> {
+            if !self.dimensions.contains(position) {
+                return None;
+            }
+            let terrain = if self.walls.contains(&position) {
+                TerrainKind::Wall
+            } else {
+                TerrainKind::Floor
+            };
+            Some(StaticTile::new(terrain))
+        }
+    }
+
+    #[test]
+    fn straight_lines_are_clear_on_open_floor() {
+        let map = WalledMap::new(&[]);
+        assert!(map.line_of_sight(Position::new(0, 2), Position::new(5, 2)));
+        assert!(map.line_of_sight(Position::new(3, 5), Position::new(3, 0)));
+        assert!(map.line_of_sight(Position::new(1, 1), Position::new(1, 1)));
+    }
+
+    #[test]
+    fn diagonal_lines_pass_between_walls_only_when_clear() {
+        let map = WalledMap::new(&[(2, 1), (1, 2)]);
+        assert!(map.line_of_sight(Position::new(0, 0), Position::new(4, 4)));
+
+        let map = WalledMap::new(&[(2, 2)]);
+        assert!(!map.line_of_sight(Position::new(0, 0), Position::new(4, 4)));
+        assert!(map.line_of_sight(Position::new(0, 0), Position::new(2, 2)));
+    }
+
+    #[test]
+    fn walls_block_sight_in_both_directions() {
+        let map = WalledMap::new(&[(3, 2)]);
+        let (a, b) = (Position::new(0, 2), Position::new(5, 2));
+
+        assert!(!map.line_of_sight(a, b));
+        assert!(!map.line_of_sight(b, a));
+        assert!(map.line_of_sight(a, Position::new(5, 0)));
     }
 }
@@ -9,7 +9,8 @@ mod config;
 mod error;
 mod items;
 mod map;
-mod rng;
+mod path;
+pub mod rng;
 mod snapshot;
 
 pub use actions::ActionOracle;
@@ -21,13 +22,19 @@ pub use items::{
     WeaponData, WeaponKind,
 };
 pub use map::{MapDimensions, MapOracle, StaticTile, TerrainKind};
-pub use rng::{PcgRng, RngOracle, compute_seed};
+pub use path::{PathSearch, find_path, search_path};
+pub use rng::{
+    DomainTag, PcgRng, RngOracle, SEED_COMMITMENT_DOMAIN, compute_seed, compute_seed_commitment,
+};
 pub use snapshot::{
-    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleSnapshot,
-    SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle, SnapshotItemOracle,
-    SnapshotMapOracle, SnapshotOracleBundle,
+    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleDiff,
+    OracleSnapshot, SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle,
+    SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle,
 };
 
+use crate::config::ActivationShape;
+use crate::state::Tick;
+
 /// Aggregates read-only oracles required by the reducer and action pipeline.
 #[derive(Clone, Copy, Debug)]
 pub struct Env<'a, M, I, T, A, C, R>
@@ -175,6 +182,42 @@ where
     pub fn activation_radius(&self) -> Result<u32, OracleError> {
         Ok(self.config()?.activation_radius())
     }
+
+    /// Returns the activation shape from the config oracle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::ConfigNotAvailable` if no config oracle was provided.
+    pub fn activation_shape(&self) -> Result<ActivationShape, OracleError> {
+        Ok(self.config()?.activation_shape())
+    }
+
+    /// Returns the partial cover damage reduction (percent) from the config oracle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::ConfigNotAvailable` if no config oracle was provided.
+    pub fn cover_damage_reduction(&self) -> Result<u32, OracleError> {
+        Ok(self.config()?.cover_damage_reduction())
+    }
+
+    /// Returns the initiative window (ticks) from the config oracle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::ConfigNotAvailable` if no config oracle was provided.
+    pub fn initiative_window(&self) -> Result<u32, OracleError> {
+        Ok(self.config()?.initiative_window())
+    }
+
+    /// Returns the aggro duration (ticks) from the config oracle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::ConfigNotAvailable` if no config oracle was provided.
+    pub fn aggro_duration(&self) -> Result<Tick, OracleError> {
+        Ok(self.config()?.aggro_duration())
+    }
 }
 
 impl<'a, M, I, T, A, C, R> Env<'a, M, I, T, A, C, R>
@@ -0,0 +1,306 @@
+//! Grid pathfinding over the static map and runtime occupancy.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+
+use super::MapOracle;
+use crate::state::{GameState, Position, TileMap};
+
+/// Neighbour offsets in the order they are explored (cardinals, then diagonals).
+const NEIGHBORS: [(i32, i32); 8] = [
+    (0, 1),
+    (0, -1),
+    (1, 0),
+    (-1, 0),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+/// Outcome of a bounded A* search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathSearch {
+    /// Steps from (excluding) the start up to and including the goal.
+    ///
+    /// `None` if the goal is unreachable or the node limit was hit first.
+    pub path: Option<Vec<Position>>,
+
+    /// Number of tiles expanded before the search ended.
+    pub nodes_expanded: usize,
+}
+
+/// Finds a shortest path from `start` to `goal` using A*.
+///
+/// Every step moves to one of the eight neighbouring tiles at a cost of 1, so
+/// Chebyshev distance is an exact lower bound and serves as the heuristic.
+/// Impassable terrain and occupied tiles are blocked. The goal itself may be
+/// occupied, which lets an NPC path up to the actor it is chasing.
+///
+/// At most `max_nodes` tiles are expanded. Ties in the open set are broken by
+/// heuristic and then by position, so the same inputs always produce the same
+/// path.
+///
+/// Returns the steps after `start`, ending with `goal` (empty when they are
+/// equal), or `None` if the goal cannot be reached within the budget.
+pub fn find_path(
+    map: &dyn MapOracle,
+    occupancy: &TileMap,
+    start: Position,
+    goal: Position,
+    max_nodes: usize,
+) -> Option<Vec<Position>> {
+    let walkable = |position: Position| {
+        map.tile(position).is_some_and(|tile| tile.is_passable())
+            && (position == goal
+                || occupancy
+                    .occupants(&position)
+                    .is_none_or(|slots| slots.is_empty()))
+    };
+    search(start, goal, max_nodes, walkable).path
+}
+
+/// Runs the [`find_path`] search over tiles a move could actually enter.
+///
+/// Blocks what movement rejects: impassable terrain, blocking props such as
+/// closed doors, and tiles holding an actor other than the one at `start`.
+/// Items on the floor do not block. Also reports how many tiles were
+/// expanded, so callers can charge the work to a budget.
+pub fn search_path(
+    map: &dyn MapOracle,
+    state: &GameState,
+    start: Position,
+    goal: Position,
+    max_nodes: usize,
+) -> PathSearch {
+    let occupied: BTreeSet<Position> = state
+        .entities
+        .all_actors()
+        .filter_map(|actor| actor.position)
+        .filter(|&position| position != start)
+        .collect();
+
+    let walkable = |position: Position| {
+        map.tile(position).is_some_and(|tile| tile.is_passable())
+            && !state.is_blocked(position)
+            && (position == goal || !occupied.contains(&position))
+    };
+    search(start, goal, max_nodes, walkable)
+}
+
+/// A* from `start` to `goal` over the tiles `walkable` accepts.
+fn search(
+    start: Position,
+    goal: Position,
+    max_nodes: usize,
+    walkable: impl Fn(Position) -> bool,
+) -> PathSearch {
+    let failed = |nodes_expanded| PathSearch {
+        path: None,
+        nodes_expanded,
+    };
+    if start == goal {
+        return PathSearch {
+            path: Some(Vec::new()),
+            nodes_expanded: 0,
+        };
+    }
+    if !walkable(goal) {
+        return failed(0);
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut closed = BTreeSet::new();
+    let mut cost = BTreeMap::from([(start, 0u32)]);
+    let mut came_from = BTreeMap::new();
+    open.push(Reverse((start.chebyshev_distance(goal), 0, start)));
+
+    while let Some(Reverse((_, _, current))) = open.pop() {
+        if current == goal {
+            return PathSearch {
+                path: Some(reconstruct(&came_from, start, goal)),
+                nodes_expanded: closed.len(),
+            };
+        }
+        if closed.contains(&current) {
+            continue;
+        }
+        if closed.len() >= max_nodes {
+            return failed(closed.len());
+        }
+        closed.insert(current);
+
+        let next_cost = cost[&current] + 1;
+        for (dx, dy) in NEIGHBORS {
+            let next = Position::new(current.x + dx, current.y + dy);
+            if closed.contains(&next) || !walkable(next) {
+                continue;
+            }
+            if cost.get(&next).is_none_or(|&known| next_cost < known) {
+                cost.insert(next, next_cost);
+                came_from.insert(next, current);
+                let heuristic = next.chebyshev_distance(goal);
+                open.push(Reverse((next_cost + heuristic, heuristic, next)));
+            }
+        }
+    }
+
+    failed(closed.len())
+}
+
+/// Walks `came_from` back from `goal` and returns the steps after `start`.
+fn reconstruct(
+    came_from: &BTreeMap<Position, Position>,
+    start: Position,
+    goal: Position,
+) -> Vec<Position> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while let Some(&previous) = came_from.get(&current) {
+        if previous == start {
+            break;
+        }
+        path.push(previous);
+        current = previous;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::{ActorTemplate, MapDimensions, StaticTile, TerrainKind};
+    use crate::state::{EntityId, PropKind, PropState};
+
+    /// Map parsed from rows of `.` (floor) and `#` (wall); row `y` is line `y`.
+    struct GridMap {
+        rows: Vec<Vec<u8>>,
+    }
+
+    impl GridMap {
+        fn new(rows: &[&str]) -> Self {
+            Self {
+                rows: rows.iter().map(|row| row.bytes().collect()).collect(),
+            }
+        }
+    }
+
+    impl MapOracle for GridMap {
+        fn dimensions(&self) -> MapDimensions {
+            MapDimensions::new(self.rows[0].len() as u32, self.rows.len() as u32)
+        }
+
+        fn tile(&self, position: Position) -> Option<StaticTile> {
+            if !self.contains(position) {
+                return None;
+            }
+            let terrain = match self.rows[position.y as usize][position.x as usize] {
+                b'#' => TerrainKind::Wall,
+                _ => TerrainKind::Floor,
+            };
+            Some(StaticTile::new(terrain))
+        }
+    }
+
+    fn assert_walkable(map: &GridMap, start: Position, path: &[Position]) {
+        let mut from = start;
+        for &step in path {
+            assert_eq!(from.chebyshev_distance(step), 1, "{from:?} -> {step:?}");
+            assert!(map.tile(step).unwrap().is_passable(), "{step:?} is a wall");
+            from = step;
+        }
+    }
+
+    #[test]
+    fn open_map_path_is_as_long_as_the_chebyshev_distance() {
+        let map = GridMap::new(&["......"; 6]);
+        let (start, goal) = (Position::new(0, 0), Position::new(5, 3));
+
+        let path = find_path(&map, &TileMap::default(), start, goal, 64).unwrap();
+
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.last(), Some(&goal));
+        assert_walkable(&map, start, &path);
+        assert_eq!(
+            find_path(&map, &TileMap::default(), start, start, 64),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn walls_force_a_detour() {
+        let map = GridMap::new(&["..#..", "..#..", "..#..", "..#..", "....."]);
+        let (start, goal) = (Position::new(0, 2), Position::new(4, 2));
+
+        let path = find_path(&map, &TileMap::default(), start, goal, 64).unwrap();
+
+        assert_eq!(path.len(), 4);
+        assert!(path.contains(&Position::new(2, 4)));
+        assert_eq!(path.last(), Some(&goal));
+        assert_walkable(&map, start, &path);
+    }
+
+    #[test]
+    fn unreachable_goal_returns_none() {
+        let walled = GridMap::new(&["..#..", "..#..", "..#.."]);
+        let (start, goal) = (Position::new(0, 1), Position::new(4, 1));
+        assert_eq!(
+            find_path(&walled, &TileMap::default(), start, goal, 64),
+            None
+        );
+
+        // A one-tile corridor plugged by an actor is just as closed
+        let corridor = GridMap::new(&["#####", ".....", "#####"]);
+        let mut occupancy = TileMap::default();
+        occupancy.add_occupant(Position::new(2, 1), EntityId(7));
+        assert_eq!(find_path(&corridor, &occupancy, start, goal, 64), None);
+    }
+
+    #[test]
+    fn occupied_goal_is_reachable_but_budget_is_enforced() {
+        let corridor = GridMap::new(&["#####", ".....", "#####"]);
+        let (start, goal) = (Position::new(0, 1), Position::new(4, 1));
+        let mut occupancy = TileMap::default();
+        occupancy.add_occupant(goal, EntityId(7));
+
+        let path = find_path(&corridor, &occupancy, start, goal, 64).unwrap();
+        assert_eq!(path.len(), 4);
+
+        assert_eq!(find_path(&corridor, &occupancy, start, goal, 2), None);
+    }
+
+    #[test]
+    fn search_path_blocks_what_movement_rejects() {
+        let corridor = GridMap::new(&["#####", ".....", "#####"]);
+        let (start, goal) = (Position::new(0, 1), Position::new(4, 1));
+        let mut state = GameState::empty();
+        state.add_npc(&ActorTemplate::test_actor(), start).unwrap();
+
+        let open = search_path(&corridor, &state, start, goal, 64);
+        assert_eq!(open.path.as_ref().map(Vec::len), Some(4));
+        assert_eq!(open.nodes_expanded, 4);
+
+        // A closed door shuts the corridor
+        let door = state.allocate_entity_id().unwrap();
+        let _ = state.entities.props.push(PropState::new(
+            door,
+            Position::new(2, 1),
+            PropKind::Door,
+            false,
+        ));
+        let shut = search_path(&corridor, &state, start, goal, 64);
+        assert_eq!(shut.path, None);
+        assert_eq!(shut.nodes_expanded, 2);
+
+        // The node limit is never exceeded
+        state.entities.props = Default::default();
+        assert_eq!(
+            search_path(&corridor, &state, start, goal, 2),
+            PathSearch {
+                path: None,
+                nodes_expanded: 2,
+            }
+        );
+    }
+}
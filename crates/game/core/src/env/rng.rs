@@ -96,6 +96,16 @@ impl PcgRng {
     }
 }
 
+impl PcgRng {
+    /// Seed for a damage roll, drawn from the [`DomainTag::Damage`] stream.
+    ///
+    /// Damage variance and crits use this instead of the general stream, so
+    /// rolls added for AI or spawning never shift a damage result.
+    pub fn damage_seed(game_seed: u64, nonce: u64, actor_id: u32, context: u32) -> u64 {
+        compute_seed(game_seed, nonce, actor_id, context, DomainTag::Damage)
+    }
+}
+
 impl RngOracle for PcgRng {
     fn next_u32(&self, seed: u64) -> u32 {
         let state = Self::pcg_step(seed);
@@ -103,6 +113,24 @@ impl RngOracle for PcgRng {
     }
 }
 
+/// Independent random streams derived from one game seed.
+///
+/// [`compute_seed`] mixes the tag into every seed, so each subsystem draws
+/// from its own stream and adding rolls to one never perturbs another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum DomainTag {
+    /// Rolls without a dedicated stream. Mixes in nothing, so these seeds
+    /// are the ones computed before streams were separated.
+    General = 0,
+    /// Combat damage (variance, critical hits)
+    Damage = 1,
+    /// AI decisions (seeded tie-breaks)
+    Ai = 2,
+    /// Spawns and procedural placement
+    Spawn = 3,
+}
+
 /// Compute deterministic seed from game state components.
 ///
 /// Combines multiple entropy sources to ensure unique seeds for each
@@ -114,6 +142,7 @@ impl RngOracle for PcgRng {
 /// * `nonce` - Action sequence number (increments each action)
 /// * `actor_id` - Entity performing the action
 /// * `context` - Additional context for multiple rolls in same action
+/// * `domain` - Stream the roll belongs to
 ///
 /// # Context Values
 ///
@@ -124,7 +153,13 @@ impl RngOracle for PcgRng {
 /// - `1`: Secondary roll (e.g., damage variance)
 /// - `2`: Tertiary roll (e.g., critical check)
 /// - etc.
-pub fn compute_seed(game_seed: u64, nonce: u64, actor_id: u32, context: u32) -> u64 {
+pub fn compute_seed(
+    game_seed: u64,
+    nonce: u64,
+    actor_id: u32,
+    context: u32,
+    domain: DomainTag,
+) -> u64 {
     // Mix all inputs using simple hash combiners
     // These constants are based on SplitMix64 and FxHash multipliers
     let mut hash = game_seed;
@@ -138,6 +173,9 @@ pub fn compute_seed(game_seed: u64, nonce: u64, actor_id: u32, context: u32) ->
     // Mix in context
     hash ^= (context as u64).wrapping_mul(0x85ebca6b);
 
+    // Mix in domain (General contributes nothing)
+    hash ^= (domain as u64).wrapping_mul(0xc2b2ae3d27d4eb4f);
+
     // Final avalanche step
     hash ^= hash >> 33;
     hash = hash.wrapping_mul(0xff51afd7ed558ccd);
@@ -145,3 +183,182 @@ pub fn compute_seed(game_seed: u64, nonce: u64, actor_id: u32, context: u32) ->
 
     hash
 }
+
+/// Domain-separation prefix of the seed commitment preimage.
+pub const SEED_COMMITMENT_DOMAIN: &[u8] = b"dungeon/seed-commitment/v1";
+
+/// Compute the public commitment to a game seed.
+///
+/// Proofs commit to this value instead of the seed itself, so the RNG stream
+/// stays hidden while every batch is bound to the same seed.
+///
+/// # Preimage
+///
+/// ```text
+/// SHA-256("dungeon/seed-commitment/v1" || game_seed as u64 little-endian)
+/// ```
+///
+/// The prefix is the 26 ASCII bytes of [`SEED_COMMITMENT_DOMAIN`] with no
+/// length or terminator, followed by the 8 seed bytes (34 bytes total).
+pub fn compute_seed_commitment(game_seed: u64) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_COMMITMENT_DOMAIN);
+    hasher.update(game_seed.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Known-answer vector for [`compute_seed`] and [`PcgRng`].
+///
+/// Host and zkVM guest builds must reproduce every vector bit for bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RngTestVector {
+    pub game_seed: u64,
+    pub nonce: u64,
+    pub actor_id: u32,
+    pub context: u32,
+    pub domain: DomainTag,
+    /// Expected `compute_seed(game_seed, nonce, actor_id, context, domain)`.
+    pub seed: u64,
+    /// Expected `PcgRng.next_u32(seed)`.
+    pub next_u32: u32,
+    /// Expected `PcgRng.roll_d100(seed)`.
+    pub roll_d100: u32,
+}
+
+/// Fixed RNG test vectors.
+///
+/// Covers the zero seed, the primary/secondary/tertiary roll contexts of a
+/// single action, distinct actors, every domain and the maximum value of
+/// every numeric input.
+/// Downstream zkVM builds can run the same vectors to confirm host/guest
+/// parity; any change here is a breaking change to proofs.
+pub fn test_vectors() -> &'static [RngTestVector] {
+    const fn vector(
+        (game_seed, nonce, actor_id, context, domain): (u64, u64, u32, u32, DomainTag),
+        seed: u64,
+        next_u32: u32,
+        roll_d100: u32,
+    ) -> RngTestVector {
+        RngTestVector {
+            game_seed,
+            nonce,
+            actor_id,
+            context,
+            domain,
+            seed,
+            next_u32,
+            roll_d100,
+        }
+    }
+
+    use DomainTag::{Ai, Damage, General, Spawn};
+
+    const VECTORS: &[RngTestVector] = &[
+        vector(
+            (0, 0, 0, 0, General),
+            0x0000_0000_0000_0000,
+            1_613_493_245,
+            46,
+        ),
+        vector(
+            (0, 1, 0, 0, General),
+            0x9341_ca26_3702_a9e6,
+            3_944_162_109,
+            10,
+        ),
+        vector(
+            (42, 7, 0, 0, General),
+            0xd8e6_731d_e035_7b7e,
+            3_700_407_753,
+            54,
+        ),
+        vector(
+            (42, 7, 0, 1, General),
+            0xafc8_7273_be8d_1e66,
+            1_479_751_103,
+            4,
+        ),
+        vector(
+            (42, 7, 0, 2, General),
+            0x57ab_5aa9_3ebd_06fa,
+            3_939_277_476,
+            77,
+        ),
+        vector(
+            (42, 7, 3, 0, General),
+            0x4158_d85b_d479_1c94,
+            3_754_556_252,
+            53,
+        ),
+        vector(
+            (42, 7, 0, 0, Damage),
+            0x810e_4b29_bbdf_afb9,
+            2_763_602_500,
+            1,
+        ),
+        vector((42, 7, 0, 0, Ai), 0x63ff_c921_1363_55a7, 3_601_319_243, 44),
+        vector(
+            (42, 7, 0, 0, Spawn),
+            0xbd6f_6380_65bb_7f0e,
+            4_004_267_313,
+            14,
+        ),
+        vector(
+            (0xdead_beef_cafe_babe, 1000, 12, 0, General),
+            0xa11f_a0c1_ee01_fc5f,
+            1_309_665_266,
+            67,
+        ),
+        vector(
+            (u64::MAX, u64::MAX, u32::MAX, u32::MAX, General),
+            0xe697_9f89_b66b_cf8d,
+            1_202_522_172,
+            73,
+        ),
+    ];
+
+    VECTORS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implementation_reproduces_the_test_vectors() {
+        for v in test_vectors() {
+            let seed = compute_seed(v.game_seed, v.nonce, v.actor_id, v.context, v.domain);
+            assert_eq!(seed, v.seed, "compute_seed for {:?}", v);
+            assert_eq!(PcgRng.next_u32(seed), v.next_u32, "next_u32 for {:?}", v);
+            assert_eq!(PcgRng.roll_d100(seed), v.roll_d100, "roll_d100 for {:?}", v);
+        }
+    }
+
+    #[test]
+    fn damage_stream_is_stable_and_separate_from_ai() {
+        let damage = PcgRng::damage_seed(42, 7, 3, 0);
+
+        assert_eq!(damage, PcgRng::damage_seed(42, 7, 3, 0));
+        assert_eq!(damage, compute_seed(42, 7, 3, 0, DomainTag::Damage));
+        assert_ne!(damage, compute_seed(42, 7, 3, 0, DomainTag::Ai));
+        assert_ne!(damage, compute_seed(42, 7, 3, 0, DomainTag::General));
+        assert_ne!(
+            PcgRng.next_u32(damage),
+            PcgRng.next_u32(compute_seed(42, 7, 3, 0, DomainTag::Ai))
+        );
+    }
+
+    #[test]
+    fn seed_commitment_matches_the_documented_preimage() {
+        assert_eq!(
+            hex::encode(compute_seed_commitment(0)),
+            "1070a358a1879119878395f4f234772f3383e37b194a8e9bf4458cd3a27004df"
+        );
+        assert_eq!(
+            hex::encode(compute_seed_commitment(42)),
+            "5317310ae73490f5f0167076a792d70c2275eeabec6905503e39980d4cf401cc"
+        );
+    }
+}
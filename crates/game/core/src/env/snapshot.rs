@@ -24,7 +24,7 @@ use super::{
     ActionOracle, ActorOracle, ConfigOracle, ItemDefinition, ItemOracle, MapDimensions, MapOracle,
     StaticTile,
 };
-use crate::{GameConfig, ItemHandle, Position};
+use crate::{ActivationShape, FactionRelations, GameConfig, ItemHandle, Position, Tick};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -135,6 +135,62 @@ impl OracleSnapshot {
 
         hasher.finalize().into()
     }
+
+    /// Checks whether this snapshot hashes to `oracle_root`.
+    ///
+    /// Use before proving a resumed session: a mismatch with the root
+    /// committed on-chain would otherwise only show up as a failed proof
+    /// verification.
+    #[cfg(feature = "serde")]
+    pub fn matches_root(&self, oracle_root: &[u8; 32]) -> bool {
+        &self.compute_oracle_root() == oracle_root
+    }
+
+    /// Lists the sections that differ from `other`, in hashing order.
+    ///
+    /// An empty result means both snapshots commit to the same oracle root.
+    pub fn diff(&self, other: &OracleSnapshot) -> Vec<OracleDiff> {
+        let mut diffs = Vec::new();
+        if self.map != other.map {
+            diffs.push(OracleDiff::Map);
+        }
+        if self.items != other.items {
+            diffs.push(OracleDiff::Items);
+        }
+        if self.actors != other.actors {
+            diffs.push(OracleDiff::Actors);
+        }
+        if self.actions != other.actions {
+            diffs.push(OracleDiff::Actions);
+        }
+        if self.config != other.config {
+            diffs.push(OracleDiff::Config);
+        }
+        diffs
+    }
+}
+
+/// Section of an [`OracleSnapshot`] that differs between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OracleDiff {
+    Map,
+    Items,
+    Actors,
+    Actions,
+    Config,
+}
+
+impl core::fmt::Display for OracleDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let section = match self {
+            OracleDiff::Map => "map",
+            OracleDiff::Items => "items",
+            OracleDiff::Actors => "actors",
+            OracleDiff::Actions => "action profiles",
+            OracleDiff::Config => "config",
+        };
+        f.write_str(section)
+    }
 }
 
 /// Snapshot of map oracle data (terrain only, no entities)
@@ -300,6 +356,12 @@ impl ConfigSnapshot {
     pub fn from_oracle(oracle: &dyn ConfigOracle) -> Self {
         let config = GameConfig {
             activation_radius: oracle.activation_radius(),
+            activation_shape: oracle.activation_shape(),
+            cover_damage_reduction: oracle.cover_damage_reduction(),
+            initiative_window: oracle.initiative_window(),
+            corpse_grace_period: oracle.corpse_grace_period(),
+            aggro_duration: oracle.aggro_duration(),
+            faction_relations: oracle.faction_relations().clone(),
         };
         Self::new(config)
     }
@@ -428,6 +490,30 @@ impl<'a> ConfigOracle for SnapshotConfigOracle<'a> {
     fn activation_radius(&self) -> u32 {
         self.snapshot.config.activation_radius
     }
+
+    fn activation_shape(&self) -> ActivationShape {
+        self.snapshot.config.activation_shape
+    }
+
+    fn cover_damage_reduction(&self) -> u32 {
+        self.snapshot.config.cover_damage_reduction
+    }
+
+    fn initiative_window(&self) -> u32 {
+        self.snapshot.config.initiative_window
+    }
+
+    fn corpse_grace_period(&self) -> Tick {
+        self.snapshot.config.corpse_grace_period
+    }
+
+    fn aggro_duration(&self) -> Tick {
+        self.snapshot.config.aggro_duration
+    }
+
+    fn faction_relations(&self) -> &FactionRelations {
+        &self.snapshot.config.faction_relations
+    }
 }
 
 /// Bundle of all snapshot-backed oracle adapters.
@@ -477,3 +563,72 @@ impl<'a> SnapshotOracleBundle<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{ActionKind, ActionProfile, TargetingMode};
+    use crate::env::{ActorTemplate, ItemKind, TerrainKind};
+
+    fn snapshot() -> OracleSnapshot {
+        OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(2, 1),
+                vec![Some(StaticTile::new(TerrainKind::Floor)); 2],
+            ),
+            ItemsSnapshot::new(vec![(
+                ItemHandle(1),
+                ItemDefinition::new(ItemHandle(1), ItemKind::Utility, 1),
+            )]),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new(BTreeMap::new()),
+            ConfigSnapshot::new(GameConfig::default()),
+        )
+    }
+
+    #[test]
+    fn diff_pinpoints_the_changed_section() {
+        let base = snapshot();
+        assert!(base.diff(&snapshot()).is_empty());
+
+        type Mutation = fn(&mut OracleSnapshot);
+        let mutations: [(OracleDiff, Mutation); 5] = [
+            (OracleDiff::Map, |s| s.map.tiles[1] = None),
+            (OracleDiff::Items, |s| s.items.items[0].1.max_stack = 99),
+            (OracleDiff::Actors, |s| {
+                s.actors
+                    .templates
+                    .push(("goblin".into(), ActorTemplate::builder().build()))
+            }),
+            (OracleDiff::Actions, |s| {
+                s.actions.action_profiles.insert(
+                    ActionKind::Wait,
+                    ActionProfile {
+                        kind: ActionKind::Wait,
+                        tags: Vec::new(),
+                        targeting: TargetingMode::None,
+                        base_cost: 100,
+                        resource_costs: Vec::new(),
+                        effects: Vec::new(),
+                        requirements: Vec::new(),
+                        cooldown: None,
+                    },
+                );
+            }),
+            (OracleDiff::Config, |s| {
+                s.config.config.activation_radius += 1
+            }),
+        ];
+
+        for (section, mutate) in mutations {
+            let mut other = snapshot();
+            mutate(&mut other);
+            assert_eq!(base.diff(&other), vec![section]);
+            #[cfg(feature = "serde")]
+            assert!(
+                !other.matches_root(&base.compute_oracle_root()),
+                "{section}"
+            );
+        }
+    }
+}
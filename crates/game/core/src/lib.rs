@@ -13,37 +13,43 @@ pub mod provider;
 pub mod state;
 pub mod stats;
 pub mod traits;
-#[cfg(feature = "serde")]
-pub use action::compute_actions_root;
 pub use action::{
     Action, ActionEffect, ActionError, ActionInput, ActionKind, ActionProfile, ActionResult,
     ActionTag, ActionTransition, ActivationAction, ActivationError, CardinalDirection,
-    CharacterAction, DamageType, DeactivateAction, EffectContext, EffectKind, ExecutionPhase,
-    Formula, PrepareTurnAction, RemoveFromWorldAction, RemoveFromWorldError, ResourceCost,
-    RestoreResourceEffect, SystemActionKind, TargetingMode, TurnError, get_available_actions,
+    CharacterAction, CreateHazardEffect, DamageType, DeactivateAction, DropLootAction,
+    DropLootError, EffectContext, EffectKind, ExecutionPhase, Formula, OpportunityAttackAction,
+    OpportunityAttackError, PrepareTurnAction, RemoveFromWorldAction, RemoveFromWorldError,
+    ResourceCost, RestoreResourceEffect, ReviveEffect, SummonEffect, SystemActionKind,
+    TargetingMode, TickStatusEffectsAction, TickStatusError, TurnError, get_available_actions,
+    resolve_targets,
 };
-pub use config::GameConfig;
+#[cfg(feature = "serde")]
+pub use action::{
+    ActionMerkleProof, compute_actions_merkle_root, compute_actions_root, merkle_proof,
+};
+pub use config::{ActivationShape, GameConfig};
 pub use engine::{
     ExecuteError, ExecutionOutcome, GameEngine, TransitionPhase, TransitionPhaseError,
 };
 pub use env::{
     ActionOracle, ActionSnapshot, ActorOracle, ActorTemplate, ActorTemplateBuilder, ActorsSnapshot,
-    ArmorData, ArmorKind, AttackType, ConfigOracle, ConfigSnapshot, ConsumableData, Env, GameEnv,
-    ItemDefinition, ItemKind, ItemOracle, ItemsSnapshot, MapDimensions, MapOracle, MapSnapshot,
-    OracleError, OracleSnapshot, PcgRng, RngOracle, SnapshotActionOracle, SnapshotActorOracle,
-    SnapshotConfigOracle, SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle, StaticTile,
-    TerrainKind, WeaponData, WeaponKind, compute_seed,
+    ArmorData, ArmorKind, AttackType, ConfigOracle, ConfigSnapshot, ConsumableData, DomainTag, Env,
+    GameEnv, ItemDefinition, ItemKind, ItemOracle, ItemsSnapshot, MapDimensions, MapOracle,
+    MapSnapshot, OracleDiff, OracleError, OracleSnapshot, PathSearch, PcgRng, RngOracle,
+    SEED_COMMITMENT_DOMAIN, SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle,
+    SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle, StaticTile, TerrainKind,
+    WeaponData, WeaponKind, compute_seed, compute_seed_commitment, find_path, search_path,
 };
 pub use error::{ErrorContext, ErrorSeverity, GameError, NeverError};
 pub use provider::{AiKind, InteractiveKind, ProviderKind};
 pub use state::{
-    ActionAbilities, ActionAbility, ActorChanges, ActorFields, ActorState, CollectionChanges,
-    EntitiesChanges, EntitiesState, EntityId, Equipment, EquipmentBuilder, GameState,
-    InventorySlot, InventoryState, ItemChanges, ItemFields, ItemHandle, ItemState,
-    OccupancyChanges, PassiveAbilities, PassiveAbility, PassiveKind, Position, PropChanges,
-    PropFields, PropKind, PropState, StateDelta, StateError, StatusEffect, StatusEffectKind,
-    StatusEffects, Tick, TileMap, TileView, TurnChanges, TurnFields, TurnState, WorldChanges,
-    WorldState,
+    ActionAbilities, ActionAbility, ActorChanges, ActorFields, ActorState, CachedRoots, Channel,
+    CollectionChanges, EntitiesChanges, EntitiesState, EntityId, Equipment, EquipmentBuilder,
+    GameState, HazardChanges, HazardOverlay, InvariantViolation, InventorySlot, InventoryState,
+    ItemChanges, ItemFields, ItemHandle, ItemState, OccupancyChanges, PassiveAbilities,
+    PassiveAbility, PassiveKind, Position, PropChanges, PropFields, PropKind, PropState,
+    StateDelta, StateError, StatusEffect, StatusEffectKind, StatusEffects, Tick, TileMap, TileView,
+    TurnChanges, TurnFields, TurnState, Visibility, VisibilitySet, WorldChanges, WorldState,
 };
 pub use stats::{
     ActorBonuses, Bonus, BonusStack, CoreEffective, CoreStatBonuses, CoreStatKind, CoreStats,
@@ -51,4 +57,6 @@ pub use stats::{
     ResourceMaximums, SpeedBonuses, SpeedKind, SpeedStats, StatBounds, StatLayer, StatModifiers,
     StatsSnapshot, StatsSnapshotBuilder, compute_actor_bonuses,
 };
-pub use traits::{Faction, Species, TraitKind, TraitProfile};
+pub use traits::{
+    Faction, FactionRelation, FactionRelations, Relation, Species, TraitKind, TraitProfile,
+};
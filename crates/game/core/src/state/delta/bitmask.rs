@@ -4,16 +4,27 @@ bitflags! {
     /// Tracks which fields of an [`ActorState`] changed during a state transition.
     ///
     /// Each bit represents a single field in the actor structure. Using bitflags
-    /// provides O(1) set/check operations and minimal memory footprint (~1 byte).
+    /// provides O(1) set/check operations and minimal memory footprint (~2 bytes).
+    ///
+    /// Every field of the actor is covered, so a delta names everything needed
+    /// to rebuild the actor from the `after` state.
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-    pub struct ActorFields: u8 {
-        const POSITION    = 1 << 0;
-        const CORE_STATS  = 1 << 1;
-        const RESOURCES   = 1 << 2;
-        const BONUSES     = 1 << 3;
-        const INVENTORY   = 1 << 4;
-        const READY_AT    = 1 << 5;
+    pub struct ActorFields: u16 {
+        const POSITION       = 1 << 0;
+        const CORE_STATS     = 1 << 1;
+        const RESOURCES      = 1 << 2;
+        const BONUSES        = 1 << 3;
+        const INVENTORY      = 1 << 4;
+        const READY_AT       = 1 << 5;
+        const ACTIONS        = 1 << 6;
+        const EQUIPMENT      = 1 << 7;
+        const STATUS_EFFECTS = 1 << 8;
+        const PASSIVES       = 1 << 9;
+        const CHANNEL        = 1 << 10;
+        /// Provider kind, trait profile, species, faction and objective.
+        const IDENTITY       = 1 << 11;
+        const DIED_AT        = 1 << 12;
     }
 }
 
@@ -24,6 +35,7 @@ bitflags! {
     pub struct PropFields: u8 {
         const POSITION  = 1 << 0;
         const IS_ACTIVE = 1 << 1;
+        const KIND      = 1 << 2;
     }
 }
 
@@ -33,6 +45,8 @@ bitflags! {
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct ItemFields: u8 {
         const POSITION = 1 << 0;
+        const HANDLE   = 1 << 1;
+        const QUANTITY = 1 << 2;
     }
 }
 
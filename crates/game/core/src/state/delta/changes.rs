@@ -51,6 +51,32 @@ impl ActorChanges {
         if before.ready_at != after.ready_at {
             fields |= ActorFields::READY_AT;
         }
+        if before.actions != after.actions {
+            fields |= ActorFields::ACTIONS;
+        }
+        if before.equipment != after.equipment {
+            fields |= ActorFields::EQUIPMENT;
+        }
+        if before.status_effects != after.status_effects {
+            fields |= ActorFields::STATUS_EFFECTS;
+        }
+        if before.passives != after.passives {
+            fields |= ActorFields::PASSIVES;
+        }
+        if before.channel != after.channel {
+            fields |= ActorFields::CHANNEL;
+        }
+        if before.provider_kind != after.provider_kind
+            || before.trait_profile != after.trait_profile
+            || before.species != after.species
+            || before.faction != after.faction
+            || before.objective != after.objective
+        {
+            fields |= ActorFields::IDENTITY;
+        }
+        if before.died_at != after.died_at {
+            fields |= ActorFields::DIED_AT;
+        }
 
         if fields.is_empty() {
             None
@@ -61,6 +87,55 @@ impl ActorChanges {
             })
         }
     }
+
+    /// Copies the fields marked in this change from `after` into `actor`.
+    pub(super) fn apply(&self, actor: &mut ActorState, after: &ActorState) {
+        let fields = self.fields;
+
+        if fields.contains(ActorFields::POSITION) {
+            actor.position = after.position;
+        }
+        if fields.contains(ActorFields::CORE_STATS) {
+            actor.core_stats = after.core_stats.clone();
+        }
+        if fields.contains(ActorFields::RESOURCES) {
+            actor.resources = after.resources.clone();
+        }
+        if fields.contains(ActorFields::BONUSES) {
+            actor.bonuses = after.bonuses.clone();
+        }
+        if fields.contains(ActorFields::INVENTORY) {
+            actor.inventory = after.inventory.clone();
+        }
+        if fields.contains(ActorFields::READY_AT) {
+            actor.ready_at = after.ready_at;
+        }
+        if fields.contains(ActorFields::ACTIONS) {
+            actor.actions = after.actions.clone();
+        }
+        if fields.contains(ActorFields::EQUIPMENT) {
+            actor.equipment = after.equipment.clone();
+        }
+        if fields.contains(ActorFields::STATUS_EFFECTS) {
+            actor.status_effects = after.status_effects.clone();
+        }
+        if fields.contains(ActorFields::PASSIVES) {
+            actor.passives = after.passives.clone();
+        }
+        if fields.contains(ActorFields::CHANNEL) {
+            actor.channel = after.channel;
+        }
+        if fields.contains(ActorFields::IDENTITY) {
+            actor.provider_kind = after.provider_kind;
+            actor.trait_profile = after.trait_profile;
+            actor.species = after.species;
+            actor.faction = after.faction;
+            actor.objective = after.objective;
+        }
+        if fields.contains(ActorFields::DIED_AT) {
+            actor.died_at = after.died_at;
+        }
+    }
 }
 
 /// Metadata describing which fields of a prop changed.
@@ -89,6 +164,9 @@ impl PropChanges {
         if before.is_active != after.is_active {
             fields |= PropFields::IS_ACTIVE;
         }
+        if before.kind != after.kind {
+            fields |= PropFields::KIND;
+        }
 
         if fields.is_empty() {
             None
@@ -99,6 +177,19 @@ impl PropChanges {
             })
         }
     }
+
+    /// Copies the fields marked in this change from `after` into `prop`.
+    pub(super) fn apply(&self, prop: &mut PropState, after: &PropState) {
+        if self.fields.contains(PropFields::POSITION) {
+            prop.position = after.position;
+        }
+        if self.fields.contains(PropFields::IS_ACTIVE) {
+            prop.is_active = after.is_active;
+        }
+        if self.fields.contains(PropFields::KIND) {
+            prop.kind = after.kind.clone();
+        }
+    }
 }
 
 /// Metadata describing which fields of an item changed.
@@ -124,6 +215,12 @@ impl ItemChanges {
         if before.position != after.position {
             fields |= ItemFields::POSITION;
         }
+        if before.handle != after.handle {
+            fields |= ItemFields::HANDLE;
+        }
+        if before.quantity != after.quantity {
+            fields |= ItemFields::QUANTITY;
+        }
 
         if fields.is_empty() {
             None
@@ -134,6 +231,19 @@ impl ItemChanges {
             })
         }
     }
+
+    /// Copies the fields marked in this change from `after` into `item`.
+    pub(super) fn apply(&self, item: &mut ItemState, after: &ItemState) {
+        if self.fields.contains(ItemFields::POSITION) {
+            item.position = after.position;
+        }
+        if self.fields.contains(ItemFields::HANDLE) {
+            item.handle = after.handle;
+        }
+        if self.fields.contains(ItemFields::QUANTITY) {
+            item.quantity = after.quantity;
+        }
+    }
 }
 
 /// Metadata describing which fields of turn state changed.
@@ -183,6 +293,24 @@ impl TurnChanges {
         }
     }
 
+    /// Copies the marked fields from `after` and replays the activation lists.
+    pub(crate) fn apply(&self, turn: &mut TurnState, after: &TurnState) {
+        if self.fields.contains(TurnFields::CLOCK) {
+            turn.clock = after.clock;
+        }
+        if self.fields.contains(TurnFields::CURRENT_ACTOR) {
+            turn.current_actor = after.current_actor;
+        }
+        if self.fields.contains(TurnFields::NONCE) {
+            turn.nonce = after.nonce;
+        }
+
+        for id in &self.deactivated {
+            turn.active_actors.remove(id);
+        }
+        turn.active_actors.extend(self.activated.iter().copied());
+    }
+
     /// Returns true if turn state is completely unchanged.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -195,8 +323,21 @@ impl TurnChanges {
 /// Instead of storing full occupancy data, we only track which tile positions
 /// had their occupant list modified. Actual occupant lists are retrieved from
 /// the before/after [`WorldState`] when needed.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// Ordered by position, which is the canonical order inside [`WorldChanges`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OccupancyChanges {
     pub position: Position,
 }
+
+/// Metadata describing a hazard placed, replaced or decayed on a tile.
+///
+/// The hazard itself is read from the before/after [`WorldState`] by position.
+///
+/// Ordered by position, which is the canonical order inside [`WorldChanges`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HazardChanges {
+    pub position: Position,
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::state::EntityId;
+use crate::state::{BoundedVec, EntityId};
 
 /// Generic collection delta tracking additions, removals, and updates.
 ///
@@ -115,3 +115,52 @@ where
 
     delta
 }
+
+/// Replays a collection delta onto `target`, reading entity data from `after`.
+///
+/// The inverse of [`diff_collection`]: removed entities are dropped, updated
+/// ones get their changed fields via `apply_fn`, and added ones are cloned from
+/// `after`. Additions are appended in `after`'s order, so a target that started
+/// as the `before` collection ends up element-for-element equal to `after`.
+pub(super) fn apply_collection<
+    T,
+    TChanges,
+    const MIN: usize,
+    const MAX: usize,
+    FId,
+    FChangeId,
+    FApply,
+>(
+    target: &mut BoundedVec<T, MIN, MAX>,
+    after: &BoundedVec<T, MIN, MAX>,
+    changes: &CollectionChanges<TChanges>,
+    id_fn: FId,
+    change_id_fn: FChangeId,
+    mut apply_fn: FApply,
+) where
+    T: Clone,
+    FId: Fn(&T) -> EntityId,
+    FChangeId: Fn(&TChanges) -> EntityId,
+    FApply: FnMut(&TChanges, &mut T, &T),
+{
+    for id in &changes.removed {
+        if let Some(index) = target.iter().position(|entity| id_fn(entity) == *id) {
+            let _ = target.remove(index);
+        }
+    }
+
+    for change in &changes.updated {
+        let id = change_id_fn(change);
+        let source = after.iter().find(|entity| id_fn(entity) == id);
+        let entity = target.iter_mut().find(|entity| id_fn(entity) == id);
+        if let (Some(entity), Some(source)) = (entity, source) {
+            apply_fn(change, entity, source);
+        }
+    }
+
+    for entity in after.iter() {
+        if changes.added.contains(&id_fn(entity)) {
+            let _ = target.push(entity.clone());
+        }
+    }
+}
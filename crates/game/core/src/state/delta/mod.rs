@@ -8,11 +8,13 @@ use crate::action::Action;
 use crate::state::{EntitiesState, GameState, Tick, WorldState};
 
 pub use bitmask::{ActorFields, ItemFields, PropFields, TurnFields};
-pub use changes::{ActorChanges, ItemChanges, OccupancyChanges, PropChanges, TurnChanges};
+pub use changes::{
+    ActorChanges, HazardChanges, ItemChanges, OccupancyChanges, PropChanges, TurnChanges,
+};
 pub use collection::CollectionChanges;
 
 use changes::{ActorChanges as AC, ItemChanges as IC, PropChanges as PC};
-use collection::diff_collection;
+use collection::{apply_collection, diff_collection};
 
 /// Minimal description of an executed action's impact on the deterministic state.
 ///
@@ -33,7 +35,7 @@ pub struct StateDelta {
     /// Changes to all game entities (player, NPCs, props, items).
     pub entities: EntitiesChanges,
 
-    /// Changes to world state (occupancy grid).
+    /// Changes to world state (occupancy grid, hazards).
     pub world: WorldChanges,
 }
 
@@ -47,7 +49,7 @@ impl StateDelta {
     ///
     /// 1. Compare turn state (clock, current actor, active set)
     /// 2. Compare entities (player, NPCs, props, items) using collection diff
-    /// 3. Compare world occupancy grid
+    /// 3. Compare world occupancy grid and hazards
     ///
     /// # Complexity
     ///
@@ -131,6 +133,34 @@ impl EntitiesChanges {
         }
     }
 
+    /// Replays these changes onto `entities`, reading values from `after`.
+    pub(super) fn apply(&self, entities: &mut EntitiesState, after: &EntitiesState) {
+        apply_collection(
+            &mut entities.actors,
+            &after.actors,
+            &self.actors,
+            |actor| actor.id,
+            |change| change.id,
+            AC::apply,
+        );
+        apply_collection(
+            &mut entities.props,
+            &after.props,
+            &self.props,
+            |prop| prop.id,
+            |change| change.id,
+            PC::apply,
+        );
+        apply_collection(
+            &mut entities.items,
+            &after.items,
+            &self.items,
+            |item| item.id,
+            |change| change.id,
+            IC::apply,
+        );
+    }
+
     /// Returns true if no entity changes occurred.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -151,7 +181,7 @@ impl EntitiesChanges {
 
 /// Changes to world state.
 ///
-/// Currently tracks only occupancy grid changes. Future extensions may include:
+/// Tracks occupancy grid and hazard overlay changes. Future extensions may include:
 /// - Terrain modifications
 /// - Fog of war updates
 /// - Region state changes
@@ -160,21 +190,75 @@ impl EntitiesChanges {
 pub struct WorldChanges {
     /// Tile positions where occupancy changed.
     ///
+    /// Sorted by position with no duplicates (see [`WorldChanges::new`]).
     /// The actual occupant lists are stored in before/after `WorldState` and
     /// can be queried by position when needed (e.g., for ZK witness generation).
     pub occupancy: Vec<OccupancyChanges>,
+
+    /// Tile positions where a hazard overlay appeared, changed or decayed.
+    ///
+    /// Sorted by position with no duplicates.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hazards: Vec<HazardChanges>,
+
+    /// Whether the player's visibility changed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub visibility: bool,
 }
 
 impl WorldChanges {
+    /// Creates world changes in canonical order.
+    ///
+    /// Occupancy changes are sorted by position and deduplicated, so the same
+    /// set of changed tiles always serializes to the same bytes. Witness
+    /// generation depends on this for stable proofs.
+    pub fn new(occupancy: impl IntoIterator<Item = OccupancyChanges>) -> Self {
+        let mut occupancy: Vec<_> = occupancy.into_iter().collect();
+        occupancy.sort_unstable();
+        occupancy.dedup();
+        Self {
+            occupancy,
+            hazards: Vec::new(),
+            visibility: false,
+        }
+    }
+
     fn from_states(before: &WorldState, after: &WorldState) -> Self {
-        let occupancy = diff_occupancy(before, after);
-        Self { occupancy }
+        Self {
+            hazards: diff_hazards(before, after),
+            visibility: before.visibility != after.visibility,
+            ..Self::new(diff_occupancy(before, after))
+        }
+    }
+
+    /// Copies the occupant lists and hazards of every listed tile from `after`,
+    /// and the visibility if it changed.
+    pub(super) fn apply(&self, world: &mut WorldState, after: &WorldState) {
+        for change in &self.occupancy {
+            match after.tile_map.occupants(&change.position) {
+                Some(occupants) => world
+                    .tile_map
+                    .replace_occupants(change.position, occupants.clone()),
+                None => world.tile_map.clear_occupants(&change.position),
+            }
+        }
+
+        for change in &self.hazards {
+            match after.tile_map.hazard(&change.position) {
+                Some(hazard) => world.tile_map.set_hazard(change.position, *hazard),
+                None => world.tile_map.clear_hazard(&change.position),
+            }
+        }
+
+        if self.visibility {
+            world.visibility = after.visibility.clone();
+        }
     }
 
     /// Returns true if no world changes occurred.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.occupancy.is_empty()
+        self.occupancy.is_empty() && self.hazards.is_empty() && !self.visibility
     }
 }
 
@@ -220,3 +304,81 @@ fn diff_occupancy(before: &WorldState, after: &WorldState) -> Vec<OccupancyChang
         })
         .collect()
 }
+
+/// Compares hazard overlays and returns the positions whose hazard differs.
+///
+/// Positions come out of a `BTreeSet`, so the result is already sorted and unique.
+fn diff_hazards(before: &WorldState, after: &WorldState) -> Vec<HazardChanges> {
+    let mut positions = BTreeSet::new();
+    positions.extend(before.tile_map.hazards().keys().copied());
+    positions.extend(after.tile_map.hazards().keys().copied());
+
+    positions
+        .into_iter()
+        .filter(|position| before.tile_map.hazard(position) != after.tile_map.hazard(position))
+        .map(|position| HazardChanges { position })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::ActorTemplate;
+    use crate::state::Position;
+
+    fn changed(x: i32, y: i32) -> OccupancyChanges {
+        OccupancyChanges {
+            position: Position::new(x, y),
+        }
+    }
+
+    fn assert_canonical(changes: &WorldChanges) {
+        assert!(
+            changes.occupancy.windows(2).all(|w| w[0] < w[1]),
+            "occupancy changes not unique and sorted: {:?}",
+            changes.occupancy
+        );
+    }
+
+    #[test]
+    fn new_sorts_and_dedups_occupancy() {
+        let changes =
+            WorldChanges::new([changed(3, 1), changed(0, 2), changed(3, 1), changed(0, 0)]);
+
+        assert_canonical(&changes);
+        assert_eq!(
+            changes.occupancy,
+            vec![changed(0, 0), changed(0, 2), changed(3, 1)]
+        );
+    }
+
+    #[test]
+    fn diffed_occupancy_is_unique_and_sorted() {
+        let template = ActorTemplate::test_actor();
+        let mut before = GameState::empty();
+        let npcs: Vec<_> = [
+            Position::new(7, 2),
+            Position::new(1, 5),
+            Position::new(4, 4),
+        ]
+        .into_iter()
+        .map(|pos| before.add_npc(&template, pos).unwrap())
+        .collect();
+
+        // Shift every NPC, vacating and filling tiles in no particular order
+        let mut after = before.clone();
+        for (id, to) in npcs.into_iter().zip([
+            Position::new(0, 0),
+            Position::new(7, 2),
+            Position::new(1, 5),
+        ]) {
+            let from = after.entities.actor(id).unwrap().position.unwrap();
+            after.world.tile_map.remove_occupant(&from, id);
+            after.world.tile_map.add_occupant(to, id);
+        }
+
+        let changes = WorldChanges::from_states(&before.world, &after.world);
+        assert_canonical(&changes);
+        assert_eq!(changes.occupancy.len(), 4);
+    }
+}
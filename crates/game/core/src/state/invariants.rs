@@ -0,0 +1,316 @@
+//! Structural invariants of [`GameState`].
+//!
+//! The engine keeps several views of the same facts in sync: actor positions
+//! and the occupancy map, `ready_at` timestamps and the active set, inventory
+//! slots and their stacks. [`GameState::check_invariants`] validates all of
+//! them at once so tests and debug builds can catch corruption right after the
+//! turn that caused it, instead of at the next scattered `debug_assert!`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{EntityId, GameState, Position};
+
+/// A single broken invariant found by [`GameState::check_invariants`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvariantViolation {
+    /// The same ID is used by more than one actor, prop, or item.
+    #[error("Entity ID {id:?} is used by more than one entity")]
+    DuplicateEntityId {
+        /// The shared ID.
+        id: EntityId,
+    },
+
+    /// An entity stands on a tile whose occupancy list does not include it.
+    #[error("Entity {entity:?} is at {position:?} but missing from occupancy")]
+    MissingOccupant {
+        /// The entity that is not registered.
+        entity: EntityId,
+        /// The entity's position.
+        position: Position,
+    },
+
+    /// The occupancy map lists an entity that is not at that position.
+    #[error("Occupancy at {position:?} lists {entity:?}, which is not there")]
+    StaleOccupant {
+        /// The entity listed in the occupancy map.
+        entity: EntityId,
+        /// The tile listing it.
+        position: Position,
+    },
+
+    /// The active set contains an ID that is not an actor.
+    #[error("Active set contains unknown actor {id:?}")]
+    UnknownActiveActor {
+        /// The unknown ID.
+        id: EntityId,
+    },
+
+    /// An actor's `ready_at` disagrees with its active set membership.
+    #[error("Actor {id:?} has ready_at={has_ready_at} but in_active_set={in_active_set}")]
+    ActiveSetMismatch {
+        /// The actor.
+        id: EntityId,
+        /// Whether the actor has a `ready_at` timestamp.
+        has_ready_at: bool,
+        /// Whether the actor is in the active set.
+        in_active_set: bool,
+    },
+
+    /// An inventory slot holds zero items instead of being removed.
+    #[error("Actor {entity:?} has an empty inventory slot {slot}")]
+    EmptyInventorySlot {
+        /// The inventory owner.
+        entity: EntityId,
+        /// Index of the empty slot.
+        slot: usize,
+    },
+}
+
+impl GameState {
+    /// Validates the structural invariants of this state.
+    ///
+    /// Checks that:
+    /// - Entity IDs are unique across actors, props, and items
+    /// - Every positioned entity is in the occupancy map at its position, and
+    ///   the occupancy map lists nothing else
+    /// - The active set contains exactly the actors with a `ready_at` timestamp
+    /// - Inventory slots are non-empty
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if every invariant holds
+    /// - `Err(violations)` listing each broken invariant in a deterministic order
+    pub fn check_invariants(&self) -> Result<(), Vec<InvariantViolation>> {
+        let mut violations = Vec::new();
+
+        self.check_unique_ids(&mut violations);
+        self.check_occupancy(&mut violations);
+        self.check_active_set(&mut violations);
+        self.check_inventories(&mut violations);
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn check_unique_ids(&self, violations: &mut Vec<InvariantViolation>) {
+        let ids = self
+            .entities
+            .all_actors()
+            .map(|actor| actor.id)
+            .chain(self.entities.all_props().map(|prop| prop.id))
+            .chain(self.entities.all_items().map(|item| item.id));
+
+        let mut seen = BTreeSet::new();
+        let mut duplicates = BTreeSet::new();
+        for id in ids {
+            if !seen.insert(id) {
+                duplicates.insert(id);
+            }
+        }
+
+        violations.extend(
+            duplicates
+                .into_iter()
+                .map(|id| InvariantViolation::DuplicateEntityId { id }),
+        );
+    }
+
+    fn check_occupancy(&self, violations: &mut Vec<InvariantViolation>) {
+        let positioned: Vec<(EntityId, Position)> = self
+            .entities
+            .all_actors()
+            .filter_map(|actor| actor.position.map(|pos| (actor.id, pos)))
+            .chain(
+                self.entities
+                    .all_props()
+                    .map(|prop| (prop.id, prop.position)),
+            )
+            .chain(
+                self.entities
+                    .all_items()
+                    .map(|item| (item.id, item.position)),
+            )
+            .collect();
+
+        for &(entity, position) in &positioned {
+            let registered = self
+                .world
+                .tile_map
+                .occupants(&position)
+                .is_some_and(|occupants| occupants.contains(&entity));
+            if !registered {
+                violations.push(InvariantViolation::MissingOccupant { entity, position });
+            }
+        }
+
+        let positions: BTreeMap<EntityId, BTreeSet<Position>> =
+            positioned
+                .into_iter()
+                .fold(BTreeMap::new(), |mut map, (entity, position)| {
+                    map.entry(entity).or_default().insert(position);
+                    map
+                });
+
+        for (&position, occupants) in self.world.tile_map.occupancy() {
+            for &entity in occupants {
+                let present = positions
+                    .get(&entity)
+                    .is_some_and(|at| at.contains(&position));
+                if !present {
+                    violations.push(InvariantViolation::StaleOccupant { entity, position });
+                }
+            }
+        }
+    }
+
+    fn check_active_set(&self, violations: &mut Vec<InvariantViolation>) {
+        for &id in &self.turn.active_actors {
+            if self.entities.actor(id).is_none() {
+                violations.push(InvariantViolation::UnknownActiveActor { id });
+            }
+        }
+
+        for actor in self.entities.all_actors() {
+            let has_ready_at = actor.ready_at.is_some();
+            let in_active_set = self.turn.active_actors.contains(&actor.id);
+            if has_ready_at != in_active_set {
+                violations.push(InvariantViolation::ActiveSetMismatch {
+                    id: actor.id,
+                    has_ready_at,
+                    in_active_set,
+                });
+            }
+        }
+    }
+
+    fn check_inventories(&self, violations: &mut Vec<InvariantViolation>) {
+        for actor in self.entities.all_actors() {
+            for (slot, item) in actor.inventory.items.iter().enumerate() {
+                if item.quantity == 0 {
+                    violations.push(InvariantViolation::EmptyInventorySlot {
+                        entity: actor.id,
+                        slot,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::ActorTemplate;
+    use crate::state::{InventorySlot, ItemHandle, ItemState};
+
+    /// Player at (1, 1) and an active NPC at (3, 3).
+    fn valid_state() -> (GameState, EntityId) {
+        let template = ActorTemplate::test_actor();
+        let mut state = GameState::empty();
+        state.add_player(&template, Position::new(1, 1)).unwrap();
+        let npc = state.add_npc(&template, Position::new(3, 3)).unwrap();
+
+        state.entities.actor_mut(npc).unwrap().ready_at = Some(10);
+        state.turn.active_actors.insert(npc);
+
+        (state, npc)
+    }
+
+    #[test]
+    fn valid_state_passes() {
+        let (mut state, _) = valid_state();
+        assert_eq!(state.check_invariants(), Ok(()));
+
+        // Items registered in occupancy and stacks split across slots are fine too
+        let item = state.allocate_entity_id().unwrap();
+        let pos = Position::new(5, 5);
+        state
+            .entities
+            .items
+            .push(ItemState::new(item, pos, ItemHandle(7), 1))
+            .unwrap();
+        state.world.tile_map.add_occupant(pos, item);
+        state
+            .entities
+            .player_mut()
+            .inventory
+            .add_item(ItemHandle(7), 3, 2);
+
+        assert_eq!(state.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn reports_occupancy_out_of_sync() {
+        let (mut state, npc) = valid_state();
+        // Move the NPC without updating occupancy
+        state.entities.actor_mut(npc).unwrap().position = Some(Position::new(4, 3));
+
+        assert_eq!(
+            state.check_invariants(),
+            Err(vec![
+                InvariantViolation::MissingOccupant {
+                    entity: npc,
+                    position: Position::new(4, 3),
+                },
+                InvariantViolation::StaleOccupant {
+                    entity: npc,
+                    position: Position::new(3, 3),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_active_set_membership() {
+        let (mut state, npc) = valid_state();
+        state.turn.active_actors.insert(EntityId(99));
+        state.entities.actor_mut(npc).unwrap().ready_at = None;
+
+        assert_eq!(
+            state.check_invariants(),
+            Err(vec![
+                InvariantViolation::UnknownActiveActor { id: EntityId(99) },
+                InvariantViolation::ActiveSetMismatch {
+                    id: npc,
+                    has_ready_at: false,
+                    in_active_set: true,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_duplicate_ids() {
+        let (mut state, npc) = valid_state();
+        let mut clone = state.entities.actor(npc).unwrap().clone();
+        clone.position = None;
+        clone.ready_at = Some(10);
+        state.entities.actors.push(clone).unwrap();
+
+        assert_eq!(
+            state.check_invariants(),
+            Err(vec![InvariantViolation::DuplicateEntityId { id: npc }])
+        );
+    }
+
+    #[test]
+    fn reports_inventory_bounds() {
+        let (mut state, npc) = valid_state();
+        let items = &mut state.entities.actor_mut(npc).unwrap().inventory.items;
+        items.push(InventorySlot::new(ItemHandle(1), 0));
+        items.push(InventorySlot::new(ItemHandle(2), 1));
+        items.push(InventorySlot::new(ItemHandle(2), 4));
+
+        // Only the empty slot is reported; an item may span several stacks
+        assert_eq!(
+            state.check_invariants(),
+            Err(vec![InvariantViolation::EmptyInventorySlot {
+                entity: npc,
+                slot: 0,
+            }])
+        );
+    }
+}
@@ -5,21 +5,25 @@
 //! query this state but mutate it exclusively through the engine.
 pub mod delta;
 pub mod error;
+pub mod invariants;
 pub mod types;
 
 use crate::config::GameConfig;
 use crate::env::MapOracle;
 pub use bounded_vector::BoundedVec;
 pub use delta::{
-    ActorChanges, ActorFields, CollectionChanges, EntitiesChanges, ItemChanges, ItemFields,
-    OccupancyChanges, PropChanges, PropFields, StateDelta, TurnChanges, TurnFields, WorldChanges,
+    ActorChanges, ActorFields, CollectionChanges, EntitiesChanges, HazardChanges, ItemChanges,
+    ItemFields, OccupancyChanges, PropChanges, PropFields, StateDelta, TurnChanges, TurnFields,
+    WorldChanges,
 };
 pub use error::StateError;
+pub use invariants::InvariantViolation;
 pub use types::{
-    ActionAbilities, ActionAbility, ActorState, EntitiesState, EntityId, Equipment,
-    EquipmentBuilder, InventorySlot, InventoryState, ItemHandle, ItemState, PassiveAbilities,
-    PassiveAbility, PassiveKind, Position, PropKind, PropState, StatusEffect, StatusEffectKind,
-    StatusEffects, Tick, TileMap, TileView, TurnState, WorldState,
+    ActionAbilities, ActionAbility, ActorState, Channel, EntitiesState, EntityId, Equipment,
+    EquipmentBuilder, HazardOverlay, InventorySlot, InventoryState, ItemHandle, ItemState,
+    PassiveAbilities, PassiveAbility, PassiveKind, Position, PropKind, PropState, StatusEffect,
+    StatusEffectKind, StatusEffects, Tick, TileMap, TileView, TurnState, Visibility, VisibilitySet,
+    WorldState,
 };
 
 /// Canonical snapshot of the deterministic game state.
@@ -88,14 +92,26 @@ impl GameState {
     }
 
     /// Returns a merged tile view that combines static map data with runtime occupants.
+    ///
+    /// A tile holding a closed door is reported as blocked, and so impassable.
     pub fn tile_view<M>(&self, map: &M, position: Position) -> Option<TileView>
     where
         M: MapOracle + ?Sized,
     {
-        self.world.tile_view(map, position)
+        let view = self.world.tile_view(map, position)?;
+        Some(view.with_blocked(self.is_blocked(position)))
+    }
+
+    /// Returns true if a prop such as a closed door blocks `position`.
+    pub fn is_blocked(&self, position: Position) -> bool {
+        self.entities
+            .props
+            .iter()
+            .any(|prop| prop.position == position && prop.is_blocking())
     }
 
-    /// Determines whether a tile can be entered considering terrain passability and occupancy.
+    /// Determines whether a tile can be entered considering terrain passability,
+    /// blocking props and occupancy.
     pub fn can_enter<M>(&self, map: &M, position: Position) -> bool
     where
         M: MapOracle + ?Sized,
@@ -128,6 +144,84 @@ impl GameState {
         Some(pos)
     }
 
+    /// Returns true if the player can currently see `position`.
+    pub fn is_visible(&self, position: Position) -> bool {
+        self.world.visibility.is_visible(&position)
+    }
+
+    /// Recomputes what the player sees from their current position.
+    ///
+    /// A tile within [`VisibilitySet::SIGHT_RADIUS`] is visible when nothing
+    /// blocks [`MapOracle::line_of_sight`] to it, so walls bounding a room
+    /// are visible while whatever lies behind them is not. Tiles that drop out
+    /// of view stay seen. Does nothing while the player is off the map.
+    pub fn refresh_visibility<M>(&mut self, map: &M)
+    where
+        M: MapOracle + ?Sized,
+    {
+        let Some(origin) = self.entities.position(EntityId::PLAYER) else {
+            return;
+        };
+
+        let radius = VisibilitySet::SIGHT_RADIUS as i32;
+        let mut visible = Vec::new();
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let position = Position::new(origin.x + dx, origin.y + dy);
+                if map.contains(position)
+                    && (position == origin || map.line_of_sight(origin, position))
+                {
+                    visible.push(position);
+                }
+            }
+        }
+        self.world.visibility.reveal(visible);
+    }
+
+    /// Deals the damage of the hazard under a living actor, if one is active.
+    ///
+    /// Called when the actor enters a tile and when it starts its turn. Like
+    /// any other hit, the damage breaks an interruptible channel.
+    ///
+    /// Returns the HP actually lost.
+    pub fn apply_hazard(&mut self, id: EntityId) -> u32 {
+        let clock = self.turn.clock;
+        let Some(hazard) = self
+            .entities
+            .position(id)
+            .and_then(|pos| self.world.tile_map.hazard(&pos).copied())
+            .filter(|hazard| hazard.is_active(clock))
+        else {
+            return 0;
+        };
+        let Some(actor) = self.entities.actor_mut(id).filter(|actor| actor.is_alive()) else {
+            return 0;
+        };
+
+        let damage = hazard.damage.min(actor.resources.hp);
+        actor.resources.hp -= damage;
+        if damage > 0 {
+            actor.interrupt_channel();
+        }
+        damage
+    }
+
+    /// Stamps actors that ran out of HP with the current clock, and clears
+    /// the stamp from actors that are alive again.
+    ///
+    /// An actor keeps its first `died_at` until it is revived, so the stamp
+    /// tells how long it has been dead.
+    pub fn record_deaths(&mut self) {
+        let clock = self.turn.clock;
+        for actor in self.entities.all_actors_mut() {
+            match (actor.is_alive(), actor.died_at) {
+                (true, Some(_)) => actor.died_at = None,
+                (false, None) => actor.died_at = Some(clock),
+                _ => {}
+            }
+        }
+    }
+
     /// Allocates a new unique EntityId.
     ///
     /// # Returns
@@ -137,30 +231,34 @@ impl GameState {
     ///
     /// # Notes
     ///
-    /// Entity IDs 0 (PLAYER) and u32::MAX (SYSTEM) are reserved and will be skipped.
+    /// Entity IDs 0 (PLAYER) and u32::MAX (SYSTEM) are reserved and will never be
+    /// returned. Once the ID space is exhausted the allocator stays exhausted: every
+    /// further call returns the same overflow error and leaves the state untouched,
+    /// so callers can surface the error without worrying about partial mutation.
     pub fn allocate_entity_id(&mut self) -> Result<EntityId, StateError> {
-        // Skip reserved IDs (0 = PLAYER, u32::MAX = SYSTEM)
-        while self.next_entity_id == EntityId::PLAYER.0 || self.next_entity_id == EntityId::SYSTEM.0
-        {
-            self.next_entity_id =
-                self.next_entity_id
-                    .checked_add(1)
-                    .ok_or(StateError::EntityIdOverflow {
-                        current: self.next_entity_id,
-                    })?;
+        // Skip PLAYER (only reachable if the allocator was constructed at 0)
+        if self.next_entity_id == EntityId::PLAYER.0 {
+            self.next_entity_id = 1;
+        }
+
+        // SYSTEM is the upper bound - reaching it means the ID space is exhausted
+        if self.next_entity_id == EntityId::SYSTEM.0 {
+            return Err(StateError::EntityIdOverflow {
+                current: self.next_entity_id,
+            });
         }
 
         let id = EntityId(self.next_entity_id);
-        self.next_entity_id =
-            self.next_entity_id
-                .checked_add(1)
-                .ok_or(StateError::EntityIdOverflow {
-                    current: self.next_entity_id,
-                })?;
+        self.next_entity_id += 1;
 
         Ok(id)
     }
 
+    /// Returns how many entity IDs can still be allocated before overflow.
+    pub fn remaining_entity_ids(&self) -> u32 {
+        EntityId::SYSTEM.0.saturating_sub(self.next_entity_id.max(1))
+    }
+
     /// Add the player actor to the game.
     ///
     /// Player is always assigned EntityId::PLAYER, set as active, and ready to act immediately.
@@ -214,6 +312,7 @@ impl GameState {
     /// # Returns
     ///
     /// - `Ok(EntityId)` - The allocated entity ID for this NPC
+    /// - `Err(StateError::EntityIdOverflow)` if no entity IDs remain
     /// - `Err(StateError::ActorListFull)` if the actors list is at maximum capacity
     pub fn add_npc(
         &mut self,
@@ -258,9 +357,12 @@ impl GameState {
 
         // IMPORTANT: Activate the default player so they can act
         // EntitiesState::with_player() creates a player actor, but doesn't add to active_actors
-        // We need to ensure player is ready to act
+        // We need to ensure player is ready to act and occupies its tile
         if let Some(player) = state.entities.actors.first_mut() {
             player.ready_at = Some(0);
+            if let Some(position) = player.position {
+                state.world.tile_map.add_occupant(position, EntityId::PLAYER);
+            }
         }
         state.turn.active_actors.insert(EntityId::PLAYER);
 
@@ -272,6 +374,25 @@ impl GameState {
         self.turn.nonce
     }
 
+    /// Applies `delta` to this state, turning it into the state the delta leads to.
+    ///
+    /// A [`StateDelta`] records only *which* fields, entities and tiles changed,
+    /// so the new values are read from `after`, the state the delta was computed
+    /// against. Only what the delta references is copied: applying
+    /// `StateDelta::from_states(action, &before, &after)` to a clone of `before`
+    /// yields a state with the same state root as `after`.
+    ///
+    /// This lets checkpoints be rebuilt from a base state plus the entities and
+    /// tiles a delta touches, instead of a full snapshot per action.
+    pub fn apply_delta(&mut self, delta: &StateDelta, after: &GameState) {
+        delta.turn.apply(&mut self.turn, &after.turn);
+        delta.entities.apply(&mut self.entities, &after.entities);
+        delta.world.apply(&mut self.world, &after.world);
+
+        // The allocator is not part of the delta; new ids can only come from `after`.
+        self.next_entity_id = self.next_entity_id.max(after.next_entity_id);
+    }
+
     /// Computes a deterministic SHA-256 hash of the entire game state.
     ///
     /// This is used as the "state root" for ZK proofs, providing a cryptographic
@@ -285,6 +406,11 @@ impl GameState {
     /// - Simpler = fewer constraints = faster proving
     /// - SHA-256 is hardware-accelerated in RISC0 zkVM
     ///
+    /// The turn, entities and world sections are hashed on their own, and the
+    /// root is the SHA-256 of `game_seed`, `next_entity_id` and the three
+    /// section hashes. That lets [`compute_state_root_incremental`] reuse the
+    /// hashes of sections an action did not touch.
+    ///
     /// For future custom circuits (Arkworks), Merkle trees may become valuable
     /// to enable partial state updates and reduce circuit size.
     ///
@@ -292,14 +418,372 @@ impl GameState {
     ///
     /// Uses bincode for deterministic binary serialization. Bincode serialization
     /// is stable across serialize-deserialize round-trips for our state structures.
+    ///
+    /// [`compute_state_root_incremental`]: Self::compute_state_root_incremental
     #[cfg(feature = "serde")]
     pub fn compute_state_root(&self) -> [u8; 32] {
+        self.compute_state_root_incremental(&CachedRoots::default())
+            .0
+    }
+
+    /// Computes the state root, reusing section hashes still valid in `last`.
+    ///
+    /// Only sections that `last` holds no hash for are re-serialized; the
+    /// result always equals [`compute_state_root`](Self::compute_state_root) as
+    /// long as `last` was invalidated with every delta since it was produced
+    /// (see [`CachedRoots::invalidate`]). Returns the root along with the cache
+    /// to pass to the next call.
+    #[cfg(feature = "serde")]
+    pub fn compute_state_root_incremental(&self, last: &CachedRoots) -> ([u8; 32], CachedRoots) {
+        use sha2::{Digest, Sha256};
+
+        let turn = last.turn.unwrap_or_else(|| hash_section(&self.turn));
+        let entities = last
+            .entities
+            .unwrap_or_else(|| hash_section(&self.entities));
+        let world = last.world.unwrap_or_else(|| hash_world(&self.world));
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.game_seed.to_le_bytes());
+        hasher.update(self.next_entity_id.to_le_bytes());
+        hasher.update(turn);
+        hasher.update(entities);
+        hasher.update(world);
+
+        let roots = CachedRoots {
+            turn: Some(turn),
+            entities: Some(entities),
+            world: Some(world),
+        };
+        (hasher.finalize().into(), roots)
+    }
+}
+
+/// Hashes one top-level section of the state for the state root.
+#[cfg(feature = "serde")]
+fn hash_section<T: serde::Serialize>(section: &T) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let serialized =
+        bincode::serialize(section).expect("GameState serialization should never fail");
+    Sha256::digest(&serialized).into()
+}
+
+/// Hashes the world section for the state root.
+///
+/// Visibility only counts with the `fog` feature, so proofs that don't track
+/// fog of war skip it. Without it, only the tile map is hashed.
+#[cfg(feature = "serde")]
+fn hash_world(world: &WorldState) -> [u8; 32] {
+    #[cfg(feature = "fog")]
+    {
+        hash_section(world)
+    }
+    #[cfg(not(feature = "fog"))]
+    {
+        hash_section(&world.tile_map)
+    }
+}
+
+/// Section hashes behind a state root, reused by
+/// [`GameState::compute_state_root_incremental`].
+///
+/// A section without a hash is re-serialized on the next call, so
+/// [`CachedRoots::default`] hashes everything. Call
+/// [`CachedRoots::invalidate`] with the [`StateDelta`] of every action applied
+/// since the cache was produced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CachedRoots {
+    turn: Option<[u8; 32]>,
+    entities: Option<[u8; 32]>,
+    world: Option<[u8; 32]>,
+}
+
+impl CachedRoots {
+    /// Drops the hash of every section `delta` changed.
+    pub fn invalidate(&mut self, delta: &StateDelta) {
+        if !delta.turn.is_empty() {
+            self.turn = None;
+        }
+        if !delta.entities.is_empty() {
+            self.entities = None;
+        }
+        if !delta.world.is_empty() {
+            self.world = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{Action, PrepareTurnAction, SystemActionKind};
+    use crate::env::ActorTemplate;
+    use crate::traits::Faction;
+
+    fn exhausted_state() -> GameState {
+        let mut state = GameState::empty();
+        state.next_entity_id = EntityId::SYSTEM.0 - 1;
+        state
+    }
+
+    #[test]
+    fn allocation_skips_reserved_ids() {
+        let mut state = GameState::empty();
+        assert_eq!(state.allocate_entity_id(), Ok(EntityId(1)));
+
+        state.next_entity_id = EntityId::PLAYER.0;
+        assert_eq!(state.allocate_entity_id(), Ok(EntityId(1)));
+    }
+
+    #[test]
+    fn last_id_is_below_system() {
+        let mut state = exhausted_state();
+        assert_eq!(state.remaining_entity_ids(), 1);
+        assert_eq!(
+            state.allocate_entity_id(),
+            Ok(EntityId(EntityId::SYSTEM.0 - 1))
+        );
+        assert_eq!(state.remaining_entity_ids(), 0);
+    }
+
+    #[test]
+    fn exhausted_allocator_returns_overflow_repeatedly() {
+        let mut state = exhausted_state();
+        state.allocate_entity_id().unwrap();
+
+        let snapshot = state.clone();
+        for _ in 0..3 {
+            assert_eq!(
+                state.allocate_entity_id(),
+                Err(StateError::EntityIdOverflow {
+                    current: EntityId::SYSTEM.0
+                })
+            );
+        }
+        assert_eq!(state, snapshot);
+    }
+
+    #[test]
+    fn add_npc_on_exhausted_ids_leaves_state_untouched() {
+        let mut state = exhausted_state();
+        state.allocate_entity_id().unwrap();
+        let snapshot = state.clone();
+
+        let template = ActorTemplate::test_actor();
+        let result = state.add_npc(&template, Position::new(1, 1));
+
+        assert!(matches!(result, Err(StateError::EntityIdOverflow { .. })));
+        assert_eq!(state, snapshot);
+    }
+
+    #[test]
+    fn apply_delta_rebuilds_the_after_state() {
+        let template = ActorTemplate::test_actor();
+        let mut before = GameState::with_player();
+        let walker = before.add_npc(&template, Position::new(2, 2)).unwrap();
+        let guard = before.add_npc(&template, Position::new(5, 5)).unwrap();
+        let lever = before.allocate_entity_id().unwrap();
+        let dropped = before.allocate_entity_id().unwrap();
+        let stack = before.allocate_entity_id().unwrap();
+        let _ = before.entities.props.push(PropState::new(
+            lever,
+            Position::new(4, 1),
+            PropKind::Switch,
+            false,
+        ));
+        for id in [dropped, stack] {
+            let _ = before.entities.items.push(ItemState::new(
+                id,
+                Position::new(3, 3),
+                ItemHandle(1),
+                2,
+            ));
+        }
+        before
+            .world
+            .tile_map
+            .set_hazard(Position::new(6, 6), HazardOverlay::new(3, 10));
+
+        let mut after = before.clone();
+        after.turn.clock = 7;
+        after.turn.nonce = 1;
+        after.turn.current_actor = walker;
+        after.turn.active_actors.insert(walker);
+        after.turn.active_actors.remove(&EntityId::PLAYER);
+
+        let walker_state = after.entities.actor_mut(walker).unwrap();
+        walker_state.position = Some(Position::new(3, 2));
+        walker_state.ready_at = Some(12);
+        after.entities.actor_mut(guard).unwrap().faction = Faction::Hostile;
+        let tiles = &mut after.world.tile_map;
+        tiles.remove_occupant(&Position::new(2, 2), walker);
+        tiles.add_occupant(Position::new(3, 2), walker);
+        let summoned = after.add_npc(&template, Position::new(1, 6)).unwrap();
+
+        let index = after
+            .entities
+            .items
+            .iter()
+            .position(|item| item.id == dropped)
+            .unwrap();
+        let _ = after.entities.items.remove(index);
+        after.entities.item_mut(stack).unwrap().quantity = 1;
+        after.entities.props.iter_mut().for_each(|prop| {
+            prop.is_active = true;
+        });
+
+        after.world.tile_map.clear_hazard(&Position::new(6, 6));
+        after
+            .world
+            .tile_map
+            .set_hazard(Position::new(0, 1), HazardOverlay::new(5, 20));
+
+        let action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let delta = StateDelta::from_states(action, &before, &after);
+        assert_eq!(delta.entities.actors.added, vec![summoned]);
+        assert_eq!(delta.entities.items.removed, vec![dropped]);
+
+        let mut rebuilt = before.clone();
+        rebuilt.apply_delta(&delta, &after);
+
+        assert_eq!(rebuilt, after);
+        #[cfg(feature = "serde")]
+        assert_eq!(rebuilt.compute_state_root(), after.compute_state_root());
+    }
+
+    #[test]
+    fn apply_delta_ignores_values_the_delta_does_not_reference() {
+        let before = GameState::with_player();
+        let mut after = before.clone();
+        after.turn.nonce = 1;
+
+        let action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let delta = StateDelta::from_states(action, &before, &after);
+
+        // Drift in `after` that the delta never saw must not leak in.
+        after.turn.clock = 99;
+        after.entities.player_mut().position = Some(Position::new(9, 9));
+
+        let mut rebuilt = before.clone();
+        rebuilt.apply_delta(&delta, &after);
+
+        assert_eq!(rebuilt.turn.nonce, 1);
+        assert_eq!(rebuilt.turn.clock, before.turn.clock);
+        assert_eq!(rebuilt.entities.player(), before.entities.player());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn incremental_root_matches_full_root_across_actions() {
+        let template = ActorTemplate::test_actor();
+        let mut state = GameState::with_player();
+        let npcs: Vec<_> = (0..3)
+            .map(|x| state.add_npc(&template, Position::new(x, 4)).unwrap())
+            .collect();
+
+        let (root, mut cache) = state.compute_state_root_incremental(&CachedRoots::default());
+        assert_eq!(root, state.compute_state_root());
+
+        // Deterministic LCG so every run plays the same "random" actions
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        for step in 0..64 {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let roll = (seed >> 33) as i32;
+            let before = state.clone();
+
+            match roll % 4 {
+                0 => {
+                    let id = npcs[roll as usize % npcs.len()];
+                    let from = state.entities.position(id).unwrap();
+                    let to = Position::new(roll % 8, 4 + roll % 3);
+                    state.world.tile_map.remove_occupant(&from, id);
+                    state.world.tile_map.add_occupant(to, id);
+                    state.entities.actor_mut(id).unwrap().position = Some(to);
+                }
+                1 => {
+                    let id = npcs[roll as usize % npcs.len()];
+                    if !state.turn.active_actors.remove(&id) {
+                        state.turn.active_actors.insert(id);
+                    }
+                }
+                2 => {
+                    let hazard = HazardOverlay::new(roll as u32 % 5, step);
+                    state
+                        .world
+                        .tile_map
+                        .set_hazard(Position::new(roll % 8, 0), hazard);
+                }
+                _ => {}
+            }
+            state.turn.nonce += 1;
+            state.turn.clock += (roll % 3) as u64;
+
+            let action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+            cache.invalidate(&StateDelta::from_states(action, &before, &state));
+            let (root, next) = state.compute_state_root_incremental(&cache);
+            assert_eq!(root, state.compute_state_root(), "diverged at step {step}");
+            cache = next;
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn state_root_combines_section_hashes() {
         use sha2::{Digest, Sha256};
 
-        let serialized =
-            bincode::serialize(self).expect("GameState serialization should never fail");
+        let mut state = GameState::with_player();
+        state
+            .add_npc(&ActorTemplate::test_actor(), Position::new(3, 3))
+            .unwrap();
+        state
+            .world
+            .tile_map
+            .set_hazard(Position::new(1, 1), HazardOverlay::new(2, 5));
+
+        let hash = |bytes: Vec<u8>| -> [u8; 32] { Sha256::digest(&bytes).into() };
+        #[cfg(feature = "fog")]
+        let world = hash(bincode::serialize(&state.world).unwrap());
+        #[cfg(not(feature = "fog"))]
+        let world = hash(bincode::serialize(&state.world.tile_map).unwrap());
+
         let mut hasher = Sha256::new();
-        hasher.update(&serialized);
-        hasher.finalize().into()
+        hasher.update(state.game_seed.to_le_bytes());
+        hasher.update(state.next_entity_id.to_le_bytes());
+        hasher.update(hash(bincode::serialize(&state.turn).unwrap()));
+        hasher.update(hash(bincode::serialize(&state.entities).unwrap()));
+        hasher.update(world);
+
+        let expected: [u8; 32] = hasher.finalize().into();
+        assert_eq!(state.compute_state_root(), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn incremental_root_reuses_cached_sections() {
+        let mut state = GameState::with_player();
+        let (_, cache) = state.compute_state_root_incremental(&CachedRoots::default());
+
+        // Change the world without invalidating: the stale world hash is reused
+        state
+            .world
+            .tile_map
+            .set_hazard(Position::new(1, 1), HazardOverlay::new(2, 5));
+        let (stale, _) = state.compute_state_root_incremental(&cache);
+
+        assert_ne!(stale, state.compute_state_root());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn visibility_counts_towards_the_root_only_with_fog() {
+        let state = GameState::with_player();
+        let mut revealed = state.clone();
+        revealed.world.visibility.reveal([Position::new(1, 1)]);
+
+        let changed = state.compute_state_root() != revealed.compute_state_root();
+        assert_eq!(changed, cfg!(feature = "fog"));
     }
 }
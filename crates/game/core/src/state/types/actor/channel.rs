@@ -0,0 +1,41 @@
+//! Channeled (multi-turn) actions.
+//!
+//! A channel is an action the actor has started but that only completes at a
+//! later tick. Until then it is pending, and if it is interruptible, any
+//! damage the actor takes cancels it.
+
+use crate::action::{ActionKind, ActionProfile, ActionTag};
+use crate::state::Tick;
+
+/// An action being channeled by an actor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Channel {
+    /// The action that completes when the channel ends.
+    pub action: ActionKind,
+    /// Tick at which the channel completes.
+    pub completes_at: Tick,
+    /// Whether taking damage cancels the channel.
+    pub interruptible: bool,
+}
+
+impl Channel {
+    /// Creates a channel for `action` completing at `completes_at`.
+    pub fn new(action: ActionKind, completes_at: Tick, interruptible: bool) -> Self {
+        Self {
+            action,
+            completes_at,
+            interruptible,
+        }
+    }
+
+    /// Creates a channel for a profile; it is interruptible if the profile
+    /// carries [`ActionTag::Interruptible`].
+    pub fn for_profile(profile: &ActionProfile, completes_at: Tick) -> Self {
+        Self::new(
+            profile.kind,
+            completes_at,
+            profile.tags.contains(&ActionTag::Interruptible),
+        )
+    }
+}
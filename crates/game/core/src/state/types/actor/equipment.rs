@@ -26,6 +26,14 @@ pub struct Equipment {
 
     /// Currently equipped armor (provides defense and may restrict actions).
     pub armor: Option<ItemHandle>,
+
+    /// Attacks the equipped weapon can still land. `None` never breaks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weapon_durability: Option<u16>,
+
+    /// Hits the equipped armor can still absorb. `None` never breaks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub armor_durability: Option<u16>,
 }
 
 impl Equipment {
@@ -39,25 +47,74 @@ impl Equipment {
         EquipmentBuilder::default()
     }
 
-    /// Equips a weapon, returning the previously equipped weapon handle if any.
-    pub fn equip_weapon(&mut self, handle: ItemHandle) -> Option<ItemHandle> {
+    /// Equips a weapon with the given durability, returning the previously
+    /// equipped weapon handle if any.
+    pub fn equip_weapon(
+        &mut self,
+        handle: ItemHandle,
+        durability: Option<u16>,
+    ) -> Option<ItemHandle> {
+        self.weapon_durability = durability;
         self.weapon.replace(handle)
     }
 
     /// Unequips the current weapon, returning its handle if any was equipped.
     pub fn unequip_weapon(&mut self) -> Option<ItemHandle> {
+        self.weapon_durability = None;
         self.weapon.take()
     }
 
-    /// Equips armor, returning the previously equipped armor handle if any.
-    pub fn equip_armor(&mut self, handle: ItemHandle) -> Option<ItemHandle> {
+    /// Equips armor with the given durability, returning the previously
+    /// equipped armor handle if any.
+    pub fn equip_armor(
+        &mut self,
+        handle: ItemHandle,
+        durability: Option<u16>,
+    ) -> Option<ItemHandle> {
+        self.armor_durability = durability;
         self.armor.replace(handle)
     }
 
     /// Unequips the current armor, returning its handle if any was equipped.
     pub fn unequip_armor(&mut self) -> Option<ItemHandle> {
+        self.armor_durability = None;
         self.armor.take()
     }
+
+    /// Wears the weapon down by one landed attack.
+    ///
+    /// Returns the weapon's handle if this used up its durability; the
+    /// weapon is then unequipped. Indestructible weapons never break.
+    pub fn wear_weapon(&mut self) -> Option<ItemHandle> {
+        if wear(&mut self.weapon_durability) {
+            self.unequip_weapon()
+        } else {
+            None
+        }
+    }
+
+    /// Wears the armor down by one absorbed hit.
+    ///
+    /// Returns the armor's handle if this used up its durability; the armor
+    /// is then unequipped. Indestructible armor never breaks.
+    pub fn wear_armor(&mut self) -> Option<ItemHandle> {
+        if wear(&mut self.armor_durability) {
+            self.unequip_armor()
+        } else {
+            None
+        }
+    }
+}
+
+/// Decrements a durability counter and returns true once it reaches zero.
+fn wear(durability: &mut Option<u16>) -> bool {
+    match durability {
+        Some(remaining) => {
+            *remaining = remaining.saturating_sub(1);
+            *remaining == 0
+        }
+        None => false,
+    }
 }
 
 /// Builder for constructing equipment.
@@ -85,6 +142,7 @@ impl EquipmentBuilder {
         Equipment {
             weapon: self.weapon,
             armor: self.armor,
+            ..Equipment::default()
         }
     }
 }
@@ -5,7 +5,7 @@
 use arrayvec::ArrayVec;
 
 use crate::config::GameConfig;
-use crate::state::types::ItemHandle;
+use crate::state::types::{ItemHandle, Tick};
 
 /// Inventory slot containing an item and its quantity.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -13,11 +13,24 @@ use crate::state::types::ItemHandle;
 pub struct InventorySlot {
     pub handle: ItemHandle,
     pub quantity: u16,
+
+    /// Clock tick before which the slot's consumable cannot be used again.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cooldown_until: Tick,
 }
 
 impl InventorySlot {
     pub fn new(handle: ItemHandle, quantity: u16) -> Self {
-        Self { handle, quantity }
+        Self {
+            handle,
+            quantity,
+            cooldown_until: 0,
+        }
+    }
+
+    /// Returns true while the slot is cooling down at `clock`.
+    pub fn is_cooling_down(&self, clock: Tick) -> bool {
+        clock < self.cooldown_until
     }
 }
 
@@ -54,29 +67,43 @@ impl InventoryState {
         self.items.get_mut(slot)
     }
 
-    /// Add item to inventory. If same item exists, stack it. Otherwise create new slot.
+    /// Returns true if at least one more `handle` fits, either on an existing
+    /// stack below `max_stack` or in a free slot.
+    pub fn can_hold(&self, handle: ItemHandle, max_stack: u16) -> bool {
+        let max_stack = max_stack.max(1);
+        !self.items.is_full()
+            || self
+                .items
+                .iter()
+                .any(|slot| slot.handle == handle && slot.quantity < max_stack)
+    }
+
+    /// Add items to inventory, stacking at most `max_stack` per slot.
     ///
-    /// Returns error if inventory is full and item cannot be stacked.
-    pub fn add_item(&mut self, handle: ItemHandle, quantity: u16) -> Result<(), &'static str> {
-        // Try to find existing slot with same handle
-        for slot in &mut self.items {
-            if slot.handle == handle {
-                // Stack with existing item
-                slot.quantity = slot.quantity.saturating_add(quantity);
-                return Ok(());
-            }
+    /// Existing stacks of the same item are filled first, in slot order; the
+    /// rest opens new slots while any are free. A `max_stack` of 0 is treated
+    /// as 1.
+    ///
+    /// Returns the quantity that did not fit (0 if everything was added).
+    pub fn add_item(&mut self, handle: ItemHandle, quantity: u16, max_stack: u16) -> u16 {
+        let max_stack = max_stack.max(1);
+        let mut remaining = quantity;
+
+        // Top up existing stacks of the same item
+        for slot in self.items.iter_mut().filter(|slot| slot.handle == handle) {
+            let added = remaining.min(max_stack.saturating_sub(slot.quantity));
+            slot.quantity += added;
+            remaining -= added;
         }
 
-        // No existing slot, create new one
-        if self.items.is_full() {
-            return Err("Inventory is full");
+        // Spill the rest into new slots
+        while remaining > 0 && !self.items.is_full() {
+            let added = remaining.min(max_stack);
+            self.items.push(InventorySlot::new(handle, added));
+            remaining -= added;
         }
 
-        self.items
-            .try_push(InventorySlot::new(handle, quantity))
-            .map_err(|_| "Inventory is full")?;
-
-        Ok(())
+        remaining
     }
 
     /// Decrease quantity of item in slot. Remove slot if quantity reaches 0.
@@ -108,3 +135,56 @@ impl InventoryState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARROW: ItemHandle = ItemHandle(1);
+    const POTION: ItemHandle = ItemHandle(2);
+
+    fn quantities(inventory: &InventoryState) -> Vec<(ItemHandle, u16)> {
+        inventory
+            .items
+            .iter()
+            .map(|slot| (slot.handle, slot.quantity))
+            .collect()
+    }
+
+    #[test]
+    fn adding_to_a_partial_stack_merges() {
+        let mut inventory = InventoryState::empty();
+        assert_eq!(inventory.add_item(ARROW, 5, 20), 0);
+        assert_eq!(inventory.add_item(POTION, 1, 20), 0);
+        assert_eq!(inventory.add_item(ARROW, 10, 20), 0);
+
+        assert_eq!(quantities(&inventory), vec![(ARROW, 15), (POTION, 1)]);
+    }
+
+    #[test]
+    fn exceeding_max_stack_spills_into_a_new_slot() {
+        let mut inventory = InventoryState::empty();
+        inventory.add_item(ARROW, 15, 20);
+        assert_eq!(inventory.add_item(ARROW, 30, 20), 0);
+
+        assert_eq!(
+            quantities(&inventory),
+            vec![(ARROW, 20), (ARROW, 20), (ARROW, 5)]
+        );
+    }
+
+    #[test]
+    fn full_inventory_returns_the_leftover_quantity() {
+        let mut inventory = InventoryState::empty();
+        for handle in 0..GameConfig::MAX_INVENTORY_SLOTS as u32 - 1 {
+            inventory.add_item(ItemHandle(100 + handle), 1, 1);
+        }
+        assert!(inventory.can_hold(ARROW, 20));
+
+        // One free slot takes a full stack; the rest is handed back
+        assert_eq!(inventory.add_item(ARROW, 25, 20), 5);
+        assert!(inventory.is_full());
+        assert!(!inventory.can_hold(ARROW, 20));
+        assert_eq!(inventory.add_item(ARROW, 3, 20), 3);
+    }
+}
@@ -6,8 +6,10 @@
 //! - Equipment: Weapon and armor system
 //! - Inventory: Item storage for actors
 //! - Status: Status effects and conditions
+//! - Channel: Multi-turn actions in progress
 
 pub mod abilities;
+pub mod channel;
 pub mod equipment;
 pub mod inventory;
 pub mod status;
@@ -17,6 +19,7 @@ use arrayvec::ArrayVec;
 pub use abilities::{
     ActionAbilities, ActionAbility, PassiveAbilities, PassiveAbility, PassiveKind,
 };
+pub use channel::Channel;
 pub use equipment::{Equipment, EquipmentBuilder};
 pub use inventory::{InventorySlot, InventoryState};
 pub use status::{StatusEffect, StatusEffectKind, StatusEffects};
@@ -24,6 +27,7 @@ pub use status::{StatusEffect, StatusEffectKind, StatusEffects};
 use super::{EntityId, Position, Tick};
 use crate::action::ActionKind;
 use crate::config::GameConfig;
+use crate::env::{AttackType, ItemKind, ItemOracle, WeaponData};
 use crate::provider::ProviderKind;
 use crate::stats::{ActorBonuses, CoreStats, ResourceCurrent, StatsSnapshot};
 use crate::traits::{Faction, Species, TraitProfile};
@@ -92,9 +96,26 @@ pub struct ActorState {
     /// Faction - relationship/allegiance (mutable).
     pub faction: Faction,
 
+    // === Objective ===
+    /// Prop this NPC is tasked with operating (e.g. an alarm lever).
+    ///
+    /// Assigned by the scenario. The objective counts as complete once the
+    /// prop is active.
+    pub objective: Option<EntityId>,
+
     // === Scheduling ===
     /// When this actor is scheduled to act next. None means not currently scheduled.
     pub ready_at: Option<Tick>,
+
+    /// Channeled action in progress, if any.
+    pub channel: Option<Channel>,
+
+    /// Clock tick at which the actor ran out of HP. None while alive.
+    ///
+    /// Maintained by the engine after every action; see
+    /// [`GameState::record_deaths`](crate::state::GameState::record_deaths).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub died_at: Option<Tick>,
 }
 
 impl ActorState {
@@ -114,6 +135,14 @@ impl ActorState {
         self.resources.hp > 0
     }
 
+    /// Checks if the actor is downed: out of HP but still on the map.
+    ///
+    /// Downed actors take no turns and can be revived by an ally.
+    #[inline]
+    pub fn is_downed(&self) -> bool {
+        self.resources.hp == 0 && self.position.is_some()
+    }
+
     pub fn with_ready_at(mut self, ready_at: Tick) -> Self {
         self.ready_at = Some(ready_at);
         self
@@ -151,6 +180,43 @@ impl ActorState {
         }
     }
 
+    // ========================================================================
+    // Equipment Helpers
+    // ========================================================================
+
+    /// Chebyshev distance this actor's melee attacks can cover.
+    ///
+    /// Taken from the equipped weapon when it is a melee weapon. Unarmed, or
+    /// holding a ranged or magic weapon, the actor has the default reach.
+    pub fn melee_reach(&self, items: &dyn ItemOracle) -> u8 {
+        self.equipment
+            .weapon
+            .and_then(|handle| items.definition(handle))
+            .and_then(|definition| match definition.kind {
+                ItemKind::Weapon(weapon) if weapon.kind.attack_type() == AttackType::Melee => {
+                    Some(weapon.reach.max(1))
+                }
+                _ => None,
+            })
+            .unwrap_or(WeaponData::DEFAULT_REACH)
+    }
+
+    // ========================================================================
+    // Channel Helpers
+    // ========================================================================
+
+    /// Interrupt hook, run whenever the actor takes damage.
+    ///
+    /// Cancels an interruptible channel and returns it; a non-interruptible
+    /// channel keeps going.
+    pub fn interrupt_channel(&mut self) -> Option<Channel> {
+        if self.channel.is_some_and(|channel| channel.interruptible) {
+            self.channel.take()
+        } else {
+            None
+        }
+    }
+
     // ========================================================================
     // Passive Ability Helpers
     // ========================================================================
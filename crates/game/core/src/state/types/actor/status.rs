@@ -12,7 +12,7 @@
 use arrayvec::ArrayVec;
 
 use crate::config::GameConfig;
-use crate::state::Tick;
+use crate::state::{EntityId, Tick};
 
 /// Active status effects on an actor.
 ///
@@ -20,7 +20,7 @@ use crate::state::Tick;
 /// - Crowd control (Stunned, Rooted, Silenced)
 /// - Buffs (Hasted, Shielded, Invisible)
 /// - Debuffs (Poisoned, Weakened, Burning)
-/// - Special states (Berserk, Frightened)
+/// - Special states (Berserk, Frightened, Lifespan, Aggro)
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusEffects {
@@ -34,6 +34,9 @@ pub struct StatusEffect {
     pub kind: StatusEffectKind,
     /// Tick at which this effect expires.
     pub expires_at: Tick,
+    /// Strength of the effect (e.g. damage per owner turn for `Burning`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub magnitude: u32,
 }
 
 /// Types of status effects.
@@ -93,6 +96,17 @@ pub enum StatusEffectKind {
 
     /// Must flee from enemies.
     Frightened,
+
+    /// Temporary entity (e.g. a summoned minion), despawned at the first turn
+    /// start once it expires.
+    Lifespan,
+
+    /// Provoked by an attack: treats `toward` as hostile until the effect
+    /// expires, whatever the factions' relation.
+    ///
+    /// An actor holds at most one, aimed at whoever provoked it last (see
+    /// [`StatusEffects::set_aggro`]).
+    Aggro { toward: EntityId },
 }
 
 impl StatusEffects {
@@ -110,6 +124,18 @@ impl StatusEffects {
             .any(|e| e.kind == kind && e.expires_at > current_tick)
     }
 
+    /// Checks if a specific status effect was applied and has run out by the given tick.
+    pub fn has_expired(&self, kind: StatusEffectKind, current_tick: Tick) -> bool {
+        self.effects
+            .iter()
+            .any(|e| e.kind == kind && e.expires_at <= current_tick)
+    }
+
+    /// Checks if the actor has been provoked into fighting `entity` at the given tick.
+    pub fn is_aggro_toward(&self, entity: EntityId, current_tick: Tick) -> bool {
+        self.has(StatusEffectKind::Aggro { toward: entity }, current_tick)
+    }
+
     /// Gets the expiration tick of a status effect.
     ///
     /// Returns None if the effect is not active.
@@ -129,19 +155,64 @@ impl StatusEffects {
     /// * `kind` - The type of status effect
     /// * `expires_at` - Tick at which the effect expires
     pub fn add(&mut self, kind: StatusEffectKind, expires_at: Tick) {
+        self.add_with_magnitude(kind, expires_at, 0);
+    }
+
+    /// Adds a status effect with expiration time and magnitude.
+    ///
+    /// If the effect already exists, keeps the later expiration time and the
+    /// stronger magnitude.
+    pub fn add_with_magnitude(&mut self, kind: StatusEffectKind, expires_at: Tick, magnitude: u32) {
         // Check if already present
         if let Some(existing) = self.effects.iter_mut().find(|e| e.kind == kind) {
             // Extend to later expiration
             existing.expires_at = existing.expires_at.max(expires_at);
+            existing.magnitude = existing.magnitude.max(magnitude);
             return;
         }
 
         // Add new effect if space available
         if !self.effects.is_full() {
-            self.effects.push(StatusEffect { kind, expires_at });
+            self.effects.push(StatusEffect {
+                kind,
+                expires_at,
+                magnitude,
+            });
         }
     }
 
+    /// Points the actor's aggro at `toward` until `expires_at`.
+    ///
+    /// An existing `Aggro` entry is retargeted and refreshed in place, so an
+    /// actor hit by several attackers still takes a single slot. Otherwise a
+    /// new entry is added, pruning effects that have run out by
+    /// `current_tick` if no slot is free.
+    ///
+    /// Returns false if every slot is held by an effect that is still running.
+    pub fn set_aggro(&mut self, toward: EntityId, expires_at: Tick, current_tick: Tick) -> bool {
+        let kind = StatusEffectKind::Aggro { toward };
+        if let Some(existing) = self
+            .effects
+            .iter_mut()
+            .find(|e| matches!(e.kind, StatusEffectKind::Aggro { .. }))
+        {
+            existing.kind = kind;
+            existing.expires_at = expires_at;
+            return true;
+        }
+
+        if self.effects.is_full() {
+            self.prune_expired(current_tick);
+        }
+        self.effects
+            .try_push(StatusEffect {
+                kind,
+                expires_at,
+                magnitude: 0,
+            })
+            .is_ok()
+    }
+
     /// Removes a status effect immediately.
     pub fn remove(&mut self, kind: StatusEffectKind) {
         self.effects.retain(|e| e.kind != kind);
@@ -154,6 +225,17 @@ impl StatusEffects {
         self.effects.retain(|e| e.expires_at > current_tick);
     }
 
+    /// Removes expired effects at the owner's turn start, returning whether any were removed.
+    ///
+    /// Unlike [`remove_expired`](Self::remove_expired), an expired `Lifespan`
+    /// is kept: it marks the entity for despawn rather than modifying it.
+    pub fn prune_expired(&mut self, current_tick: Tick) -> bool {
+        let before = self.effects.len();
+        self.effects
+            .retain(|e| e.expires_at > current_tick || e.kind == StatusEffectKind::Lifespan);
+        self.effects.len() != before
+    }
+
     /// Returns an iterator over all active effects at the given tick.
     pub fn active_at(&self, current_tick: Tick) -> impl Iterator<Item = &StatusEffect> + '_ {
         self.effects
@@ -189,6 +189,13 @@ impl PropState {
             is_active,
         }
     }
+
+    /// Returns true if the prop stops actors from entering its tile.
+    ///
+    /// Only doors block, and only while closed (`is_active` is false).
+    pub fn is_blocking(&self) -> bool {
+        self.kind == PropKind::Door && !self.is_active
+    }
 }
 
 /// Enumerates the basic prop categories. Extend as needed by gameplay.
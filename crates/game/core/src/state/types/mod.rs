@@ -12,6 +12,8 @@ pub use actor::{
     ActionAbility,
     // Main actor state
     ActorState,
+    // Channeled actions
+    Channel,
     // Equipment
     Equipment,
     EquipmentBuilder,
@@ -40,4 +42,4 @@ pub use item::{ItemHandle, ItemState};
 pub use turn::TurnState;
 
 // Re-export world types
-pub use world::{TileMap, TileView, WorldState};
+pub use world::{HazardOverlay, TileMap, TileView, Visibility, VisibilitySet, WorldState};
@@ -5,7 +5,7 @@ use arrayvec::ArrayVec;
 use crate::config::GameConfig;
 use crate::env::{MapOracle, StaticTile};
 
-use super::{EntityId, Position};
+use super::{EntityId, Position, Tick};
 
 type OccupantSlots = ArrayVec<EntityId, { GameConfig::MAX_OCCUPANTS_PER_TILE }>;
 
@@ -14,14 +14,23 @@ type OccupantSlots = ArrayVec<EntityId, { GameConfig::MAX_OCCUPANTS_PER_TILE }>;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldState {
     pub tile_map: TileMap,
+    /// What the player sees and has seen, refreshed whenever the player moves.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub visibility: VisibilitySet,
 }
 
 impl WorldState {
     pub fn new(tile_map: TileMap) -> Self {
-        Self { tile_map }
+        Self {
+            tile_map,
+            visibility: VisibilitySet::default(),
+        }
     }
 
     /// Produces a merged view combining static tile data with dynamic occupants.
+    ///
+    /// Props are not known here, so the view is never blocked; use
+    /// `GameState::tile_view` to account for closed doors.
     pub fn tile_view<M>(&self, map: &M, position: Position) -> Option<TileView>
     where
         M: MapOracle + ?Sized,
@@ -37,6 +46,7 @@ impl WorldState {
             position,
             static_tile,
             occupants,
+            blocked: false,
         })
     }
 }
@@ -46,11 +56,16 @@ impl WorldState {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TileMap {
     occupancy: BTreeMap<Position, OccupantSlots>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    hazards: BTreeMap<Position, HazardOverlay>,
 }
 
 impl TileMap {
     pub fn new(occupancy: BTreeMap<Position, OccupantSlots>) -> Self {
-        Self { occupancy }
+        Self {
+            occupancy,
+            hazards: BTreeMap::new(),
+        }
     }
 
     pub fn occupancy(&self) -> &BTreeMap<Position, OccupantSlots> {
@@ -98,6 +113,109 @@ impl TileMap {
     pub fn clear_occupants(&mut self, position: &Position) {
         self.occupancy.remove(position);
     }
+
+    pub fn hazards(&self) -> &BTreeMap<Position, HazardOverlay> {
+        &self.hazards
+    }
+
+    /// Returns the hazard on a tile, including one that expired but has not
+    /// decayed yet.
+    pub fn hazard(&self, position: &Position) -> Option<&HazardOverlay> {
+        self.hazards.get(position)
+    }
+
+    /// Places a hazard on a tile, replacing any hazard already there.
+    pub fn set_hazard(&mut self, position: Position, hazard: HazardOverlay) {
+        self.hazards.insert(position, hazard);
+    }
+
+    /// Removes the hazard on a tile, if any.
+    pub fn clear_hazard(&mut self, position: &Position) {
+        self.hazards.remove(position);
+    }
+
+    /// Removes every hazard whose `expires_at` is at or before `tick`.
+    pub fn decay_hazards(&mut self, tick: Tick) {
+        self.hazards.retain(|_, hazard| hazard.is_active(tick));
+    }
+}
+
+/// Temporary damaging overlay on a tile (caltrops, fire wall, ...).
+///
+/// Actors take `damage` when they enter the tile or start their turn on it,
+/// until the hazard decays at the first turn start at or after `expires_at`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HazardOverlay {
+    pub damage: u32,
+    pub expires_at: Tick,
+}
+
+impl HazardOverlay {
+    pub fn new(damage: u32, expires_at: Tick) -> Self {
+        Self { damage, expires_at }
+    }
+
+    /// Returns true while the hazard still deals damage at `tick`.
+    pub fn is_active(&self, tick: Tick) -> bool {
+        tick < self.expires_at
+    }
+}
+
+/// How much the player knows about a tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Visibility {
+    /// Never seen.
+    Unseen,
+    /// Seen before but not in view now.
+    Seen,
+    /// In view now.
+    Visible,
+}
+
+/// Per-tile fog-of-war state for the player.
+///
+/// Tiles missing from the map are [`Visibility::Unseen`]. Once seen, a tile
+/// never returns to unseen; it only moves between `Visible` and `Seen`.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VisibilitySet {
+    tiles: BTreeMap<Position, Visibility>,
+}
+
+impl VisibilitySet {
+    /// Chebyshev radius the player can see within.
+    pub const SIGHT_RADIUS: u32 = 8;
+
+    pub fn get(&self, position: &Position) -> Visibility {
+        self.tiles
+            .get(position)
+            .copied()
+            .unwrap_or(Visibility::Unseen)
+    }
+
+    pub fn is_visible(&self, position: &Position) -> bool {
+        self.get(position) == Visibility::Visible
+    }
+
+    /// Returns the tiles currently in view, in position order.
+    pub fn visible(&self) -> impl Iterator<Item = Position> + '_ {
+        self.tiles
+            .iter()
+            .filter(|(_, visibility)| **visibility == Visibility::Visible)
+            .map(|(position, _)| *position)
+    }
+
+    /// Replaces the tiles in view; those that drop out of view become seen.
+    pub fn reveal(&mut self, visible: impl IntoIterator<Item = Position>) {
+        for visibility in self.tiles.values_mut() {
+            *visibility = Visibility::Seen;
+        }
+        for position in visible {
+            self.tiles.insert(position, Visibility::Visible);
+        }
+    }
 }
 
 /// Aggregated tile information used by reducers and commands.
@@ -105,9 +223,14 @@ pub struct TileView {
     position: Position,
     static_tile: StaticTile,
     occupants: OccupantSlots,
+    blocked: bool,
 }
 
 impl TileView {
+    pub(crate) fn with_blocked(self, blocked: bool) -> Self {
+        Self { blocked, ..self }
+    }
+
     pub fn position(&self) -> Position {
         self.position
     }
@@ -128,8 +251,14 @@ impl TileView {
         !self.occupants.is_empty()
     }
 
+    /// Returns true if the terrain is passable and no prop blocks the tile.
     pub fn is_passable(&self) -> bool {
-        self.static_tile.is_passable()
+        self.static_tile.is_passable() && !self.blocked
+    }
+
+    /// Returns true if a prop such as a closed door blocks the tile.
+    pub fn is_blocked(&self) -> bool {
+        self.blocked
     }
 
     pub fn terrain(&self) -> crate::env::TerrainKind {
@@ -48,4 +48,7 @@ pub use derived::{DerivedBonuses, DerivedStats};
 pub use modifiers::{ModifierBonuses, StatModifiers};
 pub use resources::{ResourceBonuses, ResourceCurrent, ResourceKind, ResourceMaximums};
 pub use snapshot::{StatsSnapshot, StatsSnapshotBuilder};
-pub use speed::{SpeedBonuses, SpeedKind, SpeedStats, calculate_action_cost};
+pub use speed::{
+    SpeedBonuses, SpeedKind, SpeedStats, apply_movement_multiplier, calculate_action_cost,
+    initiative_delay,
+};
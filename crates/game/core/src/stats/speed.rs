@@ -139,3 +139,24 @@ pub fn calculate_action_cost(base_cost: u64, speed: i32) -> u64 {
     let clamped_speed = speed.clamp(MIN_SPEED, MAX_SPEED).max(1) as u64;
     (base_cost * 100) / clamped_speed
 }
+
+/// Scale an action cost by a terrain movement multiplier.
+///
+/// Formula: scaled_cost = ceil(cost × multiplier / 100)
+///
+/// The multiplier is fixed-point with 100 = 1x. Rounding is always up, so
+/// difficult terrain never costs less than the unscaled action.
+pub fn apply_movement_multiplier(cost: u64, multiplier: u16) -> u64 {
+    cost.saturating_mul(u64::from(multiplier)).div_ceil(100)
+}
+
+/// Calculate the delay before a newly activated actor's first turn.
+///
+/// Formula: delay = min(window × 100 / clamp(speed, 1, 10000), window × 2)
+///
+/// Faster actors get a shorter delay, so among actors activated on the same
+/// tick the fastest acts first. The delay never exceeds twice the window,
+/// and a window of 0 disables it.
+pub fn initiative_delay(window: u64, speed: i32) -> u64 {
+    calculate_action_cost(window, speed).min(window.saturating_mul(2))
+}
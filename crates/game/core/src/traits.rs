@@ -142,6 +142,107 @@ impl Faction {
     }
 }
 
+/// Stance one faction takes towards another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Relation {
+    /// Fights alongside: counted as an ally, healed and revived.
+    Allied,
+    /// Left alone: neither targeted nor supported.
+    Neutral,
+    /// Fought on sight.
+    Hostile,
+}
+
+/// A single directed entry of a [`FactionRelations`] table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FactionRelation {
+    /// Faction holding the stance.
+    pub from: Faction,
+    /// Faction the stance is held towards.
+    pub to: Faction,
+    /// The stance itself.
+    pub relation: Relation,
+}
+
+/// Data-driven table of how factions regard each other.
+///
+/// Entries are directed, so relations may be asymmetric (wildlife can be
+/// hostile to goblins that ignore it). Pairs without an entry fall back to
+/// [`FactionRelations::default_relation`], which reproduces the built-in
+/// [`Faction::is_hostile_to`] rules.
+///
+/// The table drives AI decisions (whom to target, whom to support) and
+/// whether damage provokes aggro; the engine's other rules still use
+/// [`Faction::is_hostile_to`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct FactionRelations {
+    entries: Vec<FactionRelation>,
+}
+
+impl FactionRelations {
+    /// Creates a table without entries (built-in rules only).
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the stance `from` takes towards `to`, replacing any previous entry.
+    pub fn set(&mut self, from: Faction, to: Faction, relation: Relation) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.from == from && entry.to == to)
+        {
+            Some(entry) => entry.relation = relation,
+            None => self.entries.push(FactionRelation { from, to, relation }),
+        }
+    }
+
+    /// Sets the same stance in both directions.
+    pub fn set_mutual(&mut self, a: Faction, b: Faction, relation: Relation) {
+        self.set(a, b, relation);
+        self.set(b, a, relation);
+    }
+
+    /// Returns the stance `from` takes towards `to`.
+    pub fn relation(&self, from: Faction, to: Faction) -> Relation {
+        self.entries
+            .iter()
+            .find(|entry| entry.from == from && entry.to == to)
+            .map_or_else(|| Self::default_relation(from, to), |entry| entry.relation)
+    }
+
+    /// Returns the stance used for pairs without an entry.
+    ///
+    /// A faction is allied with itself and the player with friendly
+    /// factions; pairs [hostile](Faction::is_hostile_to) by the built-in
+    /// rules are hostile, and everything else is neutral.
+    pub fn default_relation(from: Faction, to: Faction) -> Relation {
+        if from.is_hostile_to(&to) {
+            Relation::Hostile
+        } else if from == to
+            || matches!(
+                (from, to),
+                (Faction::Player, Faction::Friendly) | (Faction::Friendly, Faction::Player)
+            )
+        {
+            Relation::Allied
+        } else {
+            Relation::Neutral
+        }
+    }
+
+    /// Returns the explicit entries of the table.
+    pub fn entries(&self) -> &[FactionRelation] {
+        &self.entries
+    }
+}
+
 /// The 20 core behavioral traits.
 ///
 /// Each trait ranges from 0 (minimum) to 240 (maximum) in the final composed profile.
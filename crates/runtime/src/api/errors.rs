@@ -42,6 +42,45 @@ pub enum RuntimeError {
     #[error("invalid entity ID: {0:?}")]
     InvalidEntityId(game_core::EntityId),
 
+    #[error("entity ID space exhausted while spawning {context} (next id: {current})")]
+    EntityIdsExhausted { context: String, current: u32 },
+
+    #[error("session halted after fatal error [{error_code}]: {reason}")]
+    SessionHalted {
+        error_code: &'static str,
+        reason: String,
+    },
+
+    #[error("action rejected [{error_code}]: {reason}")]
+    ActionRejected {
+        error_code: &'static str,
+        reason: String,
+    },
+
+    #[error("action {action_nonce} of entity {actor:?} was already executed")]
+    DuplicateAction {
+        actor: game_core::EntityId,
+        action_nonce: u64,
+    },
+
+    #[error("no executed action left to undo")]
+    NothingToUndo,
+
+    #[error("executed actions are already in the action log and cannot be undone")]
+    UndoPersisted,
+
+    #[error("no saved state at or before nonce {nonce}")]
+    NonceTooOld { nonce: u64 },
+
+    #[error("replay script exhausted before the turn of entity {actor:?}")]
+    ReplayExhausted { actor: game_core::EntityId },
+
+    #[error("replay diverged: entity {actor:?} is acting but the script expects {expected:?}")]
+    ReplayDiverged {
+        actor: game_core::EntityId,
+        expected: game_core::EntityId,
+    },
+
     #[error("missing action provider: {0}")]
     MissingProvider(ProviderKind),
 
@@ -57,3 +96,49 @@ pub enum RuntimeError {
     #[error("blockchain integration is not enabled")]
     BlockchainNotEnabled,
 }
+
+impl RuntimeError {
+    /// Converts a state error raised while spawning an entity.
+    ///
+    /// ID exhaustion maps to [`RuntimeError::EntityIdsExhausted`] so callers can
+    /// tell it apart from ordinary capacity or placement failures.
+    pub fn from_spawn_error(context: impl Into<String>, error: game_core::StateError) -> Self {
+        let context = context.into();
+        match error {
+            game_core::StateError::EntityIdOverflow { current } => {
+                Self::EntityIdsExhausted { context, current }
+            }
+            other => Self::InvalidConfig(format!("Failed to spawn {}: {}", context, other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_core::StateError;
+
+    #[test]
+    fn id_overflow_maps_to_exhausted() {
+        let error = RuntimeError::from_spawn_error(
+            "NPC 'goblin'",
+            StateError::EntityIdOverflow { current: u32::MAX },
+        );
+
+        assert!(matches!(
+            error,
+            RuntimeError::EntityIdsExhausted { current: u32::MAX, .. }
+        ));
+        assert!(error.to_string().contains("NPC 'goblin'"));
+    }
+
+    #[test]
+    fn other_spawn_errors_stay_config_errors() {
+        let error = RuntimeError::from_spawn_error(
+            "prop",
+            StateError::PropListFull { max: 1, current: 1 },
+        );
+
+        assert!(matches!(error, RuntimeError::InvalidConfig(_)));
+    }
+}
@@ -6,12 +6,17 @@ use std::sync::{Arc, RwLock};
 
 use tokio::sync::{broadcast, mpsc, oneshot};
 
-use game_core::{Action, EntityId, GameState};
+use game_core::{
+    Action, EntityId, GameState, PrepareTurnAction, StateDelta, SystemActionKind, Tick,
+};
 
 use super::errors::{Result, RuntimeError};
+use super::metrics::ProofMetricsSnapshot;
 use super::{ActionProvider, ProviderKind, ProviderRegistry};
-use crate::events::{Event, EventBus, Topic};
-use crate::repository::ActionBatch;
+use crate::events::{Event, EventBus, FilteredReceiver, Topic};
+use crate::oracle::OracleBundle;
+use crate::proving::{self, BatchInputs};
+use crate::repository::{ActionBatch, FileActionLogReader};
 use crate::workers::persistence::Command as PersistenceCommand;
 use crate::workers::simulation::Command as SimulationCommand;
 
@@ -31,6 +36,8 @@ pub struct RuntimeHandle {
     persistence_tx: Option<mpsc::Sender<PersistenceCommand>>,
     event_bus: EventBus,
     providers: Arc<RwLock<ProviderRegistry>>,
+    proof_metrics: Arc<RwLock<ProofMetricsSnapshot>>,
+    oracles: OracleBundle,
     session_id: String,
     #[allow(dead_code)] // Used in multiple methods but clippy misdetects it
     base_dir: std::path::PathBuf,
@@ -38,23 +45,42 @@ pub struct RuntimeHandle {
     blockchain_clients: Option<Arc<crate::blockchain::BlockchainClients>>,
 }
 
+/// Shared channels and handles a [`RuntimeHandle`] is assembled from.
+pub(crate) struct HandleParts {
+    pub simulation_tx: mpsc::Sender<SimulationCommand>,
+    pub persistence_tx: Option<mpsc::Sender<PersistenceCommand>>,
+    pub event_bus: EventBus,
+    pub providers: Arc<RwLock<ProviderRegistry>>,
+    pub proof_metrics: Arc<RwLock<ProofMetricsSnapshot>>,
+    pub oracles: OracleBundle,
+    pub session_id: String,
+    pub base_dir: std::path::PathBuf,
+    #[cfg(feature = "sui")]
+    pub blockchain_clients: Option<Arc<crate::blockchain::BlockchainClients>>,
+}
+
 impl RuntimeHandle {
-    pub(crate) fn new(
-        simulation_tx: mpsc::Sender<SimulationCommand>,
-        persistence_tx: Option<mpsc::Sender<PersistenceCommand>>,
-        event_bus: EventBus,
-        providers: Arc<RwLock<ProviderRegistry>>,
-        session_id: String,
-        base_dir: std::path::PathBuf,
-        #[cfg(feature = "sui")] blockchain_clients: Option<
-            Arc<crate::blockchain::BlockchainClients>,
-        >,
-    ) -> Self {
+    pub(crate) fn new(parts: HandleParts) -> Self {
+        let HandleParts {
+            simulation_tx,
+            persistence_tx,
+            event_bus,
+            providers,
+            proof_metrics,
+            oracles,
+            session_id,
+            base_dir,
+            #[cfg(feature = "sui")]
+            blockchain_clients,
+        } = parts;
+
         Self {
             simulation_tx,
             persistence_tx,
             event_bus,
             providers,
+            proof_metrics,
+            oracles,
             session_id,
             base_dir,
             #[cfg(feature = "sui")]
@@ -81,6 +107,27 @@ impl RuntimeHandle {
         self.simulation_tx
             .send(SimulationCommand::ExecuteAction {
                 action,
+                action_nonce: None,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)?
+    }
+
+    /// Execute an action at most once per client-supplied `action_nonce`
+    ///
+    /// Safe to retry after a timeout: re-submitting the nonce of the actor's
+    /// last accepted action returns [`RuntimeError::DuplicateAction`] and
+    /// leaves the state untouched.
+    pub async fn execute_action_once(&self, action: Action, action_nonce: u64) -> Result<()> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        self.simulation_tx
+            .send(SimulationCommand::ExecuteAction {
+                action,
+                action_nonce: Some(action_nonce),
                 reply: reply_tx,
             })
             .await
@@ -99,6 +146,18 @@ impl RuntimeHandle {
         self.event_bus.subscribe(topic)
     }
 
+    /// Subscribe to the events of a topic that match `predicate`
+    ///
+    /// Non-matching events are dropped as they are received. Use
+    /// [`EntityFilter`](crate::events::EntityFilter) to follow a single entity.
+    pub fn subscribe_filtered(
+        &self,
+        topic: Topic,
+        predicate: impl Fn(&Event) -> bool + Send + 'static,
+    ) -> FilteredReceiver {
+        FilteredReceiver::new(self.event_bus.subscribe(topic), predicate)
+    }
+
     /// Subscribe to multiple topics at once
     ///
     /// Returns a map of topic to receiver for each requested topic.
@@ -109,6 +168,17 @@ impl RuntimeHandle {
         self.event_bus.subscribe_multiple(topics)
     }
 
+    /// Proving statistics aggregated from the proof events published so far
+    ///
+    /// Returns a copy, so the numbers are consistent with each other even
+    /// while proofs keep completing. All zero when proving is disabled.
+    pub fn proof_metrics(&self) -> ProofMetricsSnapshot {
+        match self.proof_metrics.read() {
+            Ok(metrics) => metrics.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
     /// Query the current game state (read-only snapshot)
     pub async fn query_state(&self) -> Result<GameState> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -121,6 +191,49 @@ impl RuntimeHandle {
         reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)
     }
 
+    /// Project the next `depth` turns as `(entity, tick)` pairs
+    ///
+    /// The simulation worker repeats turn selection on a copy of the state,
+    /// assuming every actor spends its turn waiting; the real state and clock
+    /// are not advanced. Fewer than `depth` entries are returned once no actor
+    /// is left to act.
+    pub async fn turn_order_preview(&self, depth: usize) -> Result<Vec<(EntityId, Tick)>> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        self.simulation_tx
+            .send(SimulationCommand::PreviewTurnOrder {
+                depth,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)?
+    }
+
+    /// Undo the most recently executed action
+    ///
+    /// Restores the state from right before that action, clock and nonce
+    /// included, and publishes `GameStateEvent::RolledBack`. Only the last
+    /// few actions can be undone (see [`RuntimeBuilder::undo_depth`]); beyond
+    /// that this returns [`RuntimeError::NothingToUndo`].
+    ///
+    /// Undo is meant for debugging and interactive tooling. Actions already
+    /// persisted stay in the action log, so while persistence is enabled this
+    /// returns [`RuntimeError::UndoPersisted`].
+    ///
+    /// [`RuntimeBuilder::undo_depth`]: crate::RuntimeBuilder::undo_depth
+    pub async fn undo(&self) -> Result<()> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        self.simulation_tx
+            .send(SimulationCommand::UndoLastAction { reply: reply_tx })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)?
+    }
+
     // Persistence and checkpoint methods
 
     /// Create a manual checkpoint (save point).
@@ -244,6 +357,52 @@ impl RuntimeHandle {
             .map_err(|e| RuntimeError::PersistenceError(e.to_string()))
     }
 
+    /// Compute the cumulative changes from `nonce` to the current state.
+    ///
+    /// The delta is taken against the latest saved state at or before
+    /// `nonce`, so it may also cover changes made shortly before `nonce`.
+    /// A nonce ahead of the current state is treated as the current nonce.
+    /// Applying the delta to that saved state with
+    /// [`GameState::apply_delta`] reproduces the current state.
+    ///
+    /// The delta spans many actions; its `action` is a `PrepareTurn`
+    /// placeholder and carries no meaning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Persistence is not enabled
+    /// - No state was saved at or before `nonce` ([`RuntimeError::NonceTooOld`])
+    /// - Failed to read state from disk
+    pub async fn delta_since(&self, nonce: u64) -> Result<StateDelta> {
+        let persistence_tx = self
+            .persistence_tx
+            .as_ref()
+            .ok_or(RuntimeError::PersistenceNotEnabled)?;
+
+        let current = self.query_state().await?;
+        let nonce = nonce.min(current.nonce());
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        persistence_tx
+            .send(PersistenceCommand::LoadCheckpointBefore {
+                nonce,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        let (_, base) = reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(|e| RuntimeError::PersistenceError(e.to_string()))?
+            .ok_or(RuntimeError::NonceTooOld { nonce })?;
+
+        let placeholder = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        Ok(StateDelta::from_states(placeholder, &base, &current))
+    }
+
     /// Restore game state from a checkpoint (fully load and replace current state).
     ///
     /// This combines loading state from disk and restoring it in the simulation worker.
@@ -363,6 +522,79 @@ impl RuntimeHandle {
             .map_err(|e| RuntimeError::PersistenceError(e.to_string()))
     }
 
+    /// Export the inputs needed to reproduce a checkpointed batch.
+    ///
+    /// Bundles the batch's start state, the oracle snapshot, the seed
+    /// commitment and the logged actions so a third party can
+    /// [replay](BatchInputs::replay) the transition and check it against the
+    /// proof's public values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Persistence is not enabled
+    /// - No completed batch spans exactly `start_nonce..=end_nonce`
+    /// - The start state or action log is missing or incomplete
+    pub async fn export_batch_inputs(
+        &self,
+        start_nonce: u64,
+        end_nonce: u64,
+    ) -> Result<BatchInputs> {
+        use crate::repository::ActionBatchStatus;
+
+        let batch = self.get_checkpoint(start_nonce).await?.ok_or_else(|| {
+            RuntimeError::PersistenceError(format!("Batch not found at nonce {}", start_nonce))
+        })?;
+        if batch.end_nonce != end_nonce || batch.status == ActionBatchStatus::InProgress {
+            return Err(RuntimeError::PersistenceError(format!(
+                "No completed batch spans nonces {}..={} (batch ends at {}, status: {:?})",
+                start_nonce, end_nonce, batch.end_nonce, batch.status
+            )));
+        }
+
+        // Genesis for batch 0, otherwise the previous batch's end state
+        let start_state_nonce = start_nonce.saturating_sub(1);
+        let start_state = self.load_state(start_state_nonce).await?.ok_or_else(|| {
+            RuntimeError::PersistenceError(format!("No state found at nonce {}", start_state_nonce))
+        })?;
+
+        let action_log_path = self
+            .base_dir
+            .join(&self.session_id)
+            .join("actions")
+            .join(batch.action_log_filename());
+        let actions: Vec<_> = FileActionLogReader::new(&action_log_path, self.session_id.clone())
+            .and_then(|mut reader| reader.read_all())
+            .map_err(|e| {
+                RuntimeError::PersistenceError(format!(
+                    "Failed to read action log {}: {}",
+                    action_log_path.display(),
+                    e
+                ))
+            })?
+            .into_iter()
+            .filter(|entry| batch.contains_nonce(entry.nonce))
+            .map(|entry| entry.action)
+            .collect();
+        if actions.len() as u64 != batch.action_count() {
+            return Err(RuntimeError::PersistenceError(format!(
+                "Action log for batch {} has {} actions, expected {}",
+                start_nonce,
+                actions.len(),
+                batch.action_count()
+            )));
+        }
+
+        Ok(BatchInputs {
+            start_nonce,
+            end_nonce,
+            seed_commitment: proving::seed_commitment(&start_state),
+            start_state,
+            oracle_snapshot: self.oracles.to_snapshot(),
+            actions,
+        })
+    }
+
     /// Get the session ID for this runtime instance.
     pub fn session_id(&self) -> &str {
         &self.session_id
@@ -392,6 +624,34 @@ impl RuntimeHandle {
         Ok(())
     }
 
+    /// Override the provider for a specific kind.
+    ///
+    /// The override takes precedence over the registered provider until
+    /// cleared, so a running session can swap e.g. AI for a scripted provider.
+    pub fn override_provider(
+        &self,
+        kind: ProviderKind,
+        provider: impl ActionProvider + 'static,
+    ) -> Result<()> {
+        let mut registry = self
+            .providers
+            .write()
+            .map_err(|_| RuntimeError::LockPoisoned)?;
+        registry.register_override(kind, provider);
+        Ok(())
+    }
+
+    /// Remove the override for a specific kind, restoring its registered provider.
+    ///
+    /// Returns true if the kind was overridden.
+    pub fn clear_provider_override(&self, kind: ProviderKind) -> Result<bool> {
+        let mut registry = self
+            .providers
+            .write()
+            .map_err(|_| RuntimeError::LockPoisoned)?;
+        Ok(registry.clear_override(kind).is_some())
+    }
+
     /// Bind an entity to a specific provider kind.
     ///
     /// The entity will use the provider registered for this kind.
@@ -634,8 +894,8 @@ impl RuntimeHandle {
                 RuntimeError::PersistenceError(format!("Failed to create proof submission: {}", e))
             })?;
 
-        // 7. Submit to blockchain
-        let session_id = SessionId::new(self.session_id.clone());
+        // 7. Submit to blockchain (under the on-chain object ID, not the local session name)
+        let session_id = SessionId::from(self.load_session_object_id()?);
         let tx_digest = match clients
             .sui
             .update_session(&session_id, proof_submission, &blob_object_id)
@@ -760,7 +1020,12 @@ impl RuntimeHandle {
                 ))
             })?;
 
-        let session_object_id = session_id.as_str().to_string();
+        // Store the canonical form so later loads parse it back unchanged
+        let session_object_id = zk::SessionId::try_from(&session_id)
+            .map_err(|e| {
+                RuntimeError::PersistenceError(format!("Invalid session object ID: {}", e))
+            })?
+            .to_string();
 
         // Get network name from Sui config
         let network = blockchain_clients.sui.config.network_name().to_string();
@@ -826,13 +1091,14 @@ impl RuntimeHandle {
     ///
     /// # Returns
     ///
-    /// Session object ID string
+    /// Session object ID in canonical form
     ///
     /// # Errors
     ///
-    /// Returns error if metadata file doesn't exist or is invalid.
+    /// Returns error if metadata file doesn't exist, is invalid, or holds a
+    /// malformed object ID.
     #[cfg(feature = "sui")]
-    fn load_session_object_id(&self) -> Result<String> {
+    fn load_session_object_id(&self) -> Result<zk::SessionId> {
         use crate::runtime::SessionInit;
 
         let session_dir = self.base_dir.join(&self.session_id);
@@ -854,15 +1120,22 @@ impl RuntimeHandle {
                 RuntimeError::PersistenceError(format!("Failed to parse session_init.json: {}", e))
             })?;
 
-        session_init
+        let object_id = session_init
             .blockchain
             .as_ref()
-            .map(|b| b.session_object_id.clone())
+            .map(|b| b.session_object_id.as_str())
             .ok_or_else(|| {
                 RuntimeError::InvalidConfig(
                     "Blockchain session not created yet. Use [C] key to create session on-chain first.".to_string()
                 )
-            })
+            })?;
+
+        object_id.parse().map_err(|e| {
+            RuntimeError::InvalidConfig(format!(
+                "Invalid session object ID in session_init.json: {}",
+                e
+            ))
+        })
     }
 
     /// Get blockchain session info (if available).
@@ -888,7 +1161,7 @@ impl RuntimeHandle {
         };
 
         // 3. Query blockchain
-        let session_id = SessionId::new(session_object_id);
+        let session_id = SessionId::from(session_object_id);
         match clients.sui.get_session(&session_id).await {
             Ok(session) => Ok(Some(session)),
             Err(e) => {
@@ -898,3 +1171,544 @@ impl RuntimeHandle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, ActorTemplate, CardinalDirection, CharacterAction,
+        Faction, GameConfig, ItemDefinition, ItemHandle, ItemKind, Position, TraitProfile,
+        WeaponData, WeaponKind,
+    };
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+    };
+    use crate::repository::ActionBatchStatus;
+    use crate::{EntityKind, EntityPlacement, Runtime, Scenario, ValidationMode};
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(8, 8)
+    }
+
+    const SWORD: ItemHandle = ItemHandle(1);
+
+    /// Player at (1, 1), a sword on the ground at (2, 1) and a goblin at (3, 1).
+    fn skirmish() -> (OracleBundle, Scenario) {
+        let template = |faction: Faction, actions: &[ActionKind]| {
+            ActorTemplate::builder()
+                .faction(faction)
+                .trait_profile(TraitProfile::default())
+                .actions(actions.iter().copied().map(ActionAbility::new).collect())
+                .build()
+        };
+        let mut actors = ActorOracleImpl::new();
+        actors.add(
+            "player",
+            template(
+                Faction::Player,
+                &[
+                    ActionKind::Move,
+                    ActionKind::PickupItem,
+                    ActionKind::EquipWeapon,
+                    ActionKind::MeleeAttack,
+                ],
+            ),
+        );
+        actors.add("goblin", template(Faction::GoblinClan, &[ActionKind::Wait]));
+
+        let mut items = ItemOracleImpl::new();
+        let sword = ItemKind::Weapon(WeaponData {
+            kind: WeaponKind::Sword,
+            damage: 10,
+            reach: WeaponData::DEFAULT_REACH,
+            durability: None,
+        });
+        items.add_definition(ItemDefinition::new(SWORD, sword, 1));
+
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(8, 8)),
+            Arc::new(items),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(actors),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        );
+        let place = |x, y, kind| EntityPlacement {
+            position: Position::new(x, y),
+            kind,
+            player_index: None,
+        };
+        let scenario = Scenario::new(
+            "test".to_string(),
+            vec![
+                place(1, 1, EntityKind::Player),
+                place(2, 1, EntityKind::Item { handle: SWORD }),
+                place(
+                    3,
+                    1,
+                    EntityKind::Actor {
+                        def_id: "goblin".to_string(),
+                        objective: None,
+                    },
+                ),
+            ],
+        );
+        (oracles, scenario)
+    }
+
+    /// Executes `actions` in order; any other actor whose turn comes up first waits.
+    async fn play(handle: &RuntimeHandle, actions: &[Action]) {
+        for action in actions {
+            loop {
+                let (entity, _) = handle.prepare_next_turn().await.unwrap();
+                if entity == action.actor() {
+                    break;
+                }
+                let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+                handle
+                    .execute_action(Action::character(wait))
+                    .await
+                    .unwrap();
+            }
+            handle.execute_action(action.clone()).await.unwrap();
+        }
+    }
+
+    /// Asserts that running `actions` live, replaying them from the exported
+    /// batch inputs and proving that batch all commit to the same
+    /// `new_state_root` and `new_nonce`. Returns the live end state.
+    ///
+    /// The live run goes first so a second, persisted run can checkpoint the
+    /// whole session as exactly one batch. The proof path only runs with the
+    /// `stub` backend; the other backends are too slow for a unit test.
+    async fn assert_paths_agree(
+        oracles: OracleBundle,
+        scenario: Scenario,
+        actions: &[Action],
+    ) -> GameState {
+        // 1. Live
+        let runtime = Runtime::builder()
+            .oracles(oracles.clone())
+            .scenario(scenario.clone())
+            .validation_mode(ValidationMode::Strict)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        play(&handle, actions).await;
+        let live = handle.query_state().await.unwrap();
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+        let expected = (live.compute_state_root(), live.nonce());
+
+        let dir = TempDir::new().unwrap();
+        let runtime = Runtime::builder()
+            .oracles(oracles)
+            .scenario(scenario)
+            .validation_mode(ValidationMode::Strict)
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("paths")
+            .checkpoint_interval(live.nonce())
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        assert!(handle.load_state(0).await.unwrap().is_some());
+        play(&handle, actions).await;
+
+        let end_nonce = live.nonce() - 1;
+        completed_batch(&handle, 0).await;
+        let checkpointed = handle.load_state(end_nonce).await.unwrap().unwrap();
+        assert_eq!(
+            (checkpointed.compute_state_root(), checkpointed.nonce()),
+            expected,
+            "live runs diverged"
+        );
+
+        // 2. Replay
+        let inputs = handle.export_batch_inputs(0, end_nonce).await.unwrap();
+        let replayed = inputs.replay().unwrap();
+        assert_eq!(
+            (replayed.compute_state_root(), replayed.nonce()),
+            expected,
+            "replay diverged from the live run"
+        );
+
+        // 3. Proof
+        #[cfg(feature = "stub")]
+        {
+            use zk::Prover;
+
+            let proof = zk::StubProver::new(inputs.oracle_snapshot.clone())
+                .prove(&inputs.start_state, &inputs.actions, &replayed)
+                .unwrap();
+            let journal = zk::parse_journal(&proof.journal).unwrap();
+            assert_eq!(
+                (journal.new_state_root, journal.new_nonce),
+                expected,
+                "proof diverged from the live run"
+            );
+        }
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+        live
+    }
+
+    /// Polls until the batch at `start_nonce` has been checkpointed.
+    async fn completed_batch(handle: &RuntimeHandle, start_nonce: u64) -> ActionBatch {
+        for _ in 0..200 {
+            if let Some(batch) = handle.get_checkpoint(start_nonce).await.unwrap()
+                && batch.status == ActionBatchStatus::Complete
+            {
+                return batch;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("batch {} was never checkpointed", start_nonce);
+    }
+
+    #[tokio::test]
+    async fn exported_inputs_replay_to_the_proven_state() {
+        let dir = TempDir::new().unwrap();
+        let mut genesis = GameState::with_player();
+        genesis
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        let oracles = oracles();
+        let runtime = Runtime::builder()
+            .oracles(oracles.clone())
+            .initial_state(genesis)
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("export")
+            .checkpoint_interval(4)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+
+        // Replies only once the persistence worker has saved genesis
+        assert!(handle.load_state(0).await.unwrap().is_some());
+
+        // Two player turns: PrepareTurn + Wait each
+        for _ in 0..2 {
+            let (entity, _) = handle.prepare_next_turn().await.unwrap();
+            let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+            handle
+                .execute_action(Action::character(wait))
+                .await
+                .unwrap();
+        }
+        let batch = completed_batch(&handle, 0).await;
+        assert_eq!((batch.start_nonce, batch.end_nonce), (0, 3));
+
+        let inputs = handle.export_batch_inputs(0, 3).await.unwrap();
+        assert_eq!(inputs.actions.len(), 4);
+        assert_eq!(
+            inputs.oracle_snapshot.compute_oracle_root(),
+            oracles.to_snapshot().compute_oracle_root()
+        );
+        assert_eq!(
+            inputs.seed_commitment,
+            proving::seed_commitment(&inputs.start_state)
+        );
+
+        // A proof of this batch commits the checkpointed end state's root and nonce
+        let proven = handle.load_state(3).await.unwrap().unwrap();
+        let replayed = inputs.replay().unwrap();
+        assert_eq!(replayed.compute_state_root(), proven.compute_state_root());
+        assert_eq!(replayed.nonce(), proven.nonce());
+
+        // The range must match a checkpointed batch exactly
+        assert!(handle.export_batch_inputs(0, 2).await.is_err());
+
+        // Workers only stop once every handle is gone
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn persisted_actions_cannot_be_undone() {
+        let dir = TempDir::new().unwrap();
+        let mut genesis = GameState::with_player();
+        genesis
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis)
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("undo")
+            .checkpoint_interval(4)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        assert!(handle.load_state(0).await.unwrap().is_some());
+
+        // Undo between two turns is refused, so the log keeps one action per nonce
+        for turn in 0..2 {
+            let (entity, _) = handle.prepare_next_turn().await.unwrap();
+            let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+            handle
+                .execute_action(Action::character(wait))
+                .await
+                .unwrap();
+            if turn == 0 {
+                assert!(matches!(
+                    handle.undo().await,
+                    Err(RuntimeError::UndoPersisted)
+                ));
+            }
+        }
+        let batch = completed_batch(&handle, 0).await;
+        assert_eq!((batch.start_nonce, batch.end_nonce), (0, 3));
+
+        let inputs = handle.export_batch_inputs(0, 3).await.unwrap();
+        assert_eq!(inputs.actions.len(), 4);
+        let proven = handle.load_state(3).await.unwrap().unwrap();
+        let replayed = inputs.replay().unwrap();
+        assert_eq!(replayed.compute_state_root(), proven.compute_state_root());
+        assert_eq!(replayed.nonce(), proven.nonce());
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delta_since_a_checkpoint_rebuilds_the_current_state() {
+        let dir = TempDir::new().unwrap();
+        let mut genesis = GameState::with_player();
+        genesis
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        genesis
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Move));
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis)
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("delta")
+            .checkpoint_interval(4)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        assert!(handle.load_state(0).await.unwrap().is_some());
+
+        // Three steps east: the first two are checkpointed under nonce 3
+        let step = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::East),
+        ));
+        play(&handle, &[step.clone(), step.clone(), step]).await;
+        completed_batch(&handle, 0).await;
+        let current = handle.query_state().await.unwrap();
+
+        let delta = handle.delta_since(4).await.unwrap();
+        let mut rebuilt = handle.load_state(3).await.unwrap().unwrap();
+        assert!(
+            delta
+                .entities
+                .actors
+                .updated
+                .iter()
+                .any(|change| change.id == EntityId::PLAYER)
+        );
+        rebuilt.apply_delta(&delta, &current);
+        assert_eq!(rebuilt.compute_state_root(), current.compute_state_root());
+
+        // Without the genesis state nothing predates the first checkpoint
+        std::fs::remove_file(dir.path().join("delta/states/state_0.bin")).unwrap();
+        assert!(matches!(
+            handle.delta_since(2).await,
+            Err(RuntimeError::NonceTooOld { nonce: 2 })
+        ));
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn live_replay_and_proof_agree_on_a_skirmish() {
+        let (oracles, scenario) = skirmish();
+        let genesis = scenario.create_initial_state(&oracles).unwrap();
+        let sword = genesis.entities.all_items().next().unwrap().id;
+        let goblin = genesis
+            .entities
+            .all_actors()
+            .find(|actor| actor.id != EntityId::PLAYER)
+            .unwrap()
+            .id;
+
+        let turn =
+            |kind, input| Action::character(CharacterAction::new(EntityId::PLAYER, kind, input));
+        let actions = [
+            turn(
+                ActionKind::Move,
+                ActionInput::Direction(CardinalDirection::East),
+            ),
+            turn(ActionKind::PickupItem, ActionInput::Target(sword)),
+            turn(ActionKind::EquipWeapon, ActionInput::InventorySlot(0)),
+            turn(ActionKind::MeleeAttack, ActionInput::Target(goblin)),
+        ];
+        let end = assert_paths_agree(oracles, scenario, &actions).await;
+
+        // Strict validation rejects failed turns, so all four took effect
+        let player = end.entities.actor(EntityId::PLAYER).unwrap();
+        assert_eq!(player.position, Some(Position::new(2, 1)));
+        assert_eq!(player.equipment.weapon, Some(SWORD));
+        assert!(end.entities.items.is_empty());
+        assert!(end.entities.actor(goblin).unwrap().resources.hp < 105);
+    }
+
+    #[tokio::test]
+    async fn turn_order_preview_matches_the_executed_order() {
+        let waiter = |faction: Faction| {
+            ActorTemplate::builder()
+                .faction(faction)
+                .trait_profile(TraitProfile::default())
+                .actions([ActionAbility::new(ActionKind::Wait)].into_iter().collect())
+                .build()
+        };
+        let mut genesis = GameState::empty();
+        genesis
+            .add_player(&waiter(Faction::Player), Position::new(1, 1))
+            .unwrap();
+        let mut npcs = Vec::new();
+        for (x, ready_at) in [(2, 40), (3, 15)] {
+            let npc = genesis
+                .add_npc(&waiter(Faction::GoblinClan), Position::new(x, 1))
+                .unwrap();
+            genesis.entities.actor_mut(npc).unwrap().ready_at = Some(ready_at);
+            genesis.turn.active_actors.insert(npc);
+            npcs.push(npc);
+        }
+
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+
+        let preview = handle.turn_order_preview(6).await.unwrap();
+        let first: Vec<_> = preview.iter().take(3).copied().collect();
+        assert_eq!(
+            first,
+            vec![(EntityId::PLAYER, 0), (npcs[1], 15), (npcs[0], 40)]
+        );
+
+        // Previewing neither advanced the clock nor consumed a nonce
+        let state = handle.query_state().await.unwrap();
+        assert_eq!((state.turn.clock, state.nonce()), (0, 0));
+
+        let mut executed = Vec::new();
+        for _ in 0..preview.len() {
+            let (entity, state) = handle.prepare_next_turn().await.unwrap();
+            executed.push((entity, state.turn.clock));
+            let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+            handle
+                .execute_action(Action::character(wait))
+                .await
+                .unwrap();
+        }
+        assert_eq!(preview, executed);
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+
+    /// `state` with only `remaining` entity ids left, as late in a long session.
+    ///
+    /// The allocator is private, so this round-trips the state through RON.
+    fn with_remaining_ids(state: &GameState, remaining: u32) -> GameState {
+        let next = EntityId::SYSTEM.0 - state.remaining_entity_ids();
+        let ron = ron::to_string(state).unwrap().replacen(
+            &format!("next_entity_id:{next},"),
+            &format!("next_entity_id:{},", EntityId::SYSTEM.0 - remaining),
+            1,
+        );
+        ron::from_str(&ron).unwrap()
+    }
+
+    #[tokio::test]
+    async fn running_out_of_entity_ids_halts_the_session() {
+        let template = |faction| {
+            ActorTemplate::builder()
+                .faction(faction)
+                .trait_profile(TraitProfile::default())
+                .build()
+        };
+        let mut genesis = GameState::empty();
+        genesis
+            .add_player(&template(Faction::Player), Position::new(1, 1))
+            .unwrap();
+        genesis
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+        let goblin = genesis
+            .add_npc(&template(Faction::GoblinClan), Position::new(2, 1))
+            .unwrap();
+        let carrier = genesis.entities.actor_mut(goblin).unwrap();
+        carrier.resources.hp = 1;
+        carrier.inventory.add_item(SWORD, 1, 1);
+
+        // Killing the goblin drops its sword, which needs an entity id
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(with_remaining_ids(&genesis, 0))
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        let (entity, _) = handle.prepare_next_turn().await.unwrap();
+        assert_eq!(entity, EntityId::PLAYER);
+        let attack = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(goblin),
+        ));
+        assert!(matches!(
+            handle.execute_action(attack.clone()).await,
+            Err(RuntimeError::EntityIdsExhausted { .. })
+        ));
+
+        // Later commands are refused, but queries still answer
+        assert!(matches!(
+            handle.prepare_next_turn().await,
+            Err(RuntimeError::SessionHalted {
+                error_code: "DROP_LOOT_ENTITY_IDS_EXHAUSTED",
+                ..
+            })
+        ));
+        assert!(matches!(
+            handle.execute_action(attack).await,
+            Err(RuntimeError::SessionHalted { .. })
+        ));
+        let state = handle.query_state().await.unwrap();
+        assert_eq!(state.remaining_entity_ids(), 0);
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+//! Aggregated proof generation metrics for clients.
+//!
+//! A background task folds every [`ProofEvent`] published on the event bus
+//! into a [`ProofMetricsSnapshot`], which [`RuntimeHandle::proof_metrics`]
+//! hands out as a copy.
+//!
+//! [`RuntimeHandle::proof_metrics`]: super::RuntimeHandle::proof_metrics
+
+use std::sync::{Arc, RwLock};
+
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::events::{Event, EventBus, ProofEvent, Topic};
+use crate::types::{DurationMs, ProofSize};
+
+/// Proving statistics aggregated since the runtime started.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofMetricsSnapshot {
+    /// Number of proofs generated
+    pub count: u64,
+
+    /// Number of proof generations that failed
+    pub failures: u64,
+
+    /// Total generation time of all generated proofs
+    pub total_duration_ms: DurationMs,
+
+    /// Smallest proof generated, in bytes
+    pub min_proof_size: Option<ProofSize>,
+
+    /// Largest proof generated, in bytes
+    pub max_proof_size: Option<ProofSize>,
+}
+
+impl ProofMetricsSnapshot {
+    /// Average generation time of the proofs generated so far.
+    pub fn avg_duration_ms(&self) -> DurationMs {
+        self.total_duration_ms.checked_div(self.count).unwrap_or(0)
+    }
+
+    /// Folds one proof event into the totals.
+    ///
+    /// Only `ProofGenerated` and `ProofFailed` are counted.
+    pub(crate) fn record(&mut self, event: &ProofEvent) {
+        match event {
+            ProofEvent::ProofGenerated {
+                proof_data,
+                generation_time_ms,
+                ..
+            } => {
+                let size = proof_data.bytes.len() as ProofSize;
+                self.count += 1;
+                self.total_duration_ms += generation_time_ms;
+                self.min_proof_size = Some(self.min_proof_size.map_or(size, |min| min.min(size)));
+                self.max_proof_size = Some(self.max_proof_size.map_or(size, |max| max.max(size)));
+            }
+            ProofEvent::ProofFailed { .. } => self.failures += 1,
+            ProofEvent::ProofStarted { .. } | ProofEvent::ProofProgress { .. } => {}
+        }
+    }
+}
+
+/// Starts aggregating the proof events published on `event_bus`.
+///
+/// The subscription is taken before this returns, so no event published
+/// afterwards is missed. The task stops when the event bus is dropped.
+pub(crate) fn spawn_proof_metrics(event_bus: &EventBus) -> Arc<RwLock<ProofMetricsSnapshot>> {
+    let metrics = Arc::new(RwLock::new(ProofMetricsSnapshot::default()));
+    let mut events = event_bus.subscribe(Topic::Proof);
+
+    let shared = Arc::clone(&metrics);
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(Event::Proof(event)) => {
+                    if let Ok(mut metrics) = shared.write() {
+                        metrics.record(&event);
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Proof metrics missed {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Action, PrepareTurnAction, SystemActionKind};
+    use zk::{ProofBackend, ProofData};
+
+    use super::*;
+
+    fn generated(size: usize, generation_time_ms: u64) -> ProofEvent {
+        ProofEvent::ProofGenerated {
+            action: Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+            clock: 0,
+            proof_data: ProofData {
+                bytes: vec![0; size],
+                backend: ProofBackend::Stub,
+                journal: Vec::new(),
+                journal_digest: [0; 32],
+            },
+            generation_time_ms,
+        }
+    }
+
+    fn failed() -> ProofEvent {
+        ProofEvent::ProofFailed {
+            action: Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+            clock: 0,
+            error: "guest panicked".to_string(),
+        }
+    }
+
+    #[test]
+    fn aggregates_durations_sizes_and_failures() {
+        let mut metrics = ProofMetricsSnapshot::default();
+        assert_eq!(metrics.avg_duration_ms(), 0);
+
+        for event in [
+            generated(256, 100),
+            failed(),
+            generated(64, 250),
+            generated(1024, 30),
+        ] {
+            metrics.record(&event);
+        }
+
+        assert_eq!(metrics.count, 3);
+        assert_eq!(metrics.failures, 1);
+        assert_eq!(metrics.total_duration_ms, 380);
+        assert_eq!(metrics.avg_duration_ms(), 126);
+        assert_eq!(metrics.min_proof_size, Some(64));
+        assert_eq!(metrics.max_proof_size, Some(1024));
+    }
+
+    #[tokio::test]
+    async fn published_proof_events_reach_the_snapshot() {
+        let event_bus = EventBus::new();
+        let metrics = spawn_proof_metrics(&event_bus);
+
+        event_bus.publish(Event::Proof(generated(128, 40)));
+        event_bus.publish(Event::Proof(failed()));
+
+        // Let the aggregation task drain the channel
+        for _ in 0..100 {
+            if metrics.read().unwrap().failures == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+
+        let snapshot = metrics.read().unwrap().clone();
+        assert_eq!(snapshot.count, 1);
+        assert_eq!(snapshot.avg_duration_ms(), 40);
+        assert_eq!(snapshot.min_proof_size, Some(128));
+    }
+}
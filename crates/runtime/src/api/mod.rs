@@ -5,10 +5,12 @@
 
 pub mod errors;
 pub mod handle;
+pub mod metrics;
 pub mod providers;
 pub mod registry;
 
 pub use errors::{AiKind, InteractiveKind, ProviderKind, Result, RuntimeError};
 pub use handle::RuntimeHandle;
+pub use metrics::ProofMetricsSnapshot;
 pub use providers::ActionProvider;
 pub use registry::ProviderRegistry;
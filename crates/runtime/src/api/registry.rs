@@ -8,6 +8,7 @@
 //! - **Provider instances**: Stored by `ProviderKind`, shared across entities
 //! - **Entity mappings**: Each entity can be bound to a specific `ProviderKind`
 //! - **Fallback chain**: Entity mapping → Default provider
+//! - **Kind resolution**: Override → Registered provider → Fallback provider
 //! - **Runtime changes**: Entities can switch providers dynamically
 
 use std::collections::HashMap;
@@ -23,6 +24,8 @@ use super::{ActionProvider, AiKind, ProviderKind, Result, RuntimeError};
 ///
 /// ProviderRegistry
 /// ├── providers: HashMap<ProviderKind, Provider>  (provider instances)
+/// ├── overrides: HashMap<ProviderKind, Provider>  (take precedence over providers)
+/// ├── fallback: Option<Provider>  (for kinds with no provider)
 /// ├── entity_mappings: HashMap<EntityId, ProviderKind>  (entity bindings)
 /// └── default_kind: ProviderKind  (fallback)
 pub struct ProviderRegistry {
@@ -30,6 +33,12 @@ pub struct ProviderRegistry {
     /// Uses Arc instead of Box to allow cloning providers for use outside locks
     providers: HashMap<ProviderKind, Arc<dyn ActionProvider>>,
 
+    /// Providers that replace the registered provider of a kind until cleared
+    overrides: HashMap<ProviderKind, Arc<dyn ActionProvider>>,
+
+    /// Provider used for kinds with neither an override nor a registration
+    fallback: Option<Arc<dyn ActionProvider>>,
+
     /// Entity-to-provider mappings (sparse - only non-default entities)
     entity_mappings: HashMap<EntityId, ProviderKind>,
 
@@ -42,11 +51,35 @@ impl ProviderRegistry {
     pub fn new() -> Self {
         Self {
             providers: HashMap::new(),
+            overrides: HashMap::new(),
+            fallback: None,
             entity_mappings: HashMap::new(),
             default_kind: ProviderKind::Ai(AiKind::Wait),
         }
     }
 
+    /// Create a provider registry that routes unregistered kinds to `fallback`.
+    ///
+    /// A scenario may reference provider kinds the runtime was not built
+    /// with; their entities then act through the fallback instead of failing
+    /// to resolve.
+    pub fn with_fallback(fallback: Box<dyn ActionProvider>) -> Self {
+        Self {
+            fallback: Some(Arc::from(fallback)),
+            ..Self::new()
+        }
+    }
+
+    /// Set the provider used for kinds with no registered provider.
+    pub fn set_fallback(&mut self, fallback: Box<dyn ActionProvider>) {
+        self.fallback = Some(Arc::from(fallback));
+    }
+
+    /// Check if a fallback provider is set.
+    pub fn has_fallback(&self) -> bool {
+        self.fallback.is_some()
+    }
+
     /// Register a provider for a specific kind.
     ///
     /// If a provider already exists for this kind, it will be replaced.
@@ -61,6 +94,36 @@ impl ProviderRegistry {
         self.providers.insert(kind, Arc::from(provider));
     }
 
+    /// Override the provider for a specific kind.
+    ///
+    /// The override takes precedence over the provider registered for `kind`
+    /// (which is kept, not replaced) until [`clear_override`] is called. This
+    /// lets tooling swap a kind's provider, e.g. AI for a scripted provider,
+    /// on a running registry.
+    ///
+    /// Returns the previous override for this kind, if any.
+    ///
+    /// [`clear_override`]: Self::clear_override
+    pub fn register_override(
+        &mut self,
+        kind: ProviderKind,
+        provider: impl ActionProvider + 'static,
+    ) -> Option<Arc<dyn ActionProvider>> {
+        self.overrides.insert(kind, Arc::new(provider))
+    }
+
+    /// Remove the override for a specific kind, restoring its registered provider.
+    ///
+    /// Returns the removed override, or None if the kind was not overridden.
+    pub fn clear_override(&mut self, kind: ProviderKind) -> Option<Arc<dyn ActionProvider>> {
+        self.overrides.remove(&kind)
+    }
+
+    /// Check if a specific kind is overridden.
+    pub fn is_overridden(&self, kind: ProviderKind) -> bool {
+        self.overrides.contains_key(&kind)
+    }
+
     /// Bind an entity to a specific provider kind.
     ///
     /// The entity will use this provider until unbound or rebound to a different kind.
@@ -103,12 +166,12 @@ impl ProviderRegistry {
     ///
     /// 1. Check if entity has explicit binding → use that provider kind
     /// 2. Otherwise → use default provider kind
-    /// 3. Lookup provider instance by kind
+    /// 3. Lookup provider instance by kind (see [`resolve`](Self::resolve))
     ///
     /// # Errors
     ///
     /// Returns `RuntimeError::ProviderNotSet` if the resolved provider kind
-    /// has no registered provider instance.
+    /// has no provider instance and there is no fallback.
     ///
     /// # Returns
     ///
@@ -121,24 +184,53 @@ impl ProviderRegistry {
 
     /// Get a provider for a specific kind.
     ///
+    /// Resolves like [`resolve`](Self::resolve).
+    ///
     /// # Errors
     ///
-    /// Returns `RuntimeError::ProviderNotSet` if no provider is registered for this kind.
+    /// Returns `RuntimeError::ProviderNotSet` if no provider is registered for
+    /// this kind and there is no fallback.
     ///
     /// # Returns
     ///
     /// Returns an Arc clone of the provider, allowing it to be used outside
     /// of the registry's lock scope. This is cheap (just incrementing a reference count).
     pub fn get(&self, kind: ProviderKind) -> Result<Arc<dyn ActionProvider>> {
-        self.providers
-            .get(&kind)
+        self.lookup(kind)
             .cloned()
-            .ok_or_else(|| RuntimeError::ProviderNotSet { kind })
+            .ok_or(RuntimeError::ProviderNotSet { kind })
+    }
+
+    /// Resolve the provider that acts for a specific kind.
+    ///
+    /// # Resolution Order
+    ///
+    /// 1. The override for this kind, if any
+    /// 2. The provider registered for this kind
+    /// 3. The fallback provider
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::ProviderNotSet` if none of these exist.
+    pub fn resolve(&self, kind: ProviderKind) -> Result<&dyn ActionProvider> {
+        self.lookup(kind)
+            .map(|provider| provider.as_ref())
+            .ok_or(RuntimeError::ProviderNotSet { kind })
+    }
+
+    fn lookup(&self, kind: ProviderKind) -> Option<&Arc<dyn ActionProvider>> {
+        self.overrides
+            .get(&kind)
+            .or_else(|| self.providers.get(&kind))
+            .or(self.fallback.as_ref())
     }
 
-    /// Check if a provider is registered for a specific kind.
+    /// Check if a provider is registered or overridden for a specific kind.
+    ///
+    /// The fallback is not counted: this tells whether the kind has a
+    /// provider of its own.
     pub fn has(&self, kind: ProviderKind) -> bool {
-        self.providers.contains_key(&kind)
+        self.overrides.contains_key(&kind) || self.providers.contains_key(&kind)
     }
 
     /// Check if an entity has an explicit binding.
@@ -180,3 +272,81 @@ impl Default for ProviderRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use game_core::{
+        Action, ActionInput, ActionKind, CharacterAction, GameEnv, GameState, InteractiveKind,
+    };
+
+    use super::*;
+
+    /// Provider that always answers with the same action kind.
+    struct Fixed(ActionKind);
+
+    #[async_trait]
+    impl ActionProvider for Fixed {
+        async fn provide_action(
+            &self,
+            entity: EntityId,
+            _state: &GameState,
+            _env: GameEnv<'_>,
+        ) -> Result<Action> {
+            Ok(Action::character(CharacterAction::new(
+                entity,
+                self.0,
+                ActionInput::None,
+            )))
+        }
+    }
+
+    const UTILITY: ProviderKind = ProviderKind::Ai(AiKind::Utility);
+    const CLI: ProviderKind = ProviderKind::Interactive(InteractiveKind::CliInput);
+
+    async fn kind_from(registry: &ProviderRegistry, kind: ProviderKind) -> ActionKind {
+        let action = registry
+            .resolve(kind)
+            .unwrap()
+            .provide_action(EntityId::PLAYER, &GameState::empty(), GameEnv::empty())
+            .await
+            .unwrap();
+        match action {
+            Action::Character(action) => action.kind,
+            other => panic!("unexpected action {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn unregistered_kind_routes_to_the_fallback() {
+        let mut registry = ProviderRegistry::with_fallback(Box::new(Fixed(ActionKind::Wait)));
+        registry.register(UTILITY, Fixed(ActionKind::Move));
+
+        assert_eq!(kind_from(&registry, UTILITY).await, ActionKind::Move);
+        assert_eq!(kind_from(&registry, CLI).await, ActionKind::Wait);
+        assert!(!registry.has(CLI));
+
+        // Without a fallback the kind does not resolve
+        assert!(matches!(
+            ProviderRegistry::new().resolve(CLI),
+            Err(RuntimeError::ProviderNotSet { kind: CLI })
+        ));
+    }
+
+    #[tokio::test]
+    async fn override_takes_precedence_until_cleared() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(UTILITY, Fixed(ActionKind::Move));
+        assert!(
+            registry
+                .register_override(UTILITY, Fixed(ActionKind::Wait))
+                .is_none()
+        );
+
+        assert_eq!(kind_from(&registry, UTILITY).await, ActionKind::Wait);
+        assert!(registry.is_overridden(UTILITY));
+
+        assert!(registry.clear_override(UTILITY).is_some());
+        assert_eq!(kind_from(&registry, UTILITY).await, ActionKind::Move);
+    }
+}
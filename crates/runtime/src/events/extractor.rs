@@ -3,9 +3,14 @@
 //! This module provides functions to convert low-level StateDelta into
 //! high-level GameEvent instances.
 
-use game_core::{ActorFields, GameState, StateDelta, SystemActionKind};
+use std::cmp::Ordering;
 
-use super::game_event::{GameEvent, HealthThreshold};
+use game_core::{
+    ActorFields, ActorState, EntityId, GameState, ItemHandle, MapOracle, StateDelta,
+    StatusEffectKind, SystemActionKind,
+};
+
+use super::game_event::{CrossDirection, GameEvent, HealthThreshold};
 
 /// Extract high-level game events from a state delta.
 ///
@@ -18,17 +23,23 @@ use super::game_event::{GameEvent, HealthThreshold};
 /// - `delta`: The state changes that occurred
 /// - `state_before`: Game state before the action
 /// - `state_after`: Game state after the action
+/// - `map`: Static map, used to recognize exit tiles
 ///
 /// # Event Ordering
 ///
-/// Events are ordered by semantic priority:
+/// Events are returned in the canonical order of [`GameEvent::order`]:
 /// 1. ActionCompleted (if non-system action)
-/// 2. Entity state changes (damage, movement, etc.)
-/// 3. Derived events (death, threshold crossing)
+/// 2. System action results (turn start, deactivation, removal from world)
+/// 3. Entity state changes (damage, spawns, movement, ready_at)
+/// 4. Derived events (threshold crossing, interrupted channel, escape, despawn, death)
+///
+/// Ties are broken by entity id, so the order never depends on how the delta
+/// lists its changes.
 pub fn extract_events(
     delta: &StateDelta,
     state_before: &GameState,
     state_after: &GameState,
+    map: &dyn MapOracle,
 ) -> Vec<GameEvent> {
     let mut events = Vec::new();
 
@@ -91,6 +102,39 @@ pub fn extract_events(
         });
     }
 
+    // The new turn, then temporary entities whose lifespan ran out by its clock
+    if let game_core::Action::System {
+        kind: SystemActionKind::PrepareTurn(_),
+    } = &delta.action
+    {
+        let clock = state_after.turn.clock;
+        events.push(GameEvent::TurnStarted {
+            entity: state_after.turn.current_actor,
+            clock,
+        });
+        for actor in state_after.entities.all_actors() {
+            if let Some(position) = actor.position
+                && actor
+                    .status_effects
+                    .has_expired(StatusEffectKind::Lifespan, clock)
+            {
+                events.push(GameEvent::EntityDespawned {
+                    entity: actor.id,
+                    position,
+                });
+            }
+        }
+    }
+
+    // Actors created by the action (summons)
+    for &entity in &delta.entities.actors.added {
+        events.push(GameEvent::EntitySpawned {
+            entity,
+            position: state_after.actor_position(entity),
+            summoner: delta.action.actor(),
+        });
+    }
+
     // Analyze entity changes
     for actor_change in &delta.entities.actors.updated {
         let Some(actor_before) = state_before.entities.actor(actor_change.id) else {
@@ -117,6 +161,17 @@ pub fn extract_events(
                     });
                 }
 
+                // Check for an interrupted channel (cleared by the hit)
+                if new_hp < old_hp
+                    && let Some(channel) = actor_before.channel
+                    && actor_after.channel.is_none()
+                {
+                    events.push(GameEvent::ChannelInterrupted {
+                        entity: actor_change.id,
+                        action: channel.action,
+                    });
+                }
+
                 // Check for death (HP dropped to 0)
                 if old_hp > 0 && new_hp == 0 {
                     tracing::info!(
@@ -134,21 +189,7 @@ pub fn extract_events(
                 }
 
                 // Check health threshold crossing
-                let max_hp = actor_after.snapshot().resource_max.hp_max;
-                let old_threshold = HealthThreshold::from_hp(old_hp, max_hp);
-                let new_threshold = HealthThreshold::from_hp(new_hp, max_hp);
-
-                if old_threshold != new_threshold {
-                    events.push(GameEvent::HealthThresholdCrossed {
-                        entity: actor_change.id,
-                        threshold: new_threshold,
-                        hp_percent: if max_hp > 0 {
-                            (new_hp * 100) / max_hp
-                        } else {
-                            0
-                        },
-                    });
-                }
+                events.extend(threshold_crossing(actor_before, actor_after));
             }
         }
 
@@ -163,6 +204,17 @@ pub fn extract_events(
                     from: old_position,
                     to: new_position,
                 });
+
+                // NPCs that reach an exit leave play
+                if actor_change.id != EntityId::PLAYER
+                    && let Some(position) = new_position
+                    && map.tile(position).is_some_and(|tile| tile.is_exit())
+                {
+                    events.push(GameEvent::EntityEscaped {
+                        entity: actor_change.id,
+                        position,
+                    });
+                }
             }
         }
 
@@ -181,5 +233,256 @@ pub fn extract_events(
         }
     }
 
+    events.sort_by_key(GameEvent::order);
     events
 }
+
+/// Extract only the health threshold crossings from a state delta.
+///
+/// These are the `HealthThresholdCrossed` events [`extract_events`] would
+/// produce, in delta order, without the rest of the extraction.
+pub(crate) fn health_threshold_crossings(
+    delta: &StateDelta,
+    state_before: &GameState,
+    state_after: &GameState,
+) -> Vec<GameEvent> {
+    delta
+        .entities
+        .actors
+        .updated
+        .iter()
+        .filter(|change| change.fields.contains(ActorFields::RESOURCES))
+        .filter_map(|change| {
+            let actor_before = state_before.entities.actor(change.id)?;
+            let actor_after = state_after.entities.actor(change.id)?;
+            threshold_crossing(actor_before, actor_after)
+        })
+        .collect()
+}
+
+/// Extract the equipment that broke during an action.
+///
+/// Returns `(entity, item)` pairs in delta order, weapon before armor. Equip
+/// effects swap items, so a slot that ends up empty was emptied by breakage.
+pub(crate) fn broken_equipment(
+    delta: &StateDelta,
+    state_before: &GameState,
+    state_after: &GameState,
+) -> Vec<(EntityId, ItemHandle)> {
+    let mut broken = Vec::new();
+    for change in &delta.entities.actors.updated {
+        if !change.fields.contains(ActorFields::EQUIPMENT) {
+            continue;
+        }
+        let (Some(before), Some(after)) = (
+            state_before.entities.actor(change.id),
+            state_after.entities.actor(change.id),
+        ) else {
+            continue;
+        };
+
+        let (before, after) = (&before.equipment, &after.equipment);
+        let weapon = before.weapon.filter(|_| after.weapon.is_none());
+        let armor = before.armor.filter(|_| after.armor.is_none());
+        for item in weapon.into_iter().chain(armor) {
+            broken.push((change.id, item));
+        }
+    }
+    broken
+}
+
+/// Returns a `HealthThresholdCrossed` event if the HP change moved the actor
+/// into another [`HealthThreshold`] band.
+///
+/// The direction compares the HP fractions before and after, each against
+/// the max HP at that time, so a max HP change is accounted for.
+fn threshold_crossing(actor_before: &ActorState, actor_after: &ActorState) -> Option<GameEvent> {
+    let (old_hp, new_hp) = (actor_before.resources.hp, actor_after.resources.hp);
+    if old_hp == new_hp {
+        return None;
+    }
+
+    let old_max = actor_before.snapshot().resource_max.hp_max;
+    let new_max = actor_after.snapshot().resource_max.hp_max;
+    let threshold = HealthThreshold::from_hp(new_hp, new_max);
+    if HealthThreshold::from_hp(old_hp, old_max) == threshold {
+        return None;
+    }
+
+    // old_hp / old_max vs new_hp / new_max, cross-multiplied; raw HP breaks
+    // the tie that a zero max HP would cause
+    let old_fraction = u64::from(old_hp) * u64::from(new_max);
+    let new_fraction = u64::from(new_hp) * u64::from(old_max);
+    let direction = match new_fraction.cmp(&old_fraction).then(new_hp.cmp(&old_hp)) {
+        Ordering::Less => CrossDirection::Down,
+        _ => CrossDirection::Up,
+    };
+
+    Some(GameEvent::HealthThresholdCrossed {
+        entity: actor_after.id,
+        threshold,
+        direction,
+        hp_percent: (new_hp * 100).checked_div(new_max).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::stats::compute_actor_bonuses;
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActionKind, ActorTemplate, CharacterAction, Faction,
+        GameConfig, GameEngine, ItemDefinition, ItemKind, Position, TraitProfile, WeaponData,
+        WeaponKind,
+    };
+
+    use super::*;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+
+    const SWORD: ItemHandle = ItemHandle(10);
+
+    /// Crossings extracted when the player's HP goes from `from` to `to`
+    /// percent of its max.
+    fn crossings(from: u32, to: u32) -> Vec<(HealthThreshold, CrossDirection)> {
+        let mut before = GameState::with_player();
+        let max_hp = before.entities.player().snapshot().resource_max.hp_max;
+        before.entities.player_mut().resources.hp = max_hp * from / 100;
+        let mut after = before.clone();
+        after.entities.player_mut().resources.hp = max_hp * to / 100;
+
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        let delta = StateDelta::from_states(action, &before, &after);
+        let map = MapOracleImpl::test_map(8, 8);
+
+        extract_events(&delta, &before, &after, &map)
+            .into_iter()
+            .filter_map(|event| match event {
+                GameEvent::HealthThresholdCrossed {
+                    threshold,
+                    direction,
+                    ..
+                } => Some((threshold, direction)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dropping_below_a_quarter_crosses_down() {
+        assert_eq!(
+            crossings(30, 20),
+            vec![(HealthThreshold::Critical, CrossDirection::Down)]
+        );
+    }
+
+    #[test]
+    fn recovering_above_a_quarter_crosses_up() {
+        assert_eq!(
+            crossings(20, 30),
+            vec![(HealthThreshold::Wounded, CrossDirection::Up)]
+        );
+        // Staying inside one band is not a crossing
+        assert!(crossings(30, 40).is_empty());
+    }
+
+    /// Equips a sword of the given durability and swings it at a goblin
+    /// `swings` times. Returns the state and the weapon breaks reported for
+    /// each swing.
+    fn swing_sword(
+        durability: Option<u16>,
+        swings: usize,
+    ) -> (GameState, Vec<Vec<(EntityId, ItemHandle)>>) {
+        let mut items = ItemOracleImpl::new();
+        let sword = ItemKind::Weapon(WeaponData {
+            kind: WeaponKind::Sword,
+            damage: 10,
+            reach: WeaponData::DEFAULT_REACH,
+            durability,
+        });
+        items.add_definition(ItemDefinition::new(SWORD, sword, 1));
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(8, 8)),
+            Arc::new(items),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        );
+        let template = |faction| {
+            ActorTemplate::builder()
+                .faction(faction)
+                .trait_profile(TraitProfile::default())
+                .build()
+        };
+
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(2, 2))
+            .unwrap();
+        let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::EquipWeapon));
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+        player.inventory.add_item(SWORD, 1, 1);
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(3, 2))
+            .unwrap();
+        let equip = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::EquipWeapon,
+            ActionInput::InventorySlot(0),
+        ));
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &equip)
+            .unwrap();
+
+        let swing = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(goblin),
+        ));
+        let mut breaks = Vec::new();
+        for _ in 0..swings {
+            let clock = state.turn.clock;
+            state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(clock);
+            let before = state.clone();
+            let outcome = GameEngine::new(&mut state)
+                .execute(oracles.as_game_env(), &swing)
+                .unwrap();
+            breaks.push(broken_equipment(&outcome.delta, &before, &state));
+        }
+        (state, breaks)
+    }
+
+    #[test]
+    fn weapon_breaks_once_its_durability_is_used_up() {
+        let (state, breaks) = swing_sword(Some(2), 2);
+
+        // The first hit only wears the sword down; the second breaks it
+        assert_eq!(breaks, vec![vec![], vec![(EntityId::PLAYER, SWORD)]]);
+        let player = state.entities.actor(EntityId::PLAYER).unwrap();
+        assert_eq!(player.equipment.weapon, None);
+        assert_eq!(player.equipment.weapon_durability, None);
+        assert!(player.inventory.items.is_empty());
+        assert_eq!(player.bonuses, compute_actor_bonuses());
+    }
+
+    #[test]
+    fn weapon_without_durability_never_breaks() {
+        let (state, breaks) = swing_sword(None, 5);
+
+        assert!(breaks.iter().all(Vec::is_empty));
+        let player = state.entities.actor(EntityId::PLAYER).unwrap();
+        assert_eq!(player.equipment.weapon, Some(SWORD));
+    }
+}
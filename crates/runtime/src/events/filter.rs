@@ -0,0 +1,163 @@
+//! Predicate-filtered subscriptions.
+//!
+//! Topics are coarse, so a frontend that only cares about one entity would
+//! otherwise inspect every event itself. [`FilteredReceiver`] drops
+//! non-matching events as they are received, and [`EntityFilter`] is the
+//! common predicate for "events touching this entity".
+
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+use game_core::EntityId;
+
+use super::bus::Event;
+use super::types::GameStateEvent;
+
+/// Receiver that only yields events accepted by a predicate.
+pub struct FilteredReceiver {
+    rx: broadcast::Receiver<Event>,
+    predicate: Box<dyn Fn(&Event) -> bool + Send>,
+}
+
+impl FilteredReceiver {
+    /// Wraps `rx` so that only events matching `predicate` are returned.
+    pub fn new(
+        rx: broadcast::Receiver<Event>,
+        predicate: impl Fn(&Event) -> bool + Send + 'static,
+    ) -> Self {
+        Self {
+            rx,
+            predicate: Box::new(predicate),
+        }
+    }
+
+    /// Waits for the next matching event.
+    ///
+    /// Errors are those of [`broadcast::Receiver::recv`]; a `Lagged` count
+    /// includes events that would have been filtered out.
+    pub async fn recv(&mut self) -> Result<Event, RecvError> {
+        loop {
+            let event = self.rx.recv().await?;
+            if (self.predicate)(&event) {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Returns the next matching event that is already queued.
+    ///
+    /// Non-matching events ahead of it are consumed. Returns
+    /// `TryRecvError::Empty` once the queue holds no matching event.
+    pub fn try_recv(&mut self) -> Result<Event, TryRecvError> {
+        loop {
+            let event = self.rx.try_recv()?;
+            if (self.predicate)(&event) {
+                return Ok(event);
+            }
+        }
+    }
+}
+
+/// Matches events that reference a single entity.
+///
+/// An event references the entity when the entity is the acting entity, or,
+/// for executed actions, when the action added, removed or changed it.
+/// Session-wide events (restores, undos, halts) and proof events reference no
+/// entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityFilter {
+    entity: EntityId,
+}
+
+impl EntityFilter {
+    /// Creates a filter for `entity`.
+    pub fn new(entity: EntityId) -> Self {
+        Self { entity }
+    }
+
+    /// Returns true if `event` references the entity.
+    pub fn matches(&self, event: &Event) -> bool {
+        match event {
+            Event::GameState(GameStateEvent::ActionExecuted { action, delta, .. }) => {
+                let actors = &delta.entities.actors;
+                action.actor() == self.entity
+                    || actors.added.contains(&self.entity)
+                    || actors.removed.contains(&self.entity)
+                    || actors.updated.iter().any(|change| change.id == self.entity)
+            }
+            Event::GameState(GameStateEvent::ActionFailed { action, .. }) => {
+                action.actor() == self.entity
+            }
+            Event::GameState(
+                GameStateEvent::TurnTimedOut { actor: entity, .. }
+                | GameStateEvent::HealthThresholdCrossed { entity, .. }
+                | GameStateEvent::EquipmentBroke { entity, .. },
+            ) => *entity == self.entity,
+            Event::ActionRef(action_ref) => action_ref.actor == self.entity,
+            Event::GameState(
+                GameStateEvent::StateRestored { .. }
+                | GameStateEvent::RolledBack { .. }
+                | GameStateEvent::SessionHalted { .. },
+            )
+            | Event::Proof(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        Action, ActionInput, ActionKind, ActionResult, CharacterAction, GameState, StateDelta,
+    };
+
+    use super::*;
+    use crate::events::{EventBus, Topic};
+
+    const GOBLIN: EntityId = EntityId(1);
+
+    fn executed(actor: EntityId, before: &GameState, after: &GameState) -> Event {
+        let action = Action::character(CharacterAction::new(
+            actor,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        Event::GameState(GameStateEvent::ActionExecuted {
+            nonce: 0,
+            delta: Box::new(StateDelta::from_states(action.clone(), before, after)),
+            action,
+            clock: 0,
+            before_state: Box::new(before.clone()),
+            after_state: Box::new(after.clone()),
+            action_result: ActionResult::default(),
+        })
+    }
+
+    fn actor_of(event: &Event) -> EntityId {
+        match event {
+            Event::GameState(GameStateEvent::ActionExecuted { action, .. }) => action.actor(),
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn entity_filter_passes_only_events_touching_the_entity() {
+        let bus = EventBus::new();
+        let filter = EntityFilter::new(EntityId::PLAYER);
+        let mut rx = FilteredReceiver::new(bus.subscribe(Topic::GameState), move |event| {
+            filter.matches(event)
+        });
+
+        let state = GameState::with_player();
+        let mut hurt = state.clone();
+        hurt.entities.player_mut().resources.hp -= 1;
+
+        bus.publish(executed(GOBLIN, &state, &state));
+        bus.publish(executed(EntityId::PLAYER, &state, &state));
+        bus.publish(executed(GOBLIN, &state, &hurt));
+        bus.publish(executed(GOBLIN, &hurt, &hurt));
+
+        // The player's own action, then the goblin action that hurt the player
+        assert_eq!(actor_of(&rx.try_recv().unwrap()), EntityId::PLAYER);
+        assert_eq!(actor_of(&rx.try_recv().unwrap()), GOBLIN);
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+}
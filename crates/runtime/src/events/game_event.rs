@@ -4,7 +4,8 @@
 //! extracted from low-level state deltas. Event handlers react to these events to
 //! generate system actions.
 
-use game_core::{Action, EntityId, Position, Tick};
+use game_core::{Action, ActionKind, EntityId, Position, Tick};
+use serde::{Deserialize, Serialize};
 
 /// High-level game events extracted from StateDelta.
 ///
@@ -35,6 +36,13 @@ pub enum GameEvent {
         killer: Option<EntityId>,
     },
 
+    /// A new actor appeared in the world (e.g. a summoned minion).
+    EntitySpawned {
+        entity: EntityId,
+        position: Option<Position>,
+        summoner: EntityId,
+    },
+
     /// An entity moved to a new position.
     EntityMoved {
         entity: EntityId,
@@ -42,6 +50,27 @@ pub enum GameEvent {
         to: Option<Position>,
     },
 
+    /// Damage cancelled the action an entity was channeling.
+    ChannelInterrupted {
+        entity: EntityId,
+        action: ActionKind,
+    },
+
+    /// An NPC stepped onto a map exit and leaves play.
+    EntityEscaped {
+        entity: EntityId,
+        position: Position,
+    },
+
+    /// A temporary entity's lifespan ran out at the start of a turn.
+    EntityDespawned {
+        entity: EntityId,
+        position: Position,
+    },
+
+    /// A new turn was prepared: `entity` acts next, at `clock`.
+    TurnStarted { entity: EntityId, clock: Tick },
+
     /// An entity was removed from the active set.
     EntityRemovedFromActive { entity: EntityId },
 
@@ -49,9 +78,12 @@ pub enum GameEvent {
     EntityRemovedFromWorld { entity: EntityId },
 
     /// An entity's health crossed a threshold.
+    ///
+    /// `threshold` is the band the entity is in after the change.
     HealthThresholdCrossed {
         entity: EntityId,
         threshold: HealthThreshold,
+        direction: CrossDirection,
         hp_percent: u32,
     },
 
@@ -63,8 +95,40 @@ pub enum GameEvent {
     },
 }
 
+impl GameEvent {
+    /// Canonical ordering key for events extracted from one delta.
+    ///
+    /// Events sort by kind, then by entity id:
+    /// 1. `ActionCompleted`
+    /// 2. System action results (`TurnStarted`, `EntityRemovedFromActive`,
+    ///    `EntityRemovedFromWorld`)
+    /// 3. Direct state changes (`DamageTaken`, `EntitySpawned`, `EntityMoved`, `ReadyAtUpdated`)
+    /// 4. Derived events (`HealthThresholdCrossed`, `ChannelInterrupted`, `EntityEscaped`,
+    ///    `EntityDespawned`, `EntityDied`)
+    ///
+    /// Deaths come last so every other handler sees an event before the
+    /// death cleanup it triggers.
+    pub fn order(&self) -> (u8, EntityId) {
+        match self {
+            Self::ActionCompleted { actor, .. } => (0, *actor),
+            Self::TurnStarted { entity, .. } => (1, *entity),
+            Self::EntityRemovedFromActive { entity } => (2, *entity),
+            Self::EntityRemovedFromWorld { entity } => (3, *entity),
+            Self::DamageTaken { entity, .. } => (4, *entity),
+            Self::EntitySpawned { entity, .. } => (5, *entity),
+            Self::EntityMoved { entity, .. } => (6, *entity),
+            Self::ReadyAtUpdated { entity, .. } => (7, *entity),
+            Self::HealthThresholdCrossed { entity, .. } => (8, *entity),
+            Self::ChannelInterrupted { entity, .. } => (9, *entity),
+            Self::EntityEscaped { entity, .. } => (10, *entity),
+            Self::EntityDespawned { entity, .. } => (11, *entity),
+            Self::EntityDied { entity, .. } => (12, *entity),
+        }
+    }
+}
+
 /// Health threshold levels for triggering effects.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HealthThreshold {
     /// 100% HP
     Full,
@@ -96,3 +160,12 @@ impl HealthThreshold {
         }
     }
 }
+
+/// Direction in which an entity's health crossed a threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossDirection {
+    /// HP fraction dropped (damage)
+    Down,
+    /// HP fraction rose (healing)
+    Up,
+}
@@ -5,13 +5,16 @@
 
 mod bus;
 mod extractor;
+mod filter;
 mod game_event;
 mod types;
 
 pub use bus::{Event, EventBus, Topic};
 pub use extractor::extract_events;
-pub use game_event::{GameEvent, HealthThreshold};
+pub(crate) use extractor::{broken_equipment, health_threshold_crossings};
+pub use filter::{EntityFilter, FilteredReceiver};
+pub use game_event::{CrossDirection, GameEvent, HealthThreshold};
 pub use types::{ActionRef, GameStateEvent, ProofEvent};
 
 // Re-export for backwards compatibility
-pub use types::{ProofBackend, ProofData};
+pub use types::{ProofBackend, ProofData, ProofStage};
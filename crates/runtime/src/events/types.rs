@@ -1,13 +1,15 @@
 //! Event types for different topics.
 
 use game_core::{
-    Action, ActionKind, ActionResult, EntityId, GameState, StateDelta, Tick,
+    Action, ActionKind, ActionResult, EntityId, GameState, ItemHandle, StateDelta, Tick,
     engine::TransitionPhase,
 };
 use serde::{Deserialize, Serialize};
 
+use super::game_event::{CrossDirection, HealthThreshold};
+
 // Re-export ProofData from zk crate
-pub use zk::{ProofBackend, ProofData};
+pub use zk::{ProofBackend, ProofData, ProofStage};
 
 /// Events related to game state changes (actions, failures)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,12 +31,58 @@ pub enum GameStateEvent {
         nonce: u64,
         action: Action,
         phase: TransitionPhase,
+        /// Stable machine-readable code (e.g. `ACTION_BLOCKED`) from
+        /// [`game_core::GameError::error_code`], for localization and UI reactions.
+        error_code: String,
         error: String,
         clock: Tick,
     },
 
+    /// An interactive player ran out of time and the default action was submitted
+    ///
+    /// Published before the substituted `action` executes.
+    TurnTimedOut {
+        actor: EntityId,
+        clock: Tick,
+        action: Action,
+    },
+
+    /// An actor's health moved into another [`HealthThreshold`] band
+    ///
+    /// Published right after the `ActionExecuted` whose delta caused it.
+    /// `threshold` is the band the actor is in now.
+    HealthThresholdCrossed {
+        entity: EntityId,
+        threshold: HealthThreshold,
+        direction: CrossDirection,
+    },
+
+    /// An equipped weapon or armor ran out of durability and was unequipped
+    ///
+    /// Published right after the `ActionExecuted` whose delta caused it.
+    EquipmentBroke { entity: EntityId, item: ItemHandle },
+
     /// Game state was restored from a checkpoint (load game)
     StateRestored { from_nonce: u64, to_nonce: u64 },
+
+    /// The most recent executed action was undone
+    ///
+    /// `state` is the state from right before that action, now current.
+    RolledBack {
+        from_nonce: u64,
+        to_nonce: u64,
+        state: Box<GameState>,
+    },
+
+    /// The session hit a fatal error and stopped executing actions.
+    ///
+    /// This is terminal: no further actions execute until a checkpoint is restored.
+    SessionHalted {
+        nonce: u64,
+        clock: Tick,
+        error_code: String,
+        reason: String,
+    },
 }
 
 /// Events related to ZK proof generation
@@ -43,6 +91,15 @@ pub enum ProofEvent {
     /// ZK proof generation started for an action
     ProofStarted { action: Action, clock: Tick },
 
+    /// A batch proof entered a new stage (witness, prove, serialize)
+    ///
+    /// Emitted once per stage, in order, before `ProofGenerated` or `ProofFailed`.
+    ProofProgress {
+        /// Start nonce of the batch being proven
+        batch: u64,
+        stage: ProofStage,
+    },
+
     /// ZK proof successfully generated (already verified by zkVM)
     ProofGenerated {
         action: Action,
@@ -52,3 +52,164 @@ impl SystemActionHandler for ActivationHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::stats::initiative_delay;
+    use game_core::{
+        ActivationShape, ActorTemplate, CardinalDirection, CoreStats, Faction, GameConfig,
+        GameEngine, GameState, Position, PrepareTurnAction, TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+
+    const PLAYER_POS: Position = Position { x: 10, y: 10 };
+
+    /// Runs activation around a player at (10, 10) with goblins at the given
+    /// offsets, and returns which goblins were activated.
+    fn activate(shape: ActivationShape, offsets: &[(i32, i32)]) -> Vec<bool> {
+        let config = GameConfig {
+            activation_shape: shape,
+            ..GameConfig::default()
+        };
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(32, 32)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(config)),
+        );
+        let template = |faction| {
+            ActorTemplate::builder()
+                .faction(faction)
+                .trait_profile(TraitProfile::default())
+                .build()
+        };
+
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), PLAYER_POS)
+            .unwrap();
+        // NPCs are added inactive
+        let goblins: Vec<EntityId> = offsets
+            .iter()
+            .map(|&(dx, dy)| {
+                let position = Position::new(PLAYER_POS.x + dx, PLAYER_POS.y + dy);
+                state
+                    .add_npc(&template(Faction::GoblinClan), position)
+                    .unwrap()
+            })
+            .collect();
+
+        let activation = Action::system(SystemActionKind::Activation(ActivationAction));
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &activation)
+            .unwrap();
+
+        goblins
+            .iter()
+            .map(|goblin| state.turn.active_actors.contains(goblin))
+            .collect()
+    }
+
+    #[test]
+    fn activation_follows_the_configured_shape() {
+        let offsets = [(3, 3), (4, 0), (-3, 0), (0, 5)];
+
+        assert_eq!(
+            activate(ActivationShape::Chebyshev(3), &offsets),
+            [true, false, true, false]
+        );
+        assert_eq!(
+            activate(ActivationShape::Euclidean(4), &offsets),
+            [false, true, true, false]
+        );
+        let cone = ActivationShape::Cone {
+            range: 5,
+            facing: CardinalDirection::East,
+            half_angle_deg: 45,
+        };
+        assert_eq!(activate(cone, &offsets), [true, true, false, false]);
+    }
+
+    /// Activates a slow NPC and a fast NPC on the same tick, then prepares the
+    /// next turn with the player parked out of the way.
+    ///
+    /// The slow NPC is spawned first so it holds the lower entity id.
+    fn activate_slow_and_fast() -> (GameState, EntityId, EntityId) {
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(8, 8)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        );
+
+        let mut state = GameState::empty();
+        let player = ActorTemplate::builder()
+            .faction(Faction::Player)
+            .trait_profile(TraitProfile::default())
+            .build();
+        state.add_player(&player, Position::new(2, 2)).unwrap();
+        let npc = |dex| {
+            ActorTemplate::builder()
+                .stats(CoreStats::new(10, 10, dex, 10, 10, 10, 1))
+                .faction(Faction::Hostile)
+                .trait_profile(TraitProfile::default())
+                .build()
+        };
+        let slow = state.add_npc(&npc(5), Position::new(4, 4)).unwrap();
+        let fast = state.add_npc(&npc(30), Position::new(5, 5)).unwrap();
+
+        let activation = Action::system(SystemActionKind::Activation(ActivationAction));
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &activation)
+            .unwrap();
+
+        state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(1_000);
+        let prepare = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &prepare)
+            .unwrap();
+
+        (state, slow, fast)
+    }
+
+    #[test]
+    fn faster_npc_acts_first_after_activation() {
+        let (state, slow, fast) = activate_slow_and_fast();
+        let window = u64::from(GameConfig::default().initiative_window);
+
+        let ready_at = |id| state.entities.actor(id).unwrap().ready_at.unwrap();
+        let speed = |id| state.entities.actor(id).unwrap().snapshot().speed.physical;
+        assert_eq!(ready_at(slow), initiative_delay(window, speed(slow)));
+        assert_eq!(ready_at(fast), initiative_delay(window, speed(fast)));
+        assert!(ready_at(fast) < ready_at(slow));
+        assert!(ready_at(slow) <= window * 2);
+
+        assert_eq!(state.turn.current_actor, fast);
+        assert_eq!(state.turn.clock, ready_at(fast));
+    }
+
+    #[test]
+    fn activation_order_is_stable_across_runs() {
+        let (first, _, fast) = activate_slow_and_fast();
+        for _ in 0..3 {
+            let (again, _, _) = activate_slow_and_fast();
+            assert_eq!(again.turn.current_actor, fast);
+            assert_eq!(again.turn.clock, first.turn.clock);
+            for actor in first.entities.all_actors() {
+                assert_eq!(
+                    again.entities.actor(actor.id).unwrap().ready_at,
+                    actor.ready_at
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Handler for dead actors and empty items left in the world.
+
+use game_core::action::{Action, DeactivateAction, RemoveFromWorldAction, SystemActionKind};
+use game_core::env::ConfigOracle;
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Handler that clears long-dead actors and empty ground items off the map.
+///
+/// Reacts to TurnStarted events. An actor that has been dead for longer than
+/// the configured corpse grace period while still on the map (typically an
+/// NPC left downed for a reviver that never came) is deactivated if needed
+/// and removed from the world. Ground items with a quantity of 0 are removed
+/// as well.
+///
+/// Dead actors within the grace period are left alone, so they can still be
+/// revived.
+#[derive(Debug, Clone, Copy)]
+pub struct CleanupHandler;
+
+impl SystemActionHandler for CleanupHandler {
+    fn name(&self) -> &'static str {
+        "cleanup"
+    }
+
+    fn priority(&self) -> i32 {
+        -25 // After lifespan, before opportunity attacks
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Optional: a missed cleanup only leaves a corpse around a while longer.
+        HandlerCriticality::Optional
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        let GameEvent::TurnStarted { clock, .. } = event else {
+            return vec![];
+        };
+        let state = ctx.state_after;
+        let grace = ctx.oracles.config.corpse_grace_period();
+
+        let mut actions = Vec::new();
+
+        let expired = state.entities.all_actors().filter(|actor| {
+            actor.position.is_some()
+                && !actor.is_alive()
+                && actor
+                    .died_at
+                    .is_some_and(|died_at| clock.saturating_sub(died_at) > grace)
+        });
+        for actor in expired {
+            tracing::info!(
+                target: "runtime::handlers::cleanup",
+                entity = ?actor.id,
+                died_at = ?actor.died_at,
+                "CleanupHandler: Removing actor dead past the grace period"
+            );
+
+            if actor.ready_at.is_some() || state.turn.active_actors.contains(&actor.id) {
+                actions.push(Action::system(SystemActionKind::Deactivate(
+                    DeactivateAction::new(actor.id),
+                )));
+            }
+            actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(actor.id),
+            )));
+        }
+
+        for item in state.entities.all_items().filter(|item| item.quantity == 0) {
+            tracing::info!(
+                target: "runtime::handlers::cleanup",
+                entity = ?item.id,
+                "CleanupHandler: Removing empty ground item"
+            );
+
+            actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(item.id),
+            )));
+        }
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::{
+        ActorTemplate, EntityId, Faction, GameConfig, GameEngine, GameState, ItemHandle, ItemState,
+        Position, PrepareTurnAction, TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::{ConfigOracleImpl, OracleBundle};
+    use crate::providers::SystemActionProvider;
+
+    const GRACE: u64 = 300;
+
+    fn oracles() -> OracleBundle {
+        let config = GameConfig {
+            corpse_grace_period: GRACE,
+            ..GameConfig::default()
+        };
+        let mut oracles = OracleBundle::test_bundle(16, 16);
+        oracles.config = Arc::new(ConfigOracleImpl::new(config));
+        oracles
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    /// Player at (1, 1) and a goblin downed at (5, 5) on tick 0.
+    fn downed_goblin() -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(1, 1))
+            .unwrap();
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+        state.entities.actor_mut(goblin).unwrap().resources.hp = 0;
+        state.record_deaths();
+        (state, goblin)
+    }
+
+    /// Prepares the player's turn at `clock` and returns the cleanup actions.
+    fn cleanup_at(state: &mut GameState, oracles: &OracleBundle, clock: u64) -> Vec<Action> {
+        state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(clock);
+
+        let before = state.clone();
+        let action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let outcome = GameEngine::new(state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+        assert_eq!(state.turn.clock, clock);
+
+        let mut provider = SystemActionProvider::new();
+        provider.add_handler(Box::new(CleanupHandler));
+        provider
+            .generate_actions(&outcome.delta, &before, state, oracles)
+            .into_iter()
+            .map(|(action, _, _)| action)
+            .collect()
+    }
+
+    #[test]
+    fn dead_actor_is_removed_only_after_the_grace_period() {
+        let oracles = oracles();
+        let (mut state, goblin) = downed_goblin();
+        assert_eq!(state.entities.actor(goblin).unwrap().died_at, Some(0));
+
+        assert!(cleanup_at(&mut state, &oracles, GRACE).is_empty());
+
+        let actions = cleanup_at(&mut state, &oracles, GRACE + 1);
+        assert_eq!(
+            actions,
+            vec![Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(goblin),
+            ))]
+        );
+
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &actions[0])
+            .unwrap();
+        assert_eq!(state.entities.actor(goblin).unwrap().position, None);
+    }
+
+    #[test]
+    fn revived_actor_is_not_removed() {
+        let oracles = oracles();
+        let (mut state, goblin) = downed_goblin();
+
+        state.entities.actor_mut(goblin).unwrap().resources.hp = 5;
+        state.record_deaths();
+        assert_eq!(state.entities.actor(goblin).unwrap().died_at, None);
+
+        assert!(cleanup_at(&mut state, &oracles, GRACE + 1).is_empty());
+    }
+
+    #[test]
+    fn empty_ground_items_are_removed() {
+        let oracles = oracles();
+        let (mut state, _) = downed_goblin();
+        let position = Position::new(2, 2);
+        let item = state.allocate_entity_id().unwrap();
+        state
+            .entities
+            .items
+            .push(ItemState::new(item, position, ItemHandle(1), 0))
+            .unwrap();
+        state.world.tile_map.add_occupant(position, item);
+
+        let actions = cleanup_at(&mut state, &oracles, 1);
+        assert_eq!(
+            actions,
+            vec![Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(item),
+            ))]
+        );
+
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &actions[0])
+            .unwrap();
+        assert!(state.entities.item(item).is_none());
+        assert!(state.world.tile_map.occupants(&position).is_none());
+    }
+}
@@ -1,6 +1,9 @@
 //! Handler for entity death.
 
-use game_core::action::{Action, DeactivateAction, RemoveFromWorldAction, SystemActionKind};
+use game_core::action::{
+    Action, DeactivateAction, DropLootAction, RemoveFromWorldAction, SystemActionKind,
+};
+use game_core::{ActionKind, ActorState, EntityId, GameState};
 
 use super::{EventContext, HandlerCriticality};
 use crate::events::GameEvent;
@@ -10,8 +13,13 @@ use crate::providers::SystemActionHandler;
 ///
 /// This handler reacts to EntityDied events and generates system actions
 /// to properly remove dead entities from the game world:
-/// 1. RemoveFromWorld - Clears position and world occupancy
-/// 2. Deactivate - Removes from active set and turn scheduling
+/// 1. Deactivate - Removes from active set and turn scheduling
+/// 2. DropLoot - Spills the inventory onto the map as ground items
+/// 3. RemoveFromWorld - Clears position and world occupancy
+///
+/// Loot is dropped before removal, while the entity still has a position.
+/// An NPC with a living ally able to revive it is only deactivated: it stays
+/// on the map as "downed" (0 HP, still positioned) until revived.
 ///
 /// # Design Philosophy
 ///
@@ -60,16 +68,37 @@ impl SystemActionHandler for DeathHandler {
                         )));
                     }
 
-                    // If entity has a position, remove from world
+                    // If entity has a position, remove from world unless an ally
+                    // can still revive it
                     if actor.position.is_some() {
-                        tracing::info!(
-                            target: "runtime::handlers::death",
-                            entity = ?entity,
-                            "DeathHandler: Generating RemoveFromWorldAction (entity has position)"
-                        );
-                        actions.push(Action::system(SystemActionKind::RemoveFromWorld(
-                            RemoveFromWorldAction::new(*entity),
-                        )));
+                        if can_be_revived(actor, ctx.state_after) {
+                            tracing::info!(
+                                target: "runtime::handlers::death",
+                                entity = ?entity,
+                                "DeathHandler: Leaving entity downed (an ally can revive it)"
+                            );
+                        } else {
+                            if !actor.inventory.items.is_empty() {
+                                tracing::info!(
+                                    target: "runtime::handlers::death",
+                                    entity = ?entity,
+                                    slots = actor.inventory.items.len(),
+                                    "DeathHandler: Generating DropLootAction (entity carries items)"
+                                );
+                                actions.push(Action::system(SystemActionKind::DropLoot(
+                                    DropLootAction::new(*entity),
+                                )));
+                            }
+
+                            tracing::info!(
+                                target: "runtime::handlers::death",
+                                entity = ?entity,
+                                "DeathHandler: Generating RemoveFromWorldAction (entity has position)"
+                            );
+                            actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+                                RemoveFromWorldAction::new(*entity),
+                            )));
+                        }
                     }
 
                     if actions.is_empty() {
@@ -93,3 +122,177 @@ impl SystemActionHandler for DeathHandler {
         }
     }
 }
+
+/// Checks whether a living, positioned ally of a dead NPC knows Revive.
+///
+/// The player is never left downed.
+fn can_be_revived(actor: &ActorState, state: &GameState) -> bool {
+    actor.id != EntityId::PLAYER
+        && state.entities.all_actors().any(|ally| {
+            ally.id != actor.id
+                && ally.is_alive()
+                && ally.position.is_some()
+                && ally.has_action(ActionKind::Revive)
+                && !ally.faction.is_hostile_to(&actor.faction)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActionInput, ActorTemplate, CharacterAction, ErrorSeverity, ExecuteError, Faction,
+        GameConfig, GameEngine, GameError, ItemHandle, ItemState, Position, StateDelta,
+        TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::OracleBundle;
+    use crate::providers::SystemActionProvider;
+
+    const GOBLIN_POS: Position = Position { x: 6, y: 5 };
+    const SWORD: ItemHandle = ItemHandle(10);
+    const POTION: ItemHandle = ItemHandle(11);
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(16, 16)
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    /// Player plus a goblin carrying a sword and three potions.
+    fn state_with_looted_goblin() -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(5, 5))
+            .unwrap();
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), GOBLIN_POS)
+            .unwrap();
+
+        let inventory = &mut state.entities.actor_mut(goblin).unwrap().inventory;
+        inventory.add_item(SWORD, 1, 1);
+        inventory.add_item(POTION, 3, 99);
+        (state, goblin)
+    }
+
+    /// Drops `entity` to 0 HP and runs the death cleanup it triggers.
+    fn kill(
+        state: &mut GameState,
+        entity: EntityId,
+        oracles: &OracleBundle,
+    ) -> Result<(), ExecuteError> {
+        let mut provider = SystemActionProvider::new();
+        provider.add_handler(Box::new(DeathHandler));
+
+        let before = state.clone();
+        state.entities.actor_mut(entity).unwrap().resources.hp = 0;
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        let delta = StateDelta::from_states(action, &before, state);
+
+        let cleanup = provider.generate_actions(&delta, &before, state, oracles);
+        for (action, _, _) in cleanup {
+            GameEngine::new(state).execute(oracles.as_game_env(), &action)?;
+        }
+        Ok(())
+    }
+
+    /// `state` with only `remaining` entity ids left, as late in a long session.
+    ///
+    /// The allocator is private, so this round-trips the state through RON.
+    fn with_remaining_ids(state: &GameState, remaining: u32) -> GameState {
+        let next = EntityId::SYSTEM.0 - state.remaining_entity_ids();
+        let ron = ron::to_string(state).unwrap().replacen(
+            &format!("next_entity_id:{next},"),
+            &format!("next_entity_id:{},", EntityId::SYSTEM.0 - remaining),
+            1,
+        );
+        ron::from_str(&ron).unwrap()
+    }
+
+    fn dropped(state: &GameState, handle: ItemHandle) -> &ItemState {
+        state
+            .entities
+            .all_items()
+            .find(|item| item.handle == handle)
+            .expect("item was not dropped")
+    }
+
+    #[test]
+    fn death_drops_every_inventory_item() {
+        let oracles = oracles();
+        let (mut state, goblin) = state_with_looted_goblin();
+
+        kill(&mut state, goblin, &oracles).unwrap();
+
+        assert_eq!(state.entities.items.len(), 2);
+        assert_eq!(dropped(&state, SWORD).quantity, 1);
+        assert_eq!(dropped(&state, POTION).quantity, 3);
+        for item in state.entities.all_items() {
+            assert_eq!(item.position, GOBLIN_POS);
+            let occupants = state.world.tile_map.occupants(&GOBLIN_POS).unwrap();
+            assert!(occupants.contains(&item.id));
+        }
+
+        let corpse = state.entities.actor(goblin).unwrap();
+        assert!(corpse.inventory.items.is_empty());
+        assert_eq!(corpse.position, None);
+    }
+
+    #[test]
+    fn death_on_a_crowded_tile_spills_to_neighbours() {
+        let oracles = oracles();
+        let (mut state, goblin) = state_with_looted_goblin();
+
+        // The goblin and three rocks fill every occupant slot of its tile
+        for _ in 1..GameConfig::MAX_OCCUPANTS_PER_TILE {
+            let id = state.allocate_entity_id().unwrap();
+            state
+                .entities
+                .items
+                .push(ItemState::new(id, GOBLIN_POS, ItemHandle(1), 1))
+                .unwrap();
+            state.world.tile_map.add_occupant(GOBLIN_POS, id);
+        }
+
+        kill(&mut state, goblin, &oracles).unwrap();
+
+        for handle in [SWORD, POTION] {
+            let item = dropped(&state, handle);
+            assert_eq!(item.position.chebyshev_distance(GOBLIN_POS), 1);
+            let occupants = state.world.tile_map.occupants(&item.position).unwrap();
+            assert!(occupants.contains(&item.id));
+        }
+        assert!(
+            state
+                .entities
+                .actor(goblin)
+                .unwrap()
+                .inventory
+                .items
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn running_out_of_entity_ids_while_dropping_loot_is_fatal() {
+        let oracles = oracles();
+        let (state, goblin) = state_with_looted_goblin();
+        // Room for the sword, but not the potions
+        let mut state = with_remaining_ids(&state, 1);
+
+        let error = kill(&mut state, goblin, &oracles).unwrap_err();
+
+        assert_eq!(error.severity(), ErrorSeverity::Fatal);
+        assert_eq!(error.error_code(), "DROP_LOOT_ENTITY_IDS_EXHAUSTED");
+        assert_eq!(state.remaining_entity_ids(), 0);
+    }
+}
@@ -0,0 +1,63 @@
+//! Handler for NPCs leaving the map through an exit.
+
+use game_core::action::{Action, DeactivateAction, RemoveFromWorldAction, SystemActionKind};
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Handler that removes NPCs which reached a map exit.
+///
+/// Reacts to EntityEscaped events with the same cleanup as a death, minus the
+/// corpse: the NPC is deactivated and taken off the map, but keeps its HP and
+/// inventory in the entity list.
+#[derive(Debug, Clone, Copy)]
+pub struct EscapeHandler;
+
+impl SystemActionHandler for EscapeHandler {
+    fn name(&self) -> &'static str {
+        "escape"
+    }
+
+    fn priority(&self) -> i32 {
+        -40 // After death, before activation
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Critical: an escaped NPC left behind would keep taking turns and
+        // blocking the exit tile.
+        HandlerCriticality::Critical
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        let GameEvent::EntityEscaped { entity, position } = event else {
+            return vec![];
+        };
+        let Some(actor) = ctx.state_after.entities.actor(*entity) else {
+            return vec![];
+        };
+
+        tracing::info!(
+            target: "runtime::handlers::escape",
+            entity = ?entity,
+            position = ?position,
+            "EscapeHandler: NPC reached an exit"
+        );
+
+        let mut actions = Vec::new();
+
+        // Deactivate first, as in DeathHandler
+        if actor.ready_at.is_some() || ctx.state_after.turn.active_actors.contains(entity) {
+            actions.push(Action::system(SystemActionKind::Deactivate(
+                DeactivateAction::new(*entity),
+            )));
+        }
+        if actor.position.is_some() {
+            actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(*entity),
+            )));
+        }
+
+        actions
+    }
+}
@@ -0,0 +1,59 @@
+//! Handler for temporary entities whose lifespan ran out.
+
+use game_core::action::{Action, DeactivateAction, RemoveFromWorldAction, SystemActionKind};
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Handler that despawns summoned minions and other temporary entities.
+///
+/// Reacts to EntityDespawned events, raised at turn start for every entity
+/// whose `Lifespan` status has expired. The entity is deactivated and taken
+/// off the map, freeing its tile.
+#[derive(Debug, Clone, Copy)]
+pub struct LifespanHandler;
+
+impl SystemActionHandler for LifespanHandler {
+    fn name(&self) -> &'static str {
+        "lifespan"
+    }
+
+    fn priority(&self) -> i32 {
+        -30 // After death and escape, before activation
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Critical: an expired entity left behind would keep taking turns.
+        HandlerCriticality::Critical
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        let GameEvent::EntityDespawned { entity, position } = event else {
+            return vec![];
+        };
+        let Some(actor) = ctx.state_after.entities.actor(*entity) else {
+            return vec![];
+        };
+
+        tracing::info!(
+            target: "runtime::handlers::lifespan",
+            entity = ?entity,
+            position = ?position,
+            "LifespanHandler: lifespan expired"
+        );
+
+        let mut actions = Vec::new();
+
+        if actor.ready_at.is_some() || ctx.state_after.turn.active_actors.contains(entity) {
+            actions.push(Action::system(SystemActionKind::Deactivate(
+                DeactivateAction::new(*entity),
+            )));
+        }
+        actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+            RemoveFromWorldAction::new(*entity),
+        )));
+
+        actions
+    }
+}
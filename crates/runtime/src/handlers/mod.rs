@@ -7,10 +7,18 @@
 //! within game-core, so ActionCostHandler has been removed.
 
 mod activation;
+mod cleanup;
 mod death;
+mod escape;
+mod lifespan;
+mod opportunity;
 
 pub use activation::ActivationHandler;
+pub use cleanup::CleanupHandler;
 pub use death::DeathHandler;
+pub use escape::EscapeHandler;
+pub use lifespan::LifespanHandler;
+pub use opportunity::OpportunityAttackHandler;
 
 use game_core::GameState;
 
@@ -0,0 +1,213 @@
+//! Handler for opportunity attacks provoked by movement.
+
+use game_core::action::{Action, ActionKind, OpportunityAttackAction, SystemActionKind};
+use game_core::{EntityId, Position};
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Handler that lets hostile actors strike at an actor leaving their reach.
+///
+/// Reacts to completed `Move` actions. When the mover steps from a tile next
+/// to a hostile actor to a tile out of its reach, and that actor's melee
+/// attack is readied, an OpportunityAttack system action is generated.
+///
+/// At most one reaction is generated per move, from the threatening actor
+/// with the lowest `EntityId`. The reaction is a system action, not a move,
+/// so it never provokes further reactions.
+#[derive(Debug, Clone, Copy)]
+pub struct OpportunityAttackHandler;
+
+impl SystemActionHandler for OpportunityAttackHandler {
+    fn name(&self) -> &'static str {
+        "opportunity_attack"
+    }
+
+    fn priority(&self) -> i32 {
+        -20 // After cleanup, before activation
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Optional: a missed reaction changes the fight, not state consistency.
+        HandlerCriticality::Optional
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        let GameEvent::ActionCompleted {
+            actor,
+            action: Action::Character(action),
+            ..
+        } = event
+        else {
+            return vec![];
+        };
+        if action.kind != ActionKind::Move {
+            return vec![];
+        }
+
+        let from = ctx
+            .state_before
+            .entities
+            .actor(*actor)
+            .and_then(|mover| mover.position);
+        let (Some(from), Some(mover)) = (from, ctx.state_after.entities.actor(*actor)) else {
+            return vec![];
+        };
+        let Some(to) = mover.position else {
+            return vec![];
+        };
+
+        let Some(attacker) = threatening_actor(*actor, from, to, ctx) else {
+            return vec![];
+        };
+
+        tracing::info!(
+            target: "runtime::handlers::opportunity",
+            attacker = ?attacker,
+            target_entity = ?actor,
+            from = ?from,
+            "OpportunityAttackHandler: Move provoked a reaction"
+        );
+
+        vec![Action::system(SystemActionKind::OpportunityAttack(
+            OpportunityAttackAction::new(attacker, *actor, from),
+        ))]
+    }
+}
+
+/// Finds the actor whose reach `mover` left by stepping from `from` to `to`.
+///
+/// Candidates are living actors hostile to the mover with a readied melee
+/// attack, adjacent to `from` but not to `to`. Returns the lowest `EntityId`.
+fn threatening_actor(
+    mover: EntityId,
+    from: Position,
+    to: Position,
+    ctx: &EventContext,
+) -> Option<EntityId> {
+    let state = ctx.state_after;
+    let faction = state.entities.actor(mover)?.faction;
+
+    state
+        .entities
+        .all_actors()
+        .filter(|actor| {
+            actor.id != mover
+                && actor.is_alive()
+                && actor.faction.is_hostile_to(&faction)
+                && actor.can_use_action(ActionKind::MeleeAttack, state.turn.clock)
+        })
+        .filter(|actor| {
+            actor.position.is_some_and(|position| {
+                position.chebyshev_distance(from) <= 1 && position.chebyshev_distance(to) > 1
+            })
+        })
+        .map(|actor| actor.id)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActionAbility, ActionInput, ActorTemplate, CardinalDirection, CharacterAction, Faction,
+        GameEngine, GameState, PropKind, PropState, TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::OracleBundle;
+    use crate::providers::SystemActionProvider;
+
+    const START: Position = Position { x: 5, y: 5 };
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(16, 16)
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    fn player_at_start() -> GameState {
+        let mut state = GameState::empty();
+        state.add_player(&template(Faction::Player), START).unwrap();
+        state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .actions
+            .push(ActionAbility::new(ActionKind::Move));
+        state
+    }
+
+    /// Moves the player one tile west and returns the generated reactions.
+    fn step_west(state: &mut GameState, oracles: &OracleBundle) -> Vec<Action> {
+        let mut provider = SystemActionProvider::new();
+        provider.add_handler(Box::new(OpportunityAttackHandler));
+
+        let before = state.clone();
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::West),
+        ));
+        let outcome = GameEngine::new(state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+
+        provider
+            .generate_actions(&outcome.delta, &before, state, oracles)
+            .into_iter()
+            .map(|(action, _, _)| action)
+            .collect()
+    }
+
+    #[test]
+    fn leaving_an_adjacent_enemy_provokes_one_attack() {
+        let oracles = oracles();
+        let mut state = player_at_start();
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(6, 5))
+            .unwrap();
+        let goblin_actor = state.entities.actor_mut(goblin).unwrap();
+        goblin_actor
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+        goblin_actor.ready_at = Some(100);
+
+        let reactions = step_west(&mut state, &oracles);
+        assert_eq!(
+            reactions,
+            vec![Action::system(SystemActionKind::OpportunityAttack(
+                OpportunityAttackAction::new(goblin, EntityId::PLAYER, START),
+            ))]
+        );
+
+        // The goblin strikes from two tiles away without losing its turn
+        let hp = state.entities.player().resources.hp;
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &reactions[0])
+            .unwrap();
+        assert!(state.entities.player().resources.hp < hp);
+        assert_eq!(state.entities.actor(goblin).unwrap().ready_at, Some(100));
+    }
+
+    #[test]
+    fn leaving_a_prop_provokes_nothing() {
+        let oracles = oracles();
+        let mut state = player_at_start();
+        let lever = state.allocate_entity_id().unwrap();
+        let lever_pos = Position::new(6, 5);
+        state
+            .entities
+            .props
+            .push(PropState::new(lever, lever_pos, PropKind::Switch, false))
+            .unwrap();
+        state.world.tile_map.add_occupant(lever_pos, lever);
+
+        assert!(step_west(&mut state, &oracles).is_empty());
+    }
+}
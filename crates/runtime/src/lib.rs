@@ -14,6 +14,7 @@
 //! - [`handlers`] provides event-based reactive action generation
 //! - [`oracle`] and [`repository`] provide data adapters reused by other crates
 //! - [`scenario`] provides entity placement and game initialization
+//! - [`proving`] rebuilds proof batches and session manifests from recorded action logs
 //! - [`types`] provides common type aliases for semantic clarity
 //! - [`blockchain`] provides blockchain client integration (optional, feature-gated)
 pub mod api;
@@ -22,6 +23,7 @@ pub mod events;
 pub mod handlers;
 pub mod oracle;
 pub mod providers;
+pub mod proving;
 pub mod repository;
 pub mod runtime;
 pub mod scenario;
@@ -31,21 +33,25 @@ mod utils;
 mod workers;
 
 pub use api::{
-    ActionProvider, AiKind, InteractiveKind, ProviderKind, ProviderRegistry, Result, RuntimeError,
-    RuntimeHandle,
+    ActionProvider, AiKind, InteractiveKind, ProofMetricsSnapshot, ProviderKind, ProviderRegistry,
+    Result, RuntimeError, RuntimeHandle,
 };
 #[cfg(feature = "sui")]
 pub use blockchain::BlockchainClients;
 pub use events::{
-    Event, EventBus, GameEvent, GameStateEvent, HealthThreshold, ProofEvent, Topic, extract_events,
+    CrossDirection, EntityFilter, Event, EventBus, FilteredReceiver, GameEvent, GameStateEvent,
+    HealthThreshold, ProofEvent, Topic, extract_events,
+};
+pub use handlers::{
+    ActivationHandler, CleanupHandler, DeathHandler, EscapeHandler, EventContext,
+    HandlerCriticality, LifespanHandler, OpportunityAttackHandler,
 };
-pub use handlers::{ActivationHandler, DeathHandler, EventContext, HandlerCriticality};
 pub use oracle::{
     ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
     OracleBundle,
 };
 pub use providers::ai::{AiContext, UtilityAiProvider};
-pub use providers::{SystemActionHandler, SystemActionProvider};
+pub use providers::{ReplayActionProvider, SystemActionHandler, SystemActionProvider};
 pub use repository::{
     ActionBatch, ActionBatchRepository, ActionBatchStatus, ActionLogEntry, ActionLogReader,
     ActionLogWriter, EventRepository, FileActionBatchRepository, FileActionLog,
@@ -54,8 +60,8 @@ pub use repository::{
 };
 pub use runtime::{
     BlockchainSessionData, PersistenceSettings, ProvingSettings, Runtime, RuntimeBuilder,
-    RuntimeConfig, SessionInit,
+    RuntimeConfig, SessionInit, TurnTimer,
 };
 pub use scenario::{EntityKind, EntityPlacement, Scenario};
 pub use types::{ByteOffset, DurationMs, Nonce, ProofSize, SessionId, StateHash, Timestamp};
-pub use workers::{CheckpointStrategy, PersistenceConfig, ProofMetrics};
+pub use workers::{CheckpointStrategy, PersistenceConfig, ProofMetrics, ValidationMode};
@@ -32,6 +32,13 @@ impl ActionOracleImpl {
         }
     }
 
+    /// Create from an already loaded registry (e.g. with data directory overrides).
+    pub fn from_registry(action_profiles: ActionProfileRegistry) -> Self {
+        Self {
+            action_profiles: Arc::new(action_profiles),
+        }
+    }
+
     /// Create with default profiles for testing.
     pub fn test_actions() -> Self {
         Self::new()
@@ -43,3 +50,144 @@ impl ActionOracle for ActionOracleImpl {
         self.action_profiles.get(kind).clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::action::AppliedValue;
+    use game_core::stats::calculate_action_cost;
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActorTemplate, CharacterAction, EntityId, Faction,
+        GameConfig, GameEngine, GameState, Position, TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::{
+        ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl, OracleBundle,
+    };
+
+    const POWER_ATTACK_RON: &str = r#"[
+        ActionProfile(
+            kind: PowerAttack,
+            tags: [Attack, Melee, Physical],
+            targeting: SingleTarget(range: 1, requires_los: false),
+            base_cost: 250,
+            resource_costs: [],
+            effects: [
+                ActionEffect(
+                    kind: Damage(DamageEffect(
+                        formula: Constant(42),
+                        damage_type: Physical,
+                        can_crit: false,
+                    )),
+                    phase: Primary,
+                    priority: 0,
+                ),
+            ],
+            requirements: [],
+            cooldown: Some(300),
+        ),
+    ]"#;
+
+    fn oracles(registry: ActionProfileRegistry, items: ItemOracleImpl) -> OracleBundle {
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(8, 8)),
+            Arc::new(items),
+            Arc::new(ActionOracleImpl::from_registry(registry)),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        )
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    #[test]
+    fn engine_resolves_custom_profile() {
+        let mut registry = ActionProfileRegistry::load().unwrap();
+        registry.extend_from_ron(POWER_ATTACK_RON).unwrap();
+
+        let oracles = oracles(registry, ItemOracleImpl::new());
+
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(2, 2))
+            .unwrap();
+        let target = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(3, 2))
+            .unwrap();
+
+        let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::PowerAttack));
+        let snapshot = player.snapshot();
+        let ready_at = player.ready_at.unwrap();
+        let target_hp = state.entities.actor(target).unwrap().resources.hp;
+
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::PowerAttack,
+            ActionInput::Target(target),
+        ));
+        let outcome = GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+
+        let result = outcome.action_result.unwrap();
+        assert_eq!(result.summary.total_damage, 42);
+        assert_eq!(
+            state.entities.actor(target).unwrap().resources.hp,
+            target_hp - 42
+        );
+        assert_eq!(
+            state.entities.actor(EntityId::PLAYER).unwrap().ready_at,
+            Some(ready_at + calculate_action_cost(250, snapshot.speed.physical))
+        );
+    }
+
+    #[test]
+    fn lethal_hit_records_remaining_hp_not_overkill() {
+        let mut registry = ActionProfileRegistry::load().unwrap();
+        registry.extend_from_ron(POWER_ATTACK_RON).unwrap();
+        let oracles = oracles(registry, ItemOracleImpl::new());
+
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(2, 2))
+            .unwrap();
+        let target = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(3, 2))
+            .unwrap();
+        state.entities.actor_mut(target).unwrap().resources.hp = 10;
+        state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .actions
+            .push(ActionAbility::new(ActionKind::PowerAttack));
+
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::PowerAttack,
+            ActionInput::Target(target),
+        ));
+        let outcome = GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+
+        // The profile deals a constant 42; only the 10 HP left are recorded
+        let result = outcome.action_result.unwrap();
+        assert_eq!(
+            result.effects[0].applied_value,
+            AppliedValue::Damage { actual: 10 }
+        );
+        assert_eq!(result.summary.total_damage, 10);
+        assert_eq!(state.entities.actor(target).unwrap().resources.hp, 0);
+    }
+}
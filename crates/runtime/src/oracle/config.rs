@@ -1,6 +1,6 @@
 //! Config oracle implementation for runtime.
 
-use game_core::{GameConfig, env::ConfigOracle};
+use game_core::{ActivationShape, FactionRelations, GameConfig, Tick, env::ConfigOracle};
 
 /// Runtime implementation of ConfigOracle that wraps GameConfig
 pub struct ConfigOracleImpl {
@@ -17,4 +17,28 @@ impl ConfigOracle for ConfigOracleImpl {
     fn activation_radius(&self) -> u32 {
         self.config.activation_radius
     }
+
+    fn activation_shape(&self) -> ActivationShape {
+        self.config.activation_shape
+    }
+
+    fn cover_damage_reduction(&self) -> u32 {
+        self.config.cover_damage_reduction
+    }
+
+    fn initiative_window(&self) -> u32 {
+        self.config.initiative_window
+    }
+
+    fn corpse_grace_period(&self) -> Tick {
+        self.config.corpse_grace_period
+    }
+
+    fn aggro_duration(&self) -> Tick {
+        self.config.aggro_duration
+    }
+
+    fn faction_relations(&self) -> &FactionRelations {
+        &self.config.faction_relations
+    }
 }
@@ -65,6 +65,18 @@ impl OracleBundle {
         .into_game_env()
     }
 
+    /// Creates a bundle over a `width` x `height` floor with default content.
+    #[cfg(test)]
+    pub(crate) fn test_bundle(width: u32, height: u32) -> Self {
+        Self::new(
+            Arc::new(MapOracleImpl::test_map(width, height)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(game_core::GameConfig::default())),
+        )
+    }
+
     /// Get access to actor oracle for runtime AI setup
     pub fn actors(&self) -> &ActorOracleImpl {
         &self.actors
@@ -4,10 +4,27 @@
 //!
 //! - Read access to game state
 //! - Cached available actions (computed once per turn)
+//! - A [`ThreatAssessment`] of the surrounding actors (scanned once per turn)
 //! - Helper methods for situation assessment
 //! - Access to trait profiles
 
-use game_core::{ActionKind, EntityId, GameEnv, GameState, TraitProfile};
+use std::cmp::Reverse;
+
+use game_core::{
+    ActionKind, ActionTag, ActorState, AttackType, EntityId, Faction, FactionRelations, GameEnv,
+    GameState, ItemKind, MapOracle, PropState, Relation, TargetingMode, TraitProfile,
+};
+
+use super::threat::{self, ThreatAssessment};
+
+/// How far an NPC can see, in tiles (Manhattan distance).
+pub(super) const SIGHT_RANGE: u32 = 10;
+
+/// How far a guard will leave its spot to hold a chokepoint (Manhattan).
+const GUARD_RANGE: u32 = 6;
+
+/// Relations used when no config oracle is available (built-in rules only).
+static DEFAULT_RELATIONS: FactionRelations = FactionRelations::new();
 
 /// Context for AI decision-making.
 ///
@@ -22,7 +39,9 @@ use game_core::{ActionKind, EntityId, GameEnv, GameState, TraitProfile};
 /// # Caching Strategy
 ///
 /// `available_actions` is computed once per turn using `get_available_actions()`
-/// and cached to avoid redundant computation.
+/// and cached to avoid redundant computation. Likewise, the allies, enemies
+/// and distances the situation helpers need are scanned once into a
+/// [`ThreatAssessment`] when the context is created.
 ///
 /// # Lifetime
 ///
@@ -43,6 +62,26 @@ pub struct AiContext<'a> {
     ///
     /// Computed once per turn using `game_core::get_available_actions()`.
     available_actions: Vec<ActionKind>,
+
+    /// Actors around this entity, scanned once when the context is created.
+    threat: ThreatAssessment<'a>,
+
+    /// Next tile on the A* path towards the goal's destination.
+    ///
+    /// `None` when no path was searched or the turn's node budget ran out,
+    /// in which case movement scoring falls back to greedy distance checks.
+    planned_step: Option<game_core::Position>,
+
+    /// Waypoint this entity is patrolling towards, if it has a patrol route.
+    patrol_waypoint: Option<game_core::Position>,
+
+    /// Whether equal-score candidates are ordered by a seeded hash rather
+    /// than by generation order (see [`Self::tie_break_key`]).
+    seeded_tie_break: bool,
+
+    /// Points subtracted per ally an area attack would strike
+    /// (see [`scoring::friendly_fire_penalty`](super::scoring::friendly_fire_penalty)).
+    friendly_fire_penalty: u32,
 }
 
 impl<'a> AiContext<'a> {
@@ -56,14 +95,21 @@ impl<'a> AiContext<'a> {
     ///
     /// # Returns
     ///
-    /// A new context with empty available_actions.
+    /// A new context with empty available_actions and a fresh threat scan.
     /// Use `with_available_actions()` to populate the action cache.
     pub fn new(entity: EntityId, state: &'a GameState, env: GameEnv<'a>) -> Self {
+        let threat =
+            ThreatAssessment::scan(entity, state, Self::relations_of(&env), env.map().ok());
         Self {
             entity,
             state,
             env,
             available_actions: Vec::new(),
+            threat,
+            planned_step: None,
+            patrol_waypoint: None,
+            seeded_tie_break: false,
+            friendly_fire_penalty: super::scoring::DEFAULT_FRIENDLY_FIRE_PENALTY,
         }
     }
 
@@ -84,6 +130,67 @@ impl<'a> AiContext<'a> {
         self
     }
 
+    /// Sets the next step of the planned path (builder pattern).
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - First tile of the A* path, or `None` to use greedy movement
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_planned_step(mut self, step: Option<game_core::Position>) -> Self {
+        self.planned_step = step;
+        self
+    }
+
+    /// Sets the waypoint of this entity's patrol route (builder pattern).
+    ///
+    /// # Arguments
+    ///
+    /// * `waypoint` - Current patrol waypoint, or `None` if the entity does not patrol
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_patrol_waypoint(mut self, waypoint: Option<game_core::Position>) -> Self {
+        self.patrol_waypoint = waypoint;
+        self
+    }
+
+    /// Enables seeded tie-breaking between equal-score candidates (builder pattern).
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether [`Self::tie_break_key`] hashes the candidate index
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_seeded_tie_break(mut self, enabled: bool) -> Self {
+        self.seeded_tie_break = enabled;
+        self
+    }
+
+    /// Sets the penalty for striking allies with area attacks (builder pattern).
+    ///
+    /// # Arguments
+    ///
+    /// * `points_per_ally` - Score subtracted per ally caught; 100 or more forbids it
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_friendly_fire_penalty(mut self, points_per_ally: u32) -> Self {
+        self.friendly_fire_penalty = points_per_ally;
+        self
+    }
+
+    /// Returns the score subtracted per ally an area attack would strike.
+    pub fn friendly_fire_penalty(&self) -> u32 {
+        self.friendly_fire_penalty
+    }
+
     // ========================================================================
     // Situation Assessment Helper Methods
     // ========================================================================
@@ -113,19 +220,7 @@ impl<'a> AiContext<'a> {
     ///
     /// Manhattan distance to player, or `u32::MAX` if player position unknown.
     pub fn distance_to_player(&self) -> u32 {
-        if let Some(actor) = self.state.entities.actor(self.entity) {
-            if let (Some(actor_pos), Some(player_pos)) =
-                (actor.position, self.state.entities.player().position)
-            {
-                let dx = (actor_pos.x - player_pos.x).abs();
-                let dy = (actor_pos.y - player_pos.y).abs();
-                (dx + dy) as u32
-            } else {
-                u32::MAX
-            }
-        } else {
-            u32::MAX
-        }
+        self.threat.player_distance
     }
 
     /// Checks if player is visible to this entity.
@@ -142,23 +237,13 @@ impl<'a> AiContext<'a> {
     /// - Consider perception traits (some NPCs see further)
     /// - Consider light levels (darkness reduces vision)
     pub fn can_see_player(&self) -> bool {
-        // Calculate distance to player
-        let distance = self.distance_to_player();
-
-        // If distance is MAX, entity is not an actor or other error
-        if distance == u32::MAX {
-            return false;
-        }
-
-        // Sight range: 10 tiles (TODO: make configurable, trait-based)
-        const SIGHT_RANGE: u32 = 10;
-
-        let can_see = distance <= SIGHT_RANGE;
+        // Sight range: SIGHT_RANGE tiles (TODO: make configurable, trait-based)
+        let can_see = self.threat.player_visible;
 
         tracing::debug!(
             "NPC {:?} checking vision to player: distance={}, can_see={}",
             self.entity,
-            distance,
+            self.threat.player_distance,
             can_see
         );
 
@@ -174,14 +259,248 @@ impl<'a> AiContext<'a> {
     /// # Returns
     ///
     /// Number of allied entities within range.
+    pub fn count_nearby_allies(&self, range: u32) -> u32 {
+        let Some(my_pos) = self.my_position() else {
+            return 0;
+        };
+
+        self.allies()
+            .filter_map(|ally| ally.position)
+            .filter(|pos| my_pos.manhattan_distance(*pos) <= range)
+            .count() as u32
+    }
+
+    /// Counts allies standing on or around a position.
+    ///
+    /// Uses Chebyshev distance, so `radius = 1` covers the tile itself and
+    /// its 8 neighbours. The deciding entity is never counted.
+    pub fn allies_near(&self, pos: game_core::Position, radius: u32) -> u32 {
+        self.allies()
+            .filter_map(|ally| ally.position)
+            .filter(|ally_pos| pos.chebyshev_distance(*ally_pos) <= radius)
+            .count() as u32
+    }
+
+    /// Finds the closest ally (Manhattan distance).
     ///
-    /// # TODO
+    /// Ties are broken by the lower entity ID so the choice is deterministic.
+    pub fn nearest_ally(&self) -> Option<&ActorState> {
+        let my_pos = self.my_position()?;
+
+        self.allies()
+            .filter_map(|ally| ally.position.map(|pos| (ally, pos)))
+            .min_by_key(|(ally, pos)| (my_pos.manhattan_distance(*pos), ally.id))
+            .map(|(ally, _)| ally)
+    }
+
+    /// Finds the closest downed ally within sight range (Manhattan distance).
     ///
-    /// Currently returns 0 (no allies). Need to implement entity iteration
-    /// in EntitiesState to enable proper ally counting.
-    pub fn count_nearby_allies(&self, _range: u32) -> u32 {
-        // TODO: Implement when EntitiesState has actors() iterator
-        0
+    /// Downed allies are NPCs at 0 HP still lying on the map, waiting to be
+    /// revived. Ties are broken by the lower entity ID.
+    pub fn nearest_downed_ally(&self) -> Option<&ActorState> {
+        let my_pos = self.my_position()?;
+        let my_faction = self.state.entities.actor(self.entity)?.faction;
+
+        self.state
+            .entities
+            .all_actors()
+            .filter(|actor| {
+                actor.id != self.entity
+                    && actor.id != EntityId::PLAYER
+                    && actor.is_downed()
+                    && self.relation(my_faction, actor.faction) == Relation::Allied
+            })
+            .filter_map(|ally| {
+                ally.position
+                    .map(|pos| (ally, my_pos.manhattan_distance(pos)))
+            })
+            .filter(|(_, distance)| *distance <= SIGHT_RANGE)
+            .min_by_key(|(ally, distance)| (*distance, ally.id))
+            .map(|(ally, _)| ally)
+    }
+
+    /// Finds the most injured living ally within `range` (Chebyshev distance).
+    ///
+    /// Allies at full HP are skipped. The largest [missing HP share] wins;
+    /// ties go to the nearer ally, then the lower entity ID.
+    ///
+    /// [missing HP share]: Self::wounded_percent
+    pub fn most_injured_ally_within(&self, range: u32) -> Option<&ActorState> {
+        let my_pos = self.my_position()?;
+
+        self.threat
+            .wounded_allies
+            .iter()
+            .filter_map(|&(ally, wounded)| {
+                ally.position
+                    .map(|pos| (ally, wounded, my_pos.chebyshev_distance(pos)))
+            })
+            .filter(|(_, _, distance)| *distance <= range)
+            .min_by_key(|(ally, wounded, distance)| (Reverse(*wounded), *distance, ally.id))
+            .map(|(ally, _, _)| ally)
+    }
+
+    /// Returns the share of its max HP an actor is missing, in percent.
+    ///
+    /// Rounds up, so any missing HP counts as wounded. Unknown entities and
+    /// actors at full HP return 0.
+    pub fn wounded_percent(&self, entity: EntityId) -> u32 {
+        self.state
+            .entities
+            .actor(entity)
+            .map_or(0, threat::wounded_percent)
+    }
+
+    /// Returns the range of this entity's Heal action, if it is ready to cast.
+    pub fn heal_range(&self) -> Option<u32> {
+        let me = self.state.entities.actor(self.entity)?;
+        if !me.can_use_action(ActionKind::Heal, self.state.turn.clock) {
+            return None;
+        }
+
+        match self
+            .env
+            .actions()
+            .ok()?
+            .action_profile(ActionKind::Heal)
+            .targeting
+        {
+            TargetingMode::SingleTarget { range, .. } => Some(range),
+            _ => None,
+        }
+    }
+
+    /// Returns the stance faction `from` takes towards faction `to`.
+    ///
+    /// Read from the config oracle's [`FactionRelations`] table, falling back
+    /// to the built-in rules when no config oracle is available.
+    pub fn relation(&self, from: Faction, to: Faction) -> Relation {
+        Self::relations_of(&self.env).relation(from, to)
+    }
+
+    /// Returns the stance `actor` takes towards `other` right now.
+    ///
+    /// Like [`Self::relation`], except that an actor provoked by `other` is
+    /// hostile to it until the aggro wears off.
+    pub fn stance_toward(&self, actor: &ActorState, other: &ActorState) -> Relation {
+        threat::stance(
+            actor,
+            other,
+            Self::relations_of(&self.env),
+            self.state.turn.clock,
+        )
+    }
+
+    /// Returns the faction relations table of `env`.
+    fn relations_of(env: &GameEnv<'a>) -> &'a FactionRelations {
+        env.config()
+            .map_or(&DEFAULT_RELATIONS, |config| config.faction_relations())
+    }
+
+    /// Iterates over living NPCs allied with this entity.
+    ///
+    /// The player and the deciding entity itself are excluded.
+    fn allies(&self) -> impl Iterator<Item = &'a ActorState> {
+        self.threat.allies.iter().copied()
+    }
+
+    /// Iterates over living, positioned actors this entity is hostile to.
+    fn enemies(&self) -> impl Iterator<Item = &'a ActorState> {
+        self.threat.enemies.iter().copied()
+    }
+
+    /// Finds the closest enemy within sight range (Manhattan distance).
+    ///
+    /// Ties are broken by the lower entity ID so the choice is deterministic.
+    pub fn nearest_enemy(&self) -> Option<&ActorState> {
+        self.threat.nearest_enemy.map(|(enemy, _)| enemy)
+    }
+
+    /// Iterates over enemies seen by this entity or a living ally, within
+    /// sight range and line of sight.
+    ///
+    /// Every member of a faction gets the same set from the same state, which
+    /// keeps faction-level decisions consistent across allies.
+    fn faction_visible_enemies(&self) -> impl Iterator<Item = &'a ActorState> {
+        self.threat.faction_visible_enemies.iter().copied()
+    }
+
+    /// Counts the hostiles visible to this entity's faction.
+    ///
+    /// This is the faction-level threat assessment: allies deciding against
+    /// the same state all see the same count.
+    pub fn faction_threat_count(&self) -> u32 {
+        self.faction_visible_enemies().count() as u32
+    }
+
+    /// Picks the faction-wide focus fire target.
+    ///
+    /// Considers every enemy seen by this entity or a living ally and picks
+    /// the quickest kill: the lowest current HP,
+    /// ties broken by the lower entity ID. The result depends only on the
+    /// game state, so every ally deciding against the same state agrees on it
+    /// regardless of the order in which they act.
+    pub fn designated_target(&self) -> Option<&ActorState> {
+        self.faction_visible_enemies()
+            .min_by_key(|enemy| (enemy.resources.hp, enemy.id))
+    }
+
+    /// Chooses the enemy this entity should fight.
+    ///
+    /// Prefers the [designated target](Self::designated_target) so allies
+    /// concentrate their damage, and falls back to the
+    /// [nearest enemy](Self::nearest_enemy) when the designated target is
+    /// out of this entity's sight range.
+    pub fn combat_target(&self) -> Option<&ActorState> {
+        let my_pos = self.my_position()?;
+
+        self.designated_target()
+            .filter(|target| {
+                target
+                    .position
+                    .is_some_and(|pos| my_pos.manhattan_distance(pos) <= SIGHT_RANGE)
+            })
+            .or_else(|| self.nearest_enemy())
+    }
+
+    /// Sums the current HP on each side of the local fight.
+    ///
+    /// Allied strength is this entity's HP plus that of every ally within
+    /// sight range; hostile strength is the HP of every enemy within sight
+    /// range. Both are measured from this entity's position (Manhattan).
+    ///
+    /// # Returns
+    ///
+    /// `(allied, hostile)`, or `(own HP, 0)` if the entity is not on the map.
+    pub fn local_strength(&self) -> (u32, u32) {
+        let my_hp = self
+            .state
+            .entities
+            .actor(self.entity)
+            .map(|actor| actor.resources.hp)
+            .unwrap_or(0);
+        let Some(my_pos) = self.my_position() else {
+            return (my_hp, 0);
+        };
+        let in_sight = |actor: &&ActorState| {
+            actor
+                .position
+                .is_some_and(|pos| my_pos.manhattan_distance(pos) <= SIGHT_RANGE)
+        };
+
+        let allied = my_hp
+            + self
+                .allies()
+                .filter(in_sight)
+                .map(|ally| ally.resources.hp)
+                .sum::<u32>();
+        let hostile = self
+            .enemies()
+            .filter(in_sight)
+            .map(|enemy| enemy.resources.hp)
+            .sum();
+
+        (allied, hostile)
     }
 
     /// Counts visible enemies.
@@ -201,16 +520,79 @@ impl<'a> AiContext<'a> {
 
     /// Checks if there's a valid escape route.
     ///
-    /// Currently simplified to always return true.
-    /// Future: Add proper pathfinding and passability checks.
+    /// An entity can escape as long as at least one adjacent tile is open to
+    /// step onto (see [`can_step`](Self::can_step)).
+    pub fn has_escape_route(&self) -> bool {
+        game_core::CardinalDirection::all()
+            .into_iter()
+            .any(|direction| self.can_step(direction))
+    }
+
+    /// Gets the prop this entity still has to operate.
     ///
-    /// # TODO
+    /// # Returns
     ///
-    /// Implement proper escape route checking when MapOracle exposes
-    /// necessary methods (dimensions, passability).
-    pub fn has_escape_route(&self) -> bool {
-        // TODO: Check adjacent tiles for passability
-        true
+    /// The entity's [objective](ActorState::objective) prop, or None if it has
+    /// no objective, the prop is gone, or the prop is already active.
+    pub fn pending_objective(&self) -> Option<&PropState> {
+        let prop_id = self.state.entities.actor(self.entity)?.objective?;
+        self.state
+            .entities
+            .prop(prop_id)
+            .filter(|prop| !prop.is_active)
+    }
+
+    /// Finds the map exit closest to this entity (Chebyshev distance).
+    ///
+    /// Ties are broken by position so every replay picks the same exit.
+    ///
+    /// # Returns
+    ///
+    /// The nearest exit tile, or None if the map has no exits or the entity
+    /// is not on the map.
+    pub fn nearest_exit(&self) -> Option<game_core::Position> {
+        let my_pos = self.my_position()?;
+        let map = self.env.map().ok()?;
+        let dimensions = map.dimensions();
+
+        (0..dimensions.height as i32)
+            .flat_map(|y| (0..dimensions.width as i32).map(move |x| game_core::Position::new(x, y)))
+            .filter(|&pos| map.tile(pos).is_some_and(|tile| tile.is_exit()))
+            .min_by_key(|&pos| (my_pos.chebyshev_distance(pos), pos))
+    }
+
+    /// Picks the region exit to hold against a threat.
+    ///
+    /// Region exits are the one-tile gaps where a room opens into a doorway
+    /// or corridor (see [`is_region_exit`]). Only exits within [`GUARD_RANGE`]
+    /// of this entity are considered, and only while the threat is no closer
+    /// to this entity than to the exit, i.e. has not crossed yet.
+    ///
+    /// The exit nearest the threat wins; ties go to the exit nearest this
+    /// entity, then to position, so every replay picks the same post.
+    pub fn chokepoint_to_hold(&self, threat: &ActorState) -> Option<game_core::Position> {
+        let my_pos = self.my_position()?;
+        let threat_pos = threat.position?;
+        let map = self.env.map().ok()?;
+        let range = GUARD_RANGE as i32;
+
+        ((my_pos.y - range)..=(my_pos.y + range))
+            .flat_map(|y| {
+                ((my_pos.x - range)..=(my_pos.x + range))
+                    .map(move |x| game_core::Position::new(x, y))
+            })
+            .filter(|&pos| my_pos.manhattan_distance(pos) <= GUARD_RANGE)
+            .filter(|&pos| is_region_exit(map, pos))
+            .filter(|&pos| {
+                threat_pos.manhattan_distance(pos) <= threat_pos.manhattan_distance(my_pos)
+            })
+            .min_by_key(|&pos| {
+                (
+                    threat_pos.manhattan_distance(pos),
+                    my_pos.manhattan_distance(pos),
+                    pos,
+                )
+            })
     }
 
     // ========================================================================
@@ -257,6 +639,26 @@ impl<'a> AiContext<'a> {
         Some(game_core::Position::new(current.x + dx, current.y + dy))
     }
 
+    /// Checks whether this entity can step one tile in the given direction.
+    ///
+    /// Mirrors the movement rules: the destination must be on the map,
+    /// passable, not shut by a closed door, and not held by another actor.
+    pub fn can_step(&self, direction: game_core::CardinalDirection) -> bool {
+        let (Some(destination), Ok(map)) = (self.position_after_move(direction), self.env.map())
+        else {
+            return false;
+        };
+
+        map.dimensions().contains(destination)
+            && map.tile(destination).is_some_and(|tile| tile.is_passable())
+            && !self.state.is_blocked(destination)
+            && !self
+                .state
+                .entities
+                .all_actors()
+                .any(|actor| actor.id != self.entity && actor.position == Some(destination))
+    }
+
     /// Calculates the Manhattan distance from a given position to the player.
     ///
     /// # Arguments
@@ -275,6 +677,131 @@ impl<'a> AiContext<'a> {
         (dx + dy) as u32
     }
 
+    /// Gets the next tile on the planned path, if one was found this turn.
+    pub fn planned_step(&self) -> Option<game_core::Position> {
+        self.planned_step
+    }
+
+    /// Gets the waypoint this entity is patrolling towards, if any.
+    pub fn patrol_waypoint(&self) -> Option<game_core::Position> {
+        self.patrol_waypoint
+    }
+
+    /// Returns the tie-break key of the candidate at `index`; lower wins.
+    ///
+    /// Unseeded, the key is the index itself, so the first candidate wins.
+    /// Seeded, it hashes `(game_seed, nonce, entity, index)` with
+    /// [`compute_seed`](game_core::compute_seed) in the AI stream: ties
+    /// resolve differently from turn to turn, yet every replay of a turn
+    /// picks the same action.
+    pub fn tie_break_key(&self, index: usize) -> u64 {
+        if !self.seeded_tie_break {
+            return index as u64;
+        }
+
+        game_core::compute_seed(
+            self.state.game_seed,
+            self.state.turn.nonce,
+            self.entity.0,
+            index as u32,
+            game_core::DomainTag::Ai,
+        )
+    }
+
+    // ========================================================================
+    // Cooldown Helpers
+    // ========================================================================
+
+    /// Returns the range of this entity's ranged attack while it is reloading.
+    ///
+    /// An entity is reloading when it knows at least one ranged attack and all
+    /// of them are on cooldown.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(range)` with the longest range among the cooling-down attacks
+    /// - `None` if the entity has no ranged attack or one is ready to use
+    pub fn reloading_range(&self) -> Option<u32> {
+        let actor = self.state.entities.actor(self.entity)?;
+        let actions = self.env.actions().ok()?;
+        let current_tick = self.state.turn.clock;
+
+        let mut range = None;
+        for ability in actor.actions.iter().filter(|a| a.enabled) {
+            let profile = actions.action_profile(ability.kind);
+            if !profile.tags.contains(&ActionTag::Attack)
+                || !profile.tags.contains(&ActionTag::Ranged)
+            {
+                continue;
+            }
+            if ability.is_ready(current_tick) {
+                return None;
+            }
+
+            let attack_range = match profile.targeting {
+                TargetingMode::SingleTarget { range, .. }
+                | TargetingMode::Chain { range, .. }
+                | TargetingMode::Directional { range, .. }
+                | TargetingMode::Line { length: range }
+                | TargetingMode::Cone { length: range, .. } => range,
+                TargetingMode::LineOfSight { max_range } => u32::from(max_range),
+                TargetingMode::Burst { radius, .. } => u32::from(radius),
+                TargetingMode::None | TargetingMode::SelfOnly => 0,
+            };
+            range = Some(range.map_or(attack_range, |r: u32| r.max(attack_range)));
+        }
+
+        range
+    }
+
+    // ========================================================================
+    // Equipment Helpers
+    // ========================================================================
+
+    /// Finds an inventory weapon better suited than the equipped one for
+    /// fighting `target`.
+    ///
+    /// Adjacent targets call for a melee weapon, anything farther away for a
+    /// ranged one. Among suitable weapons the highest damage wins, ties going
+    /// to the lower slot.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(slot)` holding the weapon to swap in
+    /// - `None` if no suitable weapon is carried or the equipped weapon
+    ///   already suits the range at least as well
+    pub fn better_weapon_slot(&self, target: EntityId) -> Option<u8> {
+        let actor = self.state.entities.actor(self.entity)?;
+        let distance = self
+            .my_position()?
+            .chebyshev_distance(self.state.actor_position(target)?);
+        let wanted = if distance <= 1 {
+            AttackType::Melee
+        } else {
+            AttackType::Ranged
+        };
+
+        let items = self.env.items().ok()?;
+        let suitable_damage = |handle| match items.definition(handle)?.kind {
+            ItemKind::Weapon(weapon) if weapon.kind.attack_type() == wanted => Some(weapon.damage),
+            _ => None,
+        };
+
+        let (slot, damage) = actor
+            .inventory
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, item)| Some((slot, suitable_damage(item.handle)?)))
+            .max_by_key(|(slot, damage)| (*damage, Reverse(*slot)))?;
+
+        let equipped = actor.equipment.weapon.and_then(suitable_damage);
+        if equipped.is_some_and(|equipped| equipped >= damage) {
+            return None;
+        }
+        Some(slot as u8)
+    }
+
     // ========================================================================
     // Trait Profile Access
     // ========================================================================
@@ -309,4 +836,43 @@ impl<'a> AiContext<'a> {
     pub fn available_actions(&self) -> &[ActionKind] {
         &self.available_actions
     }
+
+    /// Gets the threat assessment scanned when this context was created.
+    pub fn threat(&self) -> &ThreatAssessment<'a> {
+        &self.threat
+    }
+}
+
+/// Returns true if the tile is an exit of a region.
+///
+/// A region exit is a passable tile whose only passable orthogonal
+/// neighbours lie on opposite sides (a one-tile gap in a wall), where at
+/// least one of those neighbours opens into a room (three or more passable
+/// orthogonal neighbours). The inner tiles of a long corridor are therefore
+/// not exits; its two ends are.
+fn is_region_exit<M: MapOracle + ?Sized>(map: &M, pos: game_core::Position) -> bool {
+    let passable = |x: i32, y: i32| {
+        map.tile(game_core::Position::new(x, y))
+            .is_some_and(|tile| tile.is_passable())
+    };
+    let open_sides = |x: i32, y: i32| {
+        [(0, 1), (0, -1), (1, 0), (-1, 0)]
+            .into_iter()
+            .filter(|(dx, dy)| passable(x + dx, y + dy))
+            .count()
+    };
+    let (x, y) = (pos.x, pos.y);
+    if !passable(x, y) {
+        return false;
+    }
+
+    let vertical = passable(x, y + 1) && passable(x, y - 1);
+    let horizontal = passable(x + 1, y) && passable(x - 1, y);
+    let sides = match (vertical, horizontal) {
+        (true, false) if open_sides(x, y) == 2 => [(x, y + 1), (x, y - 1)],
+        (false, true) if open_sides(x, y) == 2 => [(x + 1, y), (x - 1, y)],
+        _ => return false,
+    };
+
+    sides.into_iter().any(|(sx, sy)| open_sides(sx, sy) >= 3)
 }
@@ -1,6 +1,6 @@
 //! Generates all possible action candidates from available actions.
 
-use game_core::{ActionInput, ActionKind, CardinalDirection, EntityId};
+use game_core::{ActionInput, ActionKind, CardinalDirection, EntityId, Relation};
 use tracing::debug;
 
 use super::AiContext;
@@ -40,6 +40,11 @@ impl ActionCandidateGenerator {
         let mut candidates = Vec::new();
 
         for &kind in available_kinds {
+            // Weapon swaps are decided by the provider's pre-combat step
+            if kind == ActionKind::EquipWeapon {
+                continue;
+            }
+
             // Get action profile to determine targeting mode
             let profile = match ctx.env.actions() {
                 Ok(actions) => actions.action_profile(kind),
@@ -58,9 +63,22 @@ impl ActionCandidateGenerator {
                 game_core::TargetingMode::SingleTarget {
                     range,
                     requires_los,
+                }
+                | game_core::TargetingMode::Chain {
+                    range,
+                    requires_los,
+                    ..
                 } => {
                     // Generate candidates for each possible target entity
-                    let targets = Self::find_valid_targets(ctx.entity, *range, *requires_los, ctx);
+                    let targets = if kind == ActionKind::Interact {
+                        Self::find_props_in_range(ctx.entity, *range, ctx)
+                    } else if kind == ActionKind::Revive {
+                        Self::find_downed_allies_in_range(ctx.entity, *range, ctx)
+                    } else if kind == ActionKind::Heal {
+                        Self::find_wounded_allies_in_range(ctx.entity, *range, ctx)
+                    } else {
+                        Self::find_valid_targets(ctx.entity, *range, *requires_los, ctx)
+                    };
 
                     for target in targets {
                         candidates.push((kind, ActionInput::Target(target)));
@@ -78,6 +96,27 @@ impl ActionCandidateGenerator {
                     }
                 }
 
+                game_core::TargetingMode::LineOfSight { max_range } => {
+                    // Projectiles only consider targets in clear view
+                    let range = u32::from(*max_range);
+                    for target in Self::find_valid_targets(ctx.entity, range, true, ctx) {
+                        candidates.push((kind, ActionInput::Target(target)));
+                    }
+                }
+
+                game_core::TargetingMode::Burst { radius, .. } => {
+                    // Bursts are centred on the player, the only target considered
+                    if let Some(center) = ctx.state.entities.player().position {
+                        candidates.push((kind, ActionInput::Position(center)));
+                    }
+
+                    tracing::trace!(
+                        "Generated burst candidate for {:?} (radius={})",
+                        kind,
+                        radius
+                    );
+                }
+
                 game_core::TargetingMode::Directional { range, width } => {
                     // Generate candidates for all 8 cardinal directions
                     for dir in CardinalDirection::all() {
@@ -91,6 +130,20 @@ impl ActionCandidateGenerator {
                         width
                     );
                 }
+
+                game_core::TargetingMode::Line { length }
+                | game_core::TargetingMode::Cone { length, .. } => {
+                    // Beams and cones can be aimed along any of the 8 directions
+                    for dir in CardinalDirection::all() {
+                        candidates.push((kind, ActionInput::Direction(dir)));
+                    }
+
+                    tracing::trace!(
+                        "Generated 8 aimed candidates for {:?} (length={})",
+                        kind,
+                        length
+                    );
+                }
             }
         }
 
@@ -103,7 +156,11 @@ impl ActionCandidateGenerator {
         candidates
     }
 
-    /// Finds all valid target entities within range.
+    /// Finds all valid target entities within range, in entity ID order.
+    ///
+    /// Only actors this entity is hostile to (see [`AiContext::stance_toward`])
+    /// are targets, so allied and neutral actors are left alone unless they
+    /// provoked it.
     ///
     /// # Arguments
     ///
@@ -123,9 +180,9 @@ impl ActionCandidateGenerator {
     ) -> Vec<EntityId> {
         let mut targets = Vec::new();
 
-        let actor_pos = match ctx.state.entities.actor(actor) {
+        let (me, actor_pos) = match ctx.state.entities.actor(actor) {
             Some(a) => match a.position {
-                Some(pos) => pos,
+                Some(pos) => (a, pos),
                 None => {
                     debug!("Actor {:?} has no position", actor);
                     return targets;
@@ -137,28 +194,134 @@ impl ActionCandidateGenerator {
             }
         };
 
-        // Check player as potential target
-        let player = ctx.state.entities.player();
-        let Some(player_pos) = player.position else {
-            return targets; // Player not on map
+        let hostiles: Vec<_> = if actor == ctx.entity {
+            ctx.threat().enemies.clone()
+        } else {
+            ctx.state
+                .entities
+                .all_actors()
+                .filter(|other| {
+                    other.id != actor
+                        && other.is_alive()
+                        && ctx.stance_toward(me, other) == Relation::Hostile
+                })
+                .collect()
         };
-        let dist = actor_pos.chebyshev_distance(player_pos);
 
-        if dist <= range {
-            // TODO: Add actual LOS check when MapOracle supports it
-            if requires_los {
-                tracing::trace!("LOS check not implemented yet, assuming LOS exists for player");
-            }
+        for enemy in hostiles {
+            let Some(enemy_pos) = enemy.position else {
+                continue;
+            };
+            let dist = actor_pos.chebyshev_distance(enemy_pos);
 
-            targets.push(EntityId::PLAYER);
-            tracing::trace!("Player is valid target: distance={}, range={}", dist, range);
-        }
+            // Without a map oracle there is nothing to block sight
+            let blocked = requires_los
+                && ctx
+                    .env
+                    .map()
+                    .is_ok_and(|map| !map.line_of_sight(actor_pos, enemy_pos));
 
-        // TODO: Add other entities when entity iteration is available
-        // - Allies (for healing, buffing)
-        // - Other enemies
-        // - Props (for interaction)
+            if dist <= range && !blocked {
+                targets.push(enemy.id);
+                tracing::trace!(
+                    "{:?} is valid target: distance={}, range={}",
+                    enemy.id,
+                    dist,
+                    range
+                );
+            }
+        }
 
+        targets.sort();
         targets
     }
+
+    /// Finds all props within range (Chebyshev distance), in entity ID order.
+    ///
+    /// Props are only ever targeted by Interact, so they are kept out of
+    /// [`Self::find_valid_targets`].
+    fn find_props_in_range(actor: EntityId, range: u32, ctx: &AiContext) -> Vec<EntityId> {
+        let Some(actor_pos) = ctx.state.actor_position(actor) else {
+            debug!("Actor {:?} has no position", actor);
+            return Vec::new();
+        };
+
+        let mut props: Vec<_> = ctx
+            .state
+            .entities
+            .props
+            .iter()
+            .filter(|prop| actor_pos.chebyshev_distance(prop.position) <= range)
+            .map(|prop| prop.id)
+            .collect();
+        props.sort();
+        props
+    }
+
+    /// Finds all downed, allied actors within range (Chebyshev distance),
+    /// in entity ID order.
+    ///
+    /// Downed allies are only ever targeted by Revive, so they are kept out of
+    /// [`Self::find_valid_targets`].
+    fn find_downed_allies_in_range(actor: EntityId, range: u32, ctx: &AiContext) -> Vec<EntityId> {
+        let Some(me) = ctx.state.entities.actor(actor) else {
+            debug!("Actor {:?} not found in entities", actor);
+            return Vec::new();
+        };
+        let Some(actor_pos) = me.position else {
+            debug!("Actor {:?} has no position", actor);
+            return Vec::new();
+        };
+
+        let mut allies: Vec<_> = ctx
+            .state
+            .entities
+            .all_actors()
+            .filter(|other| {
+                other.id != actor
+                    && other.is_downed()
+                    && ctx.relation(me.faction, other.faction) == Relation::Allied
+                    && other
+                        .position
+                        .is_some_and(|pos| actor_pos.chebyshev_distance(pos) <= range)
+            })
+            .map(|other| other.id)
+            .collect();
+        allies.sort();
+        allies
+    }
+
+    /// Finds all living, allied actors below full HP within range
+    /// (Chebyshev distance), in entity ID order.
+    ///
+    /// Heal targets its own side, so these are kept out of
+    /// [`Self::find_valid_targets`].
+    fn find_wounded_allies_in_range(actor: EntityId, range: u32, ctx: &AiContext) -> Vec<EntityId> {
+        let Some(me) = ctx.state.entities.actor(actor) else {
+            debug!("Actor {:?} not found in entities", actor);
+            return Vec::new();
+        };
+        let Some(actor_pos) = me.position else {
+            debug!("Actor {:?} has no position", actor);
+            return Vec::new();
+        };
+
+        let mut allies: Vec<_> = ctx
+            .state
+            .entities
+            .all_actors()
+            .filter(|other| {
+                other.id != actor
+                    && other.is_alive()
+                    && ctx.wounded_percent(other.id) > 0
+                    && ctx.relation(me.faction, other.faction) == Relation::Allied
+                    && other
+                        .position
+                        .is_some_and(|pos| actor_pos.chebyshev_distance(pos) <= range)
+            })
+            .map(|other| other.id)
+            .collect();
+        allies.sort();
+        allies
+    }
 }
@@ -3,7 +3,7 @@
 //! Goals are concrete objectives that drive NPC behavior.
 //! Each goal represents a specific intent (e.g., "Attack Player", "Flee from Player").
 
-use game_core::{EntityId, Position};
+use game_core::{ActionKind, EntityId, Position};
 
 use super::AiContext;
 
@@ -12,9 +12,16 @@ use super::AiContext;
 /// Goals are specific and situation-dependent:
 /// - **Attack { target }**: Engage a specific enemy
 /// - **FleeFrom { threat }**: Escape from a specific danger
+/// - **Retreat { threat, ally }**: Fall back towards an ally for protection
+/// - **Kite { target }**: Back away from an enemy while a ranged attack reloads
+/// - **Interact { prop }**: Walk to a prop and operate it (scenario objective)
 /// - **HealSelf**: Restore own HP
 /// - **MoveTo { position }**: Navigate to a location
 /// - **ProtectAlly { ally }**: Stay near and support an ally
+/// - **Guard { post, threat }**: Hold a region exit and strike whoever crosses it
+/// - **Revive { ally }**: Walk to a downed ally and get it back up
+/// - **HealAlly { ally }**: Heal the most injured ally in range
+/// - **Patrol { waypoint }**: Walk the patrol route towards its next waypoint
 /// - **Idle**: No specific objective
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Goal {
@@ -24,6 +31,15 @@ pub enum Goal {
     /// Flee from a specific entity.
     FleeFrom { threat: EntityId },
 
+    /// Back away from a threat towards an ally.
+    Retreat { threat: EntityId, ally: EntityId },
+
+    /// Keep distance from a target until the ranged attack is off cooldown.
+    Kite { target: EntityId },
+
+    /// Reach and operate a prop (e.g. pull an alarm lever).
+    Interact { prop: EntityId },
+
     /// Heal self.
     HealSelf,
 
@@ -33,6 +49,18 @@ pub enum Goal {
     /// Protect/stay near an ally.
     ProtectAlly { ally: EntityId },
 
+    /// Hold a chokepoint against an approaching threat.
+    Guard { post: Position, threat: EntityId },
+
+    /// Revive a downed ally.
+    Revive { ally: EntityId },
+
+    /// Heal a wounded ally in range.
+    HealAlly { ally: EntityId },
+
+    /// Walk towards the next waypoint of the patrol route.
+    Patrol { waypoint: Position },
+
     /// Do nothing (idle).
     Idle,
 }
@@ -45,6 +73,14 @@ impl Goal {
     /// - 50-99: Helpful for this goal
     /// - 1-49: Somewhat relevant
     /// - 0: Not relevant or counterproductive
+    ///
+    /// Movement candidates are then reduced by [`scoring::spacing_penalty`] so
+    /// groups of NPCs spread out rather than stacking on the same approach,
+    /// and area attacks by [`scoring::friendly_fire_penalty`] so NPCs avoid
+    /// catching their allies in the blast.
+    ///
+    /// [`scoring::spacing_penalty`]: super::scoring::spacing_penalty
+    /// [`scoring::friendly_fire_penalty`]: super::scoring::friendly_fire_penalty
     pub fn evaluate_action(
         &self,
         kind: game_core::ActionKind,
@@ -53,17 +89,63 @@ impl Goal {
     ) -> u32 {
         use super::scoring;
 
-        match self {
+        let score = match self {
             Goal::Attack { target } => scoring::score_for_attack(kind, input, *target, ctx),
             Goal::FleeFrom { threat } => scoring::score_for_flee(kind, input, *threat, ctx),
+            Goal::Retreat { threat, ally } => {
+                scoring::score_for_retreat(kind, input, *threat, *ally, ctx)
+            }
+            Goal::Kite { target } => scoring::score_for_kite(kind, input, *target, ctx),
+            Goal::Interact { prop } => scoring::score_for_interact(kind, input, *prop, ctx),
             Goal::HealSelf => scoring::score_for_heal_self(kind, input, ctx),
             Goal::Idle => scoring::score_for_idle(kind, input, ctx),
             Goal::MoveTo { position } => scoring::score_for_move_to(kind, input, *position, ctx),
             Goal::ProtectAlly { ally } => scoring::score_for_protect_ally(kind, input, *ally, ctx),
-        }
+            Goal::Guard { post, threat } => {
+                scoring::score_for_guard(kind, input, *post, *threat, ctx)
+            }
+            Goal::Revive { ally } => scoring::score_for_revive(kind, input, *ally, ctx),
+            Goal::HealAlly { ally } => scoring::score_for_heal_ally(kind, input, *ally, ctx),
+            Goal::Patrol { waypoint } => scoring::score_for_patrol(kind, input, *waypoint, ctx),
+        };
+
+        score
+            .saturating_sub(scoring::spacing_penalty(kind, input, ctx))
+            .saturating_sub(scoring::friendly_fire_penalty(kind, input, ctx))
     }
 }
 
+/// Maximum distance (Manhattan) to an ally worth retreating to.
+const RETREAT_ALLY_RANGE: u32 = 8;
+
+/// Hostile-to-allied strength ratio (percent) a completely timid NPC tolerates.
+const OUTNUMBERED_BASE_PERCENT: u32 = 100;
+
+/// Extra tolerated ratio (percent) per point of normalized Bravery (0-100).
+///
+/// A neutral NPC (Bravery 50) stays in a fight until the enemy is 2.5x
+/// stronger; a fearless one (Bravery 100) holds out until 4x.
+const OUTNUMBERED_BRAVERY_SCALE: u32 = 3;
+
+/// Highest Aggression (0-240) at which an NPC holds chokepoints.
+///
+/// Such defensive NPCs wait at the nearest region exit for an enemy to come
+/// through instead of charging out to meet it.
+const GUARD_AGGRESSION_MAX: u8 = 80;
+
+/// Normalized Empathy (0-100) needed to revive an adjacent ally when no
+/// enemy is in sight.
+const REVIVE_EMPATHY_BASE: u32 = 30;
+
+/// Extra Empathy needed per tile (Manhattan) between the NPC and the ally.
+const REVIVE_EMPATHY_PER_TILE: u32 = 5;
+
+/// Extra Empathy needed while an enemy is in sight.
+///
+/// A neutral NPC (Empathy 50) keeps fighting rather than turning its back on
+/// an enemy, while a caring one (Empathy 75+) still helps a nearby ally up.
+const REVIVE_EMPATHY_UNDER_THREAT: u32 = 20;
+
 /// Selects a goal based on current situation and NPC personality traits.
 pub struct GoalSelector;
 
@@ -72,15 +154,27 @@ impl GoalSelector {
     ///
     /// # Decision Process
     ///
-    /// 1. **Critical Survival**: Low HP + immediate danger → Flee or Heal
-    /// 2. **Combat**: Enemy visible + sufficient courage → Attack or Flee
-    /// 3. **Exploration/Social**: No threats → Explore or interact
-    /// 4. **Default**: Nothing to do → Idle
+    /// 1. **Critical Survival**: Low HP + immediate danger → Retreat, Flee or Heal
+    /// 2. **Objective**: Assigned prop not yet active → Interact, ignoring enemies
+    /// 3. **Assist**: Downed ally in sight + enough Empathy → Revive;
+    ///    wounded ally in range of a ready Heal → HealAlly
+    /// 4. **Combat**: Enemy visible + sufficient courage → Attack or Flee
+    ///    (Kite instead of Attack while the ranged attack is reloading;
+    ///    Guard a region exit instead of Attack when defensive).
+    ///    Allies focus the faction's designated target when they can see it.
+    ///    Outnumbered beyond the Bravery-derived tolerance → Retreat or Flee.
+    /// 5. **Exploration/Social**: No threats → Patrol the assigned route
+    /// 6. **Default**: Nothing to do → Idle
     ///
     /// # Personality Integration
     ///
-    /// - **Bravery**: Affects fight vs flight threshold
-    /// - **Aggression**: Influences attack initiative (TODO)
+    /// - **Bravery**: Affects fight vs flight threshold and how heavily
+    ///   outnumbered the NPC will stay engaged
+    /// - **Loyalty + low Bravery**: Retreat towards allies instead of fleeing alone
+    /// - **Aggression**: Low-Aggression NPCs hold a chokepoint rather than
+    ///   charging the enemy
+    /// - **Empathy**: How far, and under how much pressure, an NPC goes to
+    ///   revive a downed ally
     /// - **Loyalty**: Prioritizes ally protection (TODO)
     /// - **Curiosity**: Drives exploration (TODO)
     pub fn select(ctx: &AiContext) -> Goal {
@@ -106,12 +200,10 @@ impl GoalSelector {
         if my_hp_percent < 30 {
             tracing::debug!("  Low HP detected ({}%)", my_hp_percent);
 
-            // If player is very close and we're low HP, flee immediately
+            // If player is very close and we're low HP, escape immediately
             if can_see_player && player_distance <= 5 {
-                tracing::debug!("  → Goal: FleeFrom (critical survival)");
-                return Goal::FleeFrom {
-                    threat: EntityId::PLAYER,
-                };
+                tracing::debug!("  Escaping (critical survival)");
+                return Self::escape_from(EntityId::PLAYER, ctx);
             }
 
             // If we have healing and are safe, heal
@@ -123,11 +215,40 @@ impl GoalSelector {
         }
 
         // ====================================================================
-        // Priority 2: Combat Decision (Player Visible)
+        // Priority 2: Scenario Objective (e.g. raise the alarm)
+        // ====================================================================
+
+        if let Some(prop) = ctx.pending_objective() {
+            tracing::debug!("  → Goal: Interact (objective prop={:?})", prop.id);
+            return Goal::Interact { prop: prop.id };
+        }
+
+        // ====================================================================
+        // Priority 3: Assist a Downed or Wounded Ally
         // ====================================================================
 
-        if can_see_player {
-            tracing::debug!("  Player visible at {} tiles", player_distance);
+        if let Some(ally) = Self::ally_to_revive(ctx) {
+            tracing::debug!("  → Goal: Revive (ally={:?})", ally);
+            return Goal::Revive { ally };
+        }
+
+        if let Some(range) = ctx.heal_range()
+            && let Some(ally) = ctx.most_injured_ally_within(range)
+        {
+            tracing::debug!(
+                "  → Goal: HealAlly (ally={:?}, wounded={}%)",
+                ally.id,
+                ctx.wounded_percent(ally.id)
+            );
+            return Goal::HealAlly { ally: ally.id };
+        }
+
+        // ====================================================================
+        // Priority 4: Combat Decision (Enemy Visible)
+        // ====================================================================
+
+        if let Some((target, target_distance)) = Self::combat_target(ctx) {
+            tracing::debug!("  Target {:?} visible at {} tiles", target, target_distance);
 
             // Get bravery trait (0-240, normalized to 0-100)
             let bravery = trait_profile
@@ -138,6 +259,19 @@ impl GoalSelector {
                 })
                 .unwrap_or(50);
 
+            // Break engagement when the local fight is lost, whatever our HP
+            let (allied, hostile) = ctx.local_strength();
+            let tolerance = OUTNUMBERED_BASE_PERCENT + bravery * OUTNUMBERED_BRAVERY_SCALE;
+            if hostile * 100 > allied * tolerance {
+                tracing::debug!(
+                    "  Outnumbered: allied={}, hostile={}, tolerance={}%",
+                    allied,
+                    hostile,
+                    tolerance
+                );
+                return Self::escape_from(target, ctx);
+            }
+
             // Combine HP and bravery to decide fight vs flight
             // High HP + High Bravery = Fight
             // Low HP + Low Bravery = Flight
@@ -151,29 +285,43 @@ impl GoalSelector {
             );
 
             if courage_score > 50 {
-                // Brave enough to fight
+                // Brave enough to fight, but a ranged attacker keeps its distance
+                // until its attack is ready again
+                if let Some(range) = ctx.reloading_range() {
+                    tracing::debug!("  → Goal: Kite (ranged attack reloading, range={})", range);
+                    return Goal::Kite { target };
+                }
+
+                if let Some(post) = Self::chokepoint_post(target, ctx) {
+                    tracing::debug!("  → Goal: Guard (post={:?})", post);
+                    return Goal::Guard {
+                        post,
+                        threat: target,
+                    };
+                }
+
                 tracing::debug!("  → Goal: Attack (courage_score > 50)");
-                return Goal::Attack {
-                    target: EntityId::PLAYER,
-                };
-            } else if player_distance <= 3 {
-                // Not brave, and player is close - flee!
-                tracing::debug!("  → Goal: FleeFrom (low courage + close enemy)");
-                return Goal::FleeFrom {
-                    threat: EntityId::PLAYER,
-                };
+                return Goal::Attack { target };
+            } else if target_distance <= 3 {
+                // Not brave, and the enemy is close - get away!
+                tracing::debug!("  Escaping (low courage + close enemy)");
+                return Self::escape_from(target, ctx);
             } else {
-                // Not brave, but player is far - just stay away (idle for now)
+                // Not brave, but the enemy is far - just stay away (idle for now)
                 tracing::debug!("  → Goal: Idle (low courage but safe distance)");
                 return Goal::Idle;
             }
         }
 
         // ====================================================================
-        // Priority 3: Exploration/Social (No immediate threats)
+        // Priority 5: Exploration/Social (No immediate threats)
         // ====================================================================
 
-        // TODO: Implement exploration goals when map/patrol system exists
+        if let Some(waypoint) = Self::patrol_waypoint(ctx) {
+            tracing::debug!("  → Goal: Patrol (waypoint={:?})", waypoint);
+            return Goal::Patrol { waypoint };
+        }
+
         // TODO: Implement social goals when ally system exists
 
         // ====================================================================
@@ -183,4 +331,126 @@ impl GoalSelector {
         tracing::debug!("  → Goal: Idle (no pressing concerns)");
         Goal::Idle
     }
+
+    /// Returns the enemy to fight and its distance (Manhattan), if any.
+    ///
+    /// Allies share the faction's [designated target] when they can see it,
+    /// concentrating fire instead of spreading damage.
+    ///
+    /// [designated target]: AiContext::designated_target
+    fn combat_target(ctx: &AiContext) -> Option<(EntityId, u32)> {
+        let my_pos = ctx.my_position()?;
+        let target = ctx.combat_target()?;
+        let distance = my_pos.manhattan_distance(target.position?);
+        Some((target.id, distance))
+    }
+
+    /// Returns the patrol waypoint to walk to, if the NPC can make progress.
+    ///
+    /// Patrolling needs at least one open step that brings the NPC closer
+    /// (Chebyshev) to the waypoint; a blocked patroller idles instead.
+    fn patrol_waypoint(ctx: &AiContext) -> Option<Position> {
+        let waypoint = ctx.patrol_waypoint()?;
+        let my_pos = ctx.my_position()?;
+        let distance = my_pos.chebyshev_distance(waypoint);
+
+        game_core::CardinalDirection::all()
+            .into_iter()
+            .filter(|direction| ctx.can_step(*direction))
+            .filter_map(|direction| ctx.position_after_move(direction))
+            .any(|pos| pos.chebyshev_distance(waypoint) < distance)
+            .then_some(waypoint)
+    }
+
+    /// Returns the region exit a defensive NPC should hold against `target`.
+    ///
+    /// Only NPCs with Aggression at or below [`GUARD_AGGRESSION_MAX`] guard;
+    /// see [`AiContext::chokepoint_to_hold`] for how the exit is chosen.
+    fn chokepoint_post(target: EntityId, ctx: &AiContext) -> Option<Position> {
+        let aggression = ctx
+            .trait_profile()?
+            .get(game_content::traits::TraitKind::Aggression);
+        if aggression > GUARD_AGGRESSION_MAX {
+            return None;
+        }
+
+        ctx.chokepoint_to_hold(ctx.state.entities.actor(target)?)
+    }
+
+    /// Returns the downed ally this NPC cares enough about to revive, if any.
+    ///
+    /// Requires a ready Revive ability. The nearest downed ally in sight is
+    /// revived when normalized Empathy (0-100) reaches [`REVIVE_EMPATHY_BASE`],
+    /// plus [`REVIVE_EMPATHY_PER_TILE`] per tile of distance and
+    /// [`REVIVE_EMPATHY_UNDER_THREAT`] while an enemy is in sight.
+    fn ally_to_revive(ctx: &AiContext) -> Option<EntityId> {
+        let me = ctx.state.entities.actor(ctx.entity)?;
+        if !me.can_use_action(ActionKind::Revive, ctx.state.turn.clock) {
+            return None;
+        }
+
+        let my_pos = ctx.my_position()?;
+        let ally = ctx.nearest_downed_ally()?;
+        let distance = my_pos.manhattan_distance(ally.position?);
+
+        let empathy = ctx
+            .trait_profile()
+            .map(|p| (p.get(game_content::traits::TraitKind::Empathy) as u32 * 100) / 240)
+            .unwrap_or(50);
+        let mut needed = REVIVE_EMPATHY_BASE + distance * REVIVE_EMPATHY_PER_TILE;
+        if ctx.nearest_enemy().is_some() {
+            needed += REVIVE_EMPATHY_UNDER_THREAT;
+        }
+
+        tracing::debug!(
+            "  Downed ally {:?} at {} tiles: empathy={}, needed={}",
+            ally.id,
+            distance,
+            empathy,
+            needed
+        );
+        (empathy >= needed).then_some(ally.id)
+    }
+
+    /// Chooses between retreating to an ally and fleeing outright.
+    ///
+    /// Loyal or timid NPCs (average of Loyalty and inverted Bravery at or
+    /// above the neutral 120) fall back to the nearest ally within
+    /// [`RETREAT_ALLY_RANGE`]. Everyone else, or anyone without an ally
+    /// nearby, simply flees. An NPC boxed in on every side has nowhere to go
+    /// and fights the threat instead.
+    fn escape_from(threat: EntityId, ctx: &AiContext) -> Goal {
+        if !ctx.has_escape_route() {
+            tracing::debug!("  → Goal: Attack (cornered, no open tile)");
+            return Goal::Attack { target: threat };
+        }
+
+        let retreat_tendency = ctx
+            .trait_profile()
+            .map(|p| {
+                let loyalty = p.get(game_content::traits::TraitKind::Loyalty) as u32;
+                let bravery = p.get(game_content::traits::TraitKind::Bravery) as u32;
+                (loyalty + (240 - bravery)) / 2
+            })
+            .unwrap_or(120);
+
+        if retreat_tendency >= 120
+            && let (Some(my_pos), Some(ally)) = (ctx.my_position(), ctx.nearest_ally())
+            && let Some(ally_pos) = ally.position
+            && my_pos.manhattan_distance(ally_pos) <= RETREAT_ALLY_RANGE
+        {
+            tracing::debug!(
+                "  → Goal: Retreat (ally={:?}, tendency={})",
+                ally.id,
+                retreat_tendency
+            );
+            return Goal::Retreat {
+                threat,
+                ally: ally.id,
+            };
+        }
+
+        tracing::debug!("  → Goal: FleeFrom (tendency={})", retreat_tendency);
+        Goal::FleeFrom { threat }
+    }
 }
@@ -15,6 +15,9 @@
 //!
 //! 4. **Selection**: Execute the highest-scoring candidate
 //!
+//! Movement towards a destination follows an A* path while the shared per-turn
+//! node budget lasts, and degrades to greedy single steps once it is spent.
+//!
 //! # Core Components
 //!
 //! - [`UtilityAiProvider`]: Main AI provider implementing [`crate::ActionProvider`]
@@ -22,15 +25,24 @@
 //! - [`GoalSelector`]: Selects goal based on HP, distance, traits, etc.
 //! - [`ActionCandidateGenerator`]: Generates all valid action+input pairs
 //! - [`AiContext`]: Shared context providing game state and helper methods
+//! - [`PathBudget`]: Per-turn node budget bounding A* work across all NPCs
+//! - [`PatrolRoutes`]: Waypoint loops walked by idle NPCs
+//! - [`ThreatAssessment`]: Allies, enemies and distances scanned once per decision
 
 pub mod context;
 pub mod generator;
 pub mod goal;
+pub mod pathfinding;
+pub mod patrol;
 pub mod provider;
 pub mod scoring;
+pub mod threat;
 
 // Re-export public API
 pub use context::AiContext;
 pub use generator::ActionCandidateGenerator;
 pub use goal::{Goal, GoalSelector};
+pub use pathfinding::PathBudget;
+pub use patrol::PatrolRoutes;
 pub use provider::UtilityAiProvider;
+pub use threat::ThreatAssessment;
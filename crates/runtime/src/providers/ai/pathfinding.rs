@@ -0,0 +1,337 @@
+//! Budgeted A* pathfinding for NPC movement.
+//!
+//! Path searches run [`game_core::search_path`] and are charged against a
+//! [`PathBudget`] that is shared by every AI decision made at the same clock
+//! tick. Once the budget for a tick is spent, later NPCs receive no path and
+//! fall back to greedy single-step movement, so the total search work per turn
+//! stays bounded no matter how many NPCs act.
+//!
+//! # Determinism
+//!
+//! The search only depends on game state and the remaining budget:
+//! - The search itself is deterministic (see [`game_core::find_path`])
+//! - The budget resets whenever the clock advances
+//!
+//! NPCs acting at the same tick are resolved in a fixed order, so replaying the
+//! same turn always hands out the same paths.
+
+use std::sync::Mutex;
+
+use game_core::{GameEnv, GameState, Position, Tick};
+
+/// Default number of nodes all NPCs together may expand per clock tick.
+pub const DEFAULT_NODES_PER_TURN: u32 = 512;
+
+/// Node budget shared by all AI decisions made at the same clock tick.
+///
+/// The first search at a new tick resets the budget to `nodes_per_turn`.
+/// Each search may use at most what is left, and its expanded nodes are
+/// deducted afterwards. With nothing left, no search is run at all.
+#[derive(Debug)]
+pub struct PathBudget {
+    nodes_per_turn: u32,
+    window: Mutex<BudgetWindow>,
+}
+
+#[derive(Debug)]
+struct BudgetWindow {
+    clock: Option<Tick>,
+    remaining: u32,
+}
+
+impl PathBudget {
+    /// Creates a budget allowing `nodes_per_turn` expansions per clock tick.
+    pub fn new(nodes_per_turn: u32) -> Self {
+        Self {
+            nodes_per_turn,
+            window: Mutex::new(BudgetWindow {
+                clock: None,
+                remaining: nodes_per_turn,
+            }),
+        }
+    }
+
+    /// Returns the configured number of nodes per clock tick.
+    pub fn nodes_per_turn(&self) -> u32 {
+        self.nodes_per_turn
+    }
+
+    /// Searches a path from `start` to `goal` against the budget for the
+    /// current clock tick.
+    ///
+    /// The search may expand what is left of the budget; the nodes it
+    /// expanded are deducted afterwards.
+    ///
+    /// # Returns
+    ///
+    /// The steps after `start`, or `None` if the budget is exhausted or no
+    /// path exists.
+    pub fn find_path(
+        &self,
+        state: &GameState,
+        env: &GameEnv<'_>,
+        start: Position,
+        goal: Position,
+    ) -> Option<Vec<Position>> {
+        let map = env.map().ok()?;
+        let mut window = self
+            .window
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let clock = state.turn.clock;
+        if window.clock != Some(clock) {
+            window.clock = Some(clock);
+            window.remaining = self.nodes_per_turn;
+        }
+        if window.remaining == 0 {
+            return None;
+        }
+
+        let result = game_core::search_path(map, state, start, goal, window.remaining as usize);
+        window.remaining = window
+            .remaining
+            .saturating_sub(result.nodes_expanded as u32);
+        result.path
+    }
+}
+
+impl Default for PathBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_NODES_PER_TURN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActionKind, ActorTemplate, EntityId, Faction,
+        GameConfig, MapDimensions, PathSearch, StaticTile, TerrainKind, TraitProfile,
+    };
+
+    use super::*;
+    use crate::api::ActionProvider;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+    use crate::providers::ai::{AiContext, Goal, GoalSelector, UtilityAiProvider};
+
+    const WALL_X: i32 = 6;
+
+    fn player_pos() -> Position {
+        Position::new(10, 5)
+    }
+
+    fn is_wall(pos: Position) -> bool {
+        pos.x == WALL_X && (2..=8).contains(&pos.y)
+    }
+
+    /// 16x16 floor with a wall at x = 6 spanning y = 2..=8.
+    fn walled_oracles() -> OracleBundle {
+        oracles_with_exits(&[])
+    }
+
+    /// The walled map with exit tiles at `exits`.
+    fn oracles_with_exits(exits: &[Position]) -> OracleBundle {
+        let mut tiles = HashMap::new();
+        for x in 0..16 {
+            for y in 0..16 {
+                let pos = Position::new(x, y);
+                let terrain = if is_wall(pos) {
+                    TerrainKind::Wall
+                } else if exits.contains(&pos) {
+                    TerrainKind::Exit
+                } else {
+                    TerrainKind::Floor
+                };
+                tiles.insert(pos, StaticTile::new(terrain));
+            }
+        }
+
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::new(MapDimensions::new(16, 16), tiles)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        )
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    /// Two goblins west of the wall at (5, 7) and (5, 5), the player east of it.
+    fn setup() -> (GameState, [EntityId; 2]) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), player_pos())
+            .unwrap();
+
+        let npcs = [Position::new(5, 7), Position::new(5, 5)].map(|pos| {
+            let id = state.add_npc(&template(Faction::GoblinClan), pos).unwrap();
+            let actor = state.entities.actor_mut(id).unwrap();
+            actor.actions.push(ActionAbility::new(ActionKind::Move));
+            actor.actions.push(ActionAbility::new(ActionKind::Wait));
+            id
+        });
+
+        (state, npcs)
+    }
+
+    /// A badly wounded goblin at (12, 12) with the player 3 tiles north.
+    fn cornered_goblin() -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(12, 9))
+            .unwrap();
+
+        let id = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(12, 12))
+            .unwrap();
+        let actor = state.entities.actor_mut(id).unwrap();
+        actor.resources.hp = 10;
+        actor.actions.push(ActionAbility::new(ActionKind::Move));
+        actor.actions.push(ActionAbility::new(ActionKind::Wait));
+
+        (state, id)
+    }
+
+    fn search(
+        state: &GameState,
+        oracles: &OracleBundle,
+        npc: EntityId,
+        limit: usize,
+    ) -> PathSearch {
+        let start = state.entities.actor(npc).unwrap().position.unwrap();
+        let map = oracles.as_game_env().map().unwrap();
+        game_core::search_path(map, state, start, player_pos(), limit)
+    }
+
+    async fn next_step(
+        provider: &UtilityAiProvider,
+        npc: EntityId,
+        state: &GameState,
+        oracles: &OracleBundle,
+    ) -> Position {
+        let action = provider
+            .provide_action(npc, state, oracles.as_game_env())
+            .await
+            .unwrap();
+        let Action::Character(action) = action else {
+            panic!("expected character action");
+        };
+        let ActionInput::Direction(dir) = action.input else {
+            panic!("expected a move, got {:?}", action);
+        };
+        let (dx, dy) = dir.offset();
+        let from = state.entities.actor(npc).unwrap().position.unwrap();
+        Position::new(from.x + dx, from.y + dy)
+    }
+
+    #[test]
+    fn routes_around_walls() {
+        let (state, [npc, _]) = setup();
+        let oracles = walled_oracles();
+
+        let result = search(&state, &oracles, npc, usize::MAX);
+        let path = result.path.clone().expect("path around the wall");
+
+        assert_eq!(path.last(), Some(&player_pos()));
+        assert!(path.iter().all(|pos| !is_wall(*pos)));
+        // Over the top end of the wall at (6, 9)
+        assert_eq!(path.len(), 6);
+        assert!(path.contains(&Position::new(WALL_X, 9)));
+
+        // Same search, same result
+        assert_eq!(search(&state, &oracles, npc, usize::MAX), result);
+    }
+
+    #[test]
+    fn node_limit_stops_search() {
+        let (state, [npc, _]) = setup();
+        let oracles = walled_oracles();
+
+        assert_eq!(
+            search(&state, &oracles, npc, 3),
+            PathSearch {
+                path: None,
+                nodes_expanded: 3,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn exhausted_budget_falls_back_to_greedy_moves() {
+        let (state, [first, second]) = setup();
+        let oracles = walled_oracles();
+
+        // Exactly enough budget for the first NPC's search
+        let full = search(&state, &oracles, first, usize::MAX);
+        let needed = full.nodes_expanded as u32;
+        let first_path_step = full.path.unwrap()[0];
+
+        let run = || async {
+            let provider = UtilityAiProvider::with_path_budget(needed);
+            let first_step = next_step(&provider, first, &state, &oracles).await;
+            let second_step = next_step(&provider, second, &state, &oracles).await;
+            (first_step, second_step)
+        };
+
+        let (first_step, second_step) = run().await;
+        // Full path: first step of the A* route around the wall
+        assert_eq!(first_step, first_path_step);
+        // Budget spent: greedy step straight into the wall
+        assert!(is_wall(second_step), "second stepped to {:?}", second_step);
+
+        // Reproducible across fresh providers
+        assert_eq!(run().await, (first_step, second_step));
+
+        // The next clock tick refills the budget
+        let provider = UtilityAiProvider::with_path_budget(needed);
+        next_step(&provider, first, &state, &oracles).await;
+        let mut later = state.clone();
+        later.turn.clock += 1;
+        assert_eq!(
+            next_step(&provider, first, &later, &oracles).await,
+            first_path_step
+        );
+    }
+
+    #[tokio::test]
+    async fn fleeing_npc_heads_for_the_nearest_exit() {
+        let (state, goblin) = cornered_goblin();
+        // (15, 15) is 3 tiles away, (8, 14) is 4
+        let oracles = oracles_with_exits(&[Position::new(8, 14), Position::new(15, 15)]);
+        let provider = UtilityAiProvider::new();
+
+        let ctx = AiContext::new(goblin, &state, oracles.as_game_env());
+        assert!(matches!(GoalSelector::select(&ctx), Goal::FleeFrom { .. }));
+        assert_eq!(ctx.nearest_exit(), Some(Position::new(15, 15)));
+
+        // The only shortest route is the diagonal
+        let step = next_step(&provider, goblin, &state, &oracles).await;
+        assert_eq!(step, Position::new(13, 13));
+    }
+
+    #[tokio::test]
+    async fn fleeing_without_exits_moves_away_from_the_threat() {
+        let (state, goblin) = cornered_goblin();
+        let oracles = walled_oracles();
+        let provider = UtilityAiProvider::new();
+
+        let ctx = AiContext::new(goblin, &state, oracles.as_game_env());
+        assert_eq!(ctx.nearest_exit(), None);
+
+        let player = state.entities.player().position.unwrap();
+        let step = next_step(&provider, goblin, &state, &oracles).await;
+        assert!(step.manhattan_distance(player) > Position::new(12, 12).manhattan_distance(player));
+    }
+}
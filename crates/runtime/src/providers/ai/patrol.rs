@@ -0,0 +1,98 @@
+//! Patrol routes for idle NPCs.
+//!
+//! A patrol route is a loop of waypoints assigned to an NPC. While nothing
+//! more pressing is going on, the NPC walks to its current waypoint and, once
+//! standing on it, moves on to the next one, wrapping around at the end.
+//!
+//! # Determinism
+//!
+//! Progress only advances when the NPC is asked to decide while standing on
+//! its current waypoint, so it depends on nothing but the sequence of game
+//! states the NPC decides in. Replaying the same session hands out the same
+//! waypoints.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use game_core::{EntityId, Position};
+
+/// Waypoint loop of a single NPC and how far along it the NPC is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PatrolRoute {
+    waypoints: Vec<Position>,
+    next: usize,
+}
+
+/// Patrol routes of every patrolling NPC, shared by all AI decisions.
+#[derive(Debug, Default)]
+pub struct PatrolRoutes {
+    routes: Mutex<HashMap<EntityId, PatrolRoute>>,
+}
+
+impl PatrolRoutes {
+    /// Creates an empty set of routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns `waypoints` to `entity`, starting at the first one.
+    ///
+    /// An empty list removes the entity's route.
+    pub fn assign(&self, entity: EntityId, waypoints: Vec<Position>) {
+        let mut routes = self
+            .routes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if waypoints.is_empty() {
+            routes.remove(&entity);
+        } else {
+            routes.insert(entity, PatrolRoute { waypoints, next: 0 });
+        }
+    }
+
+    /// Returns the waypoint `entity` should head for from `position`.
+    ///
+    /// Standing on the current waypoint advances the route to the next one
+    /// first. Returns `None` if the entity has no route.
+    pub fn next_waypoint(&self, entity: EntityId, position: Position) -> Option<Position> {
+        let mut routes = self
+            .routes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let route = routes.get_mut(&entity)?;
+
+        if route.waypoints[route.next] == position {
+            route.next = (route.next + 1) % route.waypoints.len();
+        }
+        Some(route.waypoints[route.next])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUARD: EntityId = EntityId(3);
+
+    #[test]
+    fn reaching_a_waypoint_advances_and_wraps() {
+        let (a, b) = (Position::new(2, 5), Position::new(6, 5));
+        let routes = PatrolRoutes::new();
+        routes.assign(GUARD, vec![a, b]);
+
+        assert_eq!(routes.next_waypoint(GUARD, Position::new(4, 4)), Some(a));
+        assert_eq!(routes.next_waypoint(GUARD, a), Some(b));
+        assert_eq!(routes.next_waypoint(GUARD, Position::new(4, 5)), Some(b));
+        assert_eq!(routes.next_waypoint(GUARD, b), Some(a));
+    }
+
+    #[test]
+    fn entities_without_a_route_do_not_patrol() {
+        let routes = PatrolRoutes::new();
+        routes.assign(GUARD, vec![Position::new(1, 1)]);
+        routes.assign(GUARD, Vec::new());
+
+        assert_eq!(routes.next_waypoint(GUARD, Position::new(1, 1)), None);
+        assert_eq!(routes.next_waypoint(EntityId(4), Position::new(1, 1)), None);
+    }
+}
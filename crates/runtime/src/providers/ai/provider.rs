@@ -6,12 +6,19 @@
 //! 3. Score each candidate by utility (how well it serves the goal)
 //! 4. Execute the highest-scoring candidate
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use game_core::{Action, CharacterAction, EntityId, GameEnv, GameState};
+use game_core::{
+    Action, ActionInput, ActionKind, CharacterAction, EntityId, GameEnv, GameState, Position,
+};
 
 use super::AiContext;
 use super::generator::ActionCandidateGenerator;
-use super::goal::GoalSelector;
+use super::goal::{Goal, GoalSelector};
+use super::pathfinding::PathBudget;
+use super::patrol::PatrolRoutes;
+use super::scoring::DEFAULT_FRIENDLY_FIRE_PENALTY;
 use crate::api::{ActionProvider, Result};
 
 /// Utility-based AI provider with goal-directed decision making.
@@ -20,6 +27,7 @@ use crate::api::{ActionProvider, Result};
 ///
 /// 1. **Goal Selection**: Pick a concrete goal based on situation and traits
 ///    - Examples: "Attack Player", "Flee from Player", "Heal Self", "Idle"
+///    - Fighting goals first swap to the best weapon for the range, if needed
 /// 2. **Candidate Generation**: Generate all possible (Action, Input) pairs
 /// 3. **Utility Scoring**: Score each candidate by how well it serves the goal (0-100)
 /// 4. **Selection**: Execute the highest-scoring candidate
@@ -30,13 +38,150 @@ use crate::api::{ActionProvider, Result};
 /// - **Simple**: One decision (goal) → one evaluation (score actions) → one output
 /// - **Flexible**: Easy to add new goals without restructuring layers
 /// - **Debuggable**: Clear trace of goal → action → score
-#[derive(Debug, Clone, Default)]
-pub struct UtilityAiProvider;
+///
+/// # Pathfinding Budget
+///
+/// Goals with a destination (Attack, MoveTo, Interact, Revive, and FleeFrom towards
+/// the nearest exit) plan their next step with A*.
+/// All searches made at the same clock tick share one [`PathBudget`]; once it
+/// is spent, later NPCs fall back to greedy single-step movement. Clones share
+/// the same budget.
+///
+/// # Tie-Breaking
+///
+/// By default the first of several equal-score candidates wins. With
+/// [`with_seeded_tie_break`](Self::with_seeded_tie_break), ties are broken by
+/// a hash of the game seed, nonce, actor and candidate index instead, so the
+/// choice varies between turns but stays reproducible.
+///
+/// # Patrols
+///
+/// NPCs given a route with [`with_patrol_route`](Self::with_patrol_route)
+/// walk it whenever they have nothing more pressing to do. Clones share
+/// route progress.
+///
+/// # Friendly Fire
+///
+/// Area attacks that would strike allies lose
+/// [`DEFAULT_FRIENDLY_FIRE_PENALTY`] points per ally caught. Tune it with
+/// [`with_friendly_fire_penalty`](Self::with_friendly_fire_penalty); 100 or
+/// more forbids such placements, 0 ignores allies altogether.
+#[derive(Debug, Clone)]
+pub struct UtilityAiProvider {
+    path_budget: Arc<PathBudget>,
+    patrol_routes: Arc<PatrolRoutes>,
+    seeded_tie_break: bool,
+    friendly_fire_penalty: u32,
+}
+
+impl Default for UtilityAiProvider {
+    fn default() -> Self {
+        Self {
+            path_budget: Arc::default(),
+            patrol_routes: Arc::default(),
+            seeded_tie_break: false,
+            friendly_fire_penalty: DEFAULT_FRIENDLY_FIRE_PENALTY,
+        }
+    }
+}
 
 impl UtilityAiProvider {
-    /// Creates a new utility-based AI provider.
+    /// Creates a new utility-based AI provider with the default path budget.
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Creates a provider allowing `nodes_per_turn` A* expansions per clock tick.
+    pub fn with_path_budget(nodes_per_turn: u32) -> Self {
+        Self {
+            path_budget: Arc::new(PathBudget::new(nodes_per_turn)),
+            ..Self::default()
+        }
+    }
+
+    /// Assigns a loop of patrol `waypoints` to `entity`.
+    pub fn with_patrol_route(self, entity: EntityId, waypoints: Vec<Position>) -> Self {
+        self.patrol_routes.assign(entity, waypoints);
+        self
+    }
+
+    /// Breaks ties between equal-score candidates with the game seed.
+    pub fn with_seeded_tie_break(mut self, enabled: bool) -> Self {
+        self.seeded_tie_break = enabled;
+        self
+    }
+
+    /// Subtracts `points_per_ally` from area attacks for each ally they would strike.
+    pub fn with_friendly_fire_penalty(mut self, points_per_ally: u32) -> Self {
+        self.friendly_fire_penalty = points_per_ally;
+        self
+    }
+
+    /// Plans the next step towards the goal's destination within the budget.
+    ///
+    /// Attack, MoveTo, Interact and Revive stop next to their destination; FleeFrom
+    /// heads for the nearest map exit, Guard for its post and Patrol for its
+    /// waypoint, stepping onto it.
+    ///
+    /// Returns `None` for goals without a destination, when already there,
+    /// or when no path fits in what is left of this turn's budget.
+    fn plan_step(&self, goal: &Goal, ctx: &AiContext) -> Option<Position> {
+        let (destination, reach) = match goal {
+            Goal::Attack { target } => (ctx.state.entities.actor(*target)?.position?, 1),
+            Goal::MoveTo { position } => (*position, 1),
+            Goal::Interact { prop } => (ctx.state.entities.prop(*prop)?.position, 1),
+            Goal::FleeFrom { .. } => (ctx.nearest_exit()?, 0),
+            Goal::Guard { post, .. } => (*post, 0),
+            Goal::Patrol { waypoint } => (*waypoint, 0),
+            Goal::Revive { ally } => (ctx.state.entities.actor(*ally)?.position?, 1),
+            _ => return None,
+        };
+        let start = ctx.my_position()?;
+        if start.chebyshev_distance(destination) <= reach {
+            return None;
+        }
+
+        let path = self
+            .path_budget
+            .find_path(ctx.state, &ctx.env, start, destination);
+
+        if path.is_none() {
+            tracing::debug!(
+                "UtilityAI: entity={:?} has no path this turn, moving greedily",
+                ctx.entity
+            );
+        }
+
+        path.and_then(|steps| steps.first().copied())
+    }
+
+    /// Swaps to the best carried weapon for the engagement range, if any.
+    ///
+    /// Runs before candidate scoring whenever the goal is to fight, so an NPC
+    /// holding a sword pulls out its bow for a distant target and switches
+    /// back once the target is adjacent. The swap is an ordinary EquipWeapon
+    /// action and costs its full action time.
+    fn weapon_swap(goal: &Goal, ctx: &AiContext) -> Option<Action> {
+        let (Goal::Attack { target } | Goal::Kite { target } | Goal::Guard { threat: target, .. }) =
+            goal
+        else {
+            return None;
+        };
+        if !ctx.available_actions().contains(&ActionKind::EquipWeapon) {
+            return None;
+        }
+
+        let slot = ctx.better_weapon_slot(*target)?;
+        tracing::debug!(
+            "UtilityAI: entity={:?} swapping to weapon in slot {}",
+            ctx.entity,
+            slot
+        );
+        Some(Action::Character(CharacterAction::new(
+            ctx.entity,
+            ActionKind::EquipWeapon,
+            ActionInput::InventorySlot(slot),
+        )))
     }
 }
 
@@ -49,7 +194,7 @@ impl ActionProvider for UtilityAiProvider {
         env: GameEnv<'_>,
     ) -> Result<Action> {
         // Validate entity exists
-        let _actor = state
+        let actor = state
             .entities
             .actor(entity)
             .ok_or_else(|| crate::api::errors::RuntimeError::InvalidEntityId(entity))?;
@@ -63,9 +208,17 @@ impl ActionProvider for UtilityAiProvider {
             available_kinds.len()
         );
 
+        // Advance the patrol route before deciding, whatever the goal ends up
+        let patrol_waypoint = actor
+            .position
+            .and_then(|pos| self.patrol_routes.next_waypoint(entity, pos));
+
         // Build AI context
-        let ctx =
-            AiContext::new(entity, state, env).with_available_actions(available_kinds.clone());
+        let ctx = AiContext::new(entity, state, env)
+            .with_available_actions(available_kinds.clone())
+            .with_patrol_waypoint(patrol_waypoint)
+            .with_seeded_tie_break(self.seeded_tie_break)
+            .with_friendly_fire_penalty(self.friendly_fire_penalty);
 
         // ====================================================================
         // Step 1: Select Goal
@@ -75,6 +228,17 @@ impl ActionProvider for UtilityAiProvider {
 
         tracing::debug!("UtilityAI: entity={:?} selected goal: {:?}", entity, goal);
 
+        // ====================================================================
+        // Step 1b: Pre-combat Weapon Swap
+        // ====================================================================
+
+        if let Some(action) = Self::weapon_swap(&goal, &ctx) {
+            return Ok(action);
+        }
+
+        let planned_step = self.plan_step(&goal, &ctx);
+        let ctx = ctx.with_planned_step(planned_step);
+
         // ====================================================================
         // Step 2: Generate Candidates
         // ====================================================================
@@ -105,14 +269,18 @@ impl ActionProvider for UtilityAiProvider {
 
         let mut best_candidate = None;
         let mut best_score = 0;
+        let mut best_key = u64::MAX;
 
-        for (kind, input) in candidates {
+        for (index, (kind, input)) in candidates.into_iter().enumerate() {
             let score = goal.evaluate_action(kind, &input, &ctx);
 
             tracing::debug!("  Candidate: {:?} + {:?} = score {}", kind, input, score);
 
-            if score > best_score {
+            // Among equal scores the lowest tie-break key wins
+            let key = ctx.tie_break_key(index);
+            if score > best_score || (score > 0 && score == best_score && key < best_key) {
                 best_score = score;
+                best_key = key;
                 best_candidate = Some((kind, input));
             }
         }
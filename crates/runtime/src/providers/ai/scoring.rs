@@ -4,8 +4,12 @@
 //! an action+input combination serves that goal.
 //!
 //! All scoring functions are pure and return a score from 0-100.
+//! Modifiers (e.g. [`spacing_penalty`]) return points subtracted from that score.
 
-use game_core::{ActionInput, ActionKind, CardinalDirection, EntityId, Position};
+use game_core::{
+    ActionInput, ActionKind, ActionProfile, CardinalDirection, CharacterAction, EntityId, Position,
+    TraitKind,
+};
 
 use super::AiContext;
 
@@ -42,6 +46,14 @@ pub fn score_for_attack(
                     10 // No position
                 }
             }
+            ActionInput::Position(_) => {
+                // Check if the area around the position covers the target
+                if struck_actors(kind, input, &profile, ctx).contains(&target) {
+                    95 // Area catches the target
+                } else {
+                    30 // Area misses the target
+                }
+            }
             _ => 50, // Attack action but unclear targeting
         }
     }
@@ -65,10 +77,15 @@ pub fn score_for_attack(
             let current_dist = my_pos.manhattan_distance(target_pos);
             let new_dist = new_pos.manhattan_distance(target_pos);
 
-            // Prefer moving closer to target
-            if new_dist < current_dist {
+            // Follow the planned path if one was found, else step greedily
+            let approaching = match ctx.planned_step() {
+                Some(step) => new_pos == step,
+                None => new_dist < current_dist,
+            };
+
+            if approaching {
                 70 // Good: approaching target
-            } else if new_dist == current_dist {
+            } else if new_dist <= current_dist {
                 30 // Neutral: circling
             } else {
                 10 // Bad: moving away
@@ -82,6 +99,11 @@ pub fn score_for_attack(
 }
 
 /// Scores actions for the FleeFrom goal.
+///
+/// When the map has an exit within reach of the path budget, the planned step
+/// towards it wins. Without one, moves that gain distance from the threat do,
+/// the most distance first. Moves into walls or other actors score nothing,
+/// so an NPC backed against a wall still takes the best open step.
 pub fn score_for_flee(
     kind: ActionKind,
     input: &ActionInput,
@@ -107,15 +129,26 @@ pub fn score_for_flee(
                 None => return 10,
             };
 
+            if !ctx.can_step(*dir) {
+                return 0; // Blocked: wall, map edge or another actor
+            }
+
             let (dx, dy) = dir.offset();
             let new_pos = Position::new(my_pos.x + dx, my_pos.y + dy);
 
             let current_dist = my_pos.manhattan_distance(threat_pos);
             let new_dist = new_pos.manhattan_distance(threat_pos);
 
-            // Prefer moving away from threat
-            if new_dist > current_dist {
-                100 // Perfect: fleeing successfully
+            // With a route to an exit, follow it; otherwise open space will do
+            if ctx.planned_step() == Some(new_pos) {
+                100 // Perfect: heading for the exit
+            } else if new_dist > current_dist {
+                if ctx.planned_step().is_some() {
+                    70 // Good: away from the threat, but off the exit route
+                } else {
+                    // Fleeing successfully; a diagonal step gains 2 tiles
+                    80 + 10 * (new_dist - current_dist).min(2)
+                }
             } else if new_dist == current_dist {
                 30 // Neutral: circling
             } else {
@@ -133,6 +166,125 @@ pub fn score_for_flee(
     }
 }
 
+/// Scores actions for the Retreat goal.
+///
+/// Unlike [`score_for_flee`], which only maximizes distance from the threat,
+/// retreating prefers moves that also close in on the protecting ally. Moves
+/// towards the threat, and moves onto blocked tiles, are never acceptable.
+pub fn score_for_retreat(
+    kind: ActionKind,
+    input: &ActionInput,
+    threat: EntityId,
+    ally: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    let profile = match ctx.env.actions() {
+        Ok(actions) => actions.action_profile(kind),
+        Err(_) => return 0,
+    };
+
+    if profile.tags.contains(&game_core::ActionTag::Movement) {
+        if let ActionInput::Direction(dir) = input {
+            let Some(my_pos) = ctx.my_position() else {
+                return 10; // No position
+            };
+            let (Some(threat_pos), Some(ally_pos)) = (
+                ctx.state.actor_position(threat),
+                ctx.state.actor_position(ally),
+            ) else {
+                return 10;
+            };
+            if !ctx.can_step(*dir) {
+                return 0; // Blocked: wall, map edge or another actor
+            }
+
+            let (dx, dy) = dir.offset();
+            let new_pos = Position::new(my_pos.x + dx, my_pos.y + dy);
+
+            let threat_dist = my_pos.manhattan_distance(threat_pos);
+            let new_threat_dist = new_pos.manhattan_distance(threat_pos);
+            let ally_dist = my_pos.manhattan_distance(ally_pos);
+            let new_ally_dist = new_pos.manhattan_distance(ally_pos);
+
+            if new_threat_dist < threat_dist {
+                return 0; // Bad: moving towards threat
+            }
+
+            let away = new_threat_dist > threat_dist;
+            let closer_to_ally = new_ally_dist < ally_dist;
+            match (away, closer_to_ally) {
+                (true, true) => 100,  // Perfect: escaping towards the ally
+                (false, true) => 70,  // Sidestepping towards the ally
+                (true, false) => 50,  // Escaping, but into open space
+                (false, false) => 20, // Circling away from the ally
+            }
+        } else {
+            20
+        }
+    }
+    // Attacking while retreating is a last resort
+    else if profile.tags.contains(&game_core::ActionTag::Attack) {
+        10
+    } else {
+        20 // Wait
+    }
+}
+
+/// Scores actions for the Kite goal.
+///
+/// Retreating from the target is preferred as long as the new tile stays
+/// within the reloading attack's range, so the NPC can shoot again as soon as
+/// the cooldown clears. Attacks remain a fallback when cornered.
+pub fn score_for_kite(
+    kind: ActionKind,
+    input: &ActionInput,
+    target: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    let profile = match ctx.env.actions() {
+        Ok(actions) => actions.action_profile(kind),
+        Err(_) => return 0,
+    };
+
+    if profile.tags.contains(&game_core::ActionTag::Movement) {
+        if let ActionInput::Direction(dir) = input {
+            let Some(my_pos) = ctx.my_position() else {
+                return 10; // No position
+            };
+            let Some(target_pos) = ctx.state.actor_position(target) else {
+                return 10;
+            };
+
+            let (dx, dy) = dir.offset();
+            let new_pos = Position::new(my_pos.x + dx, my_pos.y + dy);
+
+            let current_dist = my_pos.manhattan_distance(target_pos);
+            let new_dist = new_pos.manhattan_distance(target_pos);
+            let range = ctx.reloading_range().unwrap_or(1);
+
+            if new_dist > current_dist {
+                if new_pos.chebyshev_distance(target_pos) <= range {
+                    100 // Perfect: retreating while staying in range
+                } else {
+                    60 // Retreating, but will need to close in again
+                }
+            } else if new_dist == current_dist {
+                30 // Neutral: circling
+            } else {
+                0 // Bad: closing in while unable to shoot
+            }
+        } else {
+            20
+        }
+    }
+    // Other attacks (e.g. melee) are a fallback when no retreat is possible
+    else if profile.tags.contains(&game_core::ActionTag::Attack) {
+        40
+    } else {
+        20 // Wait
+    }
+}
+
 /// Scores actions for the HealSelf goal.
 ///
 /// NOTE: Currently disabled - requires Heal and UseItem ActionKinds to be implemented.
@@ -191,9 +343,14 @@ pub fn score_for_move_to(
             let current_dist = my_pos.manhattan_distance(target_pos);
             let new_dist = new_pos.manhattan_distance(target_pos);
 
-            if new_dist < current_dist {
+            let approaching = match ctx.planned_step() {
+                Some(step) => new_pos == step,
+                None => new_dist < current_dist,
+            };
+
+            if approaching {
                 100 // Moving closer
-            } else if new_dist == current_dist {
+            } else if new_dist <= current_dist {
                 50 // Circling
             } else {
                 10 // Moving away
@@ -206,6 +363,81 @@ pub fn score_for_move_to(
     }
 }
 
+/// Scores actions for the Patrol goal.
+///
+/// Steps closer (Chebyshev) to the waypoint score 80, following the planned
+/// path when there is one; the spare margin below 100 keeps patrolling a
+/// low-key activity. Sidesteps score 30, steps away 10 and steps into walls
+/// or other actors nothing. Waiting scores 20.
+pub fn score_for_patrol(
+    kind: ActionKind,
+    input: &ActionInput,
+    waypoint: Position,
+    ctx: &AiContext,
+) -> u32 {
+    let profile = match ctx.env.actions() {
+        Ok(actions) => actions.action_profile(kind),
+        Err(_) => return 0,
+    };
+
+    if profile.tags.contains(&game_core::ActionTag::Movement) {
+        let ActionInput::Direction(dir) = input else {
+            return 10;
+        };
+        let (Some(my_pos), Some(new_pos)) = (ctx.my_position(), ctx.position_after_move(*dir))
+        else {
+            return 0;
+        };
+        if !ctx.can_step(*dir) {
+            return 0;
+        }
+
+        let current_dist = my_pos.chebyshev_distance(waypoint);
+        let new_dist = new_pos.chebyshev_distance(waypoint);
+        let approaching = match ctx.planned_step() {
+            Some(step) => new_pos == step,
+            None => new_dist < current_dist,
+        };
+
+        if approaching {
+            80
+        } else if new_dist <= current_dist {
+            30
+        } else {
+            10
+        }
+    } else if kind == ActionKind::Wait {
+        20
+    } else {
+        0
+    }
+}
+
+/// Scores actions for the Interact goal.
+///
+/// Operating the objective prop scores 100. Until it is in reach, movement is
+/// scored like [`score_for_move_to`] towards the prop, capped at 90 so the
+/// interaction itself always wins once available.
+pub fn score_for_interact(
+    kind: ActionKind,
+    input: &ActionInput,
+    prop: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    if kind == ActionKind::Interact {
+        return if *input == ActionInput::Target(prop) {
+            100
+        } else {
+            0 // Leave other props alone
+        };
+    }
+
+    let Some(prop_pos) = ctx.state.entities.prop(prop).map(|p| p.position) else {
+        return 0;
+    };
+    score_for_move_to(kind, input, prop_pos, ctx).min(90)
+}
+
 /// Scores actions for the ProtectAlly goal.
 pub fn score_for_protect_ally(
     kind: ActionKind,
@@ -262,10 +494,215 @@ pub fn score_for_protect_ally(
     }
 }
 
+/// Scores actions for the Guard goal.
+///
+/// Attacks on the threat are scored like [`score_for_attack`], but only once
+/// the threat is in reach; the guard never leaves its post to chase. Until
+/// then, movement is scored like [`score_for_move_to`] towards the post
+/// (capped at 90), and waiting on the post scores 90.
+pub fn score_for_guard(
+    kind: ActionKind,
+    input: &ActionInput,
+    post: Position,
+    threat: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    let profile = match ctx.env.actions() {
+        Ok(actions) => actions.action_profile(kind),
+        Err(_) => return 0,
+    };
+    let Some(my_pos) = ctx.my_position() else {
+        return 0;
+    };
+
+    if profile.tags.contains(&game_core::ActionTag::Attack) {
+        let adjacent = ctx
+            .state
+            .entities
+            .actor(threat)
+            .and_then(|actor| actor.position)
+            .is_some_and(|pos| my_pos.chebyshev_distance(pos) <= 1);
+        return match input {
+            ActionInput::Target(id) if *id == threat => 100,
+            ActionInput::Direction(_) if adjacent => score_for_attack(kind, input, threat, ctx),
+            _ => 0,
+        };
+    }
+
+    if my_pos == post {
+        if kind == ActionKind::Wait { 90 } else { 10 }
+    } else {
+        score_for_move_to(kind, input, post, ctx).min(90)
+    }
+}
+
+/// Scores actions for the Revive goal.
+///
+/// Reviving the ally scores 100. Until it is in reach, movement is scored
+/// like [`score_for_move_to`] towards the ally, capped at 90 so the revive
+/// itself always wins once available.
+pub fn score_for_revive(
+    kind: ActionKind,
+    input: &ActionInput,
+    ally: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    if kind == ActionKind::Revive {
+        return if *input == ActionInput::Target(ally) {
+            100
+        } else {
+            0 // Someone else's turn to be helped up
+        };
+    }
+
+    let Some(ally_pos) = ctx.state.entities.actor(ally).and_then(|a| a.position) else {
+        return 0;
+    };
+    score_for_move_to(kind, input, ally_pos, ctx).min(90)
+}
+
+/// Scores actions for the HealAlly goal.
+///
+/// Healing the ally scores by how wounded it is, from 50 for a scratch up to
+/// 100 for an ally on its last HP. Healing anyone else, or an ally that has
+/// left Heal's range or is back to full HP, scores 0, as does every other
+/// action.
+pub fn score_for_heal_ally(
+    kind: ActionKind,
+    input: &ActionInput,
+    ally: EntityId,
+    ctx: &AiContext,
+) -> u32 {
+    if kind != ActionKind::Heal || *input != ActionInput::Target(ally) {
+        return 0;
+    }
+
+    let in_range = match (
+        ctx.heal_range(),
+        ctx.my_position(),
+        ctx.state.actor_position(ally),
+    ) {
+        (Some(range), Some(my_pos), Some(ally_pos)) => my_pos.chebyshev_distance(ally_pos) <= range,
+        _ => false,
+    };
+    let wounded = ctx.wounded_percent(ally);
+    if !in_range || wounded == 0 {
+        return 0;
+    }
+
+    50 + wounded / 2
+}
+
+// ============================================================================
+// Modifiers
+// ============================================================================
+
+/// Upper bound for [`spacing_penalty`].
+///
+/// Kept below the gap between "approaching" (70) and "circling" (30) in
+/// [`score_for_attack`] so spacing never overrides the goal itself.
+const MAX_SPACING_PENALTY: u32 = 20;
+
+/// Faction threat count at which a group closes formation completely.
+///
+/// With one visible hostile (or none) the full spacing penalty applies; each
+/// additional hostile removes an equal share until allies stop spreading out.
+const CLOSED_FORMATION_THREATS: u32 = 4;
+
+/// Default points [`friendly_fire_penalty`] charges per ally caught in a blast.
+///
+/// One ally drops a burst that catches the target (95) below "approaching"
+/// (70) in [`score_for_attack`], so the NPC repositions rather than fire.
+pub const DEFAULT_FRIENDLY_FIRE_PENALTY: u32 = 50;
+
+/// Penalizes movement that clumps this entity together with its allies.
+///
+/// Each ally on or adjacent to the destination tile costs 3-8 points, scaled
+/// by the TacticalSense trait, capped at [`MAX_SPACING_PENALTY`]. The result
+/// then shrinks with the faction's threat count so groups spread out against
+/// few enemies and close ranks for mutual support against many (see
+/// [`CLOSED_FORMATION_THREATS`]). Non-movement actions and NPCs without nearby
+/// allies are never penalized.
+pub fn spacing_penalty(kind: ActionKind, input: &ActionInput, ctx: &AiContext) -> u32 {
+    let ActionInput::Direction(dir) = input else {
+        return 0;
+    };
+
+    let is_movement = match ctx.env.actions() {
+        Ok(actions) => actions
+            .action_profile(kind)
+            .tags
+            .contains(&game_core::ActionTag::Movement),
+        Err(_) => false,
+    };
+    if !is_movement {
+        return 0;
+    }
+
+    let Some(new_pos) = ctx.position_after_move(*dir) else {
+        return 0;
+    };
+
+    let crowding = ctx.allies_near(new_pos, 1);
+    if crowding == 0 {
+        return 0;
+    }
+
+    // TacticalSense 0-240 → 3-8 points per crowding ally
+    let tactical_sense = ctx
+        .trait_profile()
+        .map(|p| p.get(TraitKind::TacticalSense) as u32)
+        .unwrap_or(120);
+    let per_ally = 3 + (tactical_sense * 5) / 240;
+    let penalty = (crowding * per_ally).min(MAX_SPACING_PENALTY);
+
+    // Open formation against a lone enemy, closed against many
+    let threats = ctx
+        .faction_threat_count()
+        .clamp(1, CLOSED_FORMATION_THREATS);
+    penalty * (CLOSED_FORMATION_THREATS - threats) / (CLOSED_FORMATION_THREATS - 1)
+}
+
+/// Penalizes attacks that would strike allies of this entity.
+///
+/// The actors an attack hits are resolved the way the engine resolves them
+/// (see [`game_core::resolve_targets`]), so only areas that do not spare
+/// allies are penalized. Each ally struck, and this entity itself, costs
+/// [`AiContext::friendly_fire_penalty`] points. A penalty of 100 or more
+/// forbids such placements outright, since zero-score candidates are never
+/// chosen.
+pub fn friendly_fire_penalty(kind: ActionKind, input: &ActionInput, ctx: &AiContext) -> u32 {
+    let Ok(actions) = ctx.env.actions() else {
+        return 0;
+    };
+    let profile = actions.action_profile(kind);
+    if !profile.tags.contains(&game_core::ActionTag::Attack) {
+        return 0;
+    }
+
+    let allies = &ctx.threat().allies;
+    let caught = struck_actors(kind, input, &profile, ctx)
+        .into_iter()
+        .filter(|&id| id == ctx.entity || allies.iter().any(|ally| ally.id == id))
+        .count() as u32;
+    caught.saturating_mul(ctx.friendly_fire_penalty())
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Returns the actors `kind` would strike with `input`, or none if it cannot.
+fn struck_actors(
+    kind: ActionKind,
+    input: &ActionInput,
+    profile: &ActionProfile,
+    ctx: &AiContext,
+) -> Vec<EntityId> {
+    let action = CharacterAction::new(ctx.entity, kind, input.clone());
+    game_core::resolve_targets(&action, ctx.state, &ctx.env, profile).unwrap_or_default()
+}
+
 /// Calculates the cardinal direction from one position to an entity.
 pub fn direction_to_entity(
     from: Position,
@@ -291,3 +728,1179 @@ pub fn direction_to_entity(
         _ => None,      // Shouldn't happen
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use game_core::{
+        Action, ActionAbility, ActorTemplate, CharacterAction, ExecuteError, Faction, GameConfig,
+        GameEngine, GameState, ItemDefinition, ItemHandle, ItemKind, MapDimensions, PropKind,
+        PropState, Relation, StaticTile, StatusEffectKind, TerrainKind, TraitProfile, WeaponData,
+        WeaponKind,
+    };
+
+    use super::*;
+    use crate::api::ActionProvider;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+    use crate::providers::ai::{ActionCandidateGenerator, Goal, GoalSelector, UtilityAiProvider};
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(16, 16)
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    /// Player at (10, 10), deciding NPC at (5, 5), allies at the given positions.
+    fn setup(allies: &[Position]) -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(10, 10))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+        for pos in allies {
+            state.add_npc(&template(Faction::GoblinClan), *pos).unwrap();
+        }
+        (state, npc)
+    }
+
+    /// Moves `id` to `to`, keeping the occupancy map in sync.
+    fn relocate(state: &mut GameState, id: EntityId, to: Position) {
+        let from = state.entities.position(id).unwrap();
+        state.world.tile_map.remove_occupant(&from, id);
+        state.world.tile_map.add_occupant(to, id);
+        state.entities.actor_mut(id).unwrap().position = Some(to);
+    }
+
+    fn attack_score(ctx: &AiContext, dir: CardinalDirection) -> u32 {
+        Goal::Attack {
+            target: EntityId::PLAYER,
+        }
+        .evaluate_action(ActionKind::Move, &ActionInput::Direction(dir), ctx)
+    }
+
+    #[test]
+    fn prefers_approach_away_from_allies() {
+        // Ally next to the North approach tile (5, 6), far from the East tile (6, 5)
+        let (state, npc) = setup(&[Position::new(4, 7)]);
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+
+        let north = attack_score(&ctx, CardinalDirection::North);
+        let east = attack_score(&ctx, CardinalDirection::East);
+
+        assert!(east > north, "east={} north={}", east, north);
+        // Spacing never turns an approach into something worse than circling
+        assert!(north > attack_score(&ctx, CardinalDirection::NorthWest));
+    }
+
+    #[test]
+    fn lone_npc_is_unaffected() {
+        let (state, npc) = setup(&[]);
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+
+        for dir in CardinalDirection::all() {
+            let input = ActionInput::Direction(dir);
+            assert_eq!(spacing_penalty(ActionKind::Move, &input, &ctx), 0);
+        }
+        assert_eq!(
+            attack_score(&ctx, CardinalDirection::North),
+            attack_score(&ctx, CardinalDirection::East)
+        );
+    }
+
+    #[test]
+    fn heavy_threat_tightens_formation() {
+        // Ally next to the North approach tile, as in the test above
+        let (mut state, npc) = setup(&[Position::new(4, 7)]);
+        let oracles = oracles();
+        let north = ActionInput::Direction(CardinalDirection::North);
+
+        let mut assessments = Vec::new();
+        for orc_pos in [
+            Position::new(8, 3),
+            Position::new(9, 3),
+            Position::new(10, 3),
+        ] {
+            let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+            let penalty = spacing_penalty(ActionKind::Move, &north, &ctx);
+            assessments.push((ctx.faction_threat_count(), penalty));
+            state
+                .add_npc(&template(Faction::OrcHorde), orc_pos)
+                .unwrap();
+        }
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+        assessments.push((
+            ctx.faction_threat_count(),
+            spacing_penalty(ActionKind::Move, &north, &ctx),
+        ));
+
+        // Player plus up to three orcs
+        let threats: Vec<_> = assessments.iter().map(|(threats, _)| *threats).collect();
+        assert_eq!(threats, vec![1, 2, 3, 4]);
+
+        // Each extra threat shrinks the spacing penalty, down to nothing
+        assert!(assessments.windows(2).all(|w| w[1].1 < w[0].1));
+        assert_eq!(assessments[3].1, 0);
+        assert_eq!(
+            attack_score(&ctx, CardinalDirection::North),
+            attack_score(&ctx, CardinalDirection::East)
+        );
+    }
+
+    #[tokio::test]
+    async fn ranged_npc_kites_while_reloading() {
+        let (mut state, archer) = setup(&[]);
+        // Move the player within bow range: 3 tiles east of the archer
+        relocate(&mut state, EntityId::PLAYER, Position::new(8, 5));
+
+        let actor = state.entities.actor_mut(archer).unwrap();
+        for kind in [ActionKind::Move, ActionKind::RangedAttack, ActionKind::Wait] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        actor.set_action_cooldown(ActionKind::RangedAttack, 200);
+
+        let oracles = oracles();
+        let provider = UtilityAiProvider::new();
+
+        // Reloading: back away from the player instead of standing still
+        let ctx = AiContext::new(archer, &state, oracles.as_game_env());
+        assert_eq!(ctx.reloading_range(), Some(5));
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::Kite {
+                target: EntityId::PLAYER
+            }
+        );
+
+        let action = provider
+            .provide_action(archer, &state, oracles.as_game_env())
+            .await
+            .unwrap();
+        let Action::Character(action) = action else {
+            panic!("expected character action");
+        };
+        let ActionInput::Direction(dir) = action.input else {
+            panic!("expected a move, got {:?}", action);
+        };
+        let player_pos = Position::new(8, 5);
+        let new_pos = ctx.position_after_move(dir).unwrap();
+        assert_eq!(action.kind, ActionKind::Move);
+        assert!(new_pos.manhattan_distance(player_pos) > 3);
+        assert!(new_pos.chebyshev_distance(player_pos) <= 5);
+
+        // Cooldown cleared: shoot again
+        state.turn.clock = 200;
+        let action = provider
+            .provide_action(archer, &state, oracles.as_game_env())
+            .await
+            .unwrap();
+        let Action::Character(action) = action else {
+            panic!("expected character action");
+        };
+        assert_eq!(action.kind, ActionKind::RangedAttack);
+        assert_eq!(action.input, ActionInput::Target(EntityId::PLAYER));
+    }
+
+    /// Wounded NPC at (5, 5) with the player 2 tiles east.
+    fn wounded_setup(allies: &[Position]) -> (GameState, EntityId) {
+        let (mut state, npc) = setup(allies);
+        relocate(&mut state, EntityId::PLAYER, Position::new(7, 5));
+
+        let actor = state.entities.actor_mut(npc).unwrap();
+        actor.resources.hp = 20;
+        actor.actions.push(ActionAbility::new(ActionKind::Move));
+        (state, npc)
+    }
+
+    #[tokio::test]
+    async fn wounded_npc_retreats_towards_ally() {
+        let ally_pos = Position::new(5, 8);
+        let (state, npc) = wounded_setup(&[ally_pos]);
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+
+        assert!(matches!(
+            GoalSelector::select(&ctx),
+            Goal::Retreat {
+                threat: EntityId::PLAYER,
+                ..
+            }
+        ));
+
+        let action = UtilityAiProvider::new()
+            .provide_action(npc, &state, oracles.as_game_env())
+            .await
+            .unwrap();
+        let Action::Character(action) = action else {
+            panic!("expected character action");
+        };
+        let ActionInput::Direction(dir) = action.input else {
+            panic!("expected a move, got {:?}", action);
+        };
+
+        let my_pos = Position::new(5, 5);
+        let player_pos = Position::new(7, 5);
+        let new_pos = ctx.position_after_move(dir).unwrap();
+        assert!(new_pos.manhattan_distance(player_pos) > my_pos.manhattan_distance(player_pos));
+        assert!(new_pos.manhattan_distance(ally_pos) < my_pos.manhattan_distance(ally_pos));
+    }
+
+    #[test]
+    fn wounded_npc_without_ally_flees() {
+        let (state, npc) = wounded_setup(&[]);
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::FleeFrom {
+                threat: EntityId::PLAYER
+            }
+        );
+    }
+
+    /// Direction of the move `provider` picks for `npc`.
+    async fn chosen_direction(
+        provider: &UtilityAiProvider,
+        state: &GameState,
+        npc: EntityId,
+    ) -> CardinalDirection {
+        let oracles = oracles();
+        let action = provider
+            .provide_action(npc, state, oracles.as_game_env())
+            .await
+            .unwrap();
+        let Action::Character(CharacterAction {
+            input: ActionInput::Direction(dir),
+            ..
+        }) = action
+        else {
+            panic!("expected a move, got {:?}", action);
+        };
+        dir
+    }
+
+    /// Moves the wounded NPC and the player, then returns the NPC's flee step.
+    async fn flee_step(
+        mut state: GameState,
+        npc: EntityId,
+        my_pos: Position,
+        player_pos: Position,
+    ) -> CardinalDirection {
+        relocate(&mut state, npc, my_pos);
+        relocate(&mut state, EntityId::PLAYER, player_pos);
+        assert_eq!(
+            selected_goal(&state, npc),
+            Goal::FleeFrom {
+                threat: EntityId::PLAYER
+            }
+        );
+
+        chosen_direction(&UtilityAiProvider::new(), &state, npc).await
+    }
+
+    #[tokio::test]
+    async fn fleeing_npc_takes_the_step_that_gains_most_distance() {
+        let (state, npc) = wounded_setup(&[]);
+        let (my_pos, player_pos) = (Position::new(5, 5), Position::new(7, 5));
+
+        let dir = flee_step(state, npc, my_pos, player_pos).await;
+
+        let distance_after = |dir: CardinalDirection| {
+            let (dx, dy) = dir.offset();
+            Position::new(my_pos.x + dx, my_pos.y + dy).manhattan_distance(player_pos)
+        };
+        let best = CardinalDirection::all()
+            .into_iter()
+            .map(distance_after)
+            .max()
+            .unwrap();
+        assert_eq!(distance_after(dir), best);
+    }
+
+    #[tokio::test]
+    async fn cornered_npc_takes_the_least_bad_open_step() {
+        // Every step that gains more ground leaves the map; North (+y) is the
+        // only open step that gains any
+        let (state, npc) = wounded_setup(&[]);
+
+        let dir = flee_step(state, npc, Position::new(0, 0), Position::new(2, 0)).await;
+
+        assert_eq!(dir, CardinalDirection::North);
+    }
+
+    #[tokio::test]
+    async fn seeded_tie_break_is_stable_but_changes_with_the_nonce() {
+        // Fleeing north-west and south-west gain the same distance
+        let (mut state, npc) = wounded_setup(&[]);
+        let unseeded = UtilityAiProvider::new();
+        let seeded = UtilityAiProvider::new().with_seeded_tie_break(true);
+
+        let mut choices = Vec::new();
+        for nonce in 0..16 {
+            state.turn.nonce = nonce;
+            let first = chosen_direction(&unseeded, &state, npc).await;
+            assert_eq!(first, CardinalDirection::NorthWest);
+
+            let dir = chosen_direction(&seeded, &state, npc).await;
+            assert_eq!(chosen_direction(&seeded, &state, npc).await, dir);
+            assert!(matches!(
+                dir,
+                CardinalDirection::NorthWest | CardinalDirection::SouthWest
+            ));
+            choices.push(dir);
+        }
+        assert!(choices.contains(&CardinalDirection::NorthWest));
+        assert!(choices.contains(&CardinalDirection::SouthWest));
+    }
+
+    #[test]
+    fn boxed_in_npc_turns_to_fight() {
+        let allies = [
+            Position::new(1, 0),
+            Position::new(0, 1),
+            Position::new(1, 1),
+        ];
+        let (mut state, npc) = wounded_setup(&allies);
+        relocate(&mut state, npc, Position::new(0, 0));
+        relocate(&mut state, EntityId::PLAYER, Position::new(2, 2));
+
+        assert_eq!(
+            selected_goal(&state, npc),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    /// Adds a wounded orc (hostile to goblins) at (9, 3) with 20 HP.
+    fn add_wounded_orc(state: &mut GameState) -> EntityId {
+        let orc = state
+            .add_npc(&template(Faction::OrcHorde), Position::new(9, 3))
+            .unwrap();
+        state.entities.actor_mut(orc).unwrap().resources.hp = 20;
+        orc
+    }
+
+    fn selected_goal(state: &GameState, npc: EntityId) -> Goal {
+        let oracles = oracles();
+        GoalSelector::select(&AiContext::new(npc, state, oracles.as_game_env()))
+    }
+
+    #[test]
+    fn allies_focus_the_designated_target() {
+        let (mut state, first) = setup(&[Position::new(7, 5)]);
+        let second = EntityId(first.0 + 1);
+        let orc = add_wounded_orc(&mut state);
+
+        // Both goblins see the player and the orc; the orc dies faster
+        for npc in [first, second] {
+            assert_eq!(selected_goal(&state, npc), Goal::Attack { target: orc });
+        }
+
+        // Deciding order does not matter: same state, same target
+        for npc in [second, first] {
+            assert_eq!(selected_goal(&state, npc), Goal::Attack { target: orc });
+        }
+    }
+
+    #[test]
+    fn allied_factions_are_never_targeted() {
+        let (mut state, first) = setup(&[Position::new(7, 5)]);
+        let orc = add_wounded_orc(&mut state);
+        let mut config = GameConfig::default();
+        config.faction_relations.set_mutual(
+            Faction::GoblinClan,
+            Faction::OrcHorde,
+            Relation::Allied,
+        );
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(16, 16)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(config)),
+        );
+
+        // The orc stands next to the goblin but is not a melee candidate
+        relocate(&mut state, orc, Position::new(5, 6));
+        let ctx = AiContext::new(first, &state, oracles.as_game_env());
+        assert_eq!(
+            ctx.relation(Faction::GoblinClan, Faction::OrcHorde),
+            Relation::Allied
+        );
+        assert!(ctx.threat().enemies.iter().all(|enemy| enemy.id != orc));
+        assert!(ctx.threat().allies.iter().any(|ally| ally.id == orc));
+        assert!(
+            ActionCandidateGenerator::generate(&[ActionKind::MeleeAttack], &ctx)
+                .iter()
+                .all(|(_, input)| *input != ActionInput::Target(orc))
+        );
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    #[test]
+    fn provoked_neutral_npc_retaliates_until_aggro_expires() {
+        // A wild animal next to the player, which ignores it at first
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(5, 5))
+            .unwrap();
+        let wolf = state
+            .add_npc(&template(Faction::Wildlife), Position::new(6, 5))
+            .unwrap();
+        let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+        player.ready_at = Some(state.turn.clock);
+        let oracles = oracles();
+        assert_ne!(
+            selected_goal(&state, wolf),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+
+        let attack = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(wolf),
+        ));
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &attack)
+            .unwrap();
+        let clock = state.turn.clock;
+        let effects = &state.entities.actor(wolf).unwrap().status_effects;
+        assert!(effects.is_aggro_toward(EntityId::PLAYER, clock));
+
+        // Hostile for the aggro duration...
+        let ctx = AiContext::new(wolf, &state, oracles.as_game_env());
+        assert_eq!(
+            ctx.threat().enemies.first().map(|enemy| enemy.id),
+            Some(EntityId::PLAYER)
+        );
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+
+        // ...then neutral again
+        state.turn.clock = clock + GameConfig::DEFAULT_AGGRO_DURATION;
+        let ctx = AiContext::new(wolf, &state, oracles.as_game_env());
+        assert!(ctx.threat().enemies.is_empty());
+        assert_ne!(
+            GoalSelector::select(&ctx),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    /// Gives `attacker` the turn and strikes `target` with a melee attack.
+    fn strike(
+        state: &mut GameState,
+        attacker: EntityId,
+        target: EntityId,
+    ) -> Result<(), ExecuteError> {
+        state.turn.current_actor = attacker;
+        let actor = state.entities.actor_mut(attacker).unwrap();
+        actor
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+        actor.ready_at = Some(state.turn.clock);
+
+        let attack = Action::character(CharacterAction::new(
+            attacker,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(target),
+        ));
+        GameEngine::new(state)
+            .execute(oracles().as_game_env(), &attack)
+            .map(|_| ())
+    }
+
+    /// Player at (5, 5) with a wild animal east of it.
+    fn wolf_setup() -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(5, 5))
+            .unwrap();
+        let wolf = state
+            .add_npc(&template(Faction::Wildlife), Position::new(6, 5))
+            .unwrap();
+        (state, wolf)
+    }
+
+    fn aggro_entries(state: &GameState, id: EntityId) -> Vec<StatusEffectKind> {
+        let effects = &state.entities.actor(id).unwrap().status_effects;
+        effects
+            .iter()
+            .map(|effect| effect.kind)
+            .filter(|kind| matches!(kind, StatusEffectKind::Aggro { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn aggro_follows_the_latest_attacker() {
+        // A wolf between the player and a goblin, neutral to both
+        let (mut state, wolf) = wolf_setup();
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(7, 5))
+            .unwrap();
+
+        strike(&mut state, EntityId::PLAYER, wolf).unwrap();
+        strike(&mut state, goblin, wolf).unwrap();
+        strike(&mut state, goblin, wolf).unwrap();
+
+        assert_eq!(
+            aggro_entries(&state, wolf),
+            vec![StatusEffectKind::Aggro { toward: goblin }]
+        );
+    }
+
+    #[test]
+    fn hostile_attackers_do_not_provoke() {
+        let (mut state, _) = wolf_setup();
+        let goblin = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 6))
+            .unwrap();
+
+        strike(&mut state, EntityId::PLAYER, goblin).unwrap();
+
+        assert!(aggro_entries(&state, goblin).is_empty());
+    }
+
+    #[test]
+    fn aggro_needs_a_free_status_slot() {
+        let (mut state, wolf) = wolf_setup();
+        let effects = &mut state.entities.actor_mut(wolf).unwrap().status_effects;
+        for kind in [
+            StatusEffectKind::Invisible,
+            StatusEffectKind::Hasted,
+            StatusEffectKind::Shielded,
+            StatusEffectKind::Regenerating,
+            StatusEffectKind::Weakened,
+            StatusEffectKind::Slowed,
+            StatusEffectKind::Berserk,
+        ] {
+            effects.add(kind, 10_000);
+        }
+
+        // An effect that has run out makes room...
+        effects.add(StatusEffectKind::Frightened, 0);
+        let mut provoked = state.clone();
+        strike(&mut provoked, EntityId::PLAYER, wolf).unwrap();
+        assert_eq!(
+            aggro_entries(&provoked, wolf),
+            vec![StatusEffectKind::Aggro {
+                toward: EntityId::PLAYER
+            }]
+        );
+
+        // ...but running effects are never dropped for it
+        let effects = &mut state.entities.actor_mut(wolf).unwrap().status_effects;
+        effects.add(StatusEffectKind::Frightened, 10_000);
+        assert!(strike(&mut state, EntityId::PLAYER, wolf).is_err());
+    }
+
+    #[test]
+    fn out_of_sight_designated_target_falls_back_to_nearest_enemy() {
+        // Second goblin is 15 tiles from the orc but 9 from the player
+        let (mut state, first) = setup(&[Position::new(5, 14)]);
+        let second = EntityId(first.0 + 1);
+        let orc = add_wounded_orc(&mut state);
+
+        let oracles = oracles();
+        let ctx = AiContext::new(second, &state, oracles.as_game_env());
+        assert_eq!(ctx.designated_target().map(|t| t.id), Some(orc));
+        assert_eq!(ctx.nearest_enemy().map(|t| t.id), Some(EntityId::PLAYER));
+
+        assert_eq!(selected_goal(&state, first), Goal::Attack { target: orc });
+        assert_eq!(
+            selected_goal(&state, second),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    /// Lone goblin at (5, 5) facing the player plus `orcs` full-health orcs.
+    fn outnumbered_setup(orcs: &[Position]) -> (GameState, EntityId) {
+        let (mut state, npc) = setup(&[]);
+        for pos in orcs {
+            state.add_npc(&template(Faction::OrcHorde), *pos).unwrap();
+        }
+        (state, npc)
+    }
+
+    #[test]
+    fn lone_npc_breaks_engagement_when_outnumbered() {
+        let (state, npc) = outnumbered_setup(&[Position::new(8, 5), Position::new(5, 8)]);
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+
+        // Three enemies at full health against one goblin: 300% > 250%
+        let (allied, hostile) = ctx.local_strength();
+        assert_eq!(hostile, allied * 3);
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::FleeFrom {
+                threat: EntityId::PLAYER
+            }
+        );
+    }
+
+    #[test]
+    fn lone_npc_keeps_fighting_an_even_match() {
+        let (state, npc) = outnumbered_setup(&[]);
+
+        assert_eq!(
+            selected_goal(&state, npc),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    #[test]
+    fn bravery_raises_the_outnumbered_threshold() {
+        let (mut state, npc) = outnumbered_setup(&[Position::new(8, 5), Position::new(5, 8)]);
+        let mut values = TraitProfile::default().values;
+        values[TraitKind::Bravery.as_index()] = 240;
+        state.entities.actor_mut(npc).unwrap().trait_profile = TraitProfile::from_raw(values);
+
+        // Fearless: tolerates up to 400%, so 3 to 1 is still a fight
+        assert_eq!(
+            selected_goal(&state, npc),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    /// Goblin at (5, 5) tasked with pulling a lever at (9, 5), with the
+    /// player out of sight at (15, 15).
+    fn objective_setup() -> (GameState, EntityId, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(15, 15))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+
+        let lever = state.allocate_entity_id().unwrap();
+        let lever_pos = Position::new(9, 5);
+        state
+            .entities
+            .props
+            .push(PropState::new(lever, lever_pos, PropKind::Switch, false))
+            .unwrap();
+        state.world.tile_map.add_occupant(lever_pos, lever);
+
+        let actor = state.entities.actor_mut(npc).unwrap();
+        actor.objective = Some(lever);
+        for kind in [ActionKind::Move, ActionKind::Interact, ActionKind::Wait] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc, lever)
+    }
+
+    #[tokio::test]
+    async fn objective_npc_walks_to_its_lever_and_pulls_it() {
+        let (mut state, npc, lever) = objective_setup();
+        let oracles = oracles();
+        let provider = UtilityAiProvider::new();
+        assert_eq!(selected_goal(&state, npc), Goal::Interact { prop: lever });
+
+        let mut executed = Vec::new();
+        while !state.entities.prop(lever).unwrap().is_active {
+            assert!(
+                executed.len() < 10,
+                "never reached the lever: {:?}",
+                executed
+            );
+
+            // Give the goblin every turn
+            state.turn.current_actor = npc;
+            state.entities.actor_mut(npc).unwrap().ready_at = Some(state.turn.clock);
+
+            let action = provider
+                .provide_action(npc, &state, oracles.as_game_env())
+                .await
+                .unwrap();
+            GameEngine::new(&mut state)
+                .execute(oracles.as_game_env(), &action)
+                .unwrap();
+            executed.push(action.as_snake_case());
+        }
+
+        // Three steps to stand next to the lever, then pull it
+        assert_eq!(executed, vec!["move", "move", "move", "interact"]);
+        let npc_pos = state.entities.actor(npc).unwrap().position.unwrap();
+        assert_eq!(npc_pos.chebyshev_distance(Position::new(9, 5)), 1);
+    }
+
+    #[test]
+    fn completed_objective_falls_back_to_idle() {
+        let (mut state, npc, lever) = objective_setup();
+        state.entities.prop_mut(lever).unwrap().is_active = true;
+
+        assert_eq!(selected_goal(&state, npc), Goal::Idle);
+    }
+
+    const SWORD: ItemHandle = ItemHandle(10);
+    const BOW: ItemHandle = ItemHandle(12);
+
+    fn oracles_with_weapons() -> OracleBundle {
+        let mut items = ItemOracleImpl::new();
+        for (handle, kind, damage) in [(SWORD, WeaponKind::Sword, 10), (BOW, WeaponKind::Bow, 8)] {
+            let weapon = ItemKind::Weapon(WeaponData {
+                kind,
+                damage,
+                reach: WeaponData::DEFAULT_REACH,
+                durability: None,
+            });
+            items.add_definition(ItemDefinition::new(handle, weapon, 1));
+        }
+
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(16, 16)),
+            Arc::new(items),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        )
+    }
+
+    /// Gives `npc` the turn, asks the AI for an action and executes it.
+    async fn take_turn(state: &mut GameState, npc: EntityId, oracles: &OracleBundle) -> Action {
+        state.turn.current_actor = npc;
+        state.entities.actor_mut(npc).unwrap().ready_at = Some(state.turn.clock);
+
+        let action = UtilityAiProvider::new()
+            .provide_action(npc, state, oracles.as_game_env())
+            .await
+            .unwrap();
+        GameEngine::new(state)
+            .execute(oracles.as_game_env(), &action)
+            .unwrap();
+        action
+    }
+
+    fn equip(npc: EntityId, slot: u8) -> Action {
+        Action::Character(CharacterAction::new(
+            npc,
+            ActionKind::EquipWeapon,
+            ActionInput::InventorySlot(slot),
+        ))
+    }
+
+    #[tokio::test]
+    async fn npc_swaps_weapons_for_the_engagement_range() {
+        // Unarmed goblin carrying a sword and a bow, player 3 tiles east
+        let (mut state, npc) = setup(&[]);
+        relocate(&mut state, EntityId::PLAYER, Position::new(8, 5));
+        let actor = state.entities.actor_mut(npc).unwrap();
+        for kind in [
+            ActionKind::Move,
+            ActionKind::MeleeAttack,
+            ActionKind::EquipWeapon,
+        ] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        actor.inventory.add_item(SWORD, 1, 1);
+        actor.inventory.add_item(BOW, 1, 1);
+        let oracles = oracles_with_weapons();
+
+        // Far target: bow
+        assert_eq!(take_turn(&mut state, npc, &oracles).await, equip(npc, 1));
+        let actor = state.entities.actor(npc).unwrap();
+        assert_eq!(actor.equipment.weapon, Some(BOW));
+        assert_eq!(actor.inventory.items[0].handle, SWORD);
+
+        // Adjacent target: back to the sword, the bow returns to the pack
+        relocate(&mut state, EntityId::PLAYER, Position::new(6, 5));
+        assert_eq!(take_turn(&mut state, npc, &oracles).await, equip(npc, 0));
+        let actor = state.entities.actor(npc).unwrap();
+        assert_eq!(actor.equipment.weapon, Some(SWORD));
+        assert_eq!(actor.inventory.items[0].handle, BOW);
+
+        // Best weapon in hand: fight
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "melee_attack");
+    }
+
+    /// Post in the 16x9 walled map: the only gap in the wall at x = 8.
+    fn doorway() -> Position {
+        Position::new(8, 4)
+    }
+
+    /// Two rooms split by a wall at x = 8 with a single doorway.
+    fn walled_oracles() -> OracleBundle {
+        let dimensions = MapDimensions::new(16, 9);
+        let mut tiles = HashMap::new();
+        for x in 0..16 {
+            for y in 0..9 {
+                let terrain = if x == 8 && y != 4 {
+                    TerrainKind::Wall
+                } else {
+                    TerrainKind::Floor
+                };
+                tiles.insert(Position::new(x, y), StaticTile::new(terrain));
+            }
+        }
+
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::new(dimensions, tiles)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        )
+    }
+
+    /// Player in the east room, a defensive goblin (Aggression 40) in the west.
+    fn guard_setup(guard: Position, player: Position) -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), player)
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), guard)
+            .unwrap();
+
+        let actor = state.entities.actor_mut(npc).unwrap();
+        let mut values = TraitProfile::default().values;
+        values[TraitKind::Aggression as usize] = 40;
+        actor.trait_profile = TraitProfile::from_raw(values);
+        for kind in [ActionKind::Move, ActionKind::MeleeAttack, ActionKind::Wait] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc)
+    }
+
+    #[tokio::test]
+    async fn guard_moves_to_cover_the_exit() {
+        let (mut state, npc) = guard_setup(Position::new(5, 4), Position::new(13, 4));
+        let oracles = walled_oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::Guard {
+                post: doorway(),
+                threat: EntityId::PLAYER
+            }
+        );
+
+        let mut executed = Vec::new();
+        while state.entities.actor(npc).unwrap().position != Some(doorway()) {
+            assert!(executed.len() < 6, "never reached the post: {:?}", executed);
+            executed.push(take_turn(&mut state, npc, &oracles).await.as_snake_case());
+        }
+        assert_eq!(executed, vec!["move", "move", "move"]);
+
+        // Holds the doorway instead of charging across the room
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "wait");
+        assert_eq!(state.entities.actor(npc).unwrap().position, Some(doorway()));
+
+        // An aggressive goblin charges instead
+        state.entities.actor_mut(npc).unwrap().trait_profile = TraitProfile::default();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env());
+        assert_eq!(
+            GoalSelector::select(&ctx),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn guard_engages_enemies_crossing_the_chokepoint() {
+        let (mut state, npc) = guard_setup(Position::new(7, 4), doorway());
+        let oracles = walled_oracles();
+        let hp_before = state.entities.player().resources.hp;
+
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "melee_attack");
+        assert!(state.entities.player().resources.hp < hp_before);
+        assert_eq!(
+            state.entities.actor(npc).unwrap().position,
+            Some(Position::new(7, 4))
+        );
+    }
+
+    /// Goblin at (5, 5) who knows Revive, its downed ally at (5, 6) and the
+    /// player right next to it at (6, 5).
+    fn revive_setup(empathy: u8) -> (GameState, EntityId, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(6, 5))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+        let ally = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 6))
+            .unwrap();
+        state.entities.actor_mut(ally).unwrap().resources.hp = 0;
+
+        let actor = state.entities.actor_mut(npc).unwrap();
+        let mut values = TraitProfile::default().values;
+        values[TraitKind::Empathy as usize] = empathy;
+        actor.trait_profile = TraitProfile::from_raw(values);
+        for kind in [
+            ActionKind::Move,
+            ActionKind::MeleeAttack,
+            ActionKind::Wait,
+            ActionKind::Revive,
+        ] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc, ally)
+    }
+
+    #[tokio::test]
+    async fn empathetic_npc_revives_instead_of_attacking() {
+        let (mut state, npc, ally) = revive_setup(200);
+        let oracles = oracles();
+        assert_eq!(selected_goal(&state, npc), Goal::Revive { ally });
+
+        let player_hp = state.entities.player().resources.hp;
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "revive");
+        assert!(state.entities.actor(ally).unwrap().is_alive());
+        assert_eq!(state.entities.player().resources.hp, player_hp);
+    }
+
+    #[tokio::test]
+    async fn neutral_npc_keeps_fighting_next_to_a_downed_ally() {
+        let (mut state, npc, ally) = revive_setup(120);
+        let oracles = oracles();
+        assert_eq!(
+            selected_goal(&state, npc),
+            Goal::Attack {
+                target: EntityId::PLAYER
+            }
+        );
+
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "melee_attack");
+        assert!(state.entities.actor(ally).unwrap().is_downed());
+    }
+
+    /// Healer at (5, 5) with the player out of sight, and an ally at `ally_pos`
+    /// left with `hp_percent` of its max HP.
+    fn heal_setup(ally_pos: Position, hp_percent: u32) -> (GameState, EntityId, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(15, 15))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+        let ally = state
+            .add_npc(&template(Faction::GoblinClan), ally_pos)
+            .unwrap();
+
+        let ally_actor = state.entities.actor_mut(ally).unwrap();
+        ally_actor.resources.hp = ally_actor.snapshot().resource_max.hp_max * hp_percent / 100;
+
+        let actor = state.entities.actor_mut(npc).unwrap();
+        for kind in [ActionKind::Move, ActionKind::Wait, ActionKind::Heal] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc, ally)
+    }
+
+    fn heal_score(state: &GameState, npc: EntityId, ally: EntityId) -> u32 {
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, state, oracles.as_game_env());
+        Goal::HealAlly { ally }.evaluate_action(ActionKind::Heal, &ActionInput::Target(ally), &ctx)
+    }
+
+    #[test]
+    fn healer_ignores_allies_at_full_hp() {
+        let (state, npc, ally) = heal_setup(Position::new(5, 7), 100);
+
+        assert_eq!(selected_goal(&state, npc), Goal::Idle);
+        assert_eq!(heal_score(&state, npc, ally), 0);
+    }
+
+    #[tokio::test]
+    async fn badly_wounded_ally_in_range_is_healed() {
+        let (mut state, npc, ally) = heal_setup(Position::new(5, 7), 10);
+        let oracles = oracles();
+        assert_eq!(selected_goal(&state, npc), Goal::HealAlly { ally });
+        assert!(heal_score(&state, npc, ally) >= 90);
+
+        let hp_before = state.entities.actor(ally).unwrap().resources.hp;
+        let action = take_turn(&mut state, npc, &oracles).await;
+        assert_eq!(action.as_snake_case(), "heal");
+        assert!(state.entities.actor(ally).unwrap().resources.hp > hp_before);
+    }
+
+    #[test]
+    fn wounded_ally_out_of_heal_range_is_left_alone() {
+        let (state, npc, ally) = heal_setup(Position::new(5, 11), 10);
+
+        assert_eq!(selected_goal(&state, npc), Goal::Idle);
+        assert_eq!(heal_score(&state, npc, ally), 0);
+    }
+
+    /// Goblin at (5, 5) that can move and wait, with the player out of sight.
+    fn patrol_setup() -> (GameState, EntityId) {
+        let (mut state, npc) = setup(&[]);
+        relocate(&mut state, EntityId::PLAYER, Position::new(15, 15));
+        let actor = state.entities.actor_mut(npc).unwrap();
+        for kind in [ActionKind::Move, ActionKind::Wait] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc)
+    }
+
+    #[tokio::test]
+    async fn patrolling_npc_cycles_between_two_waypoints() {
+        let (mut state, npc) = patrol_setup();
+        let (west, east) = (Position::new(2, 5), Position::new(5, 5));
+        let oracles = oracles();
+        let provider = UtilityAiProvider::new().with_patrol_route(npc, vec![west, east]);
+
+        let mut arrivals = Vec::new();
+        for _ in 0..12 {
+            // Give the goblin every turn
+            state.turn.current_actor = npc;
+            state.entities.actor_mut(npc).unwrap().ready_at = Some(state.turn.clock);
+
+            let action = provider
+                .provide_action(npc, &state, oracles.as_game_env())
+                .await
+                .unwrap();
+            assert_eq!(action.as_snake_case(), "move");
+            GameEngine::new(&mut state)
+                .execute(oracles.as_game_env(), &action)
+                .unwrap();
+
+            let pos = state.entities.actor(npc).unwrap().position.unwrap();
+            if pos == west || pos == east {
+                arrivals.push(pos);
+            }
+        }
+
+        // Three steps per leg, turning around at each end
+        assert_eq!(arrivals, vec![west, east, west, east]);
+    }
+
+    #[test]
+    fn blocked_patroller_idles() {
+        let (mut state, npc) = patrol_setup();
+        for y in 4..=6 {
+            state
+                .add_npc(&template(Faction::GoblinClan), Position::new(4, y))
+                .unwrap();
+        }
+        let oracles = oracles();
+        let ctx = AiContext::new(npc, &state, oracles.as_game_env())
+            .with_patrol_waypoint(Some(Position::new(2, 5)));
+
+        assert_eq!(GoalSelector::select(&ctx), Goal::Idle);
+
+        let open = AiContext::new(npc, &state, oracles.as_game_env())
+            .with_patrol_waypoint(Some(Position::new(8, 5)));
+        assert_eq!(
+            GoalSelector::select(&open),
+            Goal::Patrol {
+                waypoint: Position::new(8, 5)
+            }
+        );
+    }
+
+    /// Player position, where the AI centres its bursts.
+    const BLAST_CENTER: Position = Position { x: 8, y: 5 };
+
+    /// Goblin caster 3 tiles west of the player, whose fireball strikes
+    /// allies too, with an ally at `ally`.
+    fn caster_setup(ally: Position) -> (GameState, EntityId) {
+        let (mut state, npc) = setup(&[ally]);
+        relocate(&mut state, EntityId::PLAYER, BLAST_CENTER);
+        let actor = state.entities.actor_mut(npc).unwrap();
+        for kind in [ActionKind::Move, ActionKind::Fireball] {
+            actor.actions.push(ActionAbility::new(kind));
+        }
+        (state, npc)
+    }
+
+    async fn caster_action(provider: UtilityAiProvider, ally: Position) -> Action {
+        let (state, npc) = caster_setup(ally);
+        provider
+            .provide_action(npc, &state, oracles().as_game_env())
+            .await
+            .unwrap()
+    }
+
+    fn fireball(npc: EntityId) -> Action {
+        Action::Character(CharacterAction::new(
+            npc,
+            ActionKind::Fireball,
+            ActionInput::Position(BLAST_CENTER),
+        ))
+    }
+
+    #[tokio::test]
+    async fn caster_fires_when_only_enemies_are_caught() {
+        let action = caster_action(UtilityAiProvider::new(), Position::new(8, 8)).await;
+        assert_eq!(action, fireball(action.actor()));
+    }
+
+    #[tokio::test]
+    async fn caster_holds_fire_when_an_ally_would_be_caught() {
+        let action = caster_action(UtilityAiProvider::new(), Position::new(8, 6)).await;
+        assert_eq!(action.as_snake_case(), "move");
+    }
+
+    #[tokio::test]
+    async fn friendly_fire_penalty_is_tunable() {
+        let ally = Position::new(8, 6);
+
+        let reckless = UtilityAiProvider::new().with_friendly_fire_penalty(0);
+        let action = caster_action(reckless, ally).await;
+        assert_eq!(action, fireball(action.actor()));
+
+        // 100 points per ally rules the blast out entirely
+        let (state, npc) = caster_setup(ally);
+        let oracles = oracles();
+        let ctx =
+            AiContext::new(npc, &state, oracles.as_game_env()).with_friendly_fire_penalty(100);
+        let score = Goal::Attack {
+            target: EntityId::PLAYER,
+        }
+        .evaluate_action(
+            ActionKind::Fireball,
+            &ActionInput::Position(BLAST_CENTER),
+            &ctx,
+        );
+        assert_eq!(score, 0);
+    }
+}
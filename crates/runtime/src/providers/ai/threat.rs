@@ -0,0 +1,316 @@
+//! Per-decision threat assessment.
+//!
+//! Goal selection, weapon choice and candidate scoring all ask the same
+//! questions about the deciding entity's surroundings: who the enemies and
+//! allies are, which enemy is closest, whether the player is in sight. Each
+//! question used to scan every actor on its own. [`ThreatAssessment`] answers
+//! them with a single scan when the [`AiContext`](super::AiContext) is built,
+//! and the context's helpers read from it.
+
+use game_core::{
+    ActorState, EntityId, FactionRelations, GameState, MapOracle, Position, Relation, Tick,
+};
+
+use super::context::SIGHT_RANGE;
+
+#[cfg(test)]
+thread_local! {
+    /// Number of threat scans run on this thread (test instrumentation).
+    static SCANS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Number of threat scans run on the current thread so far.
+#[cfg(test)]
+pub(crate) fn scan_count() -> usize {
+    SCANS.with(|scans| scans.get())
+}
+
+/// Snapshot of the actors around the deciding entity.
+///
+/// Built once per decision by [`ThreatAssessment::scan`]. All lists are in
+/// entity order, so anything derived from them is deterministic.
+#[derive(Debug, Clone, Default)]
+pub struct ThreatAssessment<'a> {
+    /// Living NPCs allied with the deciding entity (itself and the player
+    /// excluded).
+    pub allies: Vec<&'a ActorState>,
+
+    /// Allies missing HP, with the share of max HP they are missing in percent.
+    pub wounded_allies: Vec<(&'a ActorState, u32)>,
+
+    /// Living, positioned actors the deciding entity is hostile to.
+    ///
+    /// Actors it is neutral towards are in neither list.
+    pub enemies: Vec<&'a ActorState>,
+
+    /// Enemies seen by the deciding entity or a living ally: within sight
+    /// range and, when a map is known, in line of sight.
+    pub faction_visible_enemies: Vec<&'a ActorState>,
+
+    /// Closest enemy within sight range and its Manhattan distance.
+    ///
+    /// Ties are broken by the lower entity ID.
+    pub nearest_enemy: Option<(&'a ActorState, u32)>,
+
+    /// Manhattan distance to the player, or `u32::MAX` if either side is off
+    /// the map.
+    pub player_distance: u32,
+
+    /// Whether the player is within sight range.
+    pub player_visible: bool,
+}
+
+impl<'a> ThreatAssessment<'a> {
+    /// Scans `state` once from the point of view of `entity`.
+    ///
+    /// Who counts as ally or enemy is decided by [`stance`]: `relations`,
+    /// overridden by any aggro the entity holds. Walls on `map` hide enemies
+    /// from the allies looking out for them; without a map only range counts.
+    pub fn scan(
+        entity: EntityId,
+        state: &'a GameState,
+        relations: &FactionRelations,
+        map: Option<&dyn MapOracle>,
+    ) -> Self {
+        #[cfg(test)]
+        SCANS.with(|scans| scans.set(scans.get() + 1));
+
+        let mut assessment = Self {
+            player_distance: u32::MAX,
+            ..Self::default()
+        };
+        let Some(me) = state.entities.actor(entity) else {
+            return assessment;
+        };
+        let my_pos = me.position;
+        let clock = state.turn.clock;
+        let mut lookouts: Vec<Position> = my_pos.filter(|_| me.is_alive()).into_iter().collect();
+
+        for actor in state.entities.all_actors() {
+            if actor.id == entity || !actor.is_alive() {
+                continue;
+            }
+
+            match stance(me, actor, relations, clock) {
+                Relation::Hostile if actor.position.is_some() => assessment.enemies.push(actor),
+                Relation::Allied => {
+                    lookouts.extend(actor.position);
+                    if actor.id == EntityId::PLAYER {
+                        continue;
+                    }
+                    assessment.allies.push(actor);
+                    let wounded = wounded_percent(actor);
+                    if wounded > 0 {
+                        assessment.wounded_allies.push((actor, wounded));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let sees = |from: Position, to: Position| {
+            from.manhattan_distance(to) <= SIGHT_RANGE
+                && map.is_none_or(|map| map.line_of_sight(from, to))
+        };
+        assessment.faction_visible_enemies = assessment
+            .enemies
+            .iter()
+            .copied()
+            .filter(|enemy| {
+                enemy
+                    .position
+                    .is_some_and(|pos| lookouts.iter().any(|&lookout| sees(lookout, pos)))
+            })
+            .collect();
+
+        if let Some(my_pos) = my_pos {
+            assessment.nearest_enemy = assessment
+                .enemies
+                .iter()
+                .filter_map(|enemy| Some((*enemy, my_pos.manhattan_distance(enemy.position?))))
+                .filter(|(_, distance)| *distance <= SIGHT_RANGE)
+                .min_by_key(|(enemy, distance)| (*distance, enemy.id));
+
+            if let Some(player_pos) = state.entities.player().position {
+                assessment.player_distance = my_pos.manhattan_distance(player_pos);
+                assessment.player_visible = assessment.player_distance <= SIGHT_RANGE;
+            }
+        }
+
+        assessment
+    }
+}
+
+/// Returns the stance `me` takes towards `other` at `clock`.
+///
+/// An actor provoked by `other` (see [`StatusEffectKind::Aggro`]) is hostile
+/// to it until the aggro expires; otherwise the faction relation applies.
+///
+/// [`StatusEffectKind::Aggro`]: game_core::StatusEffectKind::Aggro
+pub(super) fn stance(
+    me: &ActorState,
+    other: &ActorState,
+    relations: &FactionRelations,
+    clock: Tick,
+) -> Relation {
+    if me.status_effects.is_aggro_toward(other.id, clock) {
+        Relation::Hostile
+    } else {
+        relations.relation(me.faction, other.faction)
+    }
+}
+
+/// Returns the share of its max HP `actor` is missing, in percent.
+///
+/// Rounds up, so any missing HP counts as wounded.
+pub(super) fn wounded_percent(actor: &ActorState) -> u32 {
+    let max_hp = actor.snapshot().resource_max.hp_max;
+    if max_hp == 0 {
+        return 0;
+    }
+
+    (max_hp.saturating_sub(actor.resources.hp) * 100).div_ceil(max_hp)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use game_core::{
+        ActionAbility, ActionKind, ActorTemplate, Faction, MapDimensions, StaticTile, TerrainKind,
+        TraitProfile,
+    };
+
+    use super::*;
+    use crate::api::ActionProvider;
+    use crate::oracle::{MapOracleImpl, OracleBundle};
+    use crate::providers::ai::UtilityAiProvider;
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(32, 32)
+    }
+
+    fn template(faction: Faction) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .actions(
+                [
+                    ActionAbility::new(ActionKind::Move),
+                    ActionAbility::new(ActionKind::MeleeAttack),
+                    ActionAbility::new(ActionKind::Wait),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build()
+    }
+
+    /// Player at (10, 10), deciding goblin at (5, 5) and a row of 30 goblins
+    /// along y = 20.
+    fn crowded() -> (GameState, EntityId) {
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(10, 10))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(5, 5))
+            .unwrap();
+        for x in 0..30 {
+            state
+                .add_npc(&template(Faction::GoblinClan), Position::new(x, 20))
+                .unwrap();
+        }
+        (state, npc)
+    }
+
+    #[test]
+    fn scan_sorts_actors_by_side() {
+        let (state, npc) = crowded();
+
+        let threat = ThreatAssessment::scan(npc, &state, &FactionRelations::new(), None);
+
+        assert_eq!(threat.allies.len(), 30);
+        assert!(threat.wounded_allies.is_empty());
+        assert_eq!(threat.enemies.len(), 1);
+        assert_eq!(
+            threat
+                .nearest_enemy
+                .map(|(enemy, distance)| (enemy.id, distance)),
+            Some((EntityId::PLAYER, 10))
+        );
+        assert_eq!(threat.player_distance, 10);
+        assert!(threat.player_visible);
+    }
+
+    #[tokio::test]
+    async fn threat_scan_runs_once_per_decision() {
+        let (state, npc) = crowded();
+        let oracles = oracles();
+        let provider = UtilityAiProvider::new();
+
+        let before = scan_count();
+        provider
+            .provide_action(npc, &state, oracles.as_game_env())
+            .await
+            .unwrap();
+
+        assert_eq!(scan_count() - before, 1);
+    }
+
+    /// Goblin at (1, 1), an orc at (10, 1) and the player at (14, 1) on a
+    /// 16x3 floor with walls on `walls`, plus relations allying goblins
+    /// with orcs.
+    fn lookout_setup(walls: &[Position]) -> (GameState, EntityId, MapOracleImpl, FactionRelations) {
+        let mut tiles = HashMap::new();
+        for x in 0..16 {
+            for y in 0..3 {
+                let pos = Position::new(x, y);
+                let terrain = if walls.contains(&pos) {
+                    TerrainKind::Wall
+                } else {
+                    TerrainKind::Floor
+                };
+                tiles.insert(pos, StaticTile::new(terrain));
+            }
+        }
+        let map = MapOracleImpl::new(MapDimensions::new(16, 3), tiles);
+
+        let mut state = GameState::empty();
+        state
+            .add_player(&template(Faction::Player), Position::new(14, 1))
+            .unwrap();
+        let npc = state
+            .add_npc(&template(Faction::GoblinClan), Position::new(1, 1))
+            .unwrap();
+        state
+            .add_npc(&template(Faction::OrcHorde), Position::new(10, 1))
+            .unwrap();
+        let mut relations = FactionRelations::new();
+        relations.set_mutual(Faction::GoblinClan, Faction::OrcHorde, Relation::Allied);
+        (state, npc, map, relations)
+    }
+
+    #[test]
+    fn allies_of_other_factions_look_out_for_enemies() {
+        let (state, npc, map, relations) = lookout_setup(&[]);
+
+        let threat = ThreatAssessment::scan(npc, &state, &relations, Some(&map));
+
+        // The player is out of the goblin's sight, but the orc sees it
+        assert!(threat.nearest_enemy.is_none());
+        assert_eq!(threat.faction_visible_enemies.len(), 1);
+    }
+
+    #[test]
+    fn walls_hide_enemies_from_lookouts() {
+        let (state, npc, map, relations) = lookout_setup(&[Position::new(12, 1)]);
+
+        let threat = ThreatAssessment::scan(npc, &state, &relations, Some(&map));
+        assert!(threat.faction_visible_enemies.is_empty());
+
+        // Without a map only range counts
+        let threat = ThreatAssessment::scan(npc, &state, &relations, None);
+        assert_eq!(threat.faction_visible_enemies.len(), 1);
+    }
+}
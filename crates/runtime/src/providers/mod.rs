@@ -1,6 +1,8 @@
 //! Action provider implementations for different entity behaviors.
 
 pub mod ai;
+pub mod replay;
 pub mod system_action;
 
+pub use replay::ReplayActionProvider;
 pub use system_action::{SystemActionHandler, SystemActionProvider};
@@ -0,0 +1,250 @@
+//! Scripted action provider for deterministic playback.
+//!
+//! The [`ReplayActionProvider`] yields actions from a pre-recorded script in
+//! order, which makes integration tests and demo playback reproducible without
+//! a human at the keyboard or an AI in the loop.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use game_core::{Action, EntityId, GameEnv, GameState};
+
+use crate::api::{ActionProvider, Result, RuntimeError};
+use crate::repository::ActionLogReader;
+
+/// Provider that plays back a fixed sequence of character actions.
+///
+/// Every call to [`provide_action`](ActionProvider::provide_action) pops the
+/// next scripted action. The action's actor must be the entity being asked;
+/// otherwise the run has diverged from the script and
+/// [`RuntimeError::ReplayDiverged`] is returned without consuming the entry.
+/// Once the script runs out, [`RuntimeError::ReplayExhausted`] is returned and
+/// the runtime falls back to Wait.
+///
+/// Bind it to [`InteractiveKind::Replay`](game_core::InteractiveKind::Replay)
+/// (or override a kind with it) for the entities whose turns are scripted.
+pub struct ReplayActionProvider {
+    actions: Mutex<VecDeque<Action>>,
+}
+
+impl ReplayActionProvider {
+    /// Creates a provider that plays back `actions` in order.
+    pub fn new(actions: Vec<Action>) -> Self {
+        Self {
+            actions: Mutex::new(actions.into()),
+        }
+    }
+
+    /// Creates a provider from the remaining entries of an action log.
+    ///
+    /// System actions are skipped: the runtime generates those itself, so only
+    /// character actions are ever asked of a provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuntimeError::Repository` if the log cannot be read.
+    pub fn from_reader(reader: &dyn ActionLogReader) -> Result<Self> {
+        let mut actions = Vec::new();
+        while let Some(entry) = reader.read_next()? {
+            if matches!(entry.action, Action::Character(_)) {
+                actions.push(entry.action);
+            }
+        }
+        Ok(Self::new(actions))
+    }
+
+    /// Returns the number of scripted actions not yet played back.
+    pub fn remaining(&self) -> usize {
+        self.actions.lock().map_or(0, |actions| actions.len())
+    }
+
+    /// Returns true once every scripted action has been played back.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+#[async_trait]
+impl ActionProvider for ReplayActionProvider {
+    async fn provide_action(
+        &self,
+        entity: EntityId,
+        _state: &GameState,
+        _env: GameEnv<'_>,
+    ) -> Result<Action> {
+        let mut actions = self
+            .actions
+            .lock()
+            .map_err(|_| RuntimeError::LockPoisoned)?;
+
+        let expected = match actions.front() {
+            Some(action) => action.actor(),
+            None => return Err(RuntimeError::ReplayExhausted { actor: entity }),
+        };
+        if expected != entity {
+            return Err(RuntimeError::ReplayDiverged {
+                actor: entity,
+                expected,
+            });
+        }
+
+        Ok(actions.pop_front().expect("front entry checked above"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, CardinalDirection, CharacterAction,
+        InteractiveKind, Position, PrepareTurnAction, ProviderKind, SystemActionKind,
+    };
+
+    use super::*;
+    use crate::Runtime;
+    use crate::oracle::OracleBundle;
+    use crate::repository::{ActionLogEntry, InMemoryActionLogReader};
+
+    const REPLAY: ProviderKind = ProviderKind::Interactive(InteractiveKind::Replay);
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(8, 8)
+    }
+
+    fn genesis() -> GameState {
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        let actions = &mut state.entities.player_mut().actions;
+        actions.push(ActionAbility::new(ActionKind::Move));
+        actions.push(ActionAbility::new(ActionKind::Wait));
+        state
+    }
+
+    fn player(kind: ActionKind, input: ActionInput) -> Action {
+        Action::character(CharacterAction::new(EntityId::PLAYER, kind, input))
+    }
+
+    fn script() -> Vec<Action> {
+        let east = || {
+            player(
+                ActionKind::Move,
+                ActionInput::Direction(CardinalDirection::East),
+            )
+        };
+        vec![
+            east(),
+            east(),
+            player(ActionKind::Wait, ActionInput::None),
+            east(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn replay_reproduces_the_state_trajectory() {
+        // Reference run: the script is submitted straight to the handle
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis())
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        let mut expected = Vec::new();
+        for action in script() {
+            let (entity, _) = handle.prepare_next_turn().await.unwrap();
+            assert_eq!(entity, action.actor());
+            handle.execute_action(action).await.unwrap();
+            expected.push(handle.query_state().await.unwrap().compute_state_root());
+        }
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+
+        // Replay run: the runtime asks the provider for every turn
+        let mut runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis())
+            .provider(REPLAY, ReplayActionProvider::new(script()))
+            .entity_provider(EntityId::PLAYER, REPLAY)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+        let mut replayed = Vec::new();
+        for _ in 0..expected.len() {
+            runtime.step().await.unwrap();
+            replayed.push(handle.query_state().await.unwrap().compute_state_root());
+        }
+
+        assert_eq!(replayed, expected);
+        let end = handle.query_state().await.unwrap();
+        assert_eq!(
+            end.entities.actor(EntityId::PLAYER).unwrap().position,
+            Some(Position::new(3, 0))
+        );
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn exhausted_script_is_an_error() {
+        let oracles = oracles();
+        let state = genesis();
+        let provider = ReplayActionProvider::new(script()[..1].to_vec());
+
+        let action = provider
+            .provide_action(EntityId::PLAYER, &state, oracles.as_game_env())
+            .await
+            .unwrap();
+        assert_eq!(action, script()[0]);
+        assert!(provider.is_exhausted());
+
+        assert!(matches!(
+            provider
+                .provide_action(EntityId::PLAYER, &state, oracles.as_game_env())
+                .await,
+            Err(RuntimeError::ReplayExhausted {
+                actor: EntityId::PLAYER
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn divergent_actor_is_rejected_without_consuming() {
+        let oracles = oracles();
+        let state = genesis();
+        let provider = ReplayActionProvider::new(script());
+        let stranger = EntityId(7);
+
+        assert!(matches!(
+            provider
+                .provide_action(stranger, &state, oracles.as_game_env())
+                .await,
+            Err(RuntimeError::ReplayDiverged { actor, expected })
+                if actor == stranger && expected == EntityId::PLAYER
+        ));
+        assert_eq!(provider.remaining(), script().len());
+    }
+
+    #[test]
+    fn from_reader_skips_system_actions() {
+        let prepare = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let mut entries = Vec::new();
+        for action in script() {
+            entries.push(ActionLogEntry::new(entries.len() as u64, prepare.clone()));
+            entries.push(ActionLogEntry::new(entries.len() as u64, action));
+        }
+        let reader = InMemoryActionLogReader::with_entries("session".to_string(), entries);
+
+        let provider = ReplayActionProvider::from_reader(&reader).unwrap();
+
+        assert_eq!(provider.remaining(), script().len());
+        assert_eq!(
+            Vec::from(provider.actions.lock().unwrap().clone()),
+            script()
+        );
+    }
+}
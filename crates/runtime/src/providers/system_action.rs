@@ -71,12 +71,23 @@ impl SystemActionProvider {
     ///
     /// Default handlers:
     /// - DeathHandler: Remove dead entities from turn scheduling and world
+    /// - EscapeHandler: Remove NPCs that reached a map exit
+    /// - LifespanHandler: Remove temporary entities whose lifespan ran out
+    /// - CleanupHandler: Remove long-dead actors and empty ground items
+    /// - OpportunityAttackHandler: Let hostiles strike actors leaving their reach
     /// - ActivationHandler: Activate/deactivate NPCs based on player position
     pub fn with_defaults() -> Self {
-        use crate::handlers::{ActivationHandler, DeathHandler};
+        use crate::handlers::{
+            ActivationHandler, CleanupHandler, DeathHandler, EscapeHandler, LifespanHandler,
+            OpportunityAttackHandler,
+        };
 
         let mut provider = Self::new();
         provider.add_handler(Box::new(DeathHandler));
+        provider.add_handler(Box::new(EscapeHandler));
+        provider.add_handler(Box::new(LifespanHandler));
+        provider.add_handler(Box::new(CleanupHandler));
+        provider.add_handler(Box::new(OpportunityAttackHandler));
         provider.add_handler(Box::new(ActivationHandler));
         provider
     }
@@ -106,7 +117,7 @@ impl SystemActionProvider {
         oracles: &OracleBundle,
     ) -> Vec<(Action, &'static str, HandlerCriticality)> {
         // Extract high-level events from delta
-        let events = extract_events(delta, state_before, state_after);
+        let events = extract_events(delta, state_before, state_after, oracles.map.as_ref());
 
         if events.is_empty() {
             return vec![];
@@ -0,0 +1,713 @@
+//! Proof batch construction from a recorded action log.
+//!
+//! Live sessions prove batches as the PersistenceWorker completes them. For a
+//! session that was played offline, the same work has to be reconstructed
+//! after the fact: [`build_batches`] replays the logged actions from the
+//! genesis state and cuts them into [`ProofBatch`]es, each carrying what a
+//! prover needs (start state and actions) and the state roots the resulting
+//! proof must commit to.
+//!
+//! # Batch Boundaries
+//!
+//! A batch ends when either:
+//! - It holds `max_batch` actions, or
+//! - The next action's nonce is a checkpoint nonce
+//!
+//! Batches therefore never span a checkpoint, and consecutive batches chain:
+//! each batch's `end_root` is the next batch's `start_root`.
+//!
+//! # External Verification
+//!
+//! [`BatchInputs`] bundles exactly what the zkVM guest reads for one batch,
+//! so a third party can [replay](BatchInputs::replay) the transition and
+//! compare the result with the proof's public values.
+//!
+//! # Session Manifest
+//!
+//! A [`SessionManifest`] describes a whole session for a verifier: scenario,
+//! oracle root, seed and seed commitment, and the ordered batch boundaries
+//! with their state roots. The PersistenceWorker writes it to
+//! `{base_dir}/{session_id}/manifest.json` when the session ends;
+//! [`SessionManifest::validate`] checks that its roots chain and that
+//! replaying the action log reproduces every one of them.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use game_core::{
+    Action, ExecuteError, GameEngine, GameState, OracleSnapshot, SnapshotOracleBundle,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::oracle::OracleBundle;
+use crate::repository::{
+    ActionBatch, ActionBatchStatus, ActionLogReader, FileActionLogReader, FileStateRepository,
+    InMemoryActionLogReader, RepositoryError, StateRepository,
+};
+
+/// A contiguous run of logged actions ready to be proven.
+#[derive(Debug, Clone)]
+pub struct ProofBatch {
+    /// Nonce of the first action (inclusive).
+    pub start_nonce: u64,
+
+    /// Nonce of the last action (inclusive).
+    pub end_nonce: u64,
+
+    /// State before the first action.
+    pub start_state: GameState,
+
+    /// Actions in execution order.
+    pub actions: Vec<Action>,
+
+    /// State root of `start_state`.
+    pub start_root: [u8; 32],
+
+    /// Expected state root after executing all actions.
+    pub end_root: [u8; 32],
+}
+
+impl ProofBatch {
+    /// Returns the number of actions in this batch.
+    pub fn action_count(&self) -> usize {
+        self.actions.len()
+    }
+}
+
+/// Inputs needed to reproduce a proven batch outside the runtime.
+///
+/// Mirrors the zkVM guest's inputs: executing `actions` on `start_state` with
+/// oracles backed by `oracle_snapshot` yields the state whose root and nonce
+/// the batch proof commits as `new_state_root` and `new_nonce`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchInputs {
+    /// Nonce of the first action (inclusive).
+    pub start_nonce: u64,
+
+    /// Nonce of the last action (inclusive).
+    pub end_nonce: u64,
+
+    /// State before the first action.
+    pub start_state: GameState,
+
+    /// Static game content used during the session.
+    pub oracle_snapshot: OracleSnapshot,
+
+    /// Commitment to the RNG seed, see [`seed_commitment`].
+    pub seed_commitment: [u8; 32],
+
+    /// Actions in execution order.
+    pub actions: Vec<Action>,
+}
+
+impl BatchInputs {
+    /// Re-executes the batch the way the zkVM guest does.
+    ///
+    /// Returns the final state; its `compute_state_root()` and `nonce()` are
+    /// the proof's `new_state_root` and `new_nonce`.
+    ///
+    /// # Errors
+    ///
+    /// [`ProvingError::Replay`] if an action fails to execute.
+    pub fn replay(&self) -> Result<GameState, ProvingError> {
+        let bundle = SnapshotOracleBundle::new(&self.oracle_snapshot);
+        let env = bundle.as_env();
+        let mut state = self.start_state.clone();
+
+        for action in &self.actions {
+            let nonce = state.turn.nonce;
+            GameEngine::new(&mut state)
+                .execute(env.as_game_env(), action)
+                .map_err(|source| ProvingError::Replay { nonce, source })?;
+        }
+
+        Ok(state)
+    }
+}
+
+/// Computes the seed commitment a batch proof starting at `state` commits to.
+///
+/// This is [`game_core::compute_seed_commitment`] of the state's `game_seed`,
+/// matching the value the prover passes to the zkVM guest.
+pub fn seed_commitment(state: &GameState) -> [u8; 32] {
+    game_core::compute_seed_commitment(state.game_seed)
+}
+
+/// Errors raised while rebuilding proof batches from an action log.
+#[derive(Debug, thiserror::Error)]
+pub enum ProvingError {
+    #[error("max_batch must be at least 1")]
+    InvalidBatchSize,
+
+    #[error("action log out of order: expected nonce {expected}, found {found}")]
+    NonceMismatch { expected: u64, found: u64 },
+
+    #[error("replay failed at nonce {nonce}")]
+    Replay {
+        nonce: u64,
+        #[source]
+        source: ExecuteError,
+    },
+
+    #[error("no saved state at nonce {0}")]
+    MissingState(u64),
+
+    #[error("manifest {0} does not match")]
+    ManifestMismatch(&'static str),
+
+    #[error("manifest batch at nonce {start_nonce} does not continue the previous batch")]
+    BrokenChain { start_nonce: u64 },
+
+    #[error("manifest batch at nonce {start_nonce} does not match the action log")]
+    LogMismatch { start_nonce: u64 },
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// Replays an action log and groups it into proof batches.
+///
+/// Reads entries from the reader's current position until the end of the log.
+/// Every action is re-executed on top of `genesis`, so the log must start at
+/// `genesis.turn.nonce` and contain every action (player, NPC, and system)
+/// without gaps.
+///
+/// # Arguments
+///
+/// * `log_reader` - Source of logged actions
+/// * `genesis` - State before the first logged action
+/// * `oracles` - Static game data used during the original session
+/// * `checkpoint_nonces` - Nonces at which a new batch must begin
+/// * `max_batch` - Maximum number of actions per batch
+///
+/// # Errors
+///
+/// - [`ProvingError::InvalidBatchSize`] if `max_batch` is zero
+/// - [`ProvingError::NonceMismatch`] if the log skips or repeats a nonce
+/// - [`ProvingError::Replay`] if a logged action no longer executes
+pub fn build_batches<R>(
+    log_reader: &R,
+    genesis: GameState,
+    oracles: &OracleBundle,
+    checkpoint_nonces: &[u64],
+    max_batch: usize,
+) -> Result<Vec<ProofBatch>, ProvingError>
+where
+    R: ActionLogReader + ?Sized,
+{
+    if max_batch == 0 {
+        return Err(ProvingError::InvalidBatchSize);
+    }
+
+    let checkpoints: BTreeSet<u64> = checkpoint_nonces.iter().copied().collect();
+
+    let mut state = genesis;
+    let mut batches = Vec::new();
+    let mut current: Option<ProofBatch> = None;
+
+    while let Some(entry) = log_reader.read_next()? {
+        let expected = state.turn.nonce;
+        if entry.nonce != expected {
+            return Err(ProvingError::NonceMismatch {
+                expected,
+                found: entry.nonce,
+            });
+        }
+
+        // Close the open batch at a checkpoint or when it is full
+        if let Some(batch) = current.take_if(|batch| {
+            checkpoints.contains(&entry.nonce) || batch.action_count() >= max_batch
+        }) {
+            batches.push(seal(batch, &state));
+        }
+
+        let batch = current.get_or_insert_with(|| ProofBatch {
+            start_nonce: entry.nonce,
+            end_nonce: entry.nonce,
+            start_root: state.compute_state_root(),
+            start_state: state.clone(),
+            actions: Vec::new(),
+            end_root: [0; 32],
+        });
+
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &entry.action)
+            .map_err(|source| ProvingError::Replay {
+                nonce: entry.nonce,
+                source,
+            })?;
+
+        batch.end_nonce = entry.nonce;
+        batch.actions.push(entry.action);
+    }
+
+    if let Some(batch) = current {
+        batches.push(seal(batch, &state));
+    }
+
+    Ok(batches)
+}
+
+/// Records the expected end root of a finished batch.
+fn seal(mut batch: ProofBatch, state: &GameState) -> ProofBatch {
+    batch.end_root = state.compute_state_root();
+    batch
+}
+
+/// Boundaries and state roots of one checkpointed batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestBatch {
+    /// Nonce of the first action (inclusive).
+    pub start_nonce: u64,
+
+    /// Nonce of the last action (inclusive).
+    pub end_nonce: u64,
+
+    /// State root before the first action.
+    pub start_root: [u8; 32],
+
+    /// State root after the last action.
+    pub end_root: [u8; 32],
+}
+
+/// Everything a verifier needs to reproduce a session's proofs.
+///
+/// Serializes deterministically: fields are written in declaration order and
+/// batches in nonce order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionManifest {
+    /// Session identifier.
+    pub session_id: String,
+
+    /// Map ID of the scenario the session started from, if any.
+    pub scenario: Option<String>,
+
+    /// Oracle root the session's proofs commit to.
+    pub oracle_root: [u8; 32],
+
+    /// RNG seed of the genesis state.
+    pub seed: u64,
+
+    /// Commitment to `seed`, see [`seed_commitment`].
+    pub seed_commitment: [u8; 32],
+
+    /// Checkpointed batches in nonce order.
+    pub batches: Vec<ManifestBatch>,
+}
+
+impl SessionManifest {
+    /// File name of the manifest inside a session directory.
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    /// Builds the manifest from the session's checkpointed batches.
+    ///
+    /// Batches still in progress are left out. Roots are read from the
+    /// states saved at each checkpoint; a batch starting at nonce `n` starts
+    /// from the state saved at `n - 1` (or genesis at 0).
+    ///
+    /// # Errors
+    ///
+    /// [`ProvingError::MissingState`] if a checkpoint state is missing.
+    pub fn build<S>(
+        session_id: String,
+        scenario: Option<String>,
+        oracle_root: [u8; 32],
+        batches: &[ActionBatch],
+        states: &S,
+    ) -> Result<Self, ProvingError>
+    where
+        S: StateRepository + ?Sized,
+    {
+        let load = |nonce: u64| -> Result<GameState, ProvingError> {
+            states.load(nonce)?.ok_or(ProvingError::MissingState(nonce))
+        };
+
+        let mut completed: Vec<_> = batches
+            .iter()
+            .filter(|batch| batch.status != ActionBatchStatus::InProgress)
+            .collect();
+        completed.sort_by_key(|batch| batch.start_nonce);
+
+        let genesis_nonce = completed
+            .first()
+            .map_or(0, |batch| batch.start_nonce.saturating_sub(1));
+        let genesis = load(genesis_nonce)?;
+
+        let batches = completed
+            .into_iter()
+            .map(|batch| {
+                Ok(ManifestBatch {
+                    start_nonce: batch.start_nonce,
+                    end_nonce: batch.end_nonce,
+                    start_root: load(batch.start_nonce.saturating_sub(1))?.compute_state_root(),
+                    end_root: load(batch.end_nonce)?.compute_state_root(),
+                })
+            })
+            .collect::<Result<_, ProvingError>>()?;
+
+        Ok(Self {
+            session_id,
+            scenario,
+            oracle_root,
+            seed: genesis.game_seed,
+            seed_commitment: seed_commitment(&genesis),
+            batches,
+        })
+    }
+
+    /// Writes the manifest to `{session_dir}/manifest.json`.
+    pub fn save(&self, session_dir: &Path) -> Result<(), ProvingError> {
+        let json = serde_json::to_vec_pretty(self)?;
+        fs::write(session_dir.join(Self::FILE_NAME), json)?;
+        Ok(())
+    }
+
+    /// Reads the manifest from `{session_dir}/manifest.json`.
+    pub fn load(session_dir: &Path) -> Result<Self, ProvingError> {
+        let json = fs::read(session_dir.join(Self::FILE_NAME))?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Checks the manifest on its own: the seed commitment matches the seed,
+    /// and every batch starts right after the previous one, from its end root.
+    ///
+    /// # Errors
+    ///
+    /// - [`ProvingError::ManifestMismatch`] for a wrong seed commitment
+    /// - [`ProvingError::BrokenChain`] at the first batch that does not chain
+    pub fn check_chain(&self) -> Result<(), ProvingError> {
+        if game_core::compute_seed_commitment(self.seed) != self.seed_commitment {
+            return Err(ProvingError::ManifestMismatch("seed_commitment"));
+        }
+
+        for pair in self.batches.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            if next.start_nonce != prev.end_nonce + 1 || next.start_root != prev.end_root {
+                return Err(ProvingError::BrokenChain {
+                    start_nonce: next.start_nonce,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the manifest was made with the given static game content.
+    fn check_oracle_root(&self, oracles: &OracleBundle) -> Result<(), ProvingError> {
+        if oracles.to_snapshot().compute_oracle_root() != self.oracle_root {
+            return Err(ProvingError::ManifestMismatch("oracle_root"));
+        }
+        Ok(())
+    }
+
+    /// Validates the manifest against a session's action log.
+    ///
+    /// Runs [`check_chain`](Self::check_chain), compares the oracle root and
+    /// seed, then replays the log from `genesis` and requires the same batch
+    /// boundaries and state roots.
+    ///
+    /// # Errors
+    ///
+    /// - Everything [`check_chain`](Self::check_chain) reports
+    /// - [`ProvingError::ManifestMismatch`] for a wrong oracle root or seed
+    /// - [`ProvingError::LogMismatch`] at the first batch replay disagrees with
+    /// - Replay errors from [`build_batches`]
+    pub fn validate<R>(
+        &self,
+        log_reader: &R,
+        genesis: GameState,
+        oracles: &OracleBundle,
+    ) -> Result<(), ProvingError>
+    where
+        R: ActionLogReader + ?Sized,
+    {
+        self.check_chain()?;
+        self.check_oracle_root(oracles)?;
+        if genesis.game_seed != self.seed {
+            return Err(ProvingError::ManifestMismatch("seed"));
+        }
+
+        let starts: Vec<u64> = self.batches.iter().map(|batch| batch.start_nonce).collect();
+        let replayed = build_batches(log_reader, genesis, oracles, &starts, usize::MAX)?;
+
+        for (index, expected) in self.batches.iter().enumerate() {
+            let matches = replayed.get(index).is_some_and(|batch| {
+                batch.start_nonce == expected.start_nonce
+                    && batch.end_nonce == expected.end_nonce
+                    && batch.start_root == expected.start_root
+                    && batch.end_root == expected.end_root
+            });
+            if !matches {
+                return Err(ProvingError::LogMismatch {
+                    start_nonce: expected.start_nonce,
+                });
+            }
+        }
+        if let Some(extra) = replayed.get(self.batches.len()) {
+            return Err(ProvingError::LogMismatch {
+                start_nonce: extra.start_nonce,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates the manifest against the files of a persisted session.
+    ///
+    /// Loads the genesis state from `states/` and concatenates the batches'
+    /// logs from `actions/`, then runs [`validate`](Self::validate).
+    pub fn validate_session(
+        &self,
+        session_dir: &Path,
+        oracles: &OracleBundle,
+    ) -> Result<(), ProvingError> {
+        let Some(first) = self.batches.first() else {
+            self.check_chain()?;
+            return self.check_oracle_root(oracles);
+        };
+
+        let states = FileStateRepository::new(session_dir.join("states"))?;
+        let genesis_nonce = first.start_nonce.saturating_sub(1);
+        let genesis = states
+            .load(genesis_nonce)?
+            .ok_or(ProvingError::MissingState(genesis_nonce))?;
+
+        let mut entries = Vec::new();
+        for batch in &self.batches {
+            let filename =
+                ActionBatch::new(self.session_id.clone(), batch.start_nonce).action_log_filename();
+            let mut reader = FileActionLogReader::new(
+                session_dir.join("actions").join(filename),
+                self.session_id.clone(),
+            )?;
+            entries.extend(reader.read_all()?);
+        }
+
+        let log = InMemoryActionLogReader::with_entries(self.session_id.clone(), entries);
+        self.validate(&log, genesis, oracles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, CharacterAction, EntityId, PrepareTurnAction,
+        SystemActionKind,
+    };
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::repository::{ActionLogEntry, InMemoryActionLogReader};
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(8, 8)
+    }
+
+    fn genesis() -> GameState {
+        let mut state = GameState::with_player();
+        state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        state
+    }
+
+    /// Plays `turns` player turns (PrepareTurn + Wait) and logs every action.
+    ///
+    /// Returns the log and the final state.
+    fn record(turns: usize, oracles: &OracleBundle) -> (Vec<ActionLogEntry>, GameState) {
+        let mut state = genesis();
+        let mut log = Vec::new();
+
+        for _ in 0..turns {
+            let actions = [
+                Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+                Action::character(CharacterAction::new(
+                    EntityId::PLAYER,
+                    ActionKind::Wait,
+                    ActionInput::None,
+                )),
+            ];
+            for action in actions {
+                let nonce = state.turn.nonce;
+                GameEngine::new(&mut state)
+                    .execute(oracles.as_game_env(), &action)
+                    .unwrap();
+                log.push(ActionLogEntry::new(nonce, action));
+            }
+        }
+
+        (log, state)
+    }
+
+    fn reader(log: Vec<ActionLogEntry>) -> InMemoryActionLogReader {
+        InMemoryActionLogReader::with_entries("session".to_string(), log)
+    }
+
+    #[test]
+    fn batches_chain_roots_across_boundaries() {
+        let oracles = oracles();
+        let (log, final_state) = record(5, &oracles);
+        assert_eq!(log.len(), 10);
+
+        // Checkpoint at nonce 4 splits the first run early
+        let batches = build_batches(&reader(log), genesis(), &oracles, &[4], 3).unwrap();
+
+        let ranges: Vec<_> = batches
+            .iter()
+            .map(|batch| (batch.start_nonce, batch.end_nonce))
+            .collect();
+        assert_eq!(ranges, vec![(0, 2), (3, 3), (4, 6), (7, 9)]);
+
+        assert_eq!(batches[0].start_root, genesis().compute_state_root());
+        assert_eq!(batches[0].start_state, genesis());
+        for pair in batches.windows(2) {
+            assert_eq!(pair[0].end_root, pair[1].start_root);
+            assert_eq!(pair[1].start_state.turn.nonce, pair[1].start_nonce);
+        }
+        assert_eq!(
+            batches.last().unwrap().end_root,
+            final_state.compute_state_root()
+        );
+        assert_eq!(
+            batches.iter().map(ProofBatch::action_count).sum::<usize>(),
+            10
+        );
+    }
+
+    #[test]
+    fn rejects_gaps_in_the_log() {
+        let oracles = oracles();
+        let (mut log, _) = record(2, &oracles);
+        log.remove(1);
+
+        let result = build_batches(&reader(log), genesis(), &oracles, &[], 8);
+        assert!(matches!(
+            result,
+            Err(ProvingError::NonceMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_batches() {
+        let oracles = oracles();
+        let result = build_batches(&reader(Vec::new()), genesis(), &oracles, &[], 0);
+        assert!(matches!(result, Err(ProvingError::InvalidBatchSize)));
+    }
+
+    /// Plays `turns` player turns through a persisting runtime that
+    /// checkpoints every turn, then shuts it down.
+    ///
+    /// Returns the session directory.
+    async fn persisted_session(dir: &TempDir, turns: u64) -> std::path::PathBuf {
+        let runtime = crate::Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis())
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("manifest")
+            .checkpoint_interval(2)
+            .build()
+            .await
+            .unwrap();
+        let handle = runtime.handle();
+
+        // Replies only once the persistence worker has saved genesis
+        assert!(handle.load_state(0).await.unwrap().is_some());
+
+        for _ in 0..turns {
+            let (entity, _) = handle.prepare_next_turn().await.unwrap();
+            let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+            handle
+                .execute_action(Action::character(wait))
+                .await
+                .unwrap();
+        }
+
+        // Wait for the last turn's checkpoint
+        let last_start = (turns - 1) * 2;
+        for _ in 0..200 {
+            if handle
+                .get_checkpoint(last_start)
+                .await
+                .unwrap()
+                .is_some_and(|batch| batch.status == ActionBatchStatus::Complete)
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        drop(handle);
+        runtime.shutdown().await.unwrap();
+        dir.path().join("manifest")
+    }
+
+    #[tokio::test]
+    async fn completed_session_manifest_validates() {
+        let dir = TempDir::new().unwrap();
+        let session_dir = persisted_session(&dir, 3).await;
+
+        let manifest = SessionManifest::load(&session_dir).unwrap();
+        let ranges: Vec<_> = manifest
+            .batches
+            .iter()
+            .map(|batch| (batch.start_nonce, batch.end_nonce))
+            .collect();
+        assert_eq!(ranges, vec![(0, 1), (2, 3), (4, 5)]);
+        assert_eq!(manifest.session_id, "manifest");
+        assert_eq!(manifest.scenario, None);
+        assert_eq!(manifest.seed_commitment, seed_commitment(&genesis()));
+
+        manifest.validate_session(&session_dir, &oracles()).unwrap();
+
+        // Serialization is stable
+        manifest.save(&session_dir).unwrap();
+        assert_eq!(SessionManifest::load(&session_dir).unwrap(), manifest);
+    }
+
+    #[tokio::test]
+    async fn corrupted_manifest_root_fails_validation() {
+        let dir = TempDir::new().unwrap();
+        let session_dir = persisted_session(&dir, 3).await;
+        let manifest = SessionManifest::load(&session_dir).unwrap();
+        let oracles = oracles();
+
+        // A middle root no longer chains into the next batch
+        let mut broken = manifest.clone();
+        broken.batches[0].end_root[0] ^= 1;
+        assert!(matches!(
+            broken.validate_session(&session_dir, &oracles),
+            Err(ProvingError::BrokenChain { start_nonce: 2 })
+        ));
+
+        // The final root chains trivially but disagrees with the replay
+        let mut broken = manifest.clone();
+        broken.batches[2].end_root[0] ^= 1;
+        assert!(matches!(
+            broken.validate_session(&session_dir, &oracles),
+            Err(ProvingError::LogMismatch { start_nonce: 4 })
+        ));
+
+        // A manifest for different game content is rejected
+        let mut broken = manifest;
+        broken.oracle_root = [0; 32];
+        assert!(matches!(
+            broken.validate_session(&session_dir, &oracles),
+            Err(ProvingError::ManifestMismatch("oracle_root"))
+        ));
+    }
+}
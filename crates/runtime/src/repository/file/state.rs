@@ -33,6 +33,24 @@ impl FileStateRepository {
     fn state_path(&self, nonce: u64) -> PathBuf {
         self.base_dir.join(format!("state_{}.bin", nonce))
     }
+
+    /// Load the latest checkpoint saved at or before `nonce`.
+    ///
+    /// Returns the nonce the checkpoint was saved under together with its
+    /// state, or `None` if no checkpoint is that old. Replay can then resume
+    /// from this state instead of the start of the session.
+    pub fn load_checkpoint_before(&self, nonce: u64) -> Result<Option<(u64, GameState)>> {
+        let Some(checkpoint) = self
+            .list_nonces()?
+            .into_iter()
+            .rev()
+            .find(|&saved| saved <= nonce)
+        else {
+            return Ok(None);
+        };
+
+        Ok(self.load(checkpoint)?.map(|state| (checkpoint, state)))
+    }
 }
 
 impl StateRepository for FileStateRepository {
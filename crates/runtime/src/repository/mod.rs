@@ -29,6 +29,7 @@
 //! - `types`: Shared data structures (Checkpoint, etc.)
 //! - `file`: File-based implementations
 //! - `memory`: In-memory implementation (testing)
+//! - `replay`: Action log replay onto a starting state
 //! - `snapshot`: High-level facade service
 
 pub mod file;
@@ -36,6 +37,7 @@ pub mod memory;
 pub mod types;
 
 mod error;
+mod replay;
 mod traits;
 
 // Re-export main types
@@ -44,6 +46,8 @@ pub use traits::{
     ActionBatchRepository, ActionLogReader, ActionLogWriter, EventRepository, StateRepository,
 };
 
+pub use replay::replay_actions;
+
 // Re-export shared types
 pub use types::{ActionBatch, ActionBatchStatus, ActionLogEntry};
 
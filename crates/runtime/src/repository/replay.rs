@@ -0,0 +1,180 @@
+//! Replay of a persisted action log.
+//!
+//! Re-executes logged actions against the state they started from, which is
+//! how a persisted session is checked against a committed state root.
+
+use game_core::{ExecuteError, GameEngine, GameError, GameState};
+
+use super::traits::ActionLogReader;
+use crate::api::RuntimeError;
+use crate::oracle::OracleBundle;
+
+/// Replays every remaining entry of `reader` on top of `initial`.
+///
+/// Entries are executed in log order through [`GameEngine`], exactly as the
+/// simulation worker ran them, and must carry consecutive nonces starting at
+/// `initial`'s nonce.
+///
+/// With a `checkpoint` (as returned by
+/// [`FileStateRepository::load_checkpoint_before`]) replay resumes from the
+/// checkpoint state instead of `initial`, and entries the checkpoint already
+/// covers are skipped. Checkpoints are saved under the nonce of their last
+/// action, so coverage is taken from the checkpoint state's own nonce.
+///
+/// # Errors
+///
+/// - `RuntimeError::Repository` if the log cannot be read
+/// - `RuntimeError::PersistenceError` if an entry's nonce is out of sequence
+/// - `RuntimeError::ActionRejected` if a logged action no longer executes
+///
+/// [`FileStateRepository::load_checkpoint_before`]: super::FileStateRepository::load_checkpoint_before
+pub fn replay_actions(
+    initial: GameState,
+    checkpoint: Option<(u64, GameState)>,
+    oracles: &OracleBundle,
+    reader: &mut dyn ActionLogReader,
+) -> Result<GameState, RuntimeError> {
+    let resumed = checkpoint.is_some();
+    let mut state = checkpoint.map_or(initial, |(_, state)| state);
+
+    while let Some(entry) = reader.read_next()? {
+        let expected = state.turn.nonce;
+        if resumed && entry.nonce < expected {
+            continue;
+        }
+        if entry.nonce != expected {
+            return Err(RuntimeError::PersistenceError(format!(
+                "Action log entry has nonce {}, expected {}",
+                entry.nonce, expected
+            )));
+        }
+
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &entry.action)
+            .map_err(|error| {
+                // Report the inner action error, as the simulation worker does
+                let error_code = match &error {
+                    ExecuteError::Character(phase_error) => phase_error.error_code(),
+                    other => other.error_code(),
+                };
+                RuntimeError::ActionRejected {
+                    error_code,
+                    reason: format!("replay of nonce {}: {}", entry.nonce, error.message()),
+                }
+            })?;
+    }
+
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActionKind, CharacterAction, EntityId,
+        PrepareTurnAction, SystemActionKind,
+    };
+
+    use super::*;
+    use crate::repository::{
+        ActionLogEntry, FileStateRepository, InMemoryActionLogReader, StateRepository,
+    };
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(8, 8)
+    }
+
+    fn initial() -> GameState {
+        let mut state = GameState::with_player();
+        state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        state
+    }
+
+    /// Plays `turns` player turns directly, logging every action.
+    fn record(turns: usize, oracles: &OracleBundle) -> (Vec<ActionLogEntry>, GameState) {
+        let mut state = initial();
+        let mut log = Vec::new();
+
+        for _ in 0..turns {
+            let actions = [
+                Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+                Action::character(CharacterAction::new(
+                    EntityId::PLAYER,
+                    ActionKind::Wait,
+                    ActionInput::None,
+                )),
+            ];
+            for action in actions {
+                let nonce = state.turn.nonce;
+                GameEngine::new(&mut state)
+                    .execute(oracles.as_game_env(), &action)
+                    .unwrap();
+                log.push(ActionLogEntry::new(nonce, action));
+            }
+        }
+
+        (log, state)
+    }
+
+    #[test]
+    fn replay_reproduces_the_simulated_state_root() {
+        let oracles = oracles();
+        let (log, simulated) = record(3, &oracles);
+        let mut reader = InMemoryActionLogReader::with_entries("session".to_string(), log);
+
+        let replayed = replay_actions(initial(), None, &oracles, &mut reader).unwrap();
+
+        assert_eq!(replayed.turn.nonce, 6);
+        assert_eq!(
+            replayed.compute_state_root(),
+            simulated.compute_state_root()
+        );
+    }
+
+    #[test]
+    fn out_of_sequence_nonce_is_rejected() {
+        let oracles = oracles();
+        let (mut log, _) = record(2, &oracles);
+        log.remove(1);
+        let mut reader = InMemoryActionLogReader::with_entries("session".to_string(), log);
+
+        assert!(matches!(
+            replay_actions(initial(), None, &oracles, &mut reader),
+            Err(RuntimeError::PersistenceError(_))
+        ));
+    }
+
+    #[test]
+    fn replay_from_checkpoint_matches_full_replay() {
+        let oracles = oracles();
+        let (log, simulated) = record(4, &oracles);
+
+        // Checkpoint after the action with nonce 5, as the persistence worker does
+        let mut checkpointed = initial();
+        for entry in &log[..6] {
+            GameEngine::new(&mut checkpointed)
+                .execute(oracles.as_game_env(), &entry.action)
+                .unwrap();
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let states = FileStateRepository::new(dir.path()).unwrap();
+        states.save(0, &initial()).unwrap();
+        states.save(5, &checkpointed).unwrap();
+
+        assert_eq!(states.load_checkpoint_before(4).unwrap().unwrap().0, 0);
+        let checkpoint = states.load_checkpoint_before(7).unwrap();
+        assert_eq!(checkpoint.as_ref().map(|(nonce, _)| *nonce), Some(5));
+
+        let mut reader = InMemoryActionLogReader::with_entries("session".to_string(), log.clone());
+        let resumed = replay_actions(initial(), checkpoint, &oracles, &mut reader).unwrap();
+        let mut reader = InMemoryActionLogReader::with_entries("session".to_string(), log);
+        let full = replay_actions(initial(), None, &oracles, &mut reader).unwrap();
+
+        assert_eq!(resumed.turn.nonce, 8);
+        assert_eq!(resumed.compute_state_root(), full.compute_state_root());
+        assert_eq!(full.compute_state_root(), simulated.compute_state_root());
+    }
+}
@@ -4,23 +4,26 @@
 //! exposes a builder-based API for clients to drive the simulation.
 
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use game_core::{EntityId, GameConfig, GameState};
+use game_core::{ActionKind, EntityId, GameConfig, GameState};
 
+use crate::api::handle::HandleParts;
+use crate::api::metrics::spawn_proof_metrics;
 use crate::api::{
     ActionProvider, ProviderKind, ProviderRegistry, Result, RuntimeError, RuntimeHandle,
 };
-use crate::events::{EventBus, Topic};
+use crate::events::{Event, EventBus, GameStateEvent, Topic};
 use crate::oracle::OracleBundle;
 use crate::providers::SystemActionProvider;
 use crate::repository::ActionBatch;
 use crate::scenario::Scenario;
 use crate::workers::{
-    CheckpointStrategy, Command, PersistenceConfig, PersistenceWorker, ProofMetrics, ProverConfig,
-    ProverWorker, SimulationWorker,
+    CheckpointStrategy, Command, DEFAULT_UNDO_DEPTH, PersistenceConfig, PersistenceWorker,
+    ProofMetrics, ProverConfig, ProverWorker, SimulationWorker, ValidationMode,
 };
 
 use serde::{Deserialize, Serialize};
@@ -42,6 +45,42 @@ pub struct RuntimeConfig {
     pub event_buffer_size: usize,
     pub command_buffer_size: usize,
     pub session_id: String,
+    /// Per-turn time limit for interactive players (default: none)
+    pub turn_timer: Option<TurnTimer>,
+    /// How failed actions are reported to the submitter (default: lenient)
+    pub validation_mode: ValidationMode,
+    /// Number of executed actions that can be undone (default: 8)
+    pub undo_depth: usize,
+}
+
+/// Time limit for interactive turns in timed modes.
+///
+/// When an interactive provider gives no action within `timeout`, the runtime
+/// submits `default_action` (with no input) for the actor whose turn it is and
+/// publishes [`GameStateEvent::TurnTimedOut`]. The substituted action goes
+/// through the normal pipeline and action log, so replays stay deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnTimer {
+    /// How long to wait for input before acting on the player's behalf
+    pub timeout: Duration,
+    /// Action submitted when the timeout elapses (default: Wait)
+    pub default_action: ActionKind,
+}
+
+impl TurnTimer {
+    /// Creates a timer that submits Wait after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            default_action: ActionKind::Wait,
+        }
+    }
+
+    /// Sets the action submitted when the timeout elapses.
+    pub fn with_default_action(mut self, kind: ActionKind) -> Self {
+        self.default_action = kind;
+        self
+    }
 }
 
 /// Persistence worker configuration.
@@ -76,9 +115,9 @@ pub struct SessionInit {
     pub created_at: String,
     /// Oracle root: SHA-256 hash of serialized oracle snapshot
     pub oracle_root: [u8; 32],
-    /// Random seed for RNG (kept secret)
-    pub seed: [u8; 32],
-    /// Seed commitment: SHA-256 hash of seed
+    /// RNG seed of the initial state (`game_seed`, kept secret)
+    pub seed: u64,
+    /// Seed commitment, see [`game_core::compute_seed_commitment`]
     pub seed_commitment: [u8; 32],
     /// Initial state root: hash of state_0
     pub initial_state_root: [u8; 32],
@@ -110,6 +149,9 @@ impl Default for RuntimeConfig {
             event_buffer_size: 100,
             command_buffer_size: 32,
             session_id: format!("session_{}", timestamp),
+            turn_timer: None,
+            validation_mode: ValidationMode::default(),
+            undo_depth: DEFAULT_UNDO_DEPTH,
         }
     }
 }
@@ -189,6 +231,9 @@ pub struct Runtime {
     // Oracle bundle (cloned, cheap due to Arc internals)
     oracles: OracleBundle,
 
+    // Time limit for interactive turns (from RuntimeConfig)
+    turn_timer: Option<TurnTimer>,
+
     // Blockchain clients (optional, feature-gated)
     #[cfg(feature = "sui")]
     blockchain_clients: Option<Arc<crate::blockchain::BlockchainClients>>,
@@ -223,6 +268,8 @@ impl Runtime {
     /// 3. Executes the action
     ///
     /// If the provider fails to generate an action, a fallback Wait action is used.
+    /// If a [`TurnTimer`] is configured and an interactive provider does not answer
+    /// in time, the timer's default action is used instead.
     ///
     /// # Errors
     ///
@@ -231,23 +278,53 @@ impl Runtime {
     /// - The entity's provider kind is not registered
     /// - Action execution fails
     pub async fn step(&mut self) -> Result<()> {
-        use game_core::{Action, ActionInput, ActionKind, CharacterAction};
+        use game_core::{Action, ActionInput, CharacterAction};
 
         // 1. Prepare turn (SimulationWorker determines which entity acts)
         let (entity, snapshot) = self.handle.prepare_next_turn().await?;
 
         // 2. Get provider for this entity (from Runtime's registry)
-        let provider = {
+        let (kind, provider) = {
             let registry = self
                 .providers
                 .read()
                 .map_err(|_| RuntimeError::LockPoisoned)?;
-            registry.get_for_entity(entity)?
+            let kind = registry.get_entity_kind(entity);
+            (kind, registry.get(kind)?)
         };
 
         // 3. Query provider for action (I/O operation at Runtime layer)
         let env = self.oracles.as_game_env();
-        let action = match provider.provide_action(entity, &snapshot, env).await {
+        let request = provider.provide_action(entity, &snapshot, env);
+        let provided = match self.turn_timer.filter(|_| kind.is_interactive()) {
+            Some(timer) => match tokio::time::timeout(timer.timeout, request).await {
+                Ok(provided) => provided,
+                Err(_) => {
+                    // No input in time - act on the player's behalf
+                    let action = Action::character(CharacterAction::new(
+                        entity,
+                        timer.default_action,
+                        ActionInput::None,
+                    ));
+                    tracing::info!(
+                        target: "runtime",
+                        entity = ?entity,
+                        timeout_ms = timer.timeout.as_millis() as u64,
+                        "Turn timed out, submitting default action"
+                    );
+                    self.handle.event_bus().publish(Event::GameState(
+                        GameStateEvent::TurnTimedOut {
+                            actor: entity,
+                            clock: snapshot.turn.clock,
+                            action: action.clone(),
+                        },
+                    ));
+                    Ok(action)
+                }
+            },
+            None => request.await,
+        };
+        let action = match provided {
             Ok(action) => action,
             Err(e) => {
                 // Provider failed - log and fallback to Wait action
@@ -362,6 +439,12 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Set the provider used for kinds with no registered provider.
+    pub fn fallback_provider(mut self, provider: impl ActionProvider + 'static) -> Self {
+        self.providers.set_fallback(Box::new(provider));
+        self
+    }
+
     /// Bind an entity to a specific provider kind.
     pub fn entity_provider(mut self, entity: EntityId, kind: ProviderKind) -> Self {
         self.providers.bind_entity(entity, kind);
@@ -398,6 +481,24 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Set how failed actions are reported to the submitter
+    pub fn validation_mode(mut self, mode: ValidationMode) -> Self {
+        self.config.validation_mode = mode;
+        self
+    }
+
+    /// Set how many executed actions can be undone (0 disables undo)
+    pub fn undo_depth(mut self, depth: usize) -> Self {
+        self.config.undo_depth = depth;
+        self
+    }
+
+    /// Limit how long interactive players may take per turn
+    pub fn turn_timer(mut self, timer: TurnTimer) -> Self {
+        self.config.turn_timer = Some(timer);
+        self
+    }
+
     /// Set checkpoint interval (number of actions between checkpoints)
     pub fn checkpoint_interval(mut self, interval: u64) -> Self {
         self.persistence.checkpoint_interval = interval;
@@ -505,6 +606,7 @@ impl RuntimeBuilder {
 
         let oracles = oracles.ok_or_else(|| RuntimeError::MissingOracles)?;
 
+        let mut scenario_id = None;
         let initial_state = if let Some(state) = state {
             // Use provided state if available
             tracing::info!("Using provided initial state");
@@ -512,6 +614,7 @@ impl RuntimeBuilder {
         } else if let Some(scenario) = scenario {
             // Initialize from scenario
             tracing::info!("Initializing from scenario: {}", scenario.map_id);
+            scenario_id = Some(scenario.map_id.clone());
             scenario.create_initial_state(&oracles)?
         } else {
             // No state or scenario provided - start with player state
@@ -540,12 +643,16 @@ impl RuntimeBuilder {
             command_rx,
             event_bus.clone(),
             system_provider,
+            &config,
+            persistence.enabled,
         );
 
         let (persistence_worker_handle, batch_complete_rx, persistence_cmd_tx) =
             Self::create_persistence_worker(
                 &config,
                 &persistence,
+                &oracles,
+                scenario_id,
                 command_tx.clone(),
                 event_bus.clone(),
             )?;
@@ -554,23 +661,29 @@ impl RuntimeBuilder {
         #[cfg(feature = "sui")]
         let blockchain_clients_arc = blockchain_clients.map(Arc::new);
 
+        // Aggregate proof events before the prover can publish any
+        let proof_stats = spawn_proof_metrics(&event_bus);
+
         // Create RuntimeHandle with persistence channel
-        let handle = RuntimeHandle::new(
-            command_tx.clone(),
-            persistence_cmd_tx,
-            event_bus.clone(),
-            providers.clone(),
-            config.session_id.clone(),
-            persistence.base_dir.clone(),
+        let handle = RuntimeHandle::new(HandleParts {
+            simulation_tx: command_tx.clone(),
+            persistence_tx: persistence_cmd_tx,
+            event_bus: event_bus.clone(),
+            providers: providers.clone(),
+            proof_metrics: proof_stats,
+            oracles: oracles.clone(),
+            session_id: config.session_id.clone(),
+            base_dir: persistence.base_dir.clone(),
             #[cfg(feature = "sui")]
-            blockchain_clients_arc.clone(),
-        );
+            blockchain_clients: blockchain_clients_arc.clone(),
+        });
 
         let (prover_worker_handle, proof_metrics) = Self::create_prover_worker(
             &config,
             &persistence,
             &proving,
             batch_complete_rx,
+            event_bus.clone(),
             oracles.clone(),
         )?;
 
@@ -584,6 +697,7 @@ impl RuntimeBuilder {
             proof_metrics,
             providers,
             oracles,
+            turn_timer: config.turn_timer,
             #[cfg(feature = "sui")]
             blockchain_clients: blockchain_clients_arc,
         })
@@ -596,6 +710,8 @@ impl RuntimeBuilder {
         command_rx: mpsc::Receiver<Command>,
         event_bus: EventBus,
         system_provider: SystemActionProvider,
+        config: &RuntimeConfig,
+        persisted: bool,
     ) -> JoinHandle<()> {
         let sim_worker = SimulationWorker::new(
             initial_state,
@@ -603,7 +719,10 @@ impl RuntimeBuilder {
             command_rx,
             event_bus,
             system_provider,
-        );
+        )
+        .with_validation_mode(config.validation_mode)
+        .with_undo_depth(config.undo_depth)
+        .with_persisted_actions(persisted);
 
         tokio::spawn(async move {
             sim_worker.run().await;
@@ -616,6 +735,8 @@ impl RuntimeBuilder {
     fn create_persistence_worker(
         config: &RuntimeConfig,
         persistence: &PersistenceSettings,
+        oracles: &OracleBundle,
+        scenario_id: Option<String>,
         sim_command_tx: mpsc::Sender<Command>,
         event_bus: EventBus,
     ) -> Result<PersistenceWorkerResult> {
@@ -623,11 +744,15 @@ impl RuntimeBuilder {
             return Ok((None, None, None));
         }
 
-        let persistence_config =
+        let mut persistence_config =
             PersistenceConfig::new(config.session_id.clone(), persistence.base_dir.clone())
                 .with_strategy(CheckpointStrategy::EveryNActions(
                     persistence.checkpoint_interval,
-                ));
+                ))
+                .with_oracle_root(oracles.to_snapshot().compute_oracle_root());
+        if let Some(scenario_id) = scenario_id {
+            persistence_config = persistence_config.with_scenario(scenario_id);
+        }
 
         let event_rx = event_bus.subscribe(Topic::GameState);
 
@@ -673,6 +798,7 @@ impl RuntimeBuilder {
         persistence: &PersistenceSettings,
         proving: &ProvingSettings,
         batch_complete_rx: Option<mpsc::UnboundedReceiver<ActionBatch>>,
+        event_bus: EventBus,
         _oracles: OracleBundle,
     ) -> Result<(Option<JoinHandle<()>>, Option<ProofMetricsArc>)> {
         if !proving.enabled {
@@ -725,9 +851,14 @@ impl RuntimeBuilder {
         let (prover_cmd_tx, prover_cmd_rx) = mpsc::channel(8);
 
         // Create ProverWorker
-        let prover_worker =
-            ProverWorker::new(prover_config, prover, prover_cmd_rx, batch_complete_rx)
-                .map_err(|e| RuntimeError::InvalidConfig(e.to_string()))?;
+        let prover_worker = ProverWorker::new(
+            prover_config,
+            prover,
+            event_bus,
+            prover_cmd_rx,
+            batch_complete_rx,
+        )
+        .map_err(|e| RuntimeError::InvalidConfig(e.to_string()))?;
 
         let handle = tokio::spawn(async move {
             prover_worker.run().await;
@@ -778,13 +909,11 @@ impl RuntimeBuilder {
         })?;
         let oracle_root: [u8; 32] = Sha256::digest(&oracle_bytes).into();
 
-        // 2. Generate random 32-byte seed
-        use rand::RngCore;
-        let mut seed = [0u8; 32];
-        rand::thread_rng().fill_bytes(&mut seed);
+        // 2. Take the RNG seed from the initial state
+        let seed = initial_state.game_seed;
 
-        // 3. Compute seed_commitment: SHA-256 hash of seed
-        let seed_commitment: [u8; 32] = Sha256::digest(seed).into();
+        // 3. Compute seed_commitment the way proofs commit it
+        let seed_commitment = game_core::compute_seed_commitment(seed);
 
         // 4. Compute initial_state_root
         let initial_state_root = initial_state.compute_state_root();
@@ -822,3 +951,156 @@ impl RuntimeBuilder {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use game_core::{
+        Action, ActionAbility, ActionInput, CharacterAction, GameEnv, InteractiveKind,
+    };
+    use tokio::sync::{Mutex, broadcast};
+    use tokio::time::Instant;
+
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(30);
+    const PLAYER_INPUT: ProviderKind = ProviderKind::Interactive(InteractiveKind::NetworkInput);
+
+    /// Interactive provider fed by the test through a channel.
+    struct ChannelProvider(Mutex<mpsc::Receiver<Action>>);
+
+    #[async_trait]
+    impl ActionProvider for ChannelProvider {
+        async fn provide_action(
+            &self,
+            _entity: EntityId,
+            _state: &GameState,
+            _env: GameEnv<'_>,
+        ) -> Result<Action> {
+            self.0
+                .lock()
+                .await
+                .recv()
+                .await
+                .ok_or(RuntimeError::ActionProviderChannelClosed)
+        }
+    }
+
+    fn oracles() -> OracleBundle {
+        OracleBundle::test_bundle(8, 8)
+    }
+
+    /// A timed runtime whose player is bound to a [`ChannelProvider`].
+    async fn timed_runtime(timer: TurnTimer) -> (Runtime, mpsc::Sender<Action>) {
+        let mut genesis = GameState::with_player();
+        genesis
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        let (tx, rx) = mpsc::channel(1);
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis)
+            .provider(PLAYER_INPUT, ChannelProvider(Mutex::new(rx)))
+            .entity_provider(EntityId::PLAYER, PLAYER_INPUT)
+            .turn_timer(timer)
+            .build()
+            .await
+            .unwrap();
+        (runtime, tx)
+    }
+
+    /// Drains published GameState events, returning the timeout and the
+    /// player's executed action (if any).
+    fn player_turn(events: &mut broadcast::Receiver<Event>) -> (Option<Action>, Option<Action>) {
+        let mut timed_out = None;
+        let mut executed = None;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                Event::GameState(GameStateEvent::TurnTimedOut { actor, action, .. }) => {
+                    assert_eq!(actor, EntityId::PLAYER);
+                    timed_out = Some(action);
+                }
+                Event::GameState(GameStateEvent::ActionExecuted { action, .. })
+                    if action.actor() == EntityId::PLAYER =>
+                {
+                    executed = Some(action);
+                }
+                _ => {}
+            }
+        }
+        (timed_out, executed)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn silent_player_times_out_into_the_default_action() {
+        let (mut runtime, _input) = timed_runtime(TurnTimer::new(TIMEOUT)).await;
+        let mut events = runtime.handle().subscribe(Topic::GameState);
+
+        let started = Instant::now();
+        runtime.step().await.unwrap();
+        assert!(started.elapsed() >= TIMEOUT);
+
+        let wait = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        let (timed_out, executed) = player_turn(&mut events);
+        assert_eq!(timed_out, Some(wait.clone()));
+        assert_eq!(executed, Some(wait));
+
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn input_within_the_timeout_is_not_replaced() {
+        let (mut runtime, input) = timed_runtime(TurnTimer::new(TIMEOUT)).await;
+        let mut events = runtime.handle().subscribe(Topic::GameState);
+
+        let wait = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        input.send(wait.clone()).await.unwrap();
+
+        let started = Instant::now();
+        runtime.step().await.unwrap();
+        assert!(started.elapsed() < TIMEOUT);
+
+        let (timed_out, executed) = player_turn(&mut events);
+        assert_eq!(timed_out, None);
+        assert_eq!(executed, Some(wait));
+
+        runtime.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn session_init_commits_the_game_seed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut genesis = GameState::with_player();
+        genesis.game_seed = 0x5eed;
+        let runtime = Runtime::builder()
+            .oracles(oracles())
+            .initial_state(genesis.clone())
+            .enable_persistence(true)
+            .persistence_dir(dir.path())
+            .session_id("seeded")
+            .build()
+            .await
+            .unwrap();
+        runtime.shutdown().await.unwrap();
+
+        let json = std::fs::read(dir.path().join("seeded").join("session_init.json")).unwrap();
+        let session_init: SessionInit = serde_json::from_slice(&json).unwrap();
+
+        // Proofs commit the same seed, so the on-chain session can check them
+        assert_eq!(session_init.seed, 0x5eed);
+        assert_eq!(
+            session_init.seed_commitment,
+            crate::proving::seed_commitment(&genesis)
+        );
+    }
+}
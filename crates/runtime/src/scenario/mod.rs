@@ -3,13 +3,16 @@
 //! Scenarios define which map to use and where to place entities.
 //! This separation allows:
 //! - Same map with different entity placements (easy/hard mode)
+//! - Same placements at different strengths via [`Difficulty`]
 //! - Procedural entity generation while keeping map data static
 //! - Clean responsibility separation: MapOracle = terrain, Scenario = entities
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use game_core::{
-    GameState, ItemHandle, ItemOracle, ItemState, MapOracle, Position, PropKind, PropState,
+    ActorTemplate, GameConfig, GameState, ItemHandle, ItemOracle, ItemState, MapOracle, Position,
+    PropKind, PropState,
 };
 use serde::{Deserialize, Serialize};
 
@@ -23,16 +26,43 @@ use crate::oracle::OracleBundle;
 pub struct EntityPlacement {
     pub position: Position,
     pub kind: EntityKind,
+
+    /// Which player a `Player` placement is; omitted means 0.
+    ///
+    /// Index 0 is the main player and always gets `EntityId::PLAYER`; further
+    /// indices are extra hot-seat or co-op players. Only valid on `Player`
+    /// placements.
+    #[serde(default)]
+    pub player_index: Option<u8>,
+}
+
+impl EntityPlacement {
+    /// Returns the player index of a `Player` placement, `None` for other kinds.
+    pub fn player_index(&self) -> Option<u8> {
+        match self.kind {
+            EntityKind::Player => Some(self.player_index.unwrap_or(0)),
+            _ => None,
+        }
+    }
 }
 
 /// Type of entity to place.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EntityKind {
-    /// Player character
+    /// Player character (see [`EntityPlacement::player_index()`])
     Player,
 
     /// Actor (NPC or enemy) with definition ID
-    Actor { def_id: String },
+    Actor {
+        def_id: String,
+
+        /// Position of a Prop placement this NPC should operate, such as an
+        /// alarm lever. See [`ActorState::objective`].
+        ///
+        /// [`ActorState::objective`]: game_core::ActorState::objective
+        #[serde(default)]
+        objective: Option<Position>,
+    },
 
     /// Prop entity
     Prop { kind: PropKind, is_active: bool },
@@ -41,6 +71,55 @@ pub enum EntityKind {
     Item { handle: ItemHandle },
 }
 
+/// Difficulty preset that scales a scenario's NPCs.
+///
+/// Applied by [`Scenario::create_initial_state`]; the player is never scaled.
+///
+/// | Difficulty | NPC STR/CON | NPC placements kept               |
+/// |------------|-------------|-----------------------------------|
+/// | Easy       | 75%         | every other one (objectives kept) |
+/// | Normal     | 100%        | all                               |
+/// | Hard       | 150%        | all                               |
+///
+/// HP max follows CON, so NPC HP scales with the same multiplier (up to
+/// integer rounding). Easy only ever drops placements, so every layout that
+/// validates at Normal stays valid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Percentage applied to NPC STR and CON.
+    pub fn npc_stat_percent(self) -> i32 {
+        match self {
+            Self::Easy => 75,
+            Self::Normal => 100,
+            Self::Hard => 150,
+        }
+    }
+
+    /// Keep one in this many NPC placements that have no objective.
+    pub fn npc_keep_every(self) -> usize {
+        match self {
+            Self::Easy => 2,
+            Self::Normal | Self::Hard => 1,
+        }
+    }
+
+    /// Scale an NPC template's core stats (never below 1).
+    fn scale_npc(self, template: &ActorTemplate) -> ActorTemplate {
+        let percent = self.npc_stat_percent();
+        let mut scaled = template.clone();
+        scaled.core_stats.str = (template.core_stats.str * percent / 100).max(1);
+        scaled.core_stats.con = (template.core_stats.con * percent / 100).max(1);
+        scaled
+    }
+}
+
 /// Scenario configuration for game initialization.
 ///
 /// Scenarios define which map to use and where to place entities.
@@ -51,23 +130,65 @@ pub struct Scenario {
 
     /// Entity placements for this scenario
     pub placements: Vec<EntityPlacement>,
+
+    /// NPC scaling applied at state creation (defaults to Normal)
+    #[serde(default)]
+    pub difficulty: Difficulty,
+
+    /// RNG seed for the initial state (defaults to one derived from `map_id`)
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 impl Scenario {
     /// Creates a new scenario.
     pub fn new(map_id: String, placements: Vec<EntityPlacement>) -> Self {
-        Self { map_id, placements }
+        Self {
+            map_id,
+            placements,
+            difficulty: Difficulty::default(),
+            seed: None,
+        }
+    }
+
+    /// Sets the difficulty (builder pattern).
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the RNG seed (builder pattern).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// The `game_seed` the initial state starts with.
+    ///
+    /// Without an explicit seed this is the first 8 bytes (little-endian) of
+    /// SHA-256(`map_id`), so scenarios on different maps get different RNG
+    /// streams while staying reproducible.
+    pub fn game_seed(&self) -> u64 {
+        use sha2::{Digest, Sha256};
+
+        self.seed.unwrap_or_else(|| {
+            let digest = Sha256::digest(self.map_id.as_bytes());
+            u64::from_le_bytes(digest[..8].try_into().unwrap())
+        })
     }
 
     /// Validate scenario against oracles and map.
     ///
     /// Checks:
-    /// - Exactly one Player placement
+    /// - At least one Player placement, with `player_index` values unique
+    ///   and contiguous from 0, and no `player_index` on other placements
+    /// - Actor, prop and item counts fit within the `GameConfig` capacities
     /// - All positions are within map bounds
     /// - All positions are passable (not walls)
     /// - No duplicate positions
     /// - All actor def_ids exist in ActorOracle
     /// - All item handles exist in ItemOracle
+    /// - All actor objectives point at a Prop placement
     ///
     /// # Arguments
     ///
@@ -80,26 +201,81 @@ impl Scenario {
     pub fn validate(&self, oracles: &OracleBundle) -> Result<()> {
         use std::collections::HashSet;
 
-        // 1. Check player count
-        let player_count = self
+        // 1. Check player indices
+        if let Some(placement) = self
             .placements
             .iter()
-            .filter(|p| matches!(p.kind, EntityKind::Player))
-            .count();
+            .find(|p| p.player_index.is_some() && p.kind != EntityKind::Player)
+        {
+            return Err(RuntimeError::InvalidConfig(format!(
+                "player_index set on a non-Player placement at {:?}",
+                placement.position
+            )));
+        }
+        let mut player_indices: Vec<u8> = self
+            .placements
+            .iter()
+            .filter_map(EntityPlacement::player_index)
+            .collect();
 
-        if player_count == 0 {
+        if player_indices.is_empty() {
             return Err(RuntimeError::InvalidConfig(
-                "Scenario must have exactly one Player placement".to_string(),
+                "Scenario must have at least one Player placement".to_string(),
             ));
         }
-        if player_count > 1 {
+        player_indices.sort_unstable();
+        if let Some(pair) = player_indices.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(RuntimeError::InvalidConfig(format!(
+                "Duplicate Player placement with player_index {}",
+                pair[0]
+            )));
+        }
+        if let Some((missing, _)) = player_indices
+            .iter()
+            .enumerate()
+            .find(|&(expected, &index)| usize::from(index) != expected)
+        {
             return Err(RuntimeError::InvalidConfig(format!(
-                "Scenario has {} Player placements (must be exactly 1)",
-                player_count
+                "Player indices must be contiguous from 0, missing {}",
+                missing
             )));
         }
 
-        // 2. Get map dimensions
+        // 2. Check capacities up front, instead of failing partway through
+        // create_initial_state
+        let count = |kind: fn(&EntityKind) -> bool| {
+            self.placements.iter().filter(|p| kind(&p.kind)).count()
+        };
+        let capacities = [
+            (
+                "actor",
+                count(|kind| matches!(kind, EntityKind::Player | EntityKind::Actor { .. })),
+                GameConfig::MAX_ACTORS,
+                "MAX_ACTORS",
+            ),
+            (
+                "prop",
+                count(|kind| matches!(kind, EntityKind::Prop { .. })),
+                GameConfig::MAX_PROPS,
+                "MAX_PROPS",
+            ),
+            (
+                "item",
+                count(|kind| matches!(kind, EntityKind::Item { .. })),
+                GameConfig::MAX_WORLD_ITEMS,
+                "MAX_WORLD_ITEMS",
+            ),
+        ];
+        for (kind, count, limit, name) in capacities {
+            if count > limit {
+                return Err(RuntimeError::InvalidConfig(format!(
+                    "Scenario has {} {} placements, exceeding GameConfig::{} ({})",
+                    count, kind, name, limit
+                )));
+            }
+        }
+
+        // 3. Get map dimensions
         let map = &oracles.map;
         let dimensions = map.dimensions();
 
@@ -109,8 +285,14 @@ impl Scenario {
             dimensions
         );
 
-        // 3. Check all placements
+        // 4. Check all placements
         let mut used_positions = HashSet::new();
+        let prop_positions: HashSet<_> = self
+            .placements
+            .iter()
+            .filter(|p| matches!(p.kind, EntityKind::Prop { .. }))
+            .map(|p| p.position)
+            .collect();
 
         for (idx, placement) in self.placements.iter().enumerate() {
             let pos = placement.position;
@@ -159,13 +341,21 @@ impl Scenario {
                     }
                 }
 
-                EntityKind::Actor { def_id } => {
+                EntityKind::Actor { def_id, objective } => {
                     if oracles.actors().template(def_id).is_none() {
                         return Err(RuntimeError::InvalidConfig(format!(
                             "Placement #{}: Actor template '{}' not found in ActorOracle",
                             idx, def_id
                         )));
                     }
+                    if let Some(objective) = objective
+                        && !prop_positions.contains(objective)
+                    {
+                        return Err(RuntimeError::InvalidConfig(format!(
+                            "Placement #{}: Objective {:?} is not a Prop placement",
+                            idx, objective
+                        )));
+                    }
                 }
 
                 EntityKind::Item { handle } => {
@@ -195,7 +385,9 @@ impl Scenario {
     /// Initialize GameState from this scenario.
     ///
     /// This allocates EntityIds, creates entities from templates,
-    /// and sets up initial world occupancy.
+    /// and sets up initial world occupancy. NPCs are scaled (and on Easy,
+    /// thinned out) according to [`Scenario::difficulty`], and the RNG is
+    /// seeded from [`Scenario::game_seed`].
     ///
     /// # Validation
     ///
@@ -210,41 +402,72 @@ impl Scenario {
     ///
     /// - `Ok(GameState)` - Initialized game state
     /// - `Err(RuntimeError::InvalidConfig)` - Validation failed or entity creation failed
+    /// - `Err(RuntimeError::EntityIdsExhausted)` - No entity IDs left to allocate
     pub fn create_initial_state(&self, oracles: &OracleBundle) -> Result<GameState> {
         // Validate scenario first - fail fast with clear error messages
         self.validate(oracles)?;
 
         // Start with empty state - scenario will add all entities explicitly
         let mut state = GameState::empty();
+        state.game_seed = self.game_seed();
 
         tracing::info!(
             "Creating initial state from scenario with {} placements (validation passed)",
             self.placements.len()
         );
 
-        for placement in &self.placements {
-            match &placement.kind {
-                EntityKind::Player => {
-                    // Player always gets EntityId::PLAYER (0)
-                    tracing::info!("Processing Player placement at {:?}", placement.position);
-                    let template = oracles.actors.template("player").ok_or_else(|| {
-                        RuntimeError::InvalidConfig(
-                            "Player template 'player' not found".to_string(),
-                        )
-                    })?;
+        // Players go first so the main player is the first actor and extra
+        // players get the lowest IDs, whatever the placement order
+        let mut players: Vec<_> = self
+            .placements
+            .iter()
+            .filter_map(|p| Some((p.player_index()?, p.position)))
+            .collect();
+        players.sort_unstable_by_key(|&(player_index, _)| player_index);
 
-                    state
-                        .add_player(template, placement.position)
-                        .map_err(|e| {
-                            RuntimeError::InvalidConfig(format!("Failed to add player: {}", e))
-                        })?;
-                    tracing::info!(
-                        "Player added successfully. Active actors: {:?}",
-                        state.turn.active_actors
-                    );
+        let template = oracles.actors.template("player").ok_or_else(|| {
+            RuntimeError::InvalidConfig("Player template 'player' not found".to_string())
+        })?;
+        for (player_index, position) in players {
+            tracing::info!(
+                "Processing Player {} placement at {:?}",
+                player_index,
+                position
+            );
+            if player_index == 0 {
+                // The main player always gets EntityId::PLAYER (0)
+                state
+                    .add_player(template, position)
+                    .map_err(|e| RuntimeError::from_spawn_error("player", e))?;
+            } else {
+                // Extra players are active and ready from the start, like the
+                // main player, instead of waiting for activation like NPCs
+                let id = state.add_npc(template, position).map_err(|e| {
+                    RuntimeError::from_spawn_error(format!("player {}", player_index), e)
+                })?;
+                if let Some(actor) = state.entities.actor_mut(id) {
+                    actor.ready_at = Some(0);
                 }
+                state.turn.active_actors.insert(id);
+            }
+        }
+        tracing::info!(
+            "Players added successfully. Active actors: {:?}",
+            state.turn.active_actors
+        );
+
+        // Objectives reference props by position; resolve them once every
+        // prop has an EntityId
+        let mut objectives = Vec::new();
+        let mut prop_ids = HashMap::new();
+        let mut plain_npcs = 0;
 
-                EntityKind::Actor { def_id } => {
+        for placement in &self.placements {
+            match &placement.kind {
+                // Added above, in player_index order
+                EntityKind::Player => {}
+
+                EntityKind::Actor { def_id, objective } => {
                     let template = oracles.actors.template(def_id).ok_or_else(|| {
                         RuntimeError::InvalidConfig(format!(
                             "Actor template '{}' not found",
@@ -252,18 +475,27 @@ impl Scenario {
                         ))
                     })?;
 
-                    state.add_npc(template, placement.position).map_err(|e| {
-                        RuntimeError::InvalidConfig(format!(
-                            "Failed to add NPC '{}': {}",
-                            def_id, e
-                        ))
+                    // NPCs with an objective drive the scenario, so they are always kept
+                    if objective.is_none() {
+                        plain_npcs += 1;
+                        if (plain_npcs - 1) % self.difficulty.npc_keep_every() != 0 {
+                            continue;
+                        }
+                    }
+
+                    let template = self.difficulty.scale_npc(template);
+                    let id = state.add_npc(&template, placement.position).map_err(|e| {
+                        RuntimeError::from_spawn_error(format!("NPC '{}'", def_id), e)
                     })?;
+                    if let Some(objective) = objective {
+                        objectives.push((id, *objective));
+                    }
                 }
 
                 EntityKind::Prop { kind, is_active } => {
-                    let id = state.allocate_entity_id().map_err(|e| {
-                        RuntimeError::InvalidConfig(format!("Failed to allocate entity ID: {}", e))
-                    })?;
+                    let id = state
+                        .allocate_entity_id()
+                        .map_err(|e| RuntimeError::from_spawn_error("prop", e))?;
                     let prop = PropState {
                         id,
                         position: placement.position,
@@ -277,12 +509,13 @@ impl Scenario {
                     })?;
 
                     state.world.tile_map.add_occupant(placement.position, id);
+                    prop_ids.insert(placement.position, id);
                 }
 
                 EntityKind::Item { handle } => {
-                    let id = state.allocate_entity_id().map_err(|e| {
-                        RuntimeError::InvalidConfig(format!("Failed to allocate entity ID: {}", e))
-                    })?;
+                    let id = state
+                        .allocate_entity_id()
+                        .map_err(|e| RuntimeError::from_spawn_error("item", e))?;
                     let item = ItemState {
                         id,
                         handle: *handle,
@@ -300,6 +533,18 @@ impl Scenario {
             }
         }
 
+        for (npc, position) in objectives {
+            let prop = prop_ids.get(&position).copied().ok_or_else(|| {
+                RuntimeError::InvalidConfig(format!(
+                    "Objective {:?} is not a Prop placement",
+                    position
+                ))
+            })?;
+            if let Some(actor) = state.entities.actor_mut(npc) {
+                actor.objective = Some(prop);
+            }
+        }
+
         Ok(state)
     }
 
@@ -323,4 +568,311 @@ impl Scenario {
 
         Ok(scenario)
     }
+
+    /// Serialize this scenario to pretty-printed RON.
+    ///
+    /// Fields are written in declaration order, so the same scenario always
+    /// produces the same text and [`Scenario::load_from_file`] reads it back
+    /// unchanged.
+    pub fn to_ron_string(&self) -> Result<String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| {
+            RuntimeError::InvalidConfig(format!("Failed to serialize scenario RON: {}", e))
+        })
+    }
+
+    /// Save scenario to a RON file, overwriting any existing file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the RON file to write
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        let content = self.to_ron_string()?;
+
+        std::fs::write(path, content).map_err(|e| {
+            RuntimeError::InvalidConfig(format!("Failed to write scenario file: {}", e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::{EntityId, TraitProfile};
+
+    use super::*;
+    use crate::oracle::ActorOracleImpl;
+
+    fn oracles() -> OracleBundle {
+        sized_oracles(10, 10)
+    }
+
+    fn sized_oracles(width: u32, height: u32) -> OracleBundle {
+        let template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let mut actors = ActorOracleImpl::new();
+        actors.add("player", template.clone());
+        actors.add("goblin", template);
+
+        let mut oracles = OracleBundle::test_bundle(width, height);
+        oracles.actors = Arc::new(actors);
+        oracles
+    }
+
+    fn scenario() -> Scenario {
+        let goblin = |x, y| EntityPlacement {
+            position: Position::new(x, y),
+            kind: EntityKind::Actor {
+                def_id: "goblin".to_string(),
+                objective: None,
+            },
+            player_index: None,
+        };
+        Scenario::new(
+            "test".to_string(),
+            vec![
+                EntityPlacement {
+                    position: Position::new(1, 1),
+                    kind: EntityKind::Player,
+                    player_index: None,
+                },
+                goblin(5, 5),
+                goblin(6, 5),
+                goblin(7, 5),
+            ],
+        )
+    }
+
+    fn npc_hps(state: &GameState) -> Vec<u32> {
+        state
+            .entities
+            .all_actors()
+            .filter(|actor| actor.id != EntityId::PLAYER)
+            .map(|actor| actor.resources.hp)
+            .collect()
+    }
+
+    #[test]
+    fn hard_difficulty_scales_npc_hp() {
+        let oracles = oracles();
+
+        let normal = scenario().create_initial_state(&oracles).unwrap();
+        let hard = scenario()
+            .with_difficulty(Difficulty::Hard)
+            .create_initial_state(&oracles)
+            .unwrap();
+
+        // CON 10 -> 105 HP; at 150% CON 15 -> 157 HP
+        assert_eq!(npc_hps(&normal), vec![105; 3]);
+        assert_eq!(npc_hps(&hard), vec![157; 3]);
+        let player =
+            |state: &GameState| state.entities.actor(EntityId::PLAYER).unwrap().resources.hp;
+        assert_eq!(player(&hard), player(&normal));
+    }
+
+    #[test]
+    fn scaled_scenarios_still_validate() {
+        let oracles = oracles();
+
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            let scenario = scenario().with_difficulty(difficulty);
+            scenario.validate(&oracles).unwrap();
+
+            let state = scenario.create_initial_state(&oracles).unwrap();
+            assert!(state.check_invariants().is_ok(), "{:?}", difficulty);
+            for actor in state.entities.all_actors() {
+                let position = actor.position.unwrap();
+                assert!(oracles.map.tile(position).unwrap().is_passable());
+                assert!(state.world.tile_map.occupants(&position).is_some());
+            }
+        }
+
+        // Easy keeps the first and third goblin
+        let easy = scenario()
+            .with_difficulty(Difficulty::Easy)
+            .create_initial_state(&oracles)
+            .unwrap();
+        assert_eq!(npc_hps(&easy).len(), 2);
+    }
+
+    #[test]
+    fn difficulty_defaults_to_normal_in_ron() {
+        let scenario: Scenario =
+            ron::from_str(r#"Scenario(map_id: "test", placements: [])"#).unwrap();
+        assert_eq!(scenario.difficulty, Difficulty::Normal);
+        assert_eq!(scenario.seed, None);
+    }
+
+    #[test]
+    fn different_seeds_give_different_rng_streams() {
+        let oracles = oracles();
+
+        let first = scenario()
+            .with_seed(1)
+            .create_initial_state(&oracles)
+            .unwrap();
+        let second = scenario()
+            .with_seed(2)
+            .create_initial_state(&oracles)
+            .unwrap();
+        assert_eq!(first.game_seed, 1);
+        assert_eq!(second.game_seed, 2);
+
+        // Every roll draws from compute_seed, so the same roll now differs
+        let roll = |state: &GameState| {
+            game_core::compute_seed(state.game_seed, 0, 0, 0, game_core::DomainTag::Damage)
+        };
+        assert_ne!(roll(&first), roll(&second));
+        assert_ne!(first.compute_state_root(), second.compute_state_root());
+    }
+
+    #[test]
+    fn omitted_seed_is_derived_from_the_map_id() {
+        let oracles = oracles();
+        let parsed: Scenario =
+            ron::from_str(r#"Scenario(map_id: "test", placements: [])"#).unwrap();
+
+        // First 8 bytes of SHA-256("test"), little-endian
+        assert_eq!(parsed.game_seed(), 0x657d_4c88_81d0_869f);
+        let mut other_map = parsed.clone();
+        other_map.map_id = "crypt".to_string();
+        assert_ne!(other_map.game_seed(), parsed.game_seed());
+
+        let state = scenario().create_initial_state(&oracles).unwrap();
+        assert_eq!(state.game_seed, parsed.game_seed());
+    }
+
+    #[test]
+    fn too_many_npcs_are_rejected_during_validation() {
+        let oracles = sized_oracles(14, 14);
+        let crowd = |npcs: usize| {
+            let mut scenario = scenario();
+            scenario.placements.truncate(1);
+            scenario
+                .placements
+                .extend((0..npcs).map(|i| EntityPlacement {
+                    position: Position::new(i as i32 % 14, 2 + i as i32 / 14),
+                    kind: EntityKind::Actor {
+                        def_id: "goblin".to_string(),
+                        objective: None,
+                    },
+                    player_index: None,
+                }));
+            scenario
+        };
+
+        // The player takes one of the MAX_ACTORS slots
+        let full = crowd(GameConfig::MAX_ACTORS - 1);
+        full.validate(&oracles).unwrap();
+        assert!(full.create_initial_state(&oracles).is_ok());
+
+        let over = crowd(GameConfig::MAX_ACTORS);
+        match over.validate(&oracles) {
+            Err(RuntimeError::InvalidConfig(message)) => {
+                assert!(message.contains("130 actor placements"), "{message}");
+                assert!(message.contains("MAX_ACTORS (129)"), "{message}");
+            }
+            other => panic!("expected InvalidConfig, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn second_player_is_active_with_a_fresh_id() {
+        let oracles = oracles();
+        let mut scenario = scenario();
+        // Listed before the main player on purpose
+        scenario.placements.insert(
+            0,
+            EntityPlacement {
+                position: Position::new(2, 1),
+                kind: EntityKind::Player,
+                player_index: Some(1),
+            },
+        );
+
+        let state = scenario.create_initial_state(&oracles).unwrap();
+
+        assert_eq!(state.entities.player().position, Some(Position::new(1, 1)));
+        let second = state
+            .entities
+            .all_actors()
+            .find(|actor| actor.position == Some(Position::new(2, 1)))
+            .unwrap();
+        assert_ne!(second.id, EntityId::PLAYER);
+        assert_eq!(second.ready_at, Some(0));
+        assert!(state.turn.active_actors.contains(&EntityId::PLAYER));
+        assert!(state.turn.active_actors.contains(&second.id));
+    }
+
+    #[test]
+    fn player_indices_must_be_unique_and_contiguous() {
+        let oracles = oracles();
+        let with_player = |player_index| {
+            let mut scenario = scenario();
+            scenario.placements.push(EntityPlacement {
+                position: Position::new(2, 1),
+                kind: EntityKind::Player,
+                player_index: Some(player_index),
+            });
+            scenario
+        };
+
+        let error = |scenario: Scenario| match scenario.validate(&oracles) {
+            Err(RuntimeError::InvalidConfig(message)) => message,
+            other => panic!("expected InvalidConfig, got {other:?}"),
+        };
+        assert!(error(with_player(0)).contains("Duplicate"));
+        assert!(error(with_player(2)).contains("missing 1"));
+        with_player(1).validate(&oracles).unwrap();
+
+        // A RON Player without an index is the main player
+        let parsed: EntityPlacement =
+            ron::from_str("EntityPlacement(position: (x: 1, y: 1), kind: Player)").unwrap();
+        assert_eq!(parsed.player_index(), Some(0));
+
+        let mut goblin = scenario();
+        goblin.placements[1].player_index = Some(1);
+        assert!(error(goblin).contains("non-Player"));
+    }
+
+    #[test]
+    fn saved_scenario_loads_back_unchanged() {
+        let mut scenario = scenario().with_difficulty(Difficulty::Hard).with_seed(99);
+        scenario.placements.extend([
+            EntityPlacement {
+                position: Position::new(3, 3),
+                kind: EntityKind::Prop {
+                    kind: PropKind::Switch,
+                    is_active: false,
+                },
+                player_index: None,
+            },
+            EntityPlacement {
+                position: Position::new(4, 3),
+                kind: EntityKind::Item {
+                    handle: ItemHandle(7),
+                },
+                player_index: None,
+            },
+            EntityPlacement {
+                position: Position::new(2, 3),
+                kind: EntityKind::Actor {
+                    def_id: "goblin".to_string(),
+                    objective: Some(Position::new(3, 3)),
+                },
+                player_index: None,
+            },
+        ]);
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("generated.ron");
+        scenario.save_to_file(&path).unwrap();
+
+        assert_eq!(Scenario::load_from_file(&path).unwrap(), scenario);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            scenario.to_ron_string().unwrap()
+        );
+    }
 }
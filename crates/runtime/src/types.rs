@@ -6,7 +6,10 @@
 /// Action sequence number (monotonically increasing)
 pub type Nonce = u64;
 
-/// Session identifier for game runs
+/// Local session name for game runs (e.g. `session_<timestamp>`)
+///
+/// Names the session directory and log files. The on-chain session object ID
+/// is a separate, canonical [`zk::SessionId`].
 pub type SessionId = String;
 
 /// Hash of game state (for verification)
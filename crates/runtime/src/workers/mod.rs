@@ -11,4 +11,4 @@ pub mod simulation;
 pub use metrics::ProofMetrics;
 pub use persistence::{CheckpointStrategy, PersistenceConfig, PersistenceWorker};
 pub use prover::{ProverConfig, ProverWorker};
-pub use simulation::{Command, SimulationWorker};
+pub use simulation::{Command, DEFAULT_UNDO_DEPTH, SimulationWorker, ValidationMode};
@@ -29,9 +29,16 @@
 //!   ├── states/
 //!   │   ├── state_{nonce}.bin
 //!   │   └── ...
-//!   └── events/
-//!       └── events_{session}.log
+//!   ├── events/
+//!   │   └── events_{session}.log
+//!   └── manifest.json
 //! ```
+//!
+//! # Session Manifest
+//!
+//! When the worker shuts down it writes a [`SessionManifest`] covering every
+//! checkpointed batch, so the session can be re-proven and verified from
+//! the files above alone.
 
 use std::path::PathBuf;
 
@@ -41,6 +48,7 @@ use tokio::time::{Duration, sleep};
 use tracing::{debug, error, info, warn};
 
 use crate::events::{Event, GameStateEvent};
+use crate::proving::SessionManifest;
 use crate::repository::{
     ActionBatch, ActionBatchRepository, ActionLogEntry, FileActionBatchRepository, FileActionLog,
     FileEventLog, FileStateRepository, StateRepository,
@@ -77,6 +85,9 @@ pub enum PersistenceError {
     #[error("Failed to create action log: {0}")]
     ActionLogCreate(String),
 
+    #[error("Failed to write session manifest: {0}")]
+    Manifest(String),
+
     #[error("Failed to query state from SimulationWorker")]
     StateQuery,
 
@@ -117,6 +128,14 @@ pub struct PersistenceConfig {
 
     /// Checkpoint strategy
     pub strategy: CheckpointStrategy,
+
+    /// Map ID of the scenario the session started from (for the manifest)
+    pub scenario: Option<String>,
+
+    /// Oracle root of the session's static content (for the manifest)
+    ///
+    /// No manifest is written when this is not set.
+    pub oracle_root: Option<[u8; 32]>,
 }
 
 impl PersistenceConfig {
@@ -126,6 +145,8 @@ impl PersistenceConfig {
             session_id,
             base_dir,
             strategy: CheckpointStrategy::default(),
+            scenario: None,
+            oracle_root: None,
         }
     }
 
@@ -134,6 +155,18 @@ impl PersistenceConfig {
         self.strategy = strategy;
         self
     }
+
+    /// Set the scenario recorded in the session manifest
+    pub fn with_scenario(mut self, scenario: impl Into<String>) -> Self {
+        self.scenario = Some(scenario.into());
+        self
+    }
+
+    /// Set the oracle root recorded in the session manifest
+    pub fn with_oracle_root(mut self, oracle_root: [u8; 32]) -> Self {
+        self.oracle_root = Some(oracle_root);
+        self
+    }
 }
 
 /// Commands that can be sent to the persistence worker
@@ -159,6 +192,12 @@ pub enum Command {
         reply: oneshot::Sender<Result<Option<GameState>>>,
     },
 
+    /// Load the latest saved game state at or before a nonce
+    LoadCheckpointBefore {
+        nonce: u64,
+        reply: oneshot::Sender<Result<Option<(u64, GameState)>>>,
+    },
+
     /// Update batch status (for manual workflow)
     UpdateBatchStatus {
         start_nonce: u64,
@@ -333,6 +372,11 @@ impl PersistenceWorker {
                                 .map_err(PersistenceError::from);
                             let _ = reply.send(result);
                         }
+                        Some(Command::LoadCheckpointBefore { nonce, reply }) => {
+                            let result = self.state_repo.load_checkpoint_before(nonce)
+                                .map_err(PersistenceError::from);
+                            let _ = reply.send(result);
+                        }
                         Some(Command::UpdateBatchStatus { start_nonce, status, reply }) => {
                             let result = self.update_batch_status(start_nonce, status).await;
                             let _ = reply.send(result);
@@ -385,10 +429,11 @@ impl PersistenceWorker {
             error!("Failed to flush event log: {}", e);
         }
 
-        // Check if there's an active batch with actions
+        // Check if there's an active batch with actions. A freshly started
+        // batch already spans its start nonce, so count what was persisted.
         match &self.current_batch {
-            Some(batch) if batch.action_count() > 0 => {
-                let action_count = batch.action_count();
+            Some(batch) if self.actions_since_checkpoint > 0 => {
+                let action_count = self.actions_since_checkpoint;
                 let start_nonce = batch.start_nonce;
 
                 info!(
@@ -435,10 +480,44 @@ impl PersistenceWorker {
             }
         }
 
+        if let Err(e) = self.write_manifest() {
+            error!("Failed to write session manifest: {}", e);
+        }
+
         info!("Finalization complete");
         Ok(())
     }
 
+    /// Write the session manifest for all checkpointed batches
+    fn write_manifest(&self) -> Result<()> {
+        let Some(oracle_root) = self.config.oracle_root else {
+            debug!("No oracle root configured, skipping session manifest");
+            return Ok(());
+        };
+
+        let batches = self.batch_repo.list(&self.config.session_id)?;
+        let manifest = SessionManifest::build(
+            self.config.session_id.clone(),
+            self.config.scenario.clone(),
+            oracle_root,
+            &batches,
+            &self.state_repo,
+        )
+        .map_err(|e| PersistenceError::Manifest(e.to_string()))?;
+
+        let session_dir = self.config.base_dir.join(&self.config.session_id);
+        manifest
+            .save(&session_dir)
+            .map_err(|e| PersistenceError::Manifest(e.to_string()))?;
+
+        info!(
+            "Session manifest written: session={}, batches={}",
+            self.config.session_id,
+            manifest.batches.len()
+        );
+        Ok(())
+    }
+
     /// Handle an event with exponential backoff retry
     async fn handle_event_with_retry(&mut self, event: Event) -> Result<()> {
         const MAX_RETRIES: u32 = 5;
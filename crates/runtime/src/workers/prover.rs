@@ -10,6 +10,12 @@
 //! 4. Generate proof for the entire batch
 //! 5. Save proof file and update batch status to Proven
 //!
+//! # Events
+//!
+//! Each proof publishes [`ProofEvent::ProofProgress`] as the backend enters
+//! each [`ProofStage`], followed by `ProofGenerated` or `ProofFailed`, so
+//! frontends can show what a long-running proof is doing.
+//!
 //! # Proof Generation Strategy
 //!
 //! Currently generates a single proof for the entire batch. Future optimization
@@ -23,14 +29,15 @@ use std::time::Instant;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use game_core::GameState;
+use game_core::{Action, GameState};
 
+use crate::events::{Event, EventBus, ProofEvent};
 use crate::repository::{
     ActionBatch, ActionBatchRepository, FileActionBatchRepository, FileActionLogReader,
     FileStateRepository, StateRepository,
 };
 
-use zk::{ProofData, Prover};
+use zk::{ProofData, ProofStage, Prover};
 
 /// Result type for prover operations
 pub type Result<T> = std::result::Result<T, ProverError>;
@@ -86,6 +93,9 @@ pub struct ProverWorker {
     // Prover instance (shared across parallel tasks)
     prover: Arc<dyn Prover>,
 
+    // Proof progress and results are published here
+    event_bus: EventBus,
+
     // Communication
     command_rx: mpsc::Receiver<Command>,
     batch_complete_rx: mpsc::UnboundedReceiver<ActionBatch>,
@@ -102,6 +112,7 @@ impl ProverWorker {
     pub fn new(
         config: ProverConfig,
         prover: Arc<dyn Prover>,
+        event_bus: EventBus,
         command_rx: mpsc::Receiver<Command>,
         batch_complete_rx: mpsc::UnboundedReceiver<ActionBatch>,
     ) -> Result<Self> {
@@ -120,6 +131,7 @@ impl ProverWorker {
             batch_repo: Arc::new(batch_repo),
             state_repo: Arc::new(state_repo),
             prover,
+            event_bus,
             command_rx,
             batch_complete_rx,
             running_tasks: Vec::new(),
@@ -237,15 +249,21 @@ impl ProverWorker {
         let batch_repo = Arc::clone(&self.batch_repo);
         let state_repo = Arc::clone(&self.state_repo);
         let prover = Arc::clone(&self.prover);
+        let event_bus = self.event_bus.clone();
 
         // CRITICAL: Run entire proof generation in blocking thread pool
         // This prevents blocking tokio runtime with:
         // 1. CPU-intensive proof generation (RISC0 zkVM)
         // 2. Synchronous I/O operations (file reads/writes)
         let task = tokio::task::spawn_blocking(move || {
-            if let Err(e) =
-                Self::prove_batch_blocking(start_nonce, config, batch_repo, state_repo, prover)
-            {
+            if let Err(e) = Self::prove_batch_blocking(
+                start_nonce,
+                config,
+                batch_repo,
+                state_repo,
+                prover,
+                event_bus,
+            ) {
                 error!("Proof generation failed for batch {}: {}", start_nonce, e);
                 Err(e)
             } else {
@@ -301,6 +319,7 @@ impl ProverWorker {
         batch_repo: Arc<FileActionBatchRepository>,
         state_repo: Arc<FileStateRepository>,
         prover: Arc<dyn Prover>,
+        event_bus: EventBus,
     ) -> Result<()> {
         info!("Starting proof generation for batch {}", start_nonce);
 
@@ -346,10 +365,14 @@ impl ProverWorker {
         let mut reader = FileActionLogReader::new(&action_log_path, config.session_id.clone())?;
 
         // Generate proof
-        let proof_start = Instant::now();
-        let proof_data =
-            Self::generate_batch_proof(&batch, &start_state, &end_state, &mut reader, &prover)?;
-        let generation_time_ms = proof_start.elapsed().as_millis() as u64;
+        let (proof_data, generation_time_ms) = Self::generate_batch_proof(
+            &batch,
+            &start_state,
+            &end_state,
+            &mut reader,
+            prover.as_ref(),
+            &event_bus,
+        )?;
 
         // Debug mode: Verify the generated proof immediately
         #[cfg(debug_assertions)]
@@ -401,13 +424,16 @@ impl ProverWorker {
     ///
     /// Generates a single batch proof that verifies:
     /// - start_state + [action1, action2, ..., actionN] → end_state
+    ///
+    /// Returns the proof and its generation time in milliseconds.
     fn generate_batch_proof(
         batch: &ActionBatch,
         start_state: &GameState,
         end_state: &GameState,
         reader: &mut FileActionLogReader,
-        prover: &Arc<dyn Prover>,
-    ) -> Result<ProofData> {
+        prover: &dyn Prover,
+        event_bus: &EventBus,
+    ) -> Result<(ProofData, u64)> {
         let action_count = batch.action_count();
 
         debug!(
@@ -453,7 +479,14 @@ impl ProverWorker {
             batch.start_nonce
         );
 
-        let proof = prover.prove(start_state, &batch_actions, end_state)?;
+        let proof = Self::prove_with_events(
+            batch.start_nonce,
+            start_state,
+            &batch_actions,
+            end_state,
+            prover,
+            event_bus,
+        )?;
 
         info!(
             "Batch proof generated: {} actions, start_nonce={}, end_nonce={}",
@@ -462,6 +495,60 @@ impl ProverWorker {
 
         Ok(proof)
     }
+
+    /// Proves `actions` and publishes progress and the outcome.
+    ///
+    /// Publishes `ProofProgress` for each stage the backend enters, then
+    /// `ProofGenerated` or `ProofFailed` for the batch's last action.
+    ///
+    /// Returns the proof and its generation time in milliseconds.
+    fn prove_with_events(
+        batch: u64,
+        start_state: &GameState,
+        actions: &[Action],
+        end_state: &GameState,
+        prover: &dyn Prover,
+        event_bus: &EventBus,
+    ) -> Result<(ProofData, u64)> {
+        let proof_start = Instant::now();
+        let result = prover.prove_with_progress(
+            start_state,
+            actions,
+            end_state,
+            &mut |stage: ProofStage| {
+                debug!("Batch {} proof stage: {:?}", batch, stage);
+                event_bus.publish(Event::Proof(ProofEvent::ProofProgress { batch, stage }));
+            },
+        );
+        let generation_time_ms = proof_start.elapsed().as_millis() as u64;
+
+        // Batches are never empty, see generate_batch_proof
+        let last_action = actions
+            .last()
+            .cloned()
+            .ok_or(ProverError::NoActions { start_nonce: batch })?;
+        let clock = end_state.turn.clock;
+
+        match result {
+            Ok(proof_data) => {
+                event_bus.publish(Event::Proof(ProofEvent::ProofGenerated {
+                    action: last_action,
+                    clock,
+                    proof_data: proof_data.clone(),
+                    generation_time_ms,
+                }));
+                Ok((proof_data, generation_time_ms))
+            }
+            Err(e) => {
+                event_bus.publish(Event::Proof(ProofEvent::ProofFailed {
+                    action: last_action,
+                    clock,
+                    error: e.to_string(),
+                }));
+                Err(e.into())
+            }
+        }
+    }
 }
 
 /// Errors that can occur during proof generation
@@ -501,3 +588,65 @@ pub enum ProverError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
+
+#[cfg(all(test, feature = "stub"))]
+mod tests {
+    use game_core::{GameConfig, PrepareTurnAction, SystemActionKind};
+
+    use super::*;
+    use crate::events::Topic;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+        OracleBundle,
+    };
+
+    fn stub_prover() -> zk::StubProver {
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(8, 8)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        );
+        zk::StubProver::new(oracles.to_snapshot())
+    }
+
+    #[test]
+    fn stub_proof_reports_stages_then_result() {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::Proof);
+
+        let state = GameState::with_player();
+        let actions = [Action::system(SystemActionKind::PrepareTurn(
+            PrepareTurnAction,
+        ))];
+
+        ProverWorker::prove_with_events(7, &state, &actions, &state, &stub_prover(), &event_bus)
+            .unwrap();
+
+        let mut stages = Vec::new();
+        loop {
+            match events.try_recv() {
+                Ok(Event::Proof(ProofEvent::ProofProgress { batch, stage })) => {
+                    assert_eq!(batch, 7);
+                    stages.push(stage);
+                }
+                Ok(Event::Proof(ProofEvent::ProofGenerated { proof_data, .. })) => {
+                    assert_eq!(proof_data.backend, zk::ProofBackend::Stub);
+                    break;
+                }
+                other => panic!("expected proof progress or result, got {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            stages,
+            vec![
+                ProofStage::Witness,
+                ProofStage::Prove,
+                ProofStage::Serialize
+            ]
+        );
+        assert!(events.try_recv().is_err());
+    }
+}
@@ -2,17 +2,40 @@
 //!
 //! Receives commands from [`RuntimeHandle`], executes actions via
 //! [`game_core::engine::GameEngine`], and publishes events to the EventBus.
+//!
+//! # Event Ordering
+//!
+//! A turn publishes its [`GameStateEvent`]s in a fixed order, so subscribers
+//! and the prover see the same sequence on every replay:
+//! 1. `ActionExecuted` for `PrepareTurn` (advances the clock, picks the actor),
+//!    then for its cascaded system actions (e.g. despawning expired summons)
+//! 2. `ActionExecuted` for the actor's action, or `ActionFailed` followed by
+//!    `ActionExecuted` for the Wait fallback
+//! 3. `ActionExecuted` for each cascaded system action, breadth-first: every
+//!    reaction to one pass runs before the reactions it triggers. Within a
+//!    pass, reactions follow their source deltas in execution order, then the
+//!    canonical [`crate::events::GameEvent::order`] of the triggering events,
+//!    then handler priority.
+//!
+//! Any `HealthThresholdCrossed` and then `EquipmentBroke` events follow the
+//! `ActionExecuted` of the action that caused them, before anything else is
+//! published.
+
+use std::collections::{HashMap, VecDeque};
 
 use tokio::sync::{mpsc, oneshot};
 
 use game_core::engine::{ExecuteError, TransitionPhase};
 use game_core::{
-    Action, EntityId, GameEngine, GameState, PrepareTurnAction, SystemActionKind, Tick,
+    Action, ActionInput, ActionKind, CharacterAction, EntityId, ErrorSeverity, GameEngine,
+    GameError, GameState, PrepareTurnAction, SystemActionKind, Tick,
 };
 use tracing::{debug, error, warn};
 
 use crate::api::{Result, RuntimeError};
-use crate::events::{Event, EventBus, GameStateEvent};
+use crate::events::{
+    Event, EventBus, GameEvent, GameStateEvent, broken_equipment, health_threshold_crossings,
+};
 use crate::handlers::HandlerCriticality;
 use crate::oracle::OracleBundle;
 use crate::providers::SystemActionProvider;
@@ -25,12 +48,23 @@ pub enum Command {
         reply: oneshot::Sender<Result<(EntityId, GameState)>>,
     },
     /// Execute an action (turn must already be prepared).
+    ///
+    /// With an `action_nonce`, the action is rejected as a duplicate when it
+    /// repeats the nonce of the actor's last accepted action.
     ExecuteAction {
         action: Action,
+        action_nonce: Option<u64>,
         reply: oneshot::Sender<Result<()>>,
     },
     /// Query the current game state (read-only).
     QueryState { reply: oneshot::Sender<GameState> },
+    /// Project the next `depth` turns without touching the real state.
+    PreviewTurnOrder {
+        depth: usize,
+        reply: oneshot::Sender<Result<Vec<(EntityId, Tick)>>>,
+    },
+    /// Revert the most recent executed action.
+    UndoLastAction { reply: oneshot::Sender<Result<()>> },
     /// Restore game state from a checkpoint (load game).
     RestoreState {
         state: GameState,
@@ -38,6 +72,28 @@ pub enum Command {
     },
 }
 
+/// How the simulation worker treats actions that fail validation or execution.
+///
+/// Fatal errors halt the session in both modes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Publish `ActionFailed` and spend the turn on a Wait (gameplay default).
+    #[default]
+    Lenient,
+    /// Reply to the submitter with [`RuntimeError::ActionRejected`]; the state
+    /// is untouched and the actor keeps the turn.
+    Strict,
+}
+
+/// Default number of executed actions [`Command::UndoLastAction`] can revert.
+pub const DEFAULT_UNDO_DEPTH: usize = 8;
+
+/// Worker state captured right before an executed action.
+struct UndoSnapshot {
+    state: GameState,
+    last_action_nonces: HashMap<EntityId, u64>,
+}
+
 /// Background task that processes gameplay commands.
 ///
 /// # Design Note
@@ -51,6 +107,18 @@ pub struct SimulationWorker {
     command_rx: mpsc::Receiver<Command>,
     event_bus: EventBus,
     system_provider: SystemActionProvider,
+    validation_mode: ValidationMode,
+    /// Set once a fatal error halts the session: (error code, reason).
+    halted: Option<(&'static str, String)>,
+    /// Client-supplied nonce of each actor's last accepted action.
+    last_action_nonces: HashMap<EntityId, u64>,
+    /// Maximum number of snapshots kept in `undo_history`.
+    undo_depth: usize,
+    /// Snapshots taken before the most recent executed actions, oldest first.
+    undo_history: VecDeque<UndoSnapshot>,
+    /// Whether executed actions are appended to a persisted action log, which
+    /// undo cannot take back.
+    persisted: bool,
 }
 
 impl SimulationWorker {
@@ -74,9 +142,37 @@ impl SimulationWorker {
             command_rx,
             event_bus,
             system_provider,
+            validation_mode: ValidationMode::default(),
+            halted: None,
+            last_action_nonces: HashMap::new(),
+            undo_depth: DEFAULT_UNDO_DEPTH,
+            undo_history: VecDeque::new(),
+            persisted: false,
         }
     }
 
+    /// Sets how failed actions are reported (default: lenient).
+    pub fn with_validation_mode(mut self, mode: ValidationMode) -> Self {
+        self.validation_mode = mode;
+        self
+    }
+
+    /// Sets how many executed actions can be undone (default: 8, 0 disables undo).
+    pub fn with_undo_depth(mut self, depth: usize) -> Self {
+        self.undo_depth = depth;
+        self
+    }
+
+    /// Marks executed actions as persisted to the action log (default: false).
+    ///
+    /// The persistence worker logs every executed action as soon as it is
+    /// published, so a persisted session refuses undo instead of leaving the
+    /// log with an action the state no longer reflects.
+    pub fn with_persisted_actions(mut self, persisted: bool) -> Self {
+        self.persisted = persisted;
+        self
+    }
+
     /// Main worker loop.
     pub async fn run(mut self) {
         loop {
@@ -92,13 +188,23 @@ impl SimulationWorker {
     async fn handle_command(&mut self, cmd: Command) {
         match cmd {
             Command::PrepareNextTurn { reply } => {
-                let result = self.handle_turn_preparation();
+                let result = match self.halted_error() {
+                    Some(error) => Err(error),
+                    None => self.handle_turn_preparation(),
+                };
                 if reply.send(result).is_err() {
                     debug!("PrepareNextTurn reply channel closed (caller dropped)");
                 }
             }
-            Command::ExecuteAction { action, reply } => {
-                let result = self.handle_player_action(action);
+            Command::ExecuteAction {
+                action,
+                action_nonce,
+                reply,
+            } => {
+                let result = match self.halted_error() {
+                    Some(error) => Err(error),
+                    None => self.handle_submitted_action(action, action_nonce),
+                };
                 if reply.send(result).is_err() {
                     debug!("ExecuteAction reply channel closed (caller dropped)");
                 }
@@ -108,6 +214,21 @@ impl SimulationWorker {
                     debug!("QueryState reply channel closed (caller dropped)");
                 }
             }
+            Command::PreviewTurnOrder { depth, reply } => {
+                let result = match self.halted_error() {
+                    Some(error) => Err(error),
+                    None => Ok(self.preview_turn_order(depth)),
+                };
+                if reply.send(result).is_err() {
+                    debug!("PreviewTurnOrder reply channel closed (caller dropped)");
+                }
+            }
+            Command::UndoLastAction { reply } => {
+                let result = self.handle_undo();
+                if reply.send(result).is_err() {
+                    debug!("UndoLastAction reply channel closed (caller dropped)");
+                }
+            }
             Command::RestoreState { state, reply } => {
                 let result = self.handle_restore_state(state);
                 if reply.send(result).is_err() {
@@ -119,35 +240,96 @@ impl SimulationWorker {
 
     /// Handles turn preparation workflow.
     ///
-    /// Executes PrepareTurn system action and publishes Turn event.
+    /// Executes PrepareTurn system action and publishes Turn event, then runs
+    /// the turn-start reactions (e.g. despawning expired summons). If the
+    /// selected actor was removed by those reactions, the turn is prepared
+    /// again.
     fn handle_turn_preparation(&mut self) -> Result<(EntityId, GameState)> {
-        // Create system action for turn preparation
-        let prepare_action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        loop {
+            // Create system action for turn preparation
+            let prepare_action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+            let state_before = self.state.clone();
 
-        // Execute turn preparation through unified execute_action_impl
-        let _delta = Self::execute_action_impl(
-            &prepare_action,
-            &mut self.state,
-            &self.oracles,
-            &self.event_bus,
-        )
-        .map_err(|e| match e {
-            ExecuteError::PrepareTurn(phase_error) => match phase_error.error {
-                game_core::TurnError::NoActiveEntities { .. } => RuntimeError::NoActiveEntities,
-                game_core::TurnError::NotSystemActor { .. } => {
-                    unreachable!("PrepareTurnAction is constructed with SYSTEM actor")
+            // Execute turn preparation through unified execute_action_impl
+            let delta = match Self::execute_action_impl(
+                &prepare_action,
+                &mut self.state,
+                &self.oracles,
+                &self.event_bus,
+            ) {
+                Ok(delta) => delta,
+                Err(ExecuteError::PrepareTurn(phase_error)) => match phase_error.error {
+                    game_core::TurnError::NoActiveEntities { .. } => {
+                        return Err(RuntimeError::NoActiveEntities);
+                    }
+                    game_core::TurnError::NotSystemActor { .. } => {
+                        unreachable!("PrepareTurnAction is constructed with SYSTEM actor")
+                    }
+                },
+                // Turn-start status ticking only fails on a broken state
+                Err(error) => return Err(self.halt(error.error_code(), error.message())),
+            };
+
+            if let Err(error) = self.process_cascading(delta, state_before) {
+                error!(target: "runtime::worker", error = ?error, "Turn-start system actions failed");
+                if error.severity() == ErrorSeverity::Fatal {
+                    return Err(self.halt(error.error_code(), error.message()));
                 }
-            },
-            _ => unreachable!("PrepareTurnAction should only return PrepareTurn error"),
-        })?;
+            }
+
+            // Get the current actor (now set by the system action)
+            let entity = self.state.turn.current_actor;
+            if !self.state.turn.active_actors.contains(&entity) {
+                debug!(
+                    target: "runtime::worker",
+                    entity = ?entity,
+                    "Selected actor left play at turn start, preparing again"
+                );
+                continue;
+            }
+
+            // Clone the current state for action decision-making
+            return Ok((entity, self.state.clone()));
+        }
+    }
+
+    /// Projects the next `depth` turns as `(entity, tick)` pairs.
+    ///
+    /// Runs PrepareTurn on a clone of the state `depth` times, assuming every
+    /// selected actor waits. Nothing is published and the real state, clock
+    /// included, is untouched. Turn-start reactions are not simulated, and the
+    /// projection stops early once no actor can be selected.
+    fn preview_turn_order(&self, depth: usize) -> Vec<(EntityId, Tick)> {
+        let mut state = self.state.clone();
+        let prepare_action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
 
-        // Get the current actor (now set by the system action)
-        let entity = self.state.turn.current_actor;
+        let mut order = Vec::with_capacity(depth);
+        for _ in 0..depth {
+            if GameEngine::new(&mut state)
+                .execute(self.oracles.as_game_env(), &prepare_action)
+                .is_err()
+            {
+                break;
+            }
 
-        // Clone the current state for action decision-making
-        let state_clone = self.state.clone();
+            let entity = state.turn.current_actor;
+            let tick = state.turn.clock;
+            order.push((entity, tick));
 
-        Ok((entity, state_clone))
+            // Spend the turn on a Wait, as the pipeline would
+            let Some(actor) = state.entities.actor_mut(entity) else {
+                break;
+            };
+            let wait = Action::character(CharacterAction::new(
+                entity,
+                ActionKind::Wait,
+                ActionInput::None,
+            ));
+            let cost = wait.cost(&actor.snapshot(), &self.oracles.as_game_env());
+            actor.ready_at = Some(tick + cost);
+        }
+
+        order
     }
 
     /// Executes any action (player, NPC, or system) and publishes ActionExecuted event.
@@ -198,6 +380,9 @@ impl SimulationWorker {
         let delta = outcome.delta;
         let action_result = outcome.action_result.unwrap_or_default();
 
+        let crossings = health_threshold_crossings(&delta, &before_state, &after_state);
+        let broken = broken_equipment(&delta, &before_state, &after_state);
+
         // Publish ActionExecuted event for ALL actions (player, NPC, system)
         // This ensures ProverWorker can generate proofs for every state transition
         event_bus.publish(Event::GameState(GameStateEvent::ActionExecuted {
@@ -210,6 +395,29 @@ impl SimulationWorker {
             action_result,
         }));
 
+        for crossing in crossings {
+            if let GameEvent::HealthThresholdCrossed {
+                entity,
+                threshold,
+                direction,
+                ..
+            } = crossing
+            {
+                event_bus.publish(Event::GameState(GameStateEvent::HealthThresholdCrossed {
+                    entity,
+                    threshold,
+                    direction,
+                }));
+            }
+        }
+
+        for (entity, item) in broken {
+            event_bus.publish(Event::GameState(GameStateEvent::EquipmentBroke {
+                entity,
+                item,
+            }));
+        }
+
         Ok(delta)
     }
 
@@ -221,8 +429,11 @@ impl SimulationWorker {
         let old_nonce = self.state.nonce();
         let new_nonce = state.nonce();
 
-        // Replace the entire state
+        // Replace the entire state (a restored checkpoint clears any halt)
         self.state = state;
+        self.halted = None;
+        self.last_action_nonces.clear();
+        self.undo_history.clear();
 
         // Publish event
         self.event_bus
@@ -236,12 +447,91 @@ impl SimulationWorker {
         Ok(())
     }
 
+    /// Reverts the most recent executed action.
+    ///
+    /// Restores the state captured right before it, including the turn clock
+    /// and nonce, so the same actor is up again. Like a restore, this clears
+    /// any halt. Publishes a RolledBack event carrying the restored state.
+    ///
+    /// Fails with [`RuntimeError::UndoPersisted`] when executed actions are
+    /// persisted (see [`Self::with_persisted_actions`]).
+    fn handle_undo(&mut self) -> Result<()> {
+        if self.persisted {
+            return Err(RuntimeError::UndoPersisted);
+        }
+
+        let snapshot = self
+            .undo_history
+            .pop_back()
+            .ok_or(RuntimeError::NothingToUndo)?;
+        let old_nonce = self.state.nonce();
+
+        self.state = snapshot.state;
+        self.last_action_nonces = snapshot.last_action_nonces;
+        self.halted = None;
+
+        let new_nonce = self.state.nonce();
+        self.event_bus
+            .publish(Event::GameState(GameStateEvent::RolledBack {
+                from_nonce: old_nonce,
+                to_nonce: new_nonce,
+                state: Box::new(self.state.clone()),
+            }));
+
+        tracing::info!("Last action undone: nonce {} → {}", old_nonce, new_nonce);
+
+        Ok(())
+    }
+
+    /// Rejects a re-submitted action, then runs it through
+    /// [`Self::handle_player_action`].
+    ///
+    /// A submission is a duplicate when its `action_nonce` equals the nonce of
+    /// the last action accepted for the same actor. Actions without a nonce
+    /// are never deduplicated.
+    fn handle_submitted_action(&mut self, action: Action, action_nonce: Option<u64>) -> Result<()> {
+        let actor = action.actor();
+        if let Some(action_nonce) = action_nonce
+            && self.last_action_nonces.get(&actor) == Some(&action_nonce)
+        {
+            debug!(
+                target: "runtime::worker",
+                actor = ?actor,
+                action_nonce,
+                "Duplicate action submission rejected"
+            );
+            return Err(RuntimeError::DuplicateAction {
+                actor,
+                action_nonce,
+            });
+        }
+
+        let snapshot = UndoSnapshot {
+            state: self.state.clone(),
+            last_action_nonces: self.last_action_nonces.clone(),
+        };
+        self.handle_player_action(action)?;
+
+        if self.undo_depth > 0 && !self.persisted {
+            if self.undo_history.len() == self.undo_depth {
+                self.undo_history.pop_front();
+            }
+            self.undo_history.push_back(snapshot);
+        }
+        if let Some(action_nonce) = action_nonce {
+            self.last_action_nonces.insert(actor, action_nonce);
+        }
+        Ok(())
+    }
+
     /// Handles player/NPC action with full workflow:
     /// execute → cascading system actions
     ///
     /// Actor validation is performed by GameEngine::execute (game-core).
     ///
     /// If the action fails due to ActorDead, just skip the turn without fallback.
+    /// Other failures are rejected in [`ValidationMode::Strict`] and fall back to
+    /// Wait in [`ValidationMode::Lenient`].
     fn handle_player_action(&mut self, action: Action) -> Result<()> {
         let clock = self.state.turn.clock;
 
@@ -258,6 +548,11 @@ impl SimulationWorker {
                 delta
             }
             Err(error) => {
+                // Fatal errors end the session instead of falling back to Wait
+                if error.severity() == ErrorSeverity::Fatal {
+                    return Err(self.halt(error.error_code(), error.message()));
+                }
+
                 // Check if actor is dead
                 if matches!(
                     error,
@@ -271,6 +566,24 @@ impl SimulationWorker {
                     return Ok(());
                 }
 
+                if self.validation_mode == ValidationMode::Strict {
+                    debug!(
+                        target: "runtime::worker",
+                        actor = ?action.actor(),
+                        error = %error.message(),
+                        "Action rejected (strict validation)"
+                    );
+                    // Report the inner action error, as ActionFailed does
+                    let error_code = match &error {
+                        ExecuteError::Character(phase_error) => phase_error.error_code(),
+                        other => other.error_code(),
+                    };
+                    return Err(RuntimeError::ActionRejected {
+                        error_code,
+                        reason: error.message(),
+                    });
+                }
+
                 // For other errors, try Wait fallback
                 debug!(
                     target: "runtime::worker",
@@ -299,12 +612,41 @@ impl SimulationWorker {
         // Process cascading system actions
         if let Err(error) = self.process_cascading(delta, state_before) {
             error!(target: "runtime::worker", error = ?error, "Cascading system actions failed");
+            if error.severity() == ErrorSeverity::Fatal {
+                return Err(self.halt(error.error_code(), error.message()));
+            }
             return Ok(());
         }
 
+        #[cfg(any(test, feature = "debug-invariants"))]
+        self.check_invariants()?;
+
         Ok(())
     }
 
+    /// Halts the session if the turn that just completed broke a state
+    /// invariant, logging every violation.
+    #[cfg(any(test, feature = "debug-invariants"))]
+    fn check_invariants(&mut self) -> Result<()> {
+        let Err(violations) = self.state.check_invariants() else {
+            return Ok(());
+        };
+        for violation in &violations {
+            error!(
+                target: "runtime::worker",
+                nonce = self.state.nonce(),
+                %violation,
+                "State invariant violated"
+            );
+        }
+        let reason = violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(self.halt("STATE_INVARIANT_VIOLATED", reason))
+    }
+
     /// Process cascading system actions via SystemActionProvider.
     ///
     /// This is the core of the reactive system action generation:
@@ -312,6 +654,9 @@ impl SimulationWorker {
     /// 2. Execute each system action individually
     /// 3. Each action may generate new deltas that trigger more system actions (cascading)
     /// 4. Repeat until no new actions are generated
+    ///
+    /// Deltas are processed strictly in the order they were produced, which
+    /// keeps the published event order canonical (see the module docs).
     fn process_cascading(
         &mut self,
         initial_delta: game_core::StateDelta,
@@ -415,23 +760,91 @@ impl SimulationWorker {
         Ok(())
     }
 
+    /// Halts the session after a fatal error and publishes a terminal event.
+    ///
+    /// The worker keeps serving state queries and checkpoint restores, but
+    /// rejects turn preparation and action execution until a restore.
+    /// Returns the error to hand back to the caller that triggered the halt:
+    /// [`RuntimeError::EntityIdsExhausted`] when a spawn ran out of entity
+    /// ids, [`RuntimeError::SessionHalted`] otherwise.
+    fn halt(&mut self, error_code: &'static str, reason: String) -> RuntimeError {
+        error!(
+            target: "runtime::worker",
+            error_code = error_code,
+            reason = %reason,
+            "Fatal error - halting session"
+        );
+
+        self.event_bus
+            .publish(Event::GameState(GameStateEvent::SessionHalted {
+                nonce: self.state.turn.nonce,
+                clock: self.state.turn.clock,
+                error_code: error_code.to_string(),
+                reason: reason.clone(),
+            }));
+
+        self.halted = Some((error_code, reason.clone()));
+        if let Some(context) = exhausted_spawn(error_code) {
+            return RuntimeError::EntityIdsExhausted {
+                context: context.to_string(),
+                current: EntityId::SYSTEM.0 - self.state.remaining_entity_ids(),
+            };
+        }
+        RuntimeError::SessionHalted { error_code, reason }
+    }
+
+    /// Returns the error to reply with while the session is halted.
+    fn halted_error(&self) -> Option<RuntimeError> {
+        self.halted
+            .as_ref()
+            .map(|(error_code, reason)| RuntimeError::SessionHalted {
+                error_code,
+                reason: reason.clone(),
+            })
+    }
+
     fn handle_execute_error(&self, action: &Action, error: ExecuteError, clock: Tick) {
-        let (phase, message) = match &error {
-            ExecuteError::Character(phase_error) => {
-                (phase_error.phase, phase_error.error.to_string())
-            }
-            ExecuteError::PrepareTurn(phase_error) => {
-                (phase_error.phase, phase_error.error.to_string())
-            }
-            ExecuteError::Activation(phase_error) => {
-                (phase_error.phase, phase_error.error.to_string())
-            }
-            ExecuteError::Deactivate(phase_error) => {
-                (phase_error.phase, phase_error.error.to_string())
-            }
-            ExecuteError::RemoveFromWorld(phase_error) => {
-                (phase_error.phase, phase_error.error.to_string())
-            }
+        let (phase, error_code, message) = match &error {
+            ExecuteError::Character(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::PrepareTurn(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::Activation(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::Deactivate(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::RemoveFromWorld(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::TickStatusEffects(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::OpportunityAttack(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
+            ExecuteError::DropLoot(phase_error) => (
+                phase_error.phase,
+                phase_error.error_code(),
+                phase_error.error.to_string(),
+            ),
             ExecuteError::HookChainTooDeep {
                 hook_name, depth, ..
             } => {
@@ -471,6 +884,7 @@ impl SimulationWorker {
                 target: "runtime::worker",
                 action = ?action,
                 phase = phase.as_str(),
+                error_code = error_code,
                 error = %message,
                 "Action rejected during pre-validate"
             );
@@ -479,6 +893,7 @@ impl SimulationWorker {
                 target: "runtime::worker",
                 action = ?action,
                 phase = phase.as_str(),
+                error_code = error_code,
                 error = %message,
                 "Action execution failed"
             );
@@ -491,6 +906,7 @@ impl SimulationWorker {
                 nonce,
                 action: action.clone(),
                 phase,
+                error_code: error_code.to_string(),
                 error: message,
                 clock,
             }));
@@ -519,3 +935,740 @@ impl SimulationWorker {
         Self::execute_action_impl(&wait_action, working_state, &self.oracles, &self.event_bus)
     }
 }
+
+/// What was being spawned when the fatal error `error_code` ran out of
+/// entity ids, or `None` for any other fatal error.
+fn exhausted_spawn(error_code: &str) -> Option<&'static str> {
+    match error_code {
+        "STATE_ENTITY_ID_OVERFLOW" => Some("an entity"),
+        "DROP_LOOT_ENTITY_IDS_EXHAUSTED" => Some("dropped loot"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::stats::calculate_action_cost;
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, ActorTemplate, CardinalDirection, CharacterAction,
+        GameConfig, MapDimensions, Position, StaticTile, StatusEffectKind, TerrainKind,
+        TraitProfile,
+    };
+
+    use super::*;
+    use crate::events::{GameEvent, Topic, extract_events};
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+    };
+
+    /// Default state with the player placed on the occupancy map.
+    fn player_state() -> GameState {
+        let mut state = GameState::with_player();
+        let position = state.entities.player().position.unwrap();
+        state
+            .world
+            .tile_map
+            .add_occupant(position, EntityId::PLAYER);
+        state
+    }
+
+    fn test_worker(event_bus: EventBus) -> SimulationWorker {
+        worker_with_map(event_bus, MapOracleImpl::test_map(8, 8))
+    }
+
+    fn worker_with_map(event_bus: EventBus, map: MapOracleImpl) -> SimulationWorker {
+        let oracles = OracleBundle::new(
+            Arc::new(map),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+        );
+        let (_command_tx, command_rx) = mpsc::channel(1);
+
+        SimulationWorker::new(
+            player_state(),
+            oracles,
+            command_rx,
+            event_bus,
+            SystemActionProvider::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn halted_worker_publishes_terminal_event_and_stays_responsive() {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = test_worker(event_bus);
+
+        worker.halt("STATE_ENTITY_ID_OVERFLOW", "Entity ID overflow".to_string());
+
+        match events.try_recv() {
+            Ok(Event::GameState(GameStateEvent::SessionHalted { error_code, .. })) => {
+                assert_eq!(error_code, "STATE_ENTITY_ID_OVERFLOW");
+            }
+            other => panic!("expected SessionHalted event, got {:?}", other),
+        }
+
+        // Actions are rejected with a descriptive error
+        let (reply, rx) = oneshot::channel();
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        worker
+            .handle_command(Command::ExecuteAction {
+                action,
+                action_nonce: None,
+                reply,
+            })
+            .await;
+        assert!(matches!(
+            rx.await.unwrap(),
+            Err(RuntimeError::SessionHalted { .. })
+        ));
+
+        // Queries still answer
+        let (reply, rx) = oneshot::channel();
+        worker.handle_command(Command::QueryState { reply }).await;
+        assert_eq!(rx.await.unwrap(), player_state());
+
+        // Restoring a checkpoint resumes the session
+        let (reply, rx) = oneshot::channel();
+        worker
+            .handle_command(Command::RestoreState {
+                state: player_state(),
+                reply,
+            })
+            .await;
+        rx.await.unwrap().unwrap();
+        assert!(worker.halted_error().is_none());
+    }
+
+    async fn submit(worker: &mut SimulationWorker, action: &Action, nonce: u64) -> Result<()> {
+        let (reply, rx) = oneshot::channel();
+        worker
+            .handle_command(Command::ExecuteAction {
+                action: action.clone(),
+                action_nonce: Some(nonce),
+                reply,
+            })
+            .await;
+        rx.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn resubmitted_action_is_rejected_without_state_change() {
+        let mut worker = test_worker(EventBus::new());
+        worker
+            .state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        let wait = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+
+        worker.handle_turn_preparation().unwrap();
+        submit(&mut worker, &wait, 7).await.unwrap();
+        let after_first = worker.state.clone();
+
+        // A retry after a timeout is rejected instead of applied twice
+        let retry = submit(&mut worker, &wait, 7).await;
+        assert!(matches!(
+            retry,
+            Err(RuntimeError::DuplicateAction { actor, action_nonce: 7 }) if actor == EntityId::PLAYER
+        ));
+        assert_eq!(worker.state, after_first);
+
+        // The next turn's submission carries a fresh nonce and goes through
+        worker.handle_turn_preparation().unwrap();
+        submit(&mut worker, &wait, 8).await.unwrap();
+        assert_eq!(worker.state.nonce(), after_first.nonce() + 2);
+    }
+
+    #[tokio::test]
+    async fn turn_that_breaks_an_invariant_halts_the_session() {
+        let mut worker = test_worker(EventBus::new());
+        worker
+            .state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+        let wait = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+
+        // The player drops off the occupancy map
+        let position = worker.state.entities.player().position.unwrap();
+        worker
+            .state
+            .world
+            .tile_map
+            .remove_occupant(&position, EntityId::PLAYER);
+
+        worker.handle_turn_preparation().unwrap();
+        let result = submit(&mut worker, &wait, 1).await;
+        assert!(matches!(
+            result,
+            Err(RuntimeError::SessionHalted {
+                error_code: "STATE_INVARIANT_VIOLATED",
+                ..
+            })
+        ));
+        assert!(worker.halted_error().is_some());
+    }
+
+    /// Worker whose player can step east, and the step itself.
+    fn stepping_worker(event_bus: EventBus) -> (SimulationWorker, Action) {
+        let mut worker = test_worker(event_bus);
+        worker
+            .state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Move));
+        let step = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::East),
+        ));
+        (worker, step)
+    }
+
+    async fn undo(worker: &mut SimulationWorker) -> Result<()> {
+        let (reply, rx) = oneshot::channel();
+        worker
+            .handle_command(Command::UndoLastAction { reply })
+            .await;
+        rx.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn undo_reverts_moves_exactly() {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let (mut worker, step) = stepping_worker(event_bus);
+
+        let mut before_moves = Vec::new();
+        for _ in 0..2 {
+            worker.handle_turn_preparation().unwrap();
+            before_moves.push(worker.state.clone());
+            worker.handle_submitted_action(step.clone(), None).unwrap();
+        }
+        assert_eq!(
+            worker.state.entities.player().position,
+            Some(Position::new(2, 0))
+        );
+        while events.try_recv().is_ok() {}
+
+        // Undoing the second move restores the state and clock from before it
+        undo(&mut worker).await.unwrap();
+        assert_eq!(worker.state, before_moves[1]);
+        assert_eq!(worker.state.turn.clock, before_moves[1].turn.clock);
+        match events.try_recv() {
+            Ok(Event::GameState(GameStateEvent::RolledBack {
+                to_nonce, state, ..
+            })) => {
+                assert_eq!(to_nonce, before_moves[1].nonce());
+                assert_eq!(*state, before_moves[1]);
+            }
+            other => panic!("expected RolledBack event, got {:?}", other),
+        }
+
+        undo(&mut worker).await.unwrap();
+        assert_eq!(worker.state, before_moves[0]);
+        assert!(matches!(
+            undo(&mut worker).await,
+            Err(RuntimeError::NothingToUndo)
+        ));
+        assert_eq!(worker.state, before_moves[0]);
+    }
+
+    #[tokio::test]
+    async fn undo_history_is_bounded() {
+        let (worker, step) = stepping_worker(EventBus::new());
+        let mut worker = worker.with_undo_depth(1);
+
+        for _ in 0..2 {
+            worker.handle_turn_preparation().unwrap();
+            worker.handle_submitted_action(step.clone(), None).unwrap();
+        }
+
+        undo(&mut worker).await.unwrap();
+        assert!(matches!(
+            undo(&mut worker).await,
+            Err(RuntimeError::NothingToUndo)
+        ));
+        assert_eq!(
+            worker.state.entities.player().position,
+            Some(Position::new(1, 0))
+        );
+    }
+
+    #[tokio::test]
+    async fn persisted_actions_cannot_be_undone() {
+        let (worker, step) = stepping_worker(EventBus::new());
+        let mut worker = worker.with_persisted_actions(true);
+
+        worker.handle_turn_preparation().unwrap();
+        worker.handle_submitted_action(step, None).unwrap();
+        let executed = worker.state.clone();
+
+        assert!(matches!(
+            undo(&mut worker).await,
+            Err(RuntimeError::UndoPersisted)
+        ));
+        assert_eq!(worker.state, executed);
+        assert!(worker.undo_history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_actions_carry_stable_error_codes() {
+        // 8x8 floor with a wall east of the player at (1, 0)
+        let mut tiles = std::collections::HashMap::new();
+        for x in 0..8 {
+            for y in 0..8 {
+                let terrain = if (x, y) == (1, 0) {
+                    TerrainKind::Wall
+                } else {
+                    TerrainKind::Floor
+                };
+                tiles.insert(Position::new(x, y), StaticTile::new(terrain));
+            }
+        }
+        let map = MapOracleImpl::new(MapDimensions::new(8, 8), tiles);
+
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = worker_with_map(event_bus, map);
+
+        let npc_template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let npc = worker
+            .state
+            .add_npc(&npc_template, Position::new(5, 5))
+            .unwrap();
+        let player = worker.state.entities.actor_mut(EntityId::PLAYER).unwrap();
+        player.actions.push(ActionAbility::new(ActionKind::Move));
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+
+        let cases = [
+            (
+                ActionKind::Move,
+                ActionInput::Direction(CardinalDirection::East),
+                "ACTION_BLOCKED",
+            ),
+            (
+                ActionKind::MeleeAttack,
+                ActionInput::Target(npc),
+                "ACTION_OUT_OF_RANGE",
+            ),
+            (
+                ActionKind::MeleeAttack,
+                ActionInput::None,
+                "ACTION_INVALID_TARGET",
+            ),
+        ];
+
+        for (kind, input, expected) in cases {
+            let (reply, rx) = oneshot::channel();
+            let action = Action::character(CharacterAction::new(EntityId::PLAYER, kind, input));
+            worker
+                .handle_command(Command::ExecuteAction {
+                    action,
+                    action_nonce: None,
+                    reply,
+                })
+                .await;
+            rx.await.unwrap().unwrap();
+
+            let error_code = loop {
+                match events.try_recv() {
+                    Ok(Event::GameState(GameStateEvent::ActionFailed { error_code, .. })) => {
+                        break error_code;
+                    }
+                    Ok(_) => continue,
+                    Err(e) => panic!("expected ActionFailed for {:?}, got {:?}", kind, e),
+                }
+            };
+            assert_eq!(error_code, expected, "{:?}", kind);
+        }
+    }
+
+    /// Submits a MeleeAttack without a target, which fails pre-validation.
+    async fn submit_untargeted_attack(worker: &mut SimulationWorker) -> Result<()> {
+        worker
+            .state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+
+        let (reply, rx) = oneshot::channel();
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::None,
+        ));
+        worker
+            .handle_command(Command::ExecuteAction {
+                action,
+                action_nonce: None,
+                reply,
+            })
+            .await;
+        rx.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn strict_mode_rejects_invalid_actions_synchronously() {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = test_worker(event_bus).with_validation_mode(ValidationMode::Strict);
+        let nonce = worker.state.turn.nonce;
+
+        let result = submit_untargeted_attack(&mut worker).await;
+
+        assert!(matches!(
+            result,
+            Err(RuntimeError::ActionRejected {
+                error_code: "ACTION_INVALID_TARGET",
+                ..
+            })
+        ));
+        // Nothing was published and the turn was not spent
+        assert!(events.try_recv().is_err());
+        assert_eq!(worker.state.turn.nonce, nonce);
+    }
+
+    #[tokio::test]
+    async fn lenient_mode_reports_invalid_actions_as_events() {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = test_worker(event_bus);
+        // The Wait fallback needs the ability like any other action
+        worker
+            .state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+
+        submit_untargeted_attack(&mut worker).await.unwrap();
+
+        match events.try_recv() {
+            Ok(Event::GameState(GameStateEvent::ActionFailed { error_code, .. })) => {
+                assert_eq!(error_code, "ACTION_INVALID_TARGET");
+            }
+            other => panic!("expected ActionFailed event, got {:?}", other),
+        }
+        // The turn went to the Wait fallback
+        match events.try_recv() {
+            Ok(Event::GameState(GameStateEvent::ActionExecuted {
+                action: Action::Character(wait),
+                ..
+            })) => assert_eq!(wait.kind, ActionKind::Wait),
+            other => panic!("expected Wait fallback, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn npc_reaching_an_exit_leaves_play() {
+        // 8x8 floor with an exit at (4, 3)
+        let exit = Position::new(4, 3);
+        let mut tiles = std::collections::HashMap::new();
+        for x in 0..8 {
+            for y in 0..8 {
+                let pos = Position::new(x, y);
+                let terrain = if pos == exit {
+                    TerrainKind::Exit
+                } else {
+                    TerrainKind::Floor
+                };
+                tiles.insert(pos, StaticTile::new(terrain));
+            }
+        }
+        let map = MapOracleImpl::new(MapDimensions::new(8, 8), tiles);
+
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = worker_with_map(event_bus, map);
+
+        let npc_template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let npc = worker
+            .state
+            .add_npc(&npc_template, Position::new(3, 3))
+            .unwrap();
+        let actor = worker.state.entities.actor_mut(npc).unwrap();
+        actor.actions.push(ActionAbility::new(ActionKind::Move));
+        actor.ready_at = Some(0);
+        worker.state.turn.active_actors.insert(npc);
+        worker.state.turn.current_actor = npc;
+
+        let (reply, rx) = oneshot::channel();
+        let action = Action::character(CharacterAction::new(
+            npc,
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::East),
+        ));
+        worker
+            .handle_command(Command::ExecuteAction {
+                action,
+                action_nonce: None,
+                reply,
+            })
+            .await;
+        rx.await.unwrap().unwrap();
+
+        let actor = worker.state.entities.actor(npc).unwrap();
+        assert_eq!(actor.position, None);
+        assert_eq!(actor.ready_at, None);
+        assert!(!worker.state.turn.active_actors.contains(&npc));
+        assert!(worker.state.world.tile_map.occupants(&exit).is_none());
+
+        // Move, then the escape cleanup
+        let mut executed = Vec::new();
+        while let Ok(Event::GameState(GameStateEvent::ActionExecuted { action, .. })) =
+            events.try_recv()
+        {
+            executed.push(action.as_snake_case());
+        }
+        assert_eq!(executed, vec!["move", "deactivate", "remove_from_world"]);
+    }
+
+    /// Plays Wait turns on the default map until a minion with a 5-turn
+    /// lifespan at (2, 2) leaves play.
+    ///
+    /// Returns the worker, the minion, the turn number of each of its turns
+    /// and the turn number it was removed on.
+    async fn run_minion_lifespan() -> (SimulationWorker, EntityId, Vec<u64>, u64) {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = test_worker(event_bus);
+        worker
+            .state
+            .entities
+            .player_mut()
+            .actions
+            .push(ActionAbility::new(ActionKind::Wait));
+
+        let npc_template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let minion = worker
+            .state
+            .add_npc(&npc_template, Position::new(2, 2))
+            .unwrap();
+        let actor = worker.state.entities.actor_mut(minion).unwrap();
+        actor.actions.push(ActionAbility::new(ActionKind::Wait));
+        // Summoned at clock 0, so its first turn is one Wait later
+        let turn = calculate_action_cost(100, actor.snapshot().speed.physical);
+        actor.ready_at = Some(turn);
+        actor
+            .status_effects
+            .add(StatusEffectKind::Lifespan, 5 * turn);
+        worker.state.turn.active_actors.insert(minion);
+
+        // Two actors waiting in turn: the minion is due out well within 20 turns
+        let mut minion_turns = Vec::new();
+        for _ in 0..20 {
+            if worker.state.entities.position(minion).is_none() {
+                break;
+            }
+            let (reply, rx) = oneshot::channel();
+            worker
+                .handle_command(Command::PrepareNextTurn { reply })
+                .await;
+            let (entity, state) = rx.await.unwrap().unwrap();
+            if entity == minion {
+                minion_turns.push(state.turn.clock / turn);
+            }
+
+            let (reply, rx) = oneshot::channel();
+            let action = Action::character(CharacterAction::new(
+                entity,
+                ActionKind::Wait,
+                ActionInput::None,
+            ));
+            worker
+                .handle_command(Command::ExecuteAction {
+                    action,
+                    action_nonce: None,
+                    reply,
+                })
+                .await;
+            rx.await.unwrap().unwrap();
+        }
+        assert_eq!(
+            worker.state.entities.position(minion),
+            None,
+            "minion still in play at clock {} after its lifespan ran out at {}",
+            worker.state.turn.clock,
+            5 * turn
+        );
+
+        let removed_on = loop {
+            match events.try_recv() {
+                Ok(Event::GameState(GameStateEvent::ActionExecuted {
+                    action:
+                        Action::System {
+                            kind: SystemActionKind::RemoveFromWorld(remove),
+                        },
+                    clock,
+                    ..
+                })) if remove.entity == minion => break clock / turn,
+                Ok(_) => continue,
+                Err(e) => panic!("expected the minion to be removed, got {:?}", e),
+            }
+        };
+
+        (worker, minion, minion_turns, removed_on)
+    }
+
+    #[tokio::test]
+    async fn minion_is_removed_when_its_lifespan_runs_out() {
+        let (_, _, minion_turns, removed_on) = run_minion_lifespan().await;
+
+        assert_eq!(minion_turns, vec![1, 2, 3, 4]);
+        assert_eq!(removed_on, 5);
+    }
+
+    #[tokio::test]
+    async fn despawned_minion_leaves_no_occupancy_behind() {
+        let (worker, minion, _, _) = run_minion_lifespan().await;
+
+        let actor = worker.state.entities.actor(minion).unwrap();
+        assert_eq!(actor.position, None);
+        assert_eq!(actor.ready_at, None);
+        assert!(!worker.state.turn.active_actors.contains(&minion));
+        assert!(
+            worker
+                .state
+                .world
+                .tile_map
+                .occupants(&Position::new(2, 2))
+                .is_none()
+        );
+    }
+
+    fn event_name(event: &GameEvent) -> &'static str {
+        match event {
+            GameEvent::ActionCompleted { .. } => "action_completed",
+            GameEvent::DamageTaken { .. } => "damage_taken",
+            GameEvent::EntityDied { .. } => "entity_died",
+            GameEvent::EntitySpawned { .. } => "entity_spawned",
+            GameEvent::EntityMoved { .. } => "entity_moved",
+            GameEvent::ChannelInterrupted { .. } => "channel_interrupted",
+            GameEvent::EntityEscaped { .. } => "entity_escaped",
+            GameEvent::EntityDespawned { .. } => "entity_despawned",
+            GameEvent::TurnStarted { .. } => "turn_started",
+            GameEvent::EntityRemovedFromActive { .. } => "entity_removed_from_active",
+            GameEvent::EntityRemovedFromWorld { .. } => "entity_removed_from_world",
+            GameEvent::HealthThresholdCrossed { .. } => "health_threshold_crossed",
+            GameEvent::ReadyAtUpdated { .. } => "ready_at_updated",
+        }
+    }
+
+    /// Runs a killing blow and returns every published action with the
+    /// events extracted from its delta.
+    async fn killing_blow_events() -> Vec<(u64, &'static str, Vec<&'static str>)> {
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe(Topic::GameState);
+        let mut worker = test_worker(event_bus);
+        let map = MapOracleImpl::test_map(8, 8);
+
+        let npc_template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let npc = worker
+            .state
+            .add_npc(&npc_template, Position::new(1, 0))
+            .unwrap();
+        let actor = worker.state.entities.actor_mut(npc).unwrap();
+        actor.resources.hp = 1;
+        actor.ready_at = Some(0);
+        worker.state.turn.active_actors.insert(npc);
+        let player = worker.state.entities.actor_mut(EntityId::PLAYER).unwrap();
+        player
+            .actions
+            .push(ActionAbility::new(ActionKind::MeleeAttack));
+
+        let (reply, rx) = oneshot::channel();
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(npc),
+        ));
+        worker
+            .handle_command(Command::ExecuteAction {
+                action,
+                action_nonce: None,
+                reply,
+            })
+            .await;
+        rx.await.unwrap().unwrap();
+
+        let mut published = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            let Event::GameState(GameStateEvent::ActionExecuted {
+                nonce,
+                action,
+                delta,
+                before_state,
+                after_state,
+                ..
+            }) = event
+            else {
+                continue;
+            };
+            let extracted = extract_events(&delta, &before_state, &after_state, &map)
+                .iter()
+                .map(event_name)
+                .collect();
+            published.push((nonce, action.as_snake_case(), extracted));
+        }
+        published
+    }
+
+    #[tokio::test]
+    async fn turn_events_follow_the_canonical_order() {
+        let first = killing_blow_events().await;
+
+        let actions: Vec<_> = first.iter().map(|(_, action, _)| *action).collect();
+        assert_eq!(
+            actions,
+            vec!["melee_attack", "deactivate", "remove_from_world"]
+        );
+        assert!(first.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(
+            first[0].2,
+            vec![
+                "action_completed",
+                "damage_taken",
+                "ready_at_updated",
+                "health_threshold_crossed",
+                "entity_died",
+            ]
+        );
+
+        for _ in 0..3 {
+            assert_eq!(killing_blow_events().await, first);
+        }
+    }
+}
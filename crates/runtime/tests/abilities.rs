@@ -0,0 +1,255 @@
+//! Engine tests for support and spell abilities.
+
+use std::sync::Arc;
+
+use game_core::action::AppliedValue;
+use game_core::{
+    Action, ActionAbility, ActionInput, ActionKind, ActorTemplate, Channel, CharacterAction,
+    EntityId, ExecuteError, ExecutionOutcome, Faction, GameConfig, GameEngine, GameState, Position,
+    StatusEffectKind, TraitProfile,
+};
+use runtime::{
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, GameEvent, ItemOracleImpl, MapOracleImpl,
+    OracleBundle, extract_events,
+};
+
+fn template(faction: Faction) -> ActorTemplate {
+    ActorTemplate::builder()
+        .faction(faction)
+        .trait_profile(TraitProfile::default())
+        .build()
+}
+
+/// Oracles over an 8x8 floor with the default action profiles.
+fn oracles() -> OracleBundle {
+    OracleBundle::new(
+        Arc::new(MapOracleImpl::test_map(8, 8)),
+        Arc::new(ItemOracleImpl::new()),
+        Arc::new(ActionOracleImpl::new()),
+        Arc::new(ActorOracleImpl::new()),
+        Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+    )
+}
+
+/// Player at (2, 2) who knows Revive, next to a downed NPC of `faction`.
+fn revive_setup(faction: Faction) -> (GameState, EntityId) {
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    let ally = state
+        .add_npc(&template(faction), Position::new(3, 2))
+        .unwrap();
+    state.entities.actor_mut(ally).unwrap().resources.hp = 0;
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::Revive));
+    (state, ally)
+}
+
+fn revive(target: EntityId) -> Action {
+    Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Revive,
+        ActionInput::Target(target),
+    ))
+}
+
+#[test]
+fn revive_restores_part_of_a_downed_allys_hp() {
+    let oracles = oracles();
+    let (mut state, ally) = revive_setup(Faction::Friendly);
+    let max_hp = state
+        .entities
+        .actor(ally)
+        .unwrap()
+        .snapshot()
+        .resource_max
+        .hp_max;
+    assert!(state.entities.actor(ally).unwrap().is_downed());
+
+    let outcome = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &revive(ally))
+        .unwrap();
+
+    let restored = max_hp * 30 / 100;
+    let result = outcome.action_result.unwrap();
+    assert_eq!(result.summary.total_healing, restored);
+    let ally = state.entities.actor(ally).unwrap();
+    assert_eq!(ally.resources.hp, restored);
+    assert!(ally.is_alive());
+    assert!(!ally.is_downed());
+
+    // The revive is on cooldown now
+    let player = state.entities.actor(EntityId::PLAYER).unwrap();
+    assert!(!player.can_use_action(ActionKind::Revive, state.turn.clock));
+}
+
+#[test]
+fn revive_rejects_living_and_hostile_targets() {
+    let oracles = oracles();
+
+    let (mut state, enemy) = revive_setup(Faction::GoblinClan);
+    let result = GameEngine::new(&mut state).execute(oracles.as_game_env(), &revive(enemy));
+    assert!(result.is_err());
+    assert_eq!(state.entities.actor(enemy).unwrap().resources.hp, 0);
+
+    let (mut state, ally) = revive_setup(Faction::Friendly);
+    state.entities.actor_mut(ally).unwrap().resources.hp = 5;
+    let result = GameEngine::new(&mut state).execute(oracles.as_game_env(), &revive(ally));
+    assert!(result.is_err());
+    assert_eq!(state.entities.actor(ally).unwrap().resources.hp, 5);
+}
+
+/// Casts the default Summon profile (a goblin scout) from the player at
+/// `caster` on an 8x8 floor with goblins on `blockers`.
+fn summon_at(
+    caster: Position,
+    blockers: &[Position],
+) -> (GameState, Result<ExecutionOutcome, ExecuteError>) {
+    let mut actors = ActorOracleImpl::new();
+    actors.add("goblin_scout", template(Faction::GoblinClan));
+    let oracles = OracleBundle::new(
+        Arc::new(MapOracleImpl::test_map(8, 8)),
+        Arc::new(ItemOracleImpl::new()),
+        Arc::new(ActionOracleImpl::new()),
+        Arc::new(actors),
+        Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+    );
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), caster)
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::Summon));
+    for &pos in blockers {
+        state.add_npc(&template(Faction::GoblinClan), pos).unwrap();
+    }
+
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Summon,
+        ActionInput::None,
+    ));
+    let result = GameEngine::new(&mut state).execute(oracles.as_game_env(), &action);
+    (state, result)
+}
+
+#[test]
+fn summon_spawns_an_active_minion_next_to_the_caster() {
+    // North of (2, 2) is taken, so the minion lands on the next free
+    // neighbour in direction order (south)
+    let (state, result) = summon_at(Position::new(2, 2), &[Position::new(2, 3)]);
+
+    let result = result.unwrap().action_result.unwrap();
+    let AppliedValue::Summon { entity_id } = result.effects[0].applied_value else {
+        panic!(
+            "expected a summon, got {:?}",
+            result.effects[0].applied_value
+        );
+    };
+
+    let minion = state.entities.actor(entity_id).unwrap();
+    let spawn = Position::new(2, 1);
+    assert_eq!(minion.position, Some(spawn));
+    assert_eq!(minion.faction, Faction::Player);
+    assert_eq!(minion.ready_at, Some(state.turn.clock));
+    assert!(state.turn.active_actors.contains(&entity_id));
+    assert!(
+        state
+            .world
+            .tile_map
+            .occupants(&spawn)
+            .is_some_and(|occupants| occupants.contains(&entity_id))
+    );
+    assert!(
+        minion
+            .status_effects
+            .has(StatusEffectKind::Lifespan, state.turn.clock)
+    );
+}
+
+#[test]
+fn summon_fails_without_a_free_adjacent_tile() {
+    // The corner leaves only three neighbours, all taken
+    let blockers = [
+        Position::new(1, 0),
+        Position::new(0, 1),
+        Position::new(1, 1),
+    ];
+    let (state, result) = summon_at(Position::new(0, 0), &blockers);
+
+    assert!(result.is_err());
+    assert_eq!(state.entities.all_actors().count(), 1 + blockers.len());
+    assert_eq!(state.turn.active_actors.len(), 1);
+}
+
+/// Power attacks a goblin channeling Lightning with the given
+/// interruptibility, returning the goblin and the extracted events.
+fn hit_channeling_goblin(interruptible: bool) -> (GameState, EntityId, Vec<GameEvent>) {
+    let oracles = oracles();
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::PowerAttack));
+    let goblin = state
+        .add_npc(&template(Faction::GoblinClan), Position::new(3, 2))
+        .unwrap();
+    let channel = Channel::new(ActionKind::Lightning, 500, interruptible);
+    state.entities.actor_mut(goblin).unwrap().channel = Some(channel);
+
+    let before = state.clone();
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::PowerAttack,
+        ActionInput::Target(goblin),
+    ));
+    let outcome = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap();
+    let events = extract_events(&outcome.delta, &before, &state, oracles.map.as_ref());
+    (state, goblin, events)
+}
+
+#[test]
+fn damage_interrupts_a_channeling_actor() {
+    let (state, goblin, events) = hit_channeling_goblin(true);
+
+    assert_eq!(state.entities.actor(goblin).unwrap().channel, None);
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GameEvent::ChannelInterrupted {
+            entity,
+            action: ActionKind::Lightning,
+        } if *entity == goblin
+    )));
+}
+
+#[test]
+fn non_interruptible_channel_proceeds_despite_damage() {
+    let (state, goblin, events) = hit_channeling_goblin(false);
+
+    let goblin = state.entities.actor(goblin).unwrap();
+    assert!(goblin.resources.hp < goblin.snapshot().resource_max.hp_max);
+    assert!(goblin.channel.is_some());
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, GameEvent::ChannelInterrupted { .. }))
+    );
+}
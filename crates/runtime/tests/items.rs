@@ -0,0 +1,364 @@
+//! Engine tests for equipping, using and picking up items.
+
+use std::sync::Arc;
+
+use game_content::ActionProfileRegistry;
+use game_core::action::AppliedValue;
+use game_core::stats::{BonusStack, calculate_action_cost, compute_actor_bonuses};
+use game_core::{
+    Action, ActionAbility, ActionError, ActionInput, ActionKind, ActorTemplate, ArmorData,
+    ArmorKind, CharacterAction, ConsumableData, EntityId, ExecuteError, ExecutionOutcome, Faction,
+    GameConfig, GameEngine, GameState, InventorySlot, ItemDefinition, ItemHandle, ItemKind,
+    ItemState, Position, TraitProfile, WeaponData, WeaponKind,
+};
+use runtime::{
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+    OracleBundle,
+};
+
+const SWORD: ItemHandle = ItemHandle(10);
+const AXE: ItemHandle = ItemHandle(11);
+const LEATHER: ItemHandle = ItemHandle(20);
+const POTION: ItemHandle = ItemHandle(30);
+const ARROWS: ItemHandle = ItemHandle(31);
+
+const CHEAP_EQUIP_ARMOR_RON: &str = r#"[
+    ActionProfile(
+        kind: EquipArmor,
+        tags: [Utility],
+        targeting: SelfOnly,
+        base_cost: 40,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: EquipArmor(EquipArmorEffect),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: None,
+    ),
+]"#;
+
+fn template(faction: Faction) -> ActorTemplate {
+    ActorTemplate::builder()
+        .faction(faction)
+        .trait_profile(TraitProfile::default())
+        .build()
+}
+
+fn oracles(registry: ActionProfileRegistry, items: ItemOracleImpl) -> OracleBundle {
+    OracleBundle::new(
+        Arc::new(MapOracleImpl::test_map(8, 8)),
+        Arc::new(items),
+        Arc::new(ActionOracleImpl::from_registry(registry)),
+        Arc::new(ActorOracleImpl::new()),
+        Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+    )
+}
+
+fn equipment_items() -> ItemOracleImpl {
+    let mut items = ItemOracleImpl::new();
+    for (handle, kind, damage) in [(SWORD, WeaponKind::Sword, 10), (AXE, WeaponKind::Axe, 15)] {
+        let weapon = ItemKind::Weapon(WeaponData {
+            kind,
+            damage,
+            reach: WeaponData::DEFAULT_REACH,
+            durability: None,
+        });
+        items.add_definition(ItemDefinition::new(handle, weapon, 1));
+    }
+    let armor = ItemKind::Armor(ArmorData {
+        kind: ArmorKind::Light,
+        defense: 5,
+        durability: None,
+    });
+    items.add_definition(ItemDefinition::new(LEATHER, armor, 1));
+    items
+}
+
+/// Player at (2, 2) with the given item action and a single item in slot 0.
+fn item_setup(kind: ActionKind, item: ItemHandle) -> GameState {
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+    player.actions.push(ActionAbility::new(kind));
+    player.inventory.add_item(item, 1, 1);
+    state
+}
+
+/// The player's `kind` action on inventory slot 0.
+fn use_slot(kind: ActionKind) -> Action {
+    Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        kind,
+        ActionInput::InventorySlot(0),
+    ))
+}
+
+#[test]
+fn equip_weapon_swaps_into_inventory_and_recomputes_bonuses() {
+    let oracles = oracles(ActionProfileRegistry::load().unwrap(), equipment_items());
+    let mut state = item_setup(ActionKind::EquipWeapon, AXE);
+
+    let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+    player.equipment.equip_weapon(SWORD, None);
+    // Stale bonuses from the sword must not survive the swap
+    player.bonuses.core.str_bonuses = BonusStack::new().flat(5);
+    let speed = player.snapshot().speed.physical;
+    let ready_at = player.ready_at.unwrap();
+
+    let outcome = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &use_slot(ActionKind::EquipWeapon))
+        .unwrap();
+
+    let result = outcome.action_result.unwrap();
+    assert_eq!(
+        result.effects[0].applied_value,
+        AppliedValue::ItemEquipped {
+            handle: AXE,
+            previous: Some(SWORD),
+        }
+    );
+
+    let player = state.entities.actor(EntityId::PLAYER).unwrap();
+    assert_eq!(player.equipment.weapon, Some(AXE));
+    assert_eq!(player.inventory.items.len(), 1);
+    assert_eq!(player.inventory.items[0].handle, SWORD);
+    assert_eq!(player.bonuses, compute_actor_bonuses());
+    assert_eq!(
+        player.ready_at,
+        Some(ready_at + calculate_action_cost(100, speed))
+    );
+}
+
+#[test]
+fn equip_armor_uses_the_configured_cost() {
+    let mut registry = ActionProfileRegistry::load().unwrap();
+    registry.extend_from_ron(CHEAP_EQUIP_ARMOR_RON).unwrap();
+    let oracles = oracles(registry, equipment_items());
+    let mut state = item_setup(ActionKind::EquipArmor, LEATHER);
+
+    let player = state.entities.actor(EntityId::PLAYER).unwrap();
+    let speed = player.snapshot().speed.physical;
+    let ready_at = player.ready_at.unwrap();
+
+    GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &use_slot(ActionKind::EquipArmor))
+        .unwrap();
+
+    let player = state.entities.actor(EntityId::PLAYER).unwrap();
+    assert_eq!(player.equipment.armor, Some(LEATHER));
+    assert!(player.inventory.items.is_empty());
+    assert_eq!(
+        player.ready_at,
+        Some(ready_at + calculate_action_cost(40, speed))
+    );
+}
+
+#[test]
+fn equip_rejects_items_for_the_other_slot() {
+    let oracles = oracles(ActionProfileRegistry::load().unwrap(), equipment_items());
+    let mut state = item_setup(ActionKind::EquipWeapon, LEATHER);
+
+    let result = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &use_slot(ActionKind::EquipWeapon));
+
+    assert!(result.is_err());
+    let player = state.entities.actor(EntityId::PLAYER).unwrap();
+    assert_eq!(player.equipment.weapon, None);
+    assert_eq!(player.inventory.items[0].handle, LEATHER);
+}
+
+/// Player at (2, 2) who knows UseItem, carrying three potions of the given
+/// cooldown in slot 0.
+fn potion_setup(cooldown: u64) -> (GameState, OracleBundle) {
+    let mut items = ItemOracleImpl::new();
+    let potion = ItemKind::Consumable(ConsumableData {
+        effects: vec![],
+        use_cost: 100,
+        cooldown,
+    });
+    items.add_definition(ItemDefinition::new(POTION, potion, 99));
+    let oracles = oracles(ActionProfileRegistry::load().unwrap(), items);
+
+    let mut state = item_setup(ActionKind::UseItem, POTION);
+    let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+    player.inventory.add_item(POTION, 2, 99);
+    (state, oracles)
+}
+
+/// Uses the potion in slot 0 as soon as the player is ready at the current
+/// clock.
+fn drink(state: &mut GameState, oracles: &OracleBundle) -> Result<(), ExecuteError> {
+    let clock = state.turn.clock;
+    state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(clock);
+    GameEngine::new(state)
+        .execute(oracles.as_game_env(), &use_slot(ActionKind::UseItem))
+        .map(|_| ())
+}
+
+#[test]
+fn consumable_cooldown_blocks_reuse_until_it_expires() {
+    let (mut state, oracles) = potion_setup(10);
+
+    drink(&mut state, &oracles).unwrap();
+    let slot = state.entities.player().inventory.items[0];
+    assert_eq!(slot.quantity, 2);
+    assert_eq!(slot.cooldown_until, state.turn.clock + 10);
+
+    let error = drink(&mut state, &oracles).unwrap_err();
+    assert!(error.to_string().contains("cooling down"), "{error}");
+    assert_eq!(state.entities.player().inventory.items[0].quantity, 2);
+
+    state.turn.clock += 10;
+    drink(&mut state, &oracles).unwrap();
+    assert_eq!(state.entities.player().inventory.items[0].quantity, 1);
+}
+
+#[test]
+fn consumable_without_cooldown_can_be_reused_immediately() {
+    let (mut state, oracles) = potion_setup(0);
+
+    drink(&mut state, &oracles).unwrap();
+    drink(&mut state, &oracles).unwrap();
+    assert_eq!(state.entities.player().inventory.items[0].quantity, 1);
+}
+
+/// Player at (2, 2) who knows PickupItem, with arrows (stacking to 20) in
+/// the item catalog.
+fn pickup_setup() -> (GameState, OracleBundle) {
+    let mut items = ItemOracleImpl::new();
+    let arrows = ItemKind::Consumable(ConsumableData {
+        effects: vec![],
+        use_cost: 0,
+        cooldown: 0,
+    });
+    items.add_definition(ItemDefinition::new(ARROWS, arrows, 20));
+    let oracles = oracles(ActionProfileRegistry::load().unwrap(), items);
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::PickupItem));
+    (state, oracles)
+}
+
+/// Places `quantity` arrows on the ground at `position`.
+fn drop_arrows(state: &mut GameState, position: Position, quantity: u16) -> EntityId {
+    let id = state.allocate_entity_id().unwrap();
+    state
+        .entities
+        .items
+        .push(ItemState::new(id, position, ARROWS, quantity))
+        .unwrap();
+    state.world.tile_map.add_occupant(position, id);
+    id
+}
+
+/// Picks up `item` as soon as the player is ready at the current clock.
+fn pick_up(
+    state: &mut GameState,
+    oracles: &OracleBundle,
+    item: EntityId,
+) -> Result<ExecutionOutcome, ExecuteError> {
+    let clock = state.turn.clock;
+    state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(clock);
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::PickupItem,
+        ActionInput::Target(item),
+    ));
+    GameEngine::new(state).execute(oracles.as_game_env(), &action)
+}
+
+fn on_tile(state: &GameState, position: Position, id: EntityId) -> bool {
+    state
+        .world
+        .tile_map
+        .occupants(&position)
+        .is_some_and(|occupants| occupants.contains(&id))
+}
+
+#[test]
+fn pickup_moves_a_ground_item_into_the_inventory() {
+    let (mut state, oracles) = pickup_setup();
+    let here = Position::new(2, 2);
+    let arrows = drop_arrows(&mut state, here, 5);
+
+    let outcome = pick_up(&mut state, &oracles, arrows).unwrap();
+
+    assert_eq!(
+        outcome.action_result.unwrap().effects[0].applied_value,
+        AppliedValue::ItemAcquired {
+            item_id: arrows,
+            handle: ARROWS,
+            quantity: 5,
+        }
+    );
+    let slot = state.entities.player().inventory.items[0];
+    assert_eq!((slot.handle, slot.quantity), (ARROWS, 5));
+    assert!(state.entities.item(arrows).is_none());
+    assert!(!on_tile(&state, here, arrows));
+}
+
+#[test]
+fn pickup_with_a_full_inventory_leaves_the_item_on_the_ground() {
+    let (mut state, oracles) = pickup_setup();
+    let here = Position::new(2, 2);
+    let arrows = drop_arrows(&mut state, here, 5);
+    let inventory = &mut state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .inventory;
+    for handle in 0..GameConfig::MAX_INVENTORY_SLOTS as u32 {
+        inventory.add_item(ItemHandle(100 + handle), 1, 1);
+    }
+
+    assert!(pick_up(&mut state, &oracles, arrows).is_err());
+    assert_eq!(state.entities.item(arrows).unwrap().quantity, 5);
+    assert!(on_tile(&state, here, arrows));
+
+    // With a partial stack in a full inventory, only what fits is taken
+    let inventory = &mut state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .inventory;
+    inventory.items[0] = InventorySlot::new(ARROWS, 18);
+    pick_up(&mut state, &oracles, arrows).unwrap();
+
+    assert_eq!(state.entities.player().inventory.items[0].quantity, 20);
+    assert_eq!(state.entities.item(arrows).unwrap().quantity, 3);
+    assert!(on_tile(&state, here, arrows));
+}
+
+#[test]
+fn pickup_without_an_item_on_the_tile_fails() {
+    let (mut state, oracles) = pickup_setup();
+    let next_door = drop_arrows(&mut state, Position::new(3, 2), 5);
+
+    let error = pick_up(&mut state, &oracles, next_door).unwrap_err();
+    assert!(matches!(
+        error,
+        ExecuteError::Character(ref e) if e.error == ActionError::OutOfRange
+    ));
+
+    let error = pick_up(&mut state, &oracles, EntityId(99)).unwrap_err();
+    assert!(matches!(
+        error,
+        ExecuteError::Character(ref e) if e.error == ActionError::TargetNotFound
+    ));
+    assert!(state.entities.player().inventory.items.is_empty());
+    assert!(state.entities.item(next_door).is_some());
+}
@@ -0,0 +1,400 @@
+//! Engine tests for how actions pick and strike their targets.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use game_content::ActionProfileRegistry;
+use game_core::action::AppliedValue;
+use game_core::{
+    Action, ActionAbility, ActionInput, ActionKind, ActorTemplate, CardinalDirection,
+    CharacterAction, EntityId, Faction, GameConfig, GameEngine, GameState, MapDimensions, Position,
+    StaticTile, TerrainKind, TraitProfile,
+};
+use runtime::{
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
+    OracleBundle,
+};
+
+const CHAIN_LIGHTNING_RON: &str = r#"[
+    ActionProfile(
+        kind: Lightning,
+        tags: [Attack, Spell, Lightning],
+        targeting: Chain(range: 5, requires_los: false, hop_range: 2, max_hops: 3),
+        base_cost: 100,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: Damage(DamageEffect(
+                    formula: ChainFalloff(formula: Constant(12), percent: 50),
+                    damage_type: Lightning,
+                    can_crit: false,
+                )),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: None,
+    ),
+]"#;
+
+const FIXED_SHOT_RON: &str = r#"[
+    ActionProfile(
+        kind: RangedAttack,
+        tags: [Attack, Ranged, Physical],
+        targeting: SingleTarget(range: 5, requires_los: true),
+        base_cost: 100,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: Damage(DamageEffect(
+                    formula: Constant(20),
+                    damage_type: Physical,
+                    can_crit: false,
+                )),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: None,
+    ),
+]"#;
+
+const PIERCING_SHOT_RON: &str = r#"[
+    ActionProfile(
+        kind: PiercingShot,
+        tags: [Attack, Ranged, Physical, Aoe],
+        targeting: Line(length: 10),
+        base_cost: 100,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: Damage(DamageEffect(
+                    formula: Constant(10),
+                    damage_type: Physical,
+                    can_crit: false,
+                )),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: None,
+    ),
+]"#;
+
+const WIDE_CLEAVE_RON: &str = r#"[
+    ActionProfile(
+        kind: Cleave,
+        tags: [Attack, Melee, Physical, Aoe],
+        targeting: Cone(length: 3, width: 2),
+        base_cost: 100,
+        resource_costs: [],
+        effects: [
+            ActionEffect(
+                kind: Damage(DamageEffect(
+                    formula: Constant(10),
+                    damage_type: Physical,
+                    can_crit: false,
+                )),
+                phase: Primary,
+                priority: 0,
+            ),
+        ],
+        requirements: [],
+        cooldown: None,
+    ),
+]"#;
+
+fn template(faction: Faction) -> ActorTemplate {
+    ActorTemplate::builder()
+        .faction(faction)
+        .trait_profile(TraitProfile::default())
+        .build()
+}
+
+/// Oracles over an 8x8 floor with walls at `walls`, with the default action
+/// profiles overridden by `ron`.
+fn oracles(ron: &str, walls: &[Position]) -> OracleBundle {
+    let mut tiles = HashMap::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            let pos = Position::new(x, y);
+            let terrain = if walls.contains(&pos) {
+                TerrainKind::Wall
+            } else {
+                TerrainKind::Floor
+            };
+            tiles.insert(pos, StaticTile::new(terrain));
+        }
+    }
+    let mut registry = ActionProfileRegistry::load().unwrap();
+    registry.extend_from_ron(ron).unwrap();
+    OracleBundle::new(
+        Arc::new(MapOracleImpl::new(MapDimensions::new(8, 8), tiles)),
+        Arc::new(ItemOracleImpl::new()),
+        Arc::new(ActionOracleImpl::from_registry(registry)),
+        Arc::new(ActorOracleImpl::new()),
+        Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+    )
+}
+
+/// Player at `player` who knows `kind`, with goblins at `enemies`.
+fn setup(kind: ActionKind, player: Position, enemies: &[Position]) -> (GameState, Vec<EntityId>) {
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), player)
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(kind));
+    let ids = enemies
+        .iter()
+        .map(|&pos| state.add_npc(&template(Faction::GoblinClan), pos).unwrap())
+        .collect();
+    (state, ids)
+}
+
+/// Executes the player's `kind` action with `input`, returning each struck
+/// entity with the damage it took, in strike order.
+fn strike(
+    state: &mut GameState,
+    oracles: &OracleBundle,
+    kind: ActionKind,
+    input: ActionInput,
+) -> Vec<(EntityId, u32)> {
+    let action = Action::character(CharacterAction::new(EntityId::PLAYER, kind, input));
+    let outcome = GameEngine::new(state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap();
+
+    outcome
+        .action_result
+        .unwrap()
+        .effects
+        .iter()
+        .map(|effect| match effect.applied_value {
+            AppliedValue::Damage { actual } => (effect.target, actual),
+            ref other => panic!("expected damage, got {:?}", other),
+        })
+        .collect()
+}
+
+/// Casts Lightning from (1, 1) at `target`.
+fn cast_chain(state: &mut GameState, target: EntityId) -> Vec<(EntityId, u32)> {
+    let oracles = oracles(CHAIN_LIGHTNING_RON, &[]);
+    strike(
+        state,
+        &oracles,
+        ActionKind::Lightning,
+        ActionInput::Target(target),
+    )
+}
+
+#[test]
+fn chain_hits_the_nearest_unaffected_enemies_with_falloff() {
+    // first -> near (1 tile) -> tied (1 tile, level with `farther` and
+    // `skipped` but lowest EntityId)
+    let (mut state, ids) = setup(
+        ActionKind::Lightning,
+        Position::new(1, 1),
+        &[
+            Position::new(3, 1),
+            Position::new(4, 2),
+            Position::new(5, 1),
+            Position::new(5, 3),
+            Position::new(3, 3),
+        ],
+    );
+    let [first, near, tied, farther, skipped] = ids[..] else {
+        unreachable!()
+    };
+    // An ally right next to the first target is never struck
+    let ally = state
+        .add_npc(&template(Faction::Friendly), Position::new(4, 1))
+        .unwrap();
+
+    let hits = cast_chain(&mut state, first);
+
+    assert_eq!(hits, vec![(first, 12), (near, 6), (tied, 3)]);
+    for id in [farther, skipped, ally] {
+        let actor = state.entities.actor(id).unwrap();
+        assert_eq!(actor.resources.hp, actor.snapshot().resource_max.hp_max);
+    }
+}
+
+#[test]
+fn chain_stops_when_no_enemy_is_in_hop_range() {
+    // The second goblin is 3 tiles from the first, beyond the hop range of 2
+    let (mut state, ids) = setup(
+        ActionKind::Lightning,
+        Position::new(1, 1),
+        &[Position::new(3, 1), Position::new(6, 1)],
+    );
+
+    let hits = cast_chain(&mut state, ids[0]);
+
+    assert_eq!(hits, vec![(ids[0], 12)]);
+}
+
+/// Damage dealt by a 20-damage shot from (1, 1) at a goblin on `target`,
+/// on an 8x8 floor with walls at `walls`.
+fn shot_damage(target: Position, walls: &[Position]) -> u32 {
+    let oracles = oracles(FIXED_SHOT_RON, walls);
+    let (mut state, ids) = setup(ActionKind::RangedAttack, Position::new(1, 1), &[target]);
+
+    strike(
+        &mut state,
+        &oracles,
+        ActionKind::RangedAttack,
+        ActionInput::Target(ids[0]),
+    )
+    .iter()
+    .map(|&(_, damage)| damage)
+    .sum()
+}
+
+#[test]
+fn ranged_hit_on_a_target_in_partial_cover_is_reduced() {
+    let reduction = GameConfig::default().cover_damage_reduction;
+    let covered = 20 - 20 * reduction / 100;
+    assert!(covered < 20);
+
+    // Wall next to the target on the attacker's side
+    assert_eq!(
+        shot_damage(Position::new(5, 1), &[Position::new(4, 1)]),
+        covered
+    );
+    assert_eq!(
+        shot_damage(Position::new(5, 4), &[Position::new(4, 3)]),
+        covered
+    );
+}
+
+#[test]
+fn ranged_hit_on_an_exposed_target_deals_full_damage() {
+    assert_eq!(shot_damage(Position::new(5, 1), &[]), 20);
+    // Walls behind the target or beside the attacker give no cover
+    let walls = [Position::new(6, 1), Position::new(2, 2)];
+    assert_eq!(shot_damage(Position::new(5, 1), &walls), 20);
+}
+
+/// Looses a 10-tile, 10-damage piercing shot from the player at (1, 1)
+/// along `direction`, on an 8x8 floor with walls at `walls` and goblins at
+/// `enemies`. Returns the goblin ids and each struck entity with its damage.
+fn piercing_shot(
+    direction: CardinalDirection,
+    enemies: &[Position],
+    walls: &[Position],
+) -> (Vec<EntityId>, Vec<(EntityId, u32)>) {
+    let oracles = oracles(PIERCING_SHOT_RON, walls);
+    let (mut state, ids) = setup(ActionKind::PiercingShot, Position::new(1, 1), enemies);
+
+    let hits = strike(
+        &mut state,
+        &oracles,
+        ActionKind::PiercingShot,
+        ActionInput::Direction(direction),
+    );
+    (ids, hits)
+}
+
+#[test]
+fn line_strikes_every_actor_along_it_nearest_first() {
+    let (ids, hits) = piercing_shot(
+        CardinalDirection::East,
+        &[
+            Position::new(5, 1),
+            Position::new(3, 1),
+            Position::new(3, 2),
+        ],
+        &[],
+    );
+
+    // Full damage to both goblins in the line, nothing to the one beside it
+    assert_eq!(hits, vec![(ids[1], 10), (ids[0], 10)]);
+}
+
+#[test]
+fn line_stops_at_the_first_wall() {
+    let (ids, hits) = piercing_shot(
+        CardinalDirection::East,
+        &[Position::new(2, 1), Position::new(5, 1)],
+        &[Position::new(4, 1)],
+    );
+
+    assert_eq!(hits, vec![(ids[0], 10)]);
+}
+
+#[test]
+fn line_is_clamped_at_the_map_edge() {
+    let enemies = [Position::new(7, 1), Position::new(0, 1)];
+
+    // Six tiles to the east edge, one to the west edge; both shorter than the shot
+    let (ids, hits) = piercing_shot(CardinalDirection::East, &enemies, &[]);
+    assert_eq!(hits, vec![(ids[0], 10)]);
+
+    let (ids, hits) = piercing_shot(CardinalDirection::West, &enemies, &[]);
+    assert_eq!(hits, vec![(ids[1], 10)]);
+
+    let (_, hits) = piercing_shot(CardinalDirection::SouthWest, &enemies, &[]);
+    assert!(hits.is_empty());
+}
+
+/// Cleaves a 3-long, 2-wide cone east from the player at (1, 3) on an 8x8
+/// floor with walls at `walls` and a goblin on every other tile. Returns the
+/// positions struck, in strike order.
+fn cone_hits(walls: &[Position]) -> Vec<Position> {
+    let oracles = oracles(WIDE_CLEAVE_RON, walls);
+    let player = Position::new(1, 3);
+    // Row-major spawn order, so EntityId order is row-major too
+    let goblins: Vec<Position> = (0..8)
+        .flat_map(|y| (0..8).map(move |x| Position::new(x, y)))
+        .filter(|pos| *pos != player && !walls.contains(pos))
+        .collect();
+    let (mut state, _) = setup(ActionKind::Cleave, player, &goblins);
+
+    let hits = strike(
+        &mut state,
+        &oracles,
+        ActionKind::Cleave,
+        ActionInput::Direction(CardinalDirection::East),
+    );
+    assert!(hits.iter().all(|&(_, damage)| damage == 10));
+    hits.iter()
+        .map(|&(target, _)| state.actor_position(target).unwrap())
+        .collect()
+}
+
+#[test]
+fn cone_strikes_the_wedge_in_entity_id_order() {
+    // One tile wide at 1, three at 2, five at 3
+    let expected = [
+        (4, 1),
+        (3, 2),
+        (4, 2),
+        (2, 3),
+        (3, 3),
+        (4, 3),
+        (3, 4),
+        (4, 4),
+        (4, 5),
+    ]
+    .map(|(x, y)| Position::new(x, y));
+
+    assert_eq!(cone_hits(&[]), expected);
+}
+
+#[test]
+fn cone_does_not_reach_behind_a_wall() {
+    // The wall itself and the tile straight behind it drop out; the
+    // flanks stay in sight
+    let expected =
+        [(4, 1), (3, 2), (4, 2), (2, 3), (3, 4), (4, 4), (4, 5)].map(|(x, y)| Position::new(x, y));
+
+    assert_eq!(cone_hits(&[Position::new(3, 3)]), expected);
+}
@@ -0,0 +1,345 @@
+//! Engine tests for what the tiles an actor walks over do to it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use game_core::action::AppliedValue;
+use game_core::{
+    Action, ActionAbility, ActionError, ActionInput, ActionKind, ActorTemplate, CardinalDirection,
+    CharacterAction, DeactivateAction, EntityId, ExecuteError, Faction, GameConfig, GameEngine,
+    GameState, MapDimensions, PassiveAbility, PassiveKind, Position, PrepareTurnAction, PropKind,
+    PropState, RemoveFromWorldAction, StateDelta, StaticTile, SystemActionKind, TerrainKind,
+    TraitProfile, Visibility,
+};
+use runtime::{
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DeathHandler, ItemOracleImpl,
+    MapOracleImpl, OracleBundle, SystemActionProvider,
+};
+
+fn template(faction: Faction) -> ActorTemplate {
+    ActorTemplate::builder()
+        .faction(faction)
+        .trait_profile(TraitProfile::default())
+        .build()
+}
+
+/// Oracles over `map` with the default action profiles.
+fn oracles(map: MapOracleImpl) -> OracleBundle {
+    OracleBundle::new(
+        Arc::new(map),
+        Arc::new(ItemOracleImpl::new()),
+        Arc::new(ActionOracleImpl::new()),
+        Arc::new(ActorOracleImpl::new()),
+        Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+    )
+}
+
+/// An 8x8 floor with `terrain` on the given tiles.
+fn floor_map(terrain: TerrainKind, tiles: &[Position]) -> MapOracleImpl {
+    let mut map = HashMap::new();
+    for x in 0..8 {
+        for y in 0..8 {
+            let pos = Position::new(x, y);
+            let kind = if tiles.contains(&pos) {
+                terrain
+            } else {
+                TerrainKind::Floor
+            };
+            map.insert(pos, StaticTile::new(kind));
+        }
+    }
+    MapOracleImpl::new(MapDimensions::new(8, 8), map)
+}
+
+/// Gives the player a fresh turn at the current clock and moves it east.
+fn step_east(state: &mut GameState, oracles: &OracleBundle) {
+    let clock = state.turn.clock;
+    state.entities.player_mut().ready_at = Some(clock);
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Move,
+        ActionInput::Direction(CardinalDirection::East),
+    ));
+    GameEngine::new(state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap();
+}
+
+fn prepare_turn(state: &mut GameState, oracles: &OracleBundle) -> StateDelta {
+    let action = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+    GameEngine::new(state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap()
+        .delta
+}
+
+/// Player at (2, 2) with Move and Caltrops after scattering caltrops
+/// (radius 1) around a goblin at (5, 2), so the hazard covers x 4..=6.
+fn caltrops_setup() -> (GameState, OracleBundle, EntityId) {
+    let oracles = oracles(MapOracleImpl::test_map(8, 8));
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    let player = state.entities.actor_mut(EntityId::PLAYER).unwrap();
+    player.actions.push(ActionAbility::new(ActionKind::Move));
+    player
+        .actions
+        .push(ActionAbility::new(ActionKind::Caltrops));
+    let goblin = state
+        .add_npc(&template(Faction::GoblinClan), Position::new(5, 2))
+        .unwrap();
+
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Caltrops,
+        ActionInput::Target(goblin),
+    ));
+    let outcome = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap();
+    assert_eq!(
+        outcome.action_result.unwrap().effects[0].applied_value,
+        AppliedValue::HazardCreated { tiles: 9 }
+    );
+
+    (state, oracles, goblin)
+}
+
+#[test]
+fn hazard_hurts_actors_entering_or_starting_their_turn_on_it() {
+    let (mut state, oracles, goblin) = caltrops_setup();
+    let hp = |state: &GameState, id| state.entities.actor(id).unwrap().resources.hp;
+    let player_hp = hp(&state, EntityId::PLAYER);
+    let goblin_hp = hp(&state, goblin);
+
+    // (3, 2) is clear, (4, 2) is covered
+    step_east(&mut state, &oracles);
+    assert_eq!(hp(&state, EntityId::PLAYER), player_hp);
+    step_east(&mut state, &oracles);
+    assert_eq!(hp(&state, EntityId::PLAYER), player_hp - 5);
+
+    // The goblin standing in the caltrops is hurt when its turn starts
+    state.entities.actor_mut(goblin).unwrap().ready_at = Some(0);
+    state.turn.active_actors.insert(goblin);
+    prepare_turn(&mut state, &oracles);
+    assert_eq!(state.turn.current_actor, goblin);
+    assert_eq!(hp(&state, goblin), goblin_hp - 5);
+    assert_eq!(hp(&state, EntityId::PLAYER), player_hp - 5);
+}
+
+#[test]
+fn hazard_decays_once_its_duration_runs_out() {
+    let (mut state, oracles, goblin) = caltrops_setup();
+    let hazard = *state.world.tile_map.hazard(&Position::new(5, 2)).unwrap();
+    assert_eq!(hazard.expires_at, 500);
+    let goblin_hp = state.entities.actor(goblin).unwrap().resources.hp;
+
+    // The goblin's first turn comes exactly when the caltrops expire
+    state.entities.actor_mut(goblin).unwrap().ready_at = Some(500);
+    state.turn.active_actors.insert(goblin);
+    state.entities.actor_mut(EntityId::PLAYER).unwrap().ready_at = Some(1_000);
+    let delta = prepare_turn(&mut state, &oracles);
+
+    assert!(state.world.tile_map.hazards().is_empty());
+    assert_eq!(delta.world.hazards.len(), 9);
+    assert_eq!(
+        state.entities.actor(goblin).unwrap().resources.hp,
+        goblin_hp
+    );
+
+    // Walking over the old caltrops is harmless now
+    state.turn.current_actor = EntityId::PLAYER;
+    let player_hp = state.entities.actor(EntityId::PLAYER).unwrap().resources.hp;
+    step_east(&mut state, &oracles);
+    step_east(&mut state, &oracles);
+    assert_eq!(
+        state.entities.actor(EntityId::PLAYER).unwrap().resources.hp,
+        player_hp
+    );
+}
+
+#[test]
+fn moving_reveals_tiles_in_sight_and_remembers_the_rest() {
+    // A wall down x = 3 with a doorway at (3, 4)
+    let walls: Vec<Position> = (0..8)
+        .filter(|&y| y != 4)
+        .map(|y| Position::new(3, y))
+        .collect();
+    let oracles = oracles(floor_map(TerrainKind::Wall, &walls));
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(1, 4))
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::Move));
+    let (corner, beyond) = (Position::new(0, 0), Position::new(6, 1));
+
+    // West of the wall: the near side is in view, the far side is not
+    step_east(&mut state, &oracles);
+    assert!(state.is_visible(corner));
+    assert!(state.is_visible(Position::new(3, 3)));
+    assert_eq!(state.world.visibility.get(&beyond), Visibility::Unseen);
+
+    // Through the doorway the view flips, but the near side stays seen
+    step_east(&mut state, &oracles);
+    step_east(&mut state, &oracles);
+    assert!(state.is_visible(beyond));
+    assert_eq!(state.world.visibility.get(&corner), Visibility::Seen);
+}
+
+/// Player at (2, 2) with Move, east of which (3, 2) has `terrain`.
+fn terrain_setup(terrain: TerrainKind) -> (GameState, OracleBundle) {
+    let oracles = oracles(floor_map(terrain, &[Position::new(3, 2)]));
+
+    let mut state = GameState::empty();
+    state
+        .add_player(&template(Faction::Player), Position::new(2, 2))
+        .unwrap();
+    state
+        .entities
+        .actor_mut(EntityId::PLAYER)
+        .unwrap()
+        .actions
+        .push(ActionAbility::new(ActionKind::Move));
+
+    (state, oracles)
+}
+
+#[test]
+fn stepping_onto_hazard_terrain_deals_its_damage() {
+    let (mut state, oracles) = terrain_setup(TerrainKind::Spikes);
+    let hp = state.entities.player().resources.hp;
+
+    step_east(&mut state, &oracles);
+    assert_eq!(state.entities.player().position, Some(Position::new(3, 2)));
+    assert_eq!(state.entities.player().resources.hp, hp - 5);
+
+    // Resistances apply: lava deals 20 fire, halved
+    let (mut state, oracles) = terrain_setup(TerrainKind::Lava);
+    let _ = state
+        .entities
+        .player_mut()
+        .passives
+        .try_push(PassiveAbility::new(PassiveKind::FireResistance));
+
+    step_east(&mut state, &oracles);
+    assert_eq!(state.entities.player().resources.hp, hp - 10);
+}
+
+#[test]
+fn stepping_onto_floor_deals_no_damage() {
+    let (mut state, oracles) = terrain_setup(TerrainKind::Floor);
+    let hp = state.entities.player().resources.hp;
+
+    step_east(&mut state, &oracles);
+    assert_eq!(state.entities.player().position, Some(Position::new(3, 2)));
+    assert_eq!(state.entities.player().resources.hp, hp);
+}
+
+#[test]
+fn lethal_hazard_terrain_goes_through_death_handling() {
+    let (mut state, oracles) = terrain_setup(TerrainKind::Spikes);
+    state.entities.player_mut().resources.hp = 3;
+
+    let before = state.clone();
+    let action = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Move,
+        ActionInput::Direction(CardinalDirection::East),
+    ));
+    let outcome = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &action)
+        .unwrap();
+    assert_eq!(state.entities.player().resources.hp, 0);
+
+    let mut provider = SystemActionProvider::new();
+    provider.add_handler(Box::new(DeathHandler));
+    let cleanup: Vec<_> = provider
+        .generate_actions(&outcome.delta, &before, &state, &oracles)
+        .into_iter()
+        .map(|(action, _, _)| action)
+        .collect();
+    assert_eq!(
+        cleanup,
+        vec![
+            Action::system(SystemActionKind::Deactivate(DeactivateAction::new(
+                EntityId::PLAYER
+            ))),
+            Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(EntityId::PLAYER)
+            )),
+        ]
+    );
+}
+
+#[test]
+fn moving_onto_difficult_terrain_costs_more_time() {
+    let advance = |terrain| {
+        let (mut state, oracles) = terrain_setup(terrain);
+        let ready_at = state.entities.player().ready_at.unwrap();
+        step_east(&mut state, &oracles);
+        assert_eq!(state.entities.player().position, Some(Position::new(3, 2)));
+        state.entities.player().ready_at.unwrap() - ready_at
+    };
+
+    let floor = advance(TerrainKind::Floor);
+    assert!(floor > 0);
+    assert_eq!(TerrainKind::Mud.movement_cost_multiplier(), 200);
+    assert_eq!(advance(TerrainKind::Mud), floor * 2);
+}
+
+#[test]
+fn closed_door_blocks_movement_until_opened() {
+    let (mut state, oracles) = terrain_setup(TerrainKind::Floor);
+    state
+        .entities
+        .player_mut()
+        .actions
+        .push(ActionAbility::new(ActionKind::Interact));
+    let door_pos = Position::new(3, 2);
+    let door = state.allocate_entity_id().unwrap();
+    state
+        .entities
+        .props
+        .push(PropState::new(door, door_pos, PropKind::Door, false))
+        .unwrap();
+    state.world.tile_map.add_occupant(door_pos, door);
+    let map = oracles.map.as_ref();
+    assert!(!state.tile_view(map, door_pos).unwrap().is_passable());
+
+    let step = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Move,
+        ActionInput::Direction(CardinalDirection::East),
+    ));
+    let error = GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &step)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ExecuteError::Character(ref e) if e.error == ActionError::Blocked
+    ));
+    assert_eq!(state.entities.player().position, Some(Position::new(2, 2)));
+
+    // Interacting opens the door, and the doorway can be walked through
+    let interact = Action::character(CharacterAction::new(
+        EntityId::PLAYER,
+        ActionKind::Interact,
+        ActionInput::Target(door),
+    ));
+    GameEngine::new(&mut state)
+        .execute(oracles.as_game_env(), &interact)
+        .unwrap();
+    assert!(state.entities.prop(door).unwrap().is_active);
+    assert!(state.tile_view(map, door_pos).unwrap().is_passable());
+
+    step_east(&mut state, &oracles);
+    assert_eq!(state.entities.player().position, Some(door_pos));
+}
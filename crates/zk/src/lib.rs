@@ -59,14 +59,23 @@ pub use sp1_generated::STATE_TRANSITION_ELF;
 // Oracle snapshot for serializable game content
 pub mod oracle;
 pub use oracle::{
-    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleSnapshot,
+    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleDiff,
+    OracleSnapshot,
 };
 
+// Canonical session identifier shared across crates
+pub mod session;
+pub use session::{SESSION_ID_LEN, SessionId, SessionIdError};
+
+// Public values layout shared by all zkVM guests
+pub mod public_values;
+pub use public_values::{PUBLIC_VALUES_LEN, PublicValues};
+
 // Prover module - universal interface and types for all proving backends
 pub mod prover;
 pub use prover::{
-    JournalFields, ProofBackend, ProofData, ProofError, Prover, compute_journal_digest,
-    parse_journal, verify_journal_structure,
+    JournalFields, ProofBackend, ProofData, ProofError, ProofStage, Prover, compute_journal_digest,
+    parse_journal, verify_journal, verify_journal_structure,
 };
 
 #[cfg(feature = "stub")]
@@ -12,7 +12,7 @@
 
 // Re-export snapshots from game-core for convenience
 pub use game_core::{
-    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleSnapshot,
-    SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle, SnapshotItemOracle,
-    SnapshotMapOracle, SnapshotOracleBundle,
+    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleDiff,
+    OracleSnapshot, SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle,
+    SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle,
 };
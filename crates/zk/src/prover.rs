@@ -4,6 +4,8 @@
 
 use game_core::{Action, GameState};
 
+use crate::public_values::PublicValues;
+
 /// ZK proof data container with journal and digest.
 ///
 /// **Stage 1 (On-chain):** Groth16 proof verification
@@ -44,6 +46,41 @@ pub struct ProofData {
     pub journal_digest: [u8; 32],
 }
 
+impl ProofData {
+    /// Verifies this proof with the backend that generated it.
+    ///
+    /// Checks that `journal_digest` matches the journal, then dispatches on
+    /// `backend` to the verifier compiled into this build. Unlike
+    /// [`Prover::verify`], no prover instance (or oracle snapshot) is needed.
+    ///
+    /// # Errors
+    ///
+    /// - `ProofError::InvalidJournal` / `JournalDigestMismatch` for a malformed journal
+    /// - `ProofError::BackendUnavailable` if `backend` is not compiled in
+    /// - Backend-specific errors if cryptographic verification fails
+    pub fn verify(&self) -> Result<bool, ProofError> {
+        verify_journal_structure(&self.journal, Some(&self.journal_digest))?;
+
+        match self.backend {
+            #[cfg(feature = "stub")]
+            ProofBackend::Stub => verify_stub_proof(self),
+
+            #[cfg(feature = "risc0")]
+            ProofBackend::Risc0 => crate::risc0::verify_receipt(self),
+
+            #[cfg(feature = "sp1")]
+            ProofBackend::Sp1 => crate::sp1::verify_proof(self),
+
+            #[cfg(feature = "arkworks")]
+            ProofBackend::Arkworks => Err(ProofError::CircuitProofError(
+                "Arkworks verification not yet implemented".to_string(),
+            )),
+
+            backend => Err(ProofError::BackendUnavailable(backend)),
+        }
+    }
+}
+
 /// Identifies which proving backend generated a proof.
 ///
 /// **IMPORTANT**: This enum must NOT be feature-gated to ensure stable bincode
@@ -57,6 +94,19 @@ pub enum ProofBackend {
     Arkworks,
 }
 
+/// Coarse stage of a proof generation run, reported for progress feedback.
+///
+/// Backends report each stage once, in declaration order, as they enter it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ProofStage {
+    /// Writing the oracle snapshot, start state and actions into the guest input.
+    Witness,
+    /// Running the prover on the guest program (the long stage).
+    Prove,
+    /// Checking the journal and serializing the receipt.
+    Serialize,
+}
+
 /// Errors that can occur during proof generation or verification.
 #[derive(Debug, thiserror::Error)]
 pub enum ProofError {
@@ -89,24 +139,33 @@ pub enum ProofError {
         expected: [u8; 32],
         actual: [u8; 32],
     },
+
+    #[error("Journal field {field} mismatch: expected {expected:?}, got {actual:?}")]
+    JournalFieldMismatch {
+        field: &'static str,
+        expected: Vec<u8>,
+        actual: Vec<u8>,
+    },
+
+    #[error("Nonce {new_nonce} does not follow {prev_nonce} after {action_count} actions")]
+    NonceProgression {
+        prev_nonce: u64,
+        action_count: usize,
+        new_nonce: u64,
+    },
+
+    #[error("Proof backend {0:?} is not compiled into this build")]
+    BackendUnavailable(ProofBackend),
 }
 
 // ============================================================================
 // Journal Helper Functions
 // ============================================================================
 
-/// Journal field parsed from 168-byte journal.
+/// Journal fields parsed from the 168-byte journal.
 ///
-/// Represents the 6 fields committed by the guest program.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct JournalFields {
-    pub oracle_root: [u8; 32],
-    pub seed_commitment: [u8; 32],
-    pub prev_state_root: [u8; 32],
-    pub actions_root: [u8; 32],
-    pub new_state_root: [u8; 32],
-    pub new_nonce: u64,
-}
+/// Same type as [`PublicValues`], named for the RISC0 journal.
+pub type JournalFields = PublicValues;
 
 /// Compute SHA-256 digest of journal bytes.
 ///
@@ -135,21 +194,7 @@ pub fn compute_journal_digest(journal: &[u8]) -> [u8; 32] {
 ///
 /// Returns `ProofError::InvalidJournal` if journal size is not exactly 168 bytes.
 pub fn parse_journal(journal: &[u8]) -> Result<JournalFields, ProofError> {
-    if journal.len() != 168 {
-        return Err(ProofError::InvalidJournal(format!(
-            "Expected 168 bytes, got {}",
-            journal.len()
-        )));
-    }
-
-    Ok(JournalFields {
-        oracle_root: journal[0..32].try_into().unwrap(),
-        seed_commitment: journal[32..64].try_into().unwrap(),
-        prev_state_root: journal[64..96].try_into().unwrap(),
-        actions_root: journal[96..128].try_into().unwrap(),
-        new_state_root: journal[128..160].try_into().unwrap(),
-        new_nonce: u64::from_le_bytes(journal[160..168].try_into().unwrap()),
-    })
+    PublicValues::from_bytes(journal)
 }
 
 /// Verify journal structure and compute digest.
@@ -180,6 +225,67 @@ pub fn verify_journal_structure(
     Ok(fields)
 }
 
+/// Check that a proof's journal commits exactly the expected public values.
+///
+/// Verifies the journal structure and digest, checks that the committed nonce
+/// is `prev_nonce` advanced by `action_count`, then compares the six fields in
+/// journal order. This checks what the proof claims, not the proof itself;
+/// pair it with [`ProofData::verify`] for the cryptographic check.
+///
+/// # Errors
+///
+/// - `ProofError::InvalidJournal` / `JournalDigestMismatch` for a malformed journal
+/// - `ProofError::NonceProgression` if the nonce does not match the batch size
+/// - `ProofError::JournalFieldMismatch` naming the first field that differs
+pub fn verify_journal(
+    proof: &ProofData,
+    expected: &PublicValues,
+    prev_nonce: u64,
+    action_count: usize,
+) -> Result<bool, ProofError> {
+    let actual = verify_journal_structure(&proof.journal, Some(&proof.journal_digest))?;
+    actual.validate_nonce_progression(prev_nonce, action_count)?;
+
+    let fields: [(&'static str, &[u8], &[u8]); 5] = [
+        ("oracle_root", &expected.oracle_root, &actual.oracle_root),
+        (
+            "seed_commitment",
+            &expected.seed_commitment,
+            &actual.seed_commitment,
+        ),
+        (
+            "prev_state_root",
+            &expected.prev_state_root,
+            &actual.prev_state_root,
+        ),
+        ("actions_root", &expected.actions_root, &actual.actions_root),
+        (
+            "new_state_root",
+            &expected.new_state_root,
+            &actual.new_state_root,
+        ),
+    ];
+    for (field, expected, actual) in fields {
+        if expected != actual {
+            return Err(ProofError::JournalFieldMismatch {
+                field,
+                expected: expected.to_vec(),
+                actual: actual.to_vec(),
+            });
+        }
+    }
+
+    if expected.new_nonce != actual.new_nonce {
+        return Err(ProofError::JournalFieldMismatch {
+            field: "new_nonce",
+            expected: expected.new_nonce.to_le_bytes().to_vec(),
+            actual: actual.new_nonce.to_le_bytes().to_vec(),
+        });
+    }
+
+    Ok(true)
+}
+
 /// Universal prover interface for all proving backends.
 ///
 /// All backends (zkVM, circuit, etc.) implement this trait to provide
@@ -204,6 +310,20 @@ pub trait Prover: Send + Sync {
         start_state: &GameState,
         actions: &[Action],
         end_state: &GameState,
+    ) -> Result<ProofData, ProofError> {
+        self.prove_with_progress(start_state, actions, end_state, &mut |_| {})
+    }
+
+    /// Same as [`Prover::prove`], calling `progress` as each [`ProofStage`] begins.
+    ///
+    /// Real backends take seconds to minutes, so callers use the stages to
+    /// give feedback while waiting.
+    fn prove_with_progress(
+        &self,
+        start_state: &GameState,
+        actions: &[Action],
+        end_state: &GameState,
+        progress: &mut dyn FnMut(ProofStage),
     ) -> Result<ProofData, ProofError>;
 
     /// Verify a proof locally (for testing and debugging).
@@ -219,14 +339,15 @@ pub trait Prover: Send + Sync {
 
 /// Stub prover for testing and development.
 ///
-/// Returns dummy proofs without actual proof generation. Use for fast iteration
-/// during development or testing without zkVM infrastructure.
+/// Runs the batch on the host exactly as the guest program does and commits
+/// the same 168-byte journal, but returns dummy proof bytes instead of a real
+/// proof. Use for fast iteration during development or testing without zkVM
+/// infrastructure; the journal can still be checked against live execution.
 ///
 /// **Warning**: Provides no cryptographic guarantees - do not use in production.
 #[cfg(feature = "stub")]
 #[derive(Debug, Clone)]
 pub struct StubProver {
-    #[allow(dead_code)]
     oracle_snapshot: crate::OracleSnapshot,
 }
 
@@ -235,24 +356,109 @@ impl StubProver {
     pub fn new(oracle_snapshot: crate::OracleSnapshot) -> Self {
         Self { oracle_snapshot }
     }
+
+    /// Proves a batch against an explicit oracle and seed commitment.
+    ///
+    /// Executes `actions` on `start` exactly as the guest programs do and
+    /// commits the same six public values. `bytes` holds the 168-byte journal
+    /// itself, so dev-mode consumers that read the proof bytes go through the
+    /// same [`PublicValues`] parser as real backends.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProofError::StateInconsistency` if any action fails, where
+    /// the guest program would panic.
+    pub fn prove_batch(
+        oracle: &crate::OracleSnapshot,
+        seed_commitment: [u8; 32],
+        start: &GameState,
+        actions: &[Action],
+    ) -> Result<ProofData, ProofError> {
+        let journal = execute_batch(oracle, seed_commitment, start, actions)?
+            .to_bytes()
+            .to_vec();
+
+        Ok(ProofData {
+            bytes: journal.clone(),
+            backend: ProofBackend::Stub,
+            journal_digest: compute_journal_digest(&journal),
+            journal,
+        })
+    }
+}
+
+/// Executes `actions` on `start` and returns the values the guest commits.
+///
+/// # Errors
+///
+/// Returns `ProofError::StateInconsistency` if any action fails.
+#[cfg(feature = "stub")]
+fn execute_batch(
+    oracle: &crate::OracleSnapshot,
+    seed_commitment: [u8; 32],
+    start: &GameState,
+    actions: &[Action],
+) -> Result<PublicValues, ProofError> {
+    use game_core::{GameEngine, SnapshotOracleBundle};
+
+    let prev_state_root = start.compute_state_root();
+    let actions_root = game_core::compute_actions_root(actions);
+
+    let mut state = start.clone();
+    let oracles = SnapshotOracleBundle::new(oracle);
+    let env = oracles.as_env();
+    let mut engine = GameEngine::new(&mut state);
+    for (index, action) in actions.iter().enumerate() {
+        engine.execute(env.as_game_env(), action).map_err(|e| {
+            ProofError::StateInconsistency(format!(
+                "Action {}/{} failed: {:?}",
+                index + 1,
+                actions.len(),
+                e
+            ))
+        })?;
+    }
+
+    // Same field order as the guest program
+    Ok(PublicValues {
+        oracle_root: oracle.compute_oracle_root(),
+        seed_commitment,
+        prev_state_root,
+        actions_root,
+        new_state_root: state.compute_state_root(),
+        new_nonce: state.nonce(),
+    })
 }
 
 #[cfg(feature = "stub")]
 impl Prover for StubProver {
-    fn prove(
+    fn prove_with_progress(
         &self,
-        _start_state: &GameState,
+        start_state: &GameState,
         actions: &[Action],
         _end_state: &GameState,
+        progress: &mut dyn FnMut(ProofStage),
     ) -> Result<ProofData, ProofError> {
+        // Same stages as real backends, all instant
+        progress(ProofStage::Witness);
+        progress(ProofStage::Prove);
+        let journal = execute_batch(
+            &self.oracle_snapshot,
+            game_core::compute_seed_commitment(start_state.game_seed),
+            start_state,
+            actions,
+        )?
+        .to_bytes()
+        .to_vec();
+
         // Stub prover: return dummy proof with action count encoded
         let action_count = actions.len() as u32;
         let mut proof_bytes = vec![0x5A, 0x4B]; // "ZK" prefix
         proof_bytes.extend_from_slice(&action_count.to_le_bytes());
         proof_bytes.extend_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
 
-        // Dummy journal (168 bytes of zeros)
-        let journal = vec![0u8; 168];
+        progress(ProofStage::Serialize);
+
         let journal_digest = compute_journal_digest(&journal);
 
         Ok(ProofData {
@@ -264,12 +470,177 @@ impl Prover for StubProver {
     }
 
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
-        if proof.backend != ProofBackend::Stub {
-            return Err(ProofError::ZkvmError(format!(
-                "StubProver can only verify stub proofs, got {:?}",
-                proof.backend
-            )));
+        verify_stub_proof(proof)
+    }
+}
+
+/// Accepts any proof produced by the stub backend.
+#[cfg(feature = "stub")]
+fn verify_stub_proof(proof: &ProofData) -> Result<bool, ProofError> {
+    if proof.backend != ProofBackend::Stub {
+        return Err(ProofError::ZkvmError(format!(
+            "StubProver can only verify stub proofs, got {:?}",
+            proof.backend
+        )));
+    }
+    Ok(true)
+}
+
+#[cfg(all(test, feature = "stub"))]
+mod tests {
+    use super::*;
+
+    fn stub_proof() -> ProofData {
+        let journal = vec![0u8; 168];
+        ProofData {
+            bytes: vec![0x5A, 0x4B, 0, 0, 0, 0, 0xDE, 0xAD, 0xBE, 0xEF],
+            backend: ProofBackend::Stub,
+            journal_digest: compute_journal_digest(&journal),
+            journal,
+        }
+    }
+
+    #[test]
+    fn verifies_stub_proof_without_prover() {
+        assert!(stub_proof().verify().unwrap());
+    }
+
+    #[test]
+    fn rejects_proof_from_missing_backend() {
+        let proof = ProofData {
+            backend: ProofBackend::Risc0,
+            ..stub_proof()
+        };
+
+        assert!(matches!(
+            proof.verify(),
+            Err(ProofError::BackendUnavailable(ProofBackend::Risc0))
+        ));
+    }
+
+    fn public_values() -> PublicValues {
+        PublicValues {
+            oracle_root: [1; 32],
+            seed_commitment: [2; 32],
+            prev_state_root: [3; 32],
+            actions_root: [4; 32],
+            new_state_root: [5; 32],
+            new_nonce: 42,
+        }
+    }
+
+    fn proof_committing(values: &PublicValues) -> ProofData {
+        let journal = values.to_bytes().to_vec();
+        ProofData {
+            journal_digest: compute_journal_digest(&journal),
+            journal,
+            ..stub_proof()
+        }
+    }
+
+    #[test]
+    fn journal_parses_back_to_committed_values() {
+        let values = public_values();
+        let proof = proof_committing(&values);
+
+        assert_eq!(parse_journal(&proof.journal).unwrap(), values);
+        assert!(verify_journal(&proof, &values, 40, 2).unwrap());
+    }
+
+    #[test]
+    fn each_field_mismatch_is_named() {
+        let proof = proof_committing(&public_values());
+        type Tamper = fn(&mut PublicValues);
+        let tampered: [(&str, Tamper); 6] = [
+            ("oracle_root", |v| v.oracle_root[0] ^= 1),
+            ("seed_commitment", |v| v.seed_commitment[31] ^= 1),
+            ("prev_state_root", |v| v.prev_state_root[7] ^= 1),
+            ("actions_root", |v| v.actions_root[0] ^= 1),
+            ("new_state_root", |v| v.new_state_root[16] ^= 1),
+            ("new_nonce", |v| v.new_nonce += 1),
+        ];
+
+        for (name, tamper) in tampered {
+            let mut expected = public_values();
+            tamper(&mut expected);
+
+            match verify_journal(&proof, &expected, 40, 2) {
+                Err(ProofError::JournalFieldMismatch { field, .. }) => assert_eq!(field, name),
+                other => panic!("{name}: expected a field mismatch, got {other:?}"),
+            }
         }
-        Ok(true)
+    }
+
+    #[test]
+    fn progression_check_rejects_a_stalled_nonce() {
+        let values = public_values();
+        let proof = proof_committing(&values);
+
+        assert!(verify_journal(&proof, &values, 40, 2).unwrap());
+        assert!(matches!(
+            verify_journal(&proof, &values, 41, 2),
+            Err(ProofError::NonceProgression { .. })
+        ));
+    }
+
+    #[test]
+    fn batch_proof_commits_the_guest_public_values() {
+        use game_core::{
+            ActionSnapshot, ActorsSnapshot, ConfigSnapshot, GameConfig, GameEngine, ItemsSnapshot,
+            MapDimensions, MapSnapshot, OracleSnapshot, PrepareTurnAction, SnapshotOracleBundle,
+            StaticTile, SystemActionKind, TerrainKind,
+        };
+
+        let oracle = OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(1, 1),
+                vec![Some(StaticTile::new(TerrainKind::Floor))],
+            ),
+            ItemsSnapshot::empty(),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new(Default::default()),
+            ConfigSnapshot::new(GameConfig::default()),
+        );
+        let start = GameState::with_player();
+        let actions = vec![
+            Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+            Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+        ];
+        let seed = game_core::compute_seed_commitment(7);
+
+        let proof = StubProver::prove_batch(&oracle, seed, &start, &actions).unwrap();
+        assert_eq!(proof.bytes, proof.journal);
+
+        // Recompute the end state independently of the prover
+        let mut end = start.clone();
+        let oracles = SnapshotOracleBundle::new(&oracle);
+        let env = oracles.as_env();
+        for action in &actions {
+            GameEngine::new(&mut end)
+                .execute(env.as_game_env(), action)
+                .unwrap();
+        }
+        let expected = PublicValues {
+            oracle_root: oracle.compute_oracle_root(),
+            seed_commitment: seed,
+            prev_state_root: start.compute_state_root(),
+            actions_root: game_core::compute_actions_root(&actions),
+            new_state_root: end.compute_state_root(),
+            new_nonce: end.nonce(),
+        };
+
+        assert_eq!(PublicValues::from_bytes(&proof.bytes).unwrap(), expected);
+        assert!(verify_journal(&proof, &expected, start.nonce(), actions.len()).unwrap());
+    }
+
+    #[test]
+    fn rejects_tampered_journal() {
+        let mut proof = stub_proof();
+        proof.journal[0] = 1;
+
+        assert!(matches!(
+            proof.verify(),
+            Err(ProofError::JournalDigestMismatch { .. })
+        ));
     }
 }
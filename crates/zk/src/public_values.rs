@@ -0,0 +1,190 @@
+//! Public values committed by the zkVM guest programs.
+//!
+//! The RISC0 guest commits these as its journal and the SP1 guest as its
+//! public values. Both write the same six fields into one fixed buffer, and
+//! every host-side reader (provers, runtime, Sui client) goes through
+//! [`PublicValues`] so the layout is defined in one place.
+//!
+//! # Layout (168 bytes)
+//!
+//! ```text
+//! Offset   Size  Field
+//! -------  ----  -----
+//! 0..32    32    oracle_root
+//! 32..64   32    seed_commitment
+//! 64..96   32    prev_state_root
+//! 96..128  32    actions_root
+//! 128..160 32    new_state_root
+//! 160..168 8     new_nonce (u64 little-endian)
+//! ```
+
+use crate::prover::ProofError;
+
+/// Number of bytes in the committed public values.
+pub const PUBLIC_VALUES_LEN: usize = 168;
+
+/// Field names and byte ranges in commit order, for error messages.
+const FIELDS: [(&str, usize, usize); 6] = [
+    ("oracle_root", 0, 32),
+    ("seed_commitment", 32, 64),
+    ("prev_state_root", 64, 96),
+    ("actions_root", 96, 128),
+    ("new_state_root", 128, 160),
+    ("new_nonce", 160, 168),
+];
+
+/// The six fields committed by the guest program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicValues {
+    pub oracle_root: [u8; 32],
+    pub seed_commitment: [u8; 32],
+    pub prev_state_root: [u8; 32],
+    pub actions_root: [u8; 32],
+    pub new_state_root: [u8; 32],
+    pub new_nonce: u64,
+}
+
+impl PublicValues {
+    /// Parses the committed bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProofError::InvalidJournal` unless `bytes` is exactly
+    /// [`PUBLIC_VALUES_LEN`] long. The message names the offset (and field)
+    /// where a short buffer runs out, or where trailing bytes start.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofError> {
+        if let Some((field, start, end)) = FIELDS.iter().find(|(_, _, end)| bytes.len() < *end) {
+            return Err(ProofError::InvalidJournal(format!(
+                "public values end at offset {}, inside {field} ({start}..{end})",
+                bytes.len()
+            )));
+        }
+        if bytes.len() > PUBLIC_VALUES_LEN {
+            return Err(ProofError::InvalidJournal(format!(
+                "public values have {} trailing bytes at offset {PUBLIC_VALUES_LEN}",
+                bytes.len() - PUBLIC_VALUES_LEN
+            )));
+        }
+
+        Ok(Self {
+            oracle_root: bytes[0..32].try_into().unwrap(),
+            seed_commitment: bytes[32..64].try_into().unwrap(),
+            prev_state_root: bytes[64..96].try_into().unwrap(),
+            actions_root: bytes[96..128].try_into().unwrap(),
+            new_state_root: bytes[128..160].try_into().unwrap(),
+            new_nonce: u64::from_le_bytes(bytes[160..168].try_into().unwrap()),
+        })
+    }
+
+    /// Checks that `new_nonce` is `prev_nonce` advanced once per action.
+    ///
+    /// The guests execute a batch sequentially and every executed action
+    /// bumps the nonce, so any other value means the committed state and the
+    /// action counter disagree.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProofError::NonceProgression` if the nonce is off, including
+    /// when `prev_nonce + action_count` would overflow.
+    pub fn validate_nonce_progression(
+        &self,
+        prev_nonce: u64,
+        action_count: usize,
+    ) -> Result<(), ProofError> {
+        let expected = u64::try_from(action_count)
+            .ok()
+            .and_then(|count| prev_nonce.checked_add(count));
+        if expected != Some(self.new_nonce) {
+            return Err(ProofError::NonceProgression {
+                prev_nonce,
+                action_count,
+                new_nonce: self.new_nonce,
+            });
+        }
+        Ok(())
+    }
+
+    /// Serializes in the same order and encoding as the guest programs.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_VALUES_LEN] {
+        let mut bytes = [0u8; PUBLIC_VALUES_LEN];
+        bytes[0..32].copy_from_slice(&self.oracle_root);
+        bytes[32..64].copy_from_slice(&self.seed_commitment);
+        bytes[64..96].copy_from_slice(&self.prev_state_root);
+        bytes[96..128].copy_from_slice(&self.actions_root);
+        bytes[128..160].copy_from_slice(&self.new_state_root);
+        bytes[160..168].copy_from_slice(&self.new_nonce.to_le_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> PublicValues {
+        PublicValues {
+            oracle_root: [1; 32],
+            seed_commitment: [2; 32],
+            prev_state_root: [3; 32],
+            actions_root: [4; 32],
+            new_state_root: [5; 32],
+            new_nonce: 0x0102_0304_0506_0708,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_guest_layout() {
+        let bytes = values().to_bytes();
+
+        assert_eq!(&bytes[96..128], &[4; 32]);
+        assert_eq!(&bytes[160..168], &[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(PublicValues::from_bytes(&bytes).unwrap(), values());
+    }
+
+    #[test]
+    fn nonce_must_advance_once_per_action() {
+        let values = PublicValues {
+            new_nonce: 10,
+            ..values()
+        };
+
+        assert!(values.validate_nonce_progression(7, 3).is_ok());
+        assert!(values.validate_nonce_progression(10, 0).is_ok());
+        assert!(matches!(
+            values.validate_nonce_progression(7, 2),
+            Err(ProofError::NonceProgression { new_nonce: 10, .. })
+        ));
+        assert!(values.validate_nonce_progression(7, 4).is_err());
+    }
+
+    #[test]
+    fn nonce_overflow_is_rejected() {
+        let values = PublicValues {
+            new_nonce: 1,
+            ..values()
+        };
+
+        // u64::MAX + 2 wraps to 1, which must not pass
+        assert!(values.validate_nonce_progression(u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn rejects_anything_but_168_bytes() {
+        let bytes = values().to_bytes();
+
+        let short = PublicValues::from_bytes(&bytes[..167]).unwrap_err();
+        assert!(
+            matches!(&short, ProofError::InvalidJournal(msg)
+                if msg.contains("offset 167") && msg.contains("new_nonce")),
+            "{short}"
+        );
+
+        let mut long = bytes.to_vec();
+        long.push(0);
+        let long = PublicValues::from_bytes(&long).unwrap_err();
+        assert!(
+            matches!(&long, ProofError::InvalidJournal(msg) if msg.contains("offset 168")),
+            "{long}"
+        );
+    }
+}
@@ -8,3 +8,4 @@
 
 mod prover;
 pub use prover::Risc0Prover;
+pub(crate) use prover::verify_receipt;
@@ -45,9 +45,8 @@
 //! This catches non-determinism bugs and oracle mismatches before on-chain submission.
 
 use risc0_zkvm::{ExecutorEnv, Receipt, default_prover};
-use sha2::{Digest, Sha256};
 
-use crate::prover::{ProofBackend, ProofData, ProofError};
+use crate::prover::{ProofBackend, ProofData, ProofError, ProofStage};
 use crate::{OracleSnapshot, Prover, compute_journal_digest, parse_journal};
 use game_core::{Action, GameState};
 
@@ -70,12 +69,10 @@ impl Risc0Prover {
 
     /// Computes seed commitment from GameState's game_seed.
     ///
-    /// The seed commitment is SHA-256(game_seed), providing a cryptographic
-    /// commitment to the RNG seed without revealing it.
+    /// See [`game_core::compute_seed_commitment`] for the exact preimage. It
+    /// commits to the RNG seed without revealing it.
     fn compute_seed_commitment(state: &GameState) -> [u8; 32] {
-        let mut hasher = Sha256::new();
-        hasher.update(state.game_seed.to_le_bytes());
-        hasher.finalize().into()
+        game_core::compute_seed_commitment(state.game_seed)
     }
 
     /// Verifies journal fields match expected values.
@@ -166,12 +163,15 @@ impl Risc0Prover {
 }
 
 impl Prover for Risc0Prover {
-    fn prove(
+    fn prove_with_progress(
         &self,
         start_state: &GameState,
         actions: &[Action],
         expected_end_state: &GameState,
+        progress: &mut dyn FnMut(ProofStage),
     ) -> Result<ProofData, ProofError> {
+        progress(ProofStage::Witness);
+
         // Compute seed commitment from game state
         let seed_commitment = Self::compute_seed_commitment(start_state);
 
@@ -194,6 +194,7 @@ impl Prover for Risc0Prover {
             .map_err(|e| ProofError::ZkvmError(format!("Failed to build ExecutorEnv: {}", e)))?;
 
         // Generate proof using state transition guest
+        progress(ProofStage::Prove);
         let prover = default_prover();
         let prove_info = prover
             .prove(env, STATE_TRANSITION_ELF)
@@ -201,6 +202,8 @@ impl Prover for Risc0Prover {
 
         let receipt = prove_info.receipt;
 
+        progress(ProofStage::Serialize);
+
         // Extract 168-byte journal from receipt
         let journal = receipt.journal.bytes.clone();
 
@@ -223,23 +226,31 @@ impl Prover for Risc0Prover {
     }
 
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
-        // Verify backend matches
-        if proof.backend != ProofBackend::Risc0 {
-            return Err(ProofError::ZkvmError(format!(
-                "Expected Risc0 backend, got {:?}",
-                proof.backend
-            )));
-        }
+        verify_receipt(proof)
+    }
+}
 
-        // Deserialize receipt
-        let receipt: Receipt = bincode::deserialize(&proof.bytes)
-            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+/// Verifies a RISC0 receipt against the state transition ImageID.
+///
+/// Needs no oracle snapshot, so it backs both [`Risc0Prover`] and
+/// [`ProofData::verify`].
+pub(crate) fn verify_receipt(proof: &ProofData) -> Result<bool, ProofError> {
+    // Verify backend matches
+    if proof.backend != ProofBackend::Risc0 {
+        return Err(ProofError::ZkvmError(format!(
+            "Expected Risc0 backend, got {:?}",
+            proof.backend
+        )));
+    }
 
-        // Verify against state transition guest program ImageID
-        receipt
-            .verify(STATE_TRANSITION_ID)
-            .map_err(|e| ProofError::ZkvmError(format!("Proof verification failed: {:?}", e)))?;
+    // Deserialize receipt
+    let receipt: Receipt = bincode::deserialize(&proof.bytes)
+        .map_err(|e| ProofError::SerializationError(e.to_string()))?;
 
-        Ok(true)
-    }
+    // Verify against state transition guest program ImageID
+    receipt
+        .verify(STATE_TRANSITION_ID)
+        .map_err(|e| ProofError::ZkvmError(format!("Proof verification failed: {:?}", e)))?;
+
+    Ok(true)
 }
@@ -0,0 +1,179 @@
+//! Canonical session identifier shared by the runtime and blockchain clients.
+//!
+//! A proven session is bound to exactly one on-chain session object, so every
+//! crate that names a session for proving or submission must agree on its
+//! format. [`SessionId`] is that format: the 32-byte object ID, rendered as
+//! `0x` followed by 64 lowercase hex digits.
+//!
+//! Other crates keep their own representations (plain strings, SDK types) and
+//! convert through `From`/`TryFrom`, so malformed ids are rejected at the
+//! boundary instead of being submitted on-chain.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Number of bytes in a session identifier.
+pub const SESSION_ID_LEN: usize = 32;
+
+/// Canonical 32-byte session identifier (the on-chain session object ID).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionId([u8; SESSION_ID_LEN]);
+
+/// Errors raised when parsing a [`SessionId`] from text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SessionIdError {
+    #[error("session id must start with 0x: {0:?}")]
+    MissingPrefix(String),
+
+    #[error("session id must have 1 to 64 hex digits, found {0}")]
+    InvalidLength(usize),
+
+    #[error("session id contains non-hex characters: {0:?}")]
+    InvalidHex(String),
+}
+
+impl SessionId {
+    /// Creates a session ID from raw bytes.
+    pub const fn new(bytes: [u8; SESSION_ID_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; SESSION_ID_LEN] {
+        &self.0
+    }
+}
+
+impl From<[u8; SESSION_ID_LEN]> for SessionId {
+    fn from(bytes: [u8; SESSION_ID_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<SessionId> for [u8; SESSION_ID_LEN] {
+    fn from(id: SessionId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for SessionId {
+    /// Formats as `0x` followed by 64 lowercase hex digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SessionId {
+    type Err = SessionIdError;
+
+    /// Parses a `0x`-prefixed hex object ID.
+    ///
+    /// Short forms such as `0x2` are accepted and left-padded with zeros, as
+    /// Sui does for object IDs. Upper- and lowercase digits are both accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .strip_prefix("0x")
+            .ok_or_else(|| SessionIdError::MissingPrefix(s.to_string()))?;
+
+        if digits.is_empty() || digits.len() > SESSION_ID_LEN * 2 {
+            return Err(SessionIdError::InvalidLength(digits.len()));
+        }
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(SessionIdError::InvalidHex(s.to_string()));
+        }
+
+        let padded = format!("{:0>width$}", digits, width = SESSION_ID_LEN * 2);
+        let mut bytes = [0u8; SESSION_ID_LEN];
+        for (byte, pair) in bytes.iter_mut().zip(padded.as_bytes().chunks(2)) {
+            // Both characters were checked to be ASCII hex digits above
+            let pair = std::str::from_utf8(pair).expect("hex digits are ASCII");
+            *byte = u8::from_str_radix(pair, 16).expect("validated hex digits");
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+impl TryFrom<&str> for SessionId {
+    type Error = SessionIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for SessionId {
+    type Error = SessionIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Serialize for SessionId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANONICAL: &str = "0x00000000000000000000000000000000000000000000000000000000000000ab";
+
+    #[test]
+    fn round_trips_through_text_bytes_and_json() {
+        let id: SessionId = CANONICAL.parse().unwrap();
+        assert_eq!(id.as_bytes()[31], 0xab);
+        assert_eq!(id.to_string(), CANONICAL);
+
+        let bytes: [u8; SESSION_ID_LEN] = id.into();
+        assert_eq!(SessionId::from(bytes), id);
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", CANONICAL));
+        assert_eq!(serde_json::from_str::<SessionId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn short_and_uppercase_forms_normalize() {
+        assert_eq!(SessionId::try_from("0xAB").unwrap().to_string(), CANONICAL);
+    }
+
+    #[test]
+    fn rejects_malformed_ids() {
+        assert_eq!(
+            SessionId::try_from("session_1700000000"),
+            Err(SessionIdError::MissingPrefix(
+                "session_1700000000".to_string()
+            ))
+        );
+        assert_eq!(
+            SessionId::try_from("0x"),
+            Err(SessionIdError::InvalidLength(0))
+        );
+        assert_eq!(
+            SessionId::try_from(format!("0x{}", "1".repeat(65))),
+            Err(SessionIdError::InvalidLength(65))
+        );
+        assert!(matches!(
+            SessionId::try_from("0xnothex"),
+            Err(SessionIdError::InvalidHex(_))
+        ));
+        assert!(serde_json::from_str::<SessionId>("\"0xzz\"").is_err());
+    }
+}
@@ -4,3 +4,4 @@
 
 mod prover;
 pub use prover::Sp1Prover;
+pub(crate) use prover::verify_proof;
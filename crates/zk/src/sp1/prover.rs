@@ -2,10 +2,9 @@
 //!
 //! Generates zero-knowledge proofs of game action execution using SP1's zkVM.
 
-use sha2::{Digest, Sha256};
 use sp1_sdk::{EnvProver, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey};
 
-use crate::prover::{ProofBackend, ProofData, ProofError, Prover};
+use crate::prover::{ProofBackend, ProofData, ProofError, ProofStage, Prover};
 use crate::{OracleSnapshot, compute_journal_digest, parse_journal};
 use game_core::{Action, GameState};
 
@@ -79,12 +78,10 @@ impl Sp1Prover {
 
     /// Computes seed commitment from GameState's game_seed.
     ///
-    /// The seed commitment is SHA-256(game_seed), providing a cryptographic
-    /// commitment to the RNG seed without revealing it.
+    /// See [`game_core::compute_seed_commitment`] for the exact preimage. It
+    /// commits to the RNG seed without revealing it.
     pub fn compute_seed_commitment(state: &GameState) -> [u8; 32] {
-        let mut hasher = Sha256::new();
-        hasher.update(state.game_seed.to_le_bytes());
-        hasher.finalize().into()
+        game_core::compute_seed_commitment(state.game_seed)
     }
 
     /// Verifies public values fields match expected values (exposed for groth16).
@@ -167,12 +164,15 @@ impl Sp1Prover {
 }
 
 impl Prover for Sp1Prover {
-    fn prove(
+    fn prove_with_progress(
         &self,
         start_state: &GameState,
         actions: &[Action],
         expected_end_state: &GameState,
+        progress: &mut dyn FnMut(ProofStage),
     ) -> Result<ProofData, ProofError> {
+        progress(ProofStage::Witness);
+
         // Compute seed commitment from game state
         let seed_commitment = Self::compute_seed_commitment(start_state);
 
@@ -193,6 +193,7 @@ impl Prover for Sp1Prover {
         stdin.write(&actions.to_vec());
 
         // Generate proof using state transition guest
+        progress(ProofStage::Prove);
         let proof_mode =
             std::env::var("SP1_PROOF_MODE").unwrap_or_else(|_| "compressed".to_string());
 
@@ -254,6 +255,8 @@ impl Prover for Sp1Prover {
             }
         };
 
+        progress(ProofStage::Serialize);
+
         // Extract 168-byte public values from proof
         let journal = proof.public_values.to_vec();
 
@@ -276,24 +279,42 @@ impl Prover for Sp1Prover {
     }
 
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
-        // Verify backend matches
-        if proof.backend != ProofBackend::Sp1 {
-            return Err(ProofError::ZkvmError(format!(
-                "Expected SP1 backend, got {:?}",
-                proof.backend
-            )));
-        }
-
-        // Deserialize proof
-        let sp1_proof: SP1ProofWithPublicValues = bincode::deserialize(&proof.bytes)
-            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
-
-        // Verify proof against state transition guest program
         // Reuse the cached ProverClient from initialization for performance
-        self.client.verify(&sp1_proof, &self.vk).map_err(|e| {
-            ProofError::ZkvmError(format!("SP1 proof verification failed: {:?}", e))
-        })?;
+        verify_with(&self.client, &self.vk, proof)
+    }
+}
+
+/// Verifies an SP1 proof without a prover instance.
+///
+/// Builds a fresh client and verifying key from the environment, which is
+/// slow; prefer [`Sp1Prover`] when verifying many proofs.
+pub(crate) fn verify_proof(proof: &ProofData) -> Result<bool, ProofError> {
+    let client = sp1_sdk::ProverClient::from_env();
+    let (_, vk) = client.setup(STATE_TRANSITION_ELF);
+    verify_with(&client, &vk, proof)
+}
 
-        Ok(true)
+fn verify_with(
+    client: &EnvProver,
+    vk: &SP1VerifyingKey,
+    proof: &ProofData,
+) -> Result<bool, ProofError> {
+    // Verify backend matches
+    if proof.backend != ProofBackend::Sp1 {
+        return Err(ProofError::ZkvmError(format!(
+            "Expected SP1 backend, got {:?}",
+            proof.backend
+        )));
     }
+
+    // Deserialize proof
+    let sp1_proof: SP1ProofWithPublicValues = bincode::deserialize(&proof.bytes)
+        .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+    // Verify proof against state transition guest program
+    client
+        .verify(&sp1_proof, vk)
+        .map_err(|e| ProofError::ZkvmError(format!("SP1 proof verification failed: {:?}", e)))?;
+
+    Ok(true)
 }